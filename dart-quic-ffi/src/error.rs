@@ -23,6 +23,8 @@ pub struct QuicError {
     code: QuicResult,
     /// Detailed error message (only used for unknown error types)
     message: Option<String>,
+    /// Application error code sent by the peer (stream reset / stop sending)
+    app_error_code: Option<u64>,
 }
 
 impl QuicError {
@@ -31,6 +33,7 @@ impl QuicError {
         Self {
             code,
             message: None,
+            app_error_code: None,
         }
     }
 
@@ -39,6 +42,25 @@ impl QuicError {
         Self {
             code: QuicResult::UnknownError,
             message: Some(message.into()),
+            app_error_code: None,
+        }
+    }
+
+    /// Create from error code with a detail message (e.g. the host that failed to resolve)
+    pub fn with_message(code: QuicResult, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: Some(message.into()),
+            app_error_code: None,
+        }
+    }
+
+    /// Create from error code with the peer's application error code
+    pub fn with_app_error_code(code: QuicResult, app_error_code: u64) -> Self {
+        Self {
+            code,
+            message: None,
+            app_error_code: Some(app_error_code),
         }
     }
 
@@ -57,6 +79,11 @@ impl QuicError {
         self.message.as_deref()
     }
 
+    /// Get the peer's application error code (only for `StreamReset` / `StreamStopped`)
+    pub fn app_error_code(&self) -> Option<u64> {
+        self.app_error_code
+    }
+
     /// Check if successful (not a true error)
     pub fn is_success(&self) -> bool {
         matches!(self.code, QuicResult::Success)
@@ -70,6 +97,9 @@ impl fmt::Debug for QuicError {
         if let Some(msg) = &self.message {
             d.field("message", msg);
         }
+        if let Some(code) = self.app_error_code {
+            d.field("app_error_code", &code);
+        }
         d.finish()
     }
 }
@@ -77,9 +107,13 @@ impl fmt::Debug for QuicError {
 impl fmt::Display for QuicError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self.message {
-            Some(msg) => write!(f, "[{:?}] {}", self.code, msg),
-            None => write!(f, "[{:?}]", self.code),
+            Some(msg) => write!(f, "[{:?}] {}", self.code, msg)?,
+            None => write!(f, "[{:?}]", self.code)?,
+        }
+        if let Some(code) = self.app_error_code {
+            write!(f, " (application error code {})", code)?;
         }
+        Ok(())
     }
 }
 
@@ -95,6 +129,7 @@ impl From<std::io::Error> for QuicError {
         Self {
             code: QuicResult::IoError,
             message: Some(err.to_string()),
+            app_error_code: None,
         }
     }
 }
@@ -132,7 +167,9 @@ impl From<quinn::ConnectError> for QuicError {
 impl From<quinn::WriteError> for QuicError {
     fn from(err: quinn::WriteError) -> Self {
         let code = match &err {
-            quinn::WriteError::Stopped(_) => QuicResult::StreamStopped,
+            quinn::WriteError::Stopped(code) => {
+                return Self::with_app_error_code(QuicResult::StreamStopped, code.into_inner());
+            }
             quinn::WriteError::ConnectionLost(_) => QuicResult::ConnectionLost,
             quinn::WriteError::ClosedStream => QuicResult::StreamClosed,
             quinn::WriteError::ZeroRttRejected => QuicResult::ZeroRttRejected,
@@ -144,7 +181,9 @@ impl From<quinn::WriteError> for QuicError {
 impl From<quinn::ReadError> for QuicError {
     fn from(err: quinn::ReadError) -> Self {
         let code = match &err {
-            quinn::ReadError::Reset(_) => QuicResult::StreamReset,
+            quinn::ReadError::Reset(code) => {
+                return Self::with_app_error_code(QuicResult::StreamReset, code.into_inner());
+            }
             quinn::ReadError::ConnectionLost(_) => QuicResult::ConnectionLost,
             quinn::ReadError::ClosedStream => QuicResult::StreamClosed,
             quinn::ReadError::IllegalOrderedRead => QuicResult::InvalidOperation,
@@ -154,6 +193,16 @@ impl From<quinn::ReadError> for QuicError {
     }
 }
 
+impl From<quinn::StoppedError> for QuicError {
+    fn from(err: quinn::StoppedError) -> Self {
+        let code = match &err {
+            quinn::StoppedError::ConnectionLost(_) => QuicResult::ConnectionLost,
+            quinn::StoppedError::ZeroRttRejected => QuicResult::ZeroRttRejected,
+        };
+        Self::from_code(code)
+    }
+}
+
 impl From<quinn::ReadToEndError> for QuicError {
     fn from(err: quinn::ReadToEndError) -> Self {
         match err {
@@ -163,6 +212,15 @@ impl From<quinn::ReadToEndError> for QuicError {
     }
 }
 
+impl From<quinn::ReadExactError> for QuicError {
+    fn from(err: quinn::ReadExactError) -> Self {
+        match err {
+            quinn::ReadExactError::ReadError(e) => e.into(),
+            quinn::ReadExactError::FinishedEarly(_) => Self::from_code(QuicResult::NoMoreData),
+        }
+    }
+}
+
 impl From<quinn::SendDatagramError> for QuicError {
     fn from(err: quinn::SendDatagramError) -> Self {
         let code = match &err {
@@ -0,0 +1,309 @@
+//! Generational handle registry
+//!
+//! Clients, servers, endpoints, connections and streams handed to Dart live in a global
+//! table and are referred to by `u64` handles instead of raw pointers:
+//!
+//! `[type tag: 8 bits][generation: 24 bits][slot index: 32 bits]`
+//!
+//! Freeing a handle empties its slot and bumps the slot generation, so stale,
+//! double-freed or wrongly typed handles fail the lookup (`InvalidParameter`) instead
+//! of dereferencing freed memory. Lookups return an `Arc`, so an async operation keeps
+//! its object alive even if the handle is freed while the operation is in flight.
+//! Handle 0 is never valid.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+
+use crate::quic::{QuicClient, QuicConnection, QuicEndpoint, QuicServer};
+use crate::quic_ffi_stream_result::{FfiRecvStream, FfiSendStream};
+
+/// Type tag stored in the top byte of every handle
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuicHandleType {
+    Client = 1,
+    Server = 2,
+    Endpoint = 3,
+    Connection = 4,
+    SendStream = 5,
+    RecvStream = 6,
+}
+
+impl QuicHandleType {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::Client),
+            2 => Some(Self::Server),
+            3 => Some(Self::Endpoint),
+            4 => Some(Self::Connection),
+            5 => Some(Self::SendStream),
+            6 => Some(Self::RecvStream),
+            _ => None,
+        }
+    }
+}
+
+/// Object that can be stored in the registry under a fixed type tag
+pub trait QuicHandleObject: Any + Send + Sync {
+    const HANDLE_TYPE: QuicHandleType;
+}
+
+impl QuicHandleObject for QuicClient {
+    const HANDLE_TYPE: QuicHandleType = QuicHandleType::Client;
+}
+
+impl QuicHandleObject for QuicServer {
+    const HANDLE_TYPE: QuicHandleType = QuicHandleType::Server;
+}
+
+impl QuicHandleObject for QuicEndpoint {
+    const HANDLE_TYPE: QuicHandleType = QuicHandleType::Endpoint;
+}
+
+impl QuicHandleObject for QuicConnection {
+    const HANDLE_TYPE: QuicHandleType = QuicHandleType::Connection;
+}
+
+impl QuicHandleObject for FfiSendStream {
+    const HANDLE_TYPE: QuicHandleType = QuicHandleType::SendStream;
+}
+
+impl QuicHandleObject for FfiRecvStream {
+    const HANDLE_TYPE: QuicHandleType = QuicHandleType::RecvStream;
+}
+
+// ============================================
+// Handle Encoding
+// ============================================
+
+const GENERATION_BITS: u32 = 24;
+const GENERATION_MASK: u32 = (1 << GENERATION_BITS) - 1;
+const GENERATION_SHIFT: u32 = 32;
+const TYPE_SHIFT: u32 = GENERATION_SHIFT + GENERATION_BITS;
+
+fn encode(handle_type: QuicHandleType, generation: u32, index: u32) -> u64 {
+    ((handle_type as u64) << TYPE_SHIFT) | ((generation as u64) << GENERATION_SHIFT) | index as u64
+}
+
+/// Split a handle into (type tag, generation, slot index)
+fn decode(handle: u64) -> (u8, u32, u32) {
+    (
+        (handle >> TYPE_SHIFT) as u8,
+        (handle >> GENERATION_SHIFT) as u32 & GENERATION_MASK,
+        handle as u32,
+    )
+}
+
+/// Type tag encoded in `handle`, without checking that the handle is live
+pub fn handle_type(handle: u64) -> Option<QuicHandleType> {
+    QuicHandleType::from_u8(decode(handle).0)
+}
+
+// ============================================
+// Handle Table
+// ============================================
+
+struct Slot {
+    /// Current generation; starts at 1 so no live handle encodes to 0
+    generation: u32,
+    handle_type: u8,
+    object: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+struct HandleTable {
+    slots: Vec<Slot>,
+    free: Vec<u32>,
+}
+
+/// Generation following `generation`, skipping 0 on wrap-around so handles never encode to 0
+fn next_generation(generation: u32) -> u32 {
+    match (generation + 1) & GENERATION_MASK {
+        0 => 1,
+        generation => generation,
+    }
+}
+
+impl HandleTable {
+    const fn new() -> Self {
+        Self { slots: Vec::new(), free: Vec::new() }
+    }
+
+    fn insert(&mut self, handle_type: QuicHandleType, object: Arc<dyn Any + Send + Sync>) -> u64 {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(Slot { generation: 1, handle_type: 0, object: None });
+                (self.slots.len() - 1) as u32
+            }
+        };
+
+        let slot = &mut self.slots[index as usize];
+        slot.handle_type = handle_type as u8;
+        slot.object = Some(object);
+        encode(handle_type, slot.generation, index)
+    }
+
+    fn get(&mut self, handle: u64, handle_type: QuicHandleType) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.slot_mut(handle, handle_type)?.object.clone()
+    }
+
+    /// Empty the slot of a live handle and bump its generation
+    fn remove(&mut self, handle: u64, handle_type: QuicHandleType) -> Option<Arc<dyn Any + Send + Sync>> {
+        let slot = self.slot_mut(handle, handle_type)?;
+        let object = slot.object.take()?;
+        slot.generation = next_generation(slot.generation);
+        self.free.push(decode(handle).2);
+        Some(object)
+    }
+
+    /// Slot for a live handle whose generation and type tag match
+    fn slot_mut(&mut self, handle: u64, handle_type: QuicHandleType) -> Option<&mut Slot> {
+        let (tag, generation, index) = decode(handle);
+        if tag != handle_type as u8 {
+            return None;
+        }
+        self.slots
+            .get_mut(index as usize)
+            .filter(|slot| slot.object.is_some() && slot.generation == generation && slot.handle_type == tag)
+    }
+}
+
+static HANDLES: Mutex<HandleTable> = Mutex::new(HandleTable::new());
+
+/// Store `object` and return its handle
+pub fn insert<T: QuicHandleObject>(object: T) -> u64 {
+    insert_arc(Arc::new(object))
+}
+
+/// Store a shared object and return its handle
+pub fn insert_arc<T: QuicHandleObject>(object: Arc<T>) -> u64 {
+    HANDLES.lock().insert(T::HANDLE_TYPE, object)
+}
+
+/// Look up a live handle of type `T`
+pub fn get<T: QuicHandleObject>(handle: u64) -> Option<Arc<T>> {
+    let object = HANDLES.lock().get(handle, T::HANDLE_TYPE)?;
+    object.downcast::<T>().ok()
+}
+
+/// Free a handle of type `T`, returning the registry's reference to the object
+///
+/// The object is dropped once in-flight operations holding it complete.
+pub fn remove<T: QuicHandleObject>(handle: u64) -> Option<Arc<T>> {
+    let object = HANDLES.lock().remove(handle, T::HANDLE_TYPE)?;
+    // Dropped outside the lock: the object's destructor may run here
+    object.downcast::<T>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(value: u32) -> Arc<dyn Any + Send + Sync> {
+        Arc::new(value)
+    }
+
+    fn value(object: Option<Arc<dyn Any + Send + Sync>>) -> Option<u32> {
+        object.map(|object| *object.downcast::<u32>().unwrap())
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let handle = encode(QuicHandleType::RecvStream, GENERATION_MASK, u32::MAX);
+        assert_eq!(decode(handle), (QuicHandleType::RecvStream as u8, GENERATION_MASK, u32::MAX));
+        assert_eq!(handle_type(handle), Some(QuicHandleType::RecvStream));
+        assert_eq!(handle_type(0), None);
+    }
+
+    #[test]
+    fn remove_bumps_generation() {
+        let mut table = HandleTable::new();
+        let first = table.insert(QuicHandleType::Connection, object(1));
+        assert_eq!(value(table.remove(first, QuicHandleType::Connection)), Some(1));
+
+        // The freed slot is reused under the next generation
+        let second = table.insert(QuicHandleType::Connection, object(2));
+        let (_, first_generation, first_index) = decode(first);
+        let (_, second_generation, second_index) = decode(second);
+        assert_eq!(second_index, first_index);
+        assert_eq!(second_generation, first_generation + 1);
+        assert_ne!(second, first);
+
+        assert_eq!(value(table.get(first, QuicHandleType::Connection)), None);
+        assert_eq!(value(table.get(second, QuicHandleType::Connection)), Some(2));
+    }
+
+    #[test]
+    fn generation_skips_zero_on_wrap() {
+        assert_eq!(next_generation(1), 2);
+        assert_eq!(next_generation(GENERATION_MASK - 1), GENERATION_MASK);
+        assert_eq!(next_generation(GENERATION_MASK), 1);
+
+        let mut table = HandleTable::new();
+        let first = table.insert(QuicHandleType::Client, object(1));
+        let index = decode(first).2 as usize;
+        table.slots[index].generation = GENERATION_MASK;
+        let last = encode(QuicHandleType::Client, GENERATION_MASK, index as u32);
+        assert!(table.remove(last, QuicHandleType::Client).is_some());
+        assert_eq!(table.slots[index].generation, 1);
+
+        let reused = table.insert(QuicHandleType::Client, object(2));
+        assert_eq!(decode(reused), (QuicHandleType::Client as u8, 1, index as u32));
+        assert_ne!(reused, 0);
+    }
+
+    #[test]
+    fn type_tag_mismatch_fails() {
+        let mut table = HandleTable::new();
+        let handle = table.insert(QuicHandleType::SendStream, object(1));
+        assert_eq!(value(table.get(handle, QuicHandleType::RecvStream)), None);
+        assert!(table.remove(handle, QuicHandleType::RecvStream).is_none());
+
+        // Same slot and generation, forged type tag
+        let (_, generation, index) = decode(handle);
+        let forged = encode(QuicHandleType::RecvStream, generation, index);
+        assert_eq!(value(table.get(forged, QuicHandleType::RecvStream)), None);
+
+        assert_eq!(value(table.get(handle, QuicHandleType::SendStream)), Some(1));
+    }
+
+    #[test]
+    fn stale_handle_fails_after_free() {
+        let mut table = HandleTable::new();
+        let handle = table.insert(QuicHandleType::Server, object(1));
+        assert!(table.remove(handle, QuicHandleType::Server).is_some());
+
+        assert_eq!(value(table.get(handle, QuicHandleType::Server)), None);
+        assert!(table.remove(handle, QuicHandleType::Server).is_none());
+        assert_eq!(table.free.len(), 1);
+    }
+
+    #[test]
+    fn zero_and_unknown_handles_fail() {
+        let mut table = HandleTable::new();
+        table.insert(QuicHandleType::Endpoint, object(1));
+        assert_eq!(value(table.get(0, QuicHandleType::Endpoint)), None);
+        assert_eq!(value(table.get(encode(QuicHandleType::Endpoint, 1, 7), QuicHandleType::Endpoint)), None);
+    }
+
+    struct Probe;
+    impl QuicHandleObject for Probe {
+        const HANDLE_TYPE: QuicHandleType = QuicHandleType::Client;
+    }
+
+    #[test]
+    fn global_registry_checks_type() {
+        let handle = insert(Probe);
+        assert_eq!(handle_type(handle), Some(QuicHandleType::Client));
+        assert!(get::<Probe>(handle).is_some());
+        assert!(get::<QuicServer>(handle).is_none());
+        // Same tag, different Rust type
+        assert!(get::<QuicClient>(handle).is_none());
+
+        assert!(remove::<Probe>(handle).is_some());
+        assert!(get::<Probe>(handle).is_none());
+        assert!(remove::<Probe>(handle).is_none());
+    }
+}
@@ -2,13 +2,18 @@
 //!
 //! Module organization:
 //! - lib.rs: Common types, executor, memory manager, transport config
+//! - handle_registry.rs: Generational `u64` handles for objects owned by Rust
 //! - quic_ffi_client.rs: Client endpoint FFI
 //! - quic_ffi_conn.rs: Connection handle, stream, datagram FFI
 //! - quic_ffi_server.rs: Server endpoint FFI
+//! - quic_ffi_port.rs: Port-based completion (allo-isolate) for async operations
 
 pub mod runtime_manager;
 pub mod memory_manager;
 pub mod quic_executor;
+pub mod handle_registry;
+pub mod panic_guard;
+pub mod logging;
 pub mod types;
 pub mod error;
 pub mod quic;
@@ -17,6 +22,7 @@ pub mod quic_ffi_endpoint;
 pub mod quic_ffi_client;
 pub mod quic_ffi_conn;
 pub mod quic_ffi_server;
+pub mod quic_ffi_port;
 
 use quic_executor::{QuicExecutor, BoolCallback};
 use error::QuicError;
@@ -127,6 +133,20 @@ impl QuicFfiResult {
         }
     }
 
+    /// Write a result without a value to self (`handle` stays null), returns error code
+    pub fn write_status(&mut self, result: Result<(), QuicError>) -> i32 {
+        match result {
+            Ok(()) => {
+                *self = Self::null();
+                types::QuicResult::Success as i32
+            }
+            Err(e) => {
+                *self = Self::error(&e);
+                e.code_value()
+            }
+        }
+    }
+
     /// Write error string to existing result (for parameter validation)
     /// 
     /// This method modifies the existing result in-place without allocating new QuicFfiResult,
@@ -151,14 +171,16 @@ impl QuicFfiResult {
 /// Free error message allocated by QuicFfiResult
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_ffi_result_free_error(result: *mut QuicFfiResult) {
-    if !result.is_null() {
-        let r = unsafe { &mut *result };
-        if !r.error_msg.is_null() && r.error_msg_len > 0 {
-            deallocate(r.error_msg, r.error_msg_len);
-            r.error_msg = std::ptr::null_mut();
-            r.error_msg_len = 0;
+    panic_guard::guard(|| {
+        if !result.is_null() {
+            let r = unsafe { &mut *result };
+            if !r.error_msg.is_null() && r.error_msg_len > 0 {
+                deallocate(r.error_msg, r.error_msg_len);
+                r.error_msg = std::ptr::null_mut();
+                r.error_msg_len = 0;
+            }
         }
-    }
+    })
 }
 
 // ============================================
@@ -180,6 +202,10 @@ pub static ERR_PTR_NULL: &str = "Pointer is null";
 pub static ERR_SUBMIT_FAILED: &str = "Failed to submit async task";
 #[doc(hidden)]
 pub static ERR_CONFIG_REQUIRED: &str = "Config is required";
+#[doc(hidden)]
+pub static ERR_CANCELLED: &str = "[Cancelled] Operation cancelled";
+#[doc(hidden)]
+pub static ERR_INVALID_HANDLE: &str = "[InvalidParameter] Invalid or freed handle";
 
 // ============================================
 // Error String Helper
@@ -236,56 +262,92 @@ unsafe impl Send for FfiErrBuf {}
 // ============================================
 
 /// Check if executor is null, return early with UsizeCallback error
+///
+/// Pass `$ret` as a third argument in functions that return a value.
 #[macro_export]
 macro_rules! check_executor_usize {
     ($executor:expr, $callback:expr) => {
+        $crate::check_executor_usize!($executor, $callback, ())
+    };
+    ($executor:expr, $callback:expr, $ret:expr) => {
         if $executor.is_null() {
             $callback(false, 0, $crate::ERR_EXECUTOR_NULL.as_bytes().as_ptr(), $crate::ERR_EXECUTOR_NULL.len());
-            return;
+            return $ret;
         }
     };
 }
 
 /// Check if pointer is null, return early with UsizeCallback error
+///
+/// Pass `$ret` as a third argument in functions that return a value.
 #[macro_export]
 macro_rules! check_ptr_usize {
     ($ptr:expr, $callback:expr) => {
+        $crate::check_ptr_usize!($ptr, $callback, ())
+    };
+    ($ptr:expr, $callback:expr, $ret:expr) => {
         if $ptr.is_null() {
             $callback(false, 0, $crate::ERR_PTR_NULL.as_bytes().as_ptr(), $crate::ERR_PTR_NULL.len());
-            return;
+            return $ret;
         }
     };
 }
 
 /// Check if executor is null, return early with VoidCallback error
+///
+/// Pass `$ret` as a third argument in functions that return a value.
 #[macro_export]
 macro_rules! check_executor_void {
     ($executor:expr, $callback:expr) => {
+        $crate::check_executor_void!($executor, $callback, ())
+    };
+    ($executor:expr, $callback:expr, $ret:expr) => {
         if $executor.is_null() {
             $callback(false, $crate::ERR_EXECUTOR_NULL.as_bytes().as_ptr(), $crate::ERR_EXECUTOR_NULL.len());
-            return;
+            return $ret;
         }
     };
 }
 
 /// Check if pointer is null, return early with VoidCallback error
+///
+/// Pass `$ret` as a third argument in functions that return a value.
 #[macro_export]
 macro_rules! check_ptr_void {
     ($ptr:expr, $callback:expr) => {
+        $crate::check_ptr_void!($ptr, $callback, ())
+    };
+    ($ptr:expr, $callback:expr, $ret:expr) => {
         if $ptr.is_null() {
             $callback(false, $crate::ERR_PTR_NULL.as_bytes().as_ptr(), $crate::ERR_PTR_NULL.len());
+            return $ret;
+        }
+    };
+}
+
+/// Check if executor is null, return early with BoolCallback error
+#[macro_export]
+macro_rules! check_executor_bool {
+    ($executor:expr, $callback:expr) => {
+        if $executor.is_null() {
+            $callback(false, false, $crate::ERR_EXECUTOR_NULL.as_bytes().as_ptr(), $crate::ERR_EXECUTOR_NULL.len());
             return;
         }
     };
 }
 
 /// Check if executor is null, return early with BytesCallback error
+///
+/// Pass `$ret` as a third argument in functions that return a value.
 #[macro_export]
 macro_rules! check_executor_bytes {
     ($executor:expr, $callback:expr) => {
+        $crate::check_executor_bytes!($executor, $callback, ())
+    };
+    ($executor:expr, $callback:expr, $ret:expr) => {
         if $executor.is_null() {
             $callback(false, std::ptr::null_mut(), 0, $crate::ERR_EXECUTOR_NULL.as_bytes().as_ptr(), $crate::ERR_EXECUTOR_NULL.len());
-            return;
+            return $ret;
         }
     };
 }
@@ -301,6 +363,59 @@ macro_rules! check_ptr_bytes {
     };
 }
 
+/// Look up a registry handle of type `$ty`, return early with UsizeCallback error if it is stale
+///
+/// Evaluates to the `Arc` held by the registry.
+/// Pass `$ret` as a fourth argument in functions that return a value.
+#[macro_export]
+macro_rules! check_handle_usize {
+    ($ty:ty, $handle:expr, $callback:expr) => {
+        $crate::check_handle_usize!($ty, $handle, $callback, ())
+    };
+    ($ty:ty, $handle:expr, $callback:expr, $ret:expr) => {
+        match $crate::handle_registry::get::<$ty>($handle) {
+            Some(object) => object,
+            None => {
+                $callback(false, 0, $crate::ERR_INVALID_HANDLE.as_ptr(), $crate::ERR_INVALID_HANDLE.len());
+                return $ret;
+            }
+        }
+    };
+}
+
+/// Look up a registry handle of type `$ty`, return early with VoidCallback error if it is stale
+///
+/// Pass `$ret` as a third argument in functions that return a value.
+#[macro_export]
+macro_rules! check_handle_void {
+    ($ty:ty, $handle:expr, $callback:expr) => {
+        $crate::check_handle_void!($ty, $handle, $callback, ())
+    };
+    ($ty:ty, $handle:expr, $callback:expr, $ret:expr) => {
+        match $crate::handle_registry::get::<$ty>($handle) {
+            Some(object) => object,
+            None => {
+                $callback(false, $crate::ERR_INVALID_HANDLE.as_ptr(), $crate::ERR_INVALID_HANDLE.len());
+                return $ret;
+            }
+        }
+    };
+}
+
+/// Look up a registry handle of type `$ty`, return early with BytesCallback error if it is stale
+#[macro_export]
+macro_rules! check_handle_bytes {
+    ($ty:ty, $handle:expr, $callback:expr) => {
+        match $crate::handle_registry::get::<$ty>($handle) {
+            Some(object) => object,
+            None => {
+                $callback(false, std::ptr::null_mut(), 0, $crate::ERR_INVALID_HANDLE.as_ptr(), $crate::ERR_INVALID_HANDLE.len());
+                return;
+            }
+        }
+    };
+}
+
 /// Check if executor is null, return early with ResultCallback error
 #[macro_export]
 macro_rules! check_executor_result {
@@ -336,7 +451,9 @@ macro_rules! check_ptr_result {
 
 #[unsafe(no_mangle)]
 pub extern "C" fn dart_quic_executor_new() -> *mut QuicExecutor {
-    Box::into_raw(Box::new(QuicExecutor::new()))
+    panic_guard::guard(|| {
+        Box::into_raw(Box::new(QuicExecutor::new()))
+    })
 }
 
 #[unsafe(no_mangle)]
@@ -345,26 +462,128 @@ pub unsafe extern "C" fn dart_quic_executor_init(
     threads: usize,
     callback: BoolCallback,
 ) {
-    if executor.is_null() {
-        callback(false, false, ERR_EXECUTOR_NULL.as_ptr(), ERR_EXECUTOR_NULL.len());
-        return;
-    }
-    unsafe { (*executor).init_runtime(threads, callback) }
+    panic_guard::guard_with(callback, || {
+        if executor.is_null() {
+            callback(false, false, ERR_EXECUTOR_NULL.as_ptr(), ERR_EXECUTOR_NULL.len());
+            return;
+        }
+        unsafe { (*executor).init_runtime(threads, callback) }
+    })
+}
+
+/// Initialize the executor with a runtime configuration (async)
+///
+/// Like `dart_quic_executor_init`, but exposes the tokio runtime builder options.
+/// A null `config` uses the defaults. Invalid options or runtime build failures
+/// are reported through `callback`.
+///
+/// # Safety
+/// `executor` must be null or a valid executor; `config` must be null or valid
+/// for the duration of the call (it is copied before returning).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_executor_init_with_config(
+    executor: *mut QuicExecutor,
+    config: *const runtime_manager::QuicFfiRuntimeConfig,
+    callback: BoolCallback,
+) {
+    panic_guard::guard_with(callback, || {
+        if executor.is_null() {
+            callback(false, false, ERR_EXECUTOR_NULL.as_ptr(), ERR_EXECUTOR_NULL.len());
+            return;
+        }
+        let config = if config.is_null() {
+            runtime_manager::RuntimeConfig::default()
+        } else {
+            match runtime_manager::RuntimeConfig::try_from(unsafe { &*config }) {
+                Ok(config) => config,
+                Err(e) => {
+                    let err = FfiErrBuf::new(format!("[InvalidParameter] {}", e));
+                    callback(false, false, err.as_ptr(), err.len());
+                    return;
+                }
+            }
+        };
+        unsafe { (*executor).init_runtime_with_config(config, callback) }
+    })
 }
 
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_executor_is_running(executor: *mut QuicExecutor) -> bool {
-    if executor.is_null() { return false }
-    unsafe { (*executor).is_running() }
+    panic_guard::guard(|| {
+        if executor.is_null() { return false }
+        unsafe { (*executor).is_running() }
+    })
 }
 
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_executor_free(executor: *mut QuicExecutor) {
-    if !executor.is_null() {
-        let executor_box = unsafe { Box::from_raw(executor) };
-        executor_box.shutdown();
-        drop(executor_box);
-    }
+    panic_guard::guard(|| {
+        if !executor.is_null() {
+            let executor_box = unsafe { Box::from_raw(executor) };
+            executor_box.shutdown();
+            drop(executor_box);
+        }
+    })
+}
+
+/// Gracefully shut down the executor (async)
+///
+/// Stops accepting new operations and closes every client / server created on the
+/// executor (pending accepts complete as closed), then waits up to `grace_period_ms`
+/// for in-flight operations to finish. Operations still running afterwards are aborted
+/// without invoking their callbacks, and the tokio runtime is destroyed.
+///
+/// `callback` fires once the runtime is gone, with `value` = true if all operations
+/// finished within the grace period, false if some were aborted. It fails with
+/// "Executor not running" if the executor is not initialized or already shutting down.
+///
+/// After the callback fires the executor can be initialized again with
+/// `dart_quic_executor_init`.
+///
+/// # Safety
+/// `executor` must be null or a valid executor.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_executor_shutdown(
+    executor: *mut QuicExecutor,
+    grace_period_ms: u64,
+    callback: BoolCallback,
+) {
+    panic_guard::guard_with(callback, || {
+        if executor.is_null() {
+            callback(false, false, ERR_EXECUTOR_NULL.as_ptr(), ERR_EXECUTOR_NULL.len());
+            return;
+        }
+        unsafe { (*executor).shutdown_graceful(std::time::Duration::from_millis(grace_period_ms), callback) }
+    })
+}
+
+/// Cancel an in-flight async operation (sync)
+///
+/// Applies to functions that return an operation ID (`dart_quic_recv_stream_read`,
+/// `dart_quic_connection_accept_bi`, `dart_quic_server_accept`). The operation's
+/// callback then fails with an error starting with `[Cancelled]` (QuicResult::Cancelled),
+/// fired after the operation has stopped using its stream / connection.
+///
+/// # Returns
+/// - 0 (Success) if the operation was cancelled
+/// - InvalidOperation if the ID is unknown or the operation already completed
+///   (its callback fires with the real result)
+/// - InvalidParameter if executor is null
+///
+/// # Safety
+/// `executor` must be null or a valid executor.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_operation_cancel(executor: *mut QuicExecutor, operation_id: u64) -> i32 {
+    panic_guard::guard(|| {
+        if executor.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
+        if unsafe { (*executor).cancel(operation_id) } {
+            types::QuicResult::Success as i32
+        } else {
+            types::QuicResult::InvalidOperation as i32
+        }
+    })
 }
 
 // ============================================
@@ -373,32 +592,42 @@ pub unsafe extern "C" fn dart_quic_executor_free(executor: *mut QuicExecutor) {
 
 #[unsafe(no_mangle)]
 pub extern "C" fn dart_allocate_memory(size: usize) -> *mut u8 {
-    allocate(size)
+    panic_guard::guard(|| {
+        allocate(size)
+    })
 }
 
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_free_memory(ptr: *mut u8, size: usize) {
-    deallocate(ptr, size);
+    panic_guard::guard(|| {
+        deallocate(ptr, size);
+    })
 }
 
 #[unsafe(no_mangle)]
 pub extern "C" fn dart_get_memory_stats() -> *const MemoryStats {
-    match memory_stats() {
-        Some(stats) => Box::into_raw(Box::new(stats)),
-        None => std::ptr::null(),
-    }
+    panic_guard::guard(|| {
+        match memory_stats() {
+            Some(stats) => Box::into_raw(Box::new(stats)),
+            None => std::ptr::null(),
+        }
+    })
 }
 
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_free_memory_stats(stats: *mut MemoryStats) {
-    if !stats.is_null() {
-        unsafe { let _ = Box::from_raw(stats); }
-    }
+    panic_guard::guard(|| {
+        if !stats.is_null() {
+            unsafe { let _ = Box::from_raw(stats); }
+        }
+    })
 }
 
 #[unsafe(no_mangle)]
 pub extern "C" fn dart_initialize_memory_manager() -> bool {
-    initialize_memory_manager()
+    panic_guard::guard(|| {
+        initialize_memory_manager()
+    })
 }
 
 #[unsafe(no_mangle)]
@@ -410,25 +639,31 @@ pub extern "C" fn dart_initialize_memory_manager_with_config(
     huge_pool_size: i32,
     xlarge_pool_size: i32,
 ) -> bool {
-    let config = PoolConfig {
-        tiny_pool_size: if tiny_pool_size >= 0 { Some(tiny_pool_size as usize) } else { None },
-        small_pool_size: if small_pool_size >= 0 { Some(small_pool_size as usize) } else { None },
-        medium_pool_size: if medium_pool_size >= 0 { Some(medium_pool_size as usize) } else { None },
-        large_pool_size: if large_pool_size >= 0 { Some(large_pool_size as usize) } else { None },
-        huge_pool_size: if huge_pool_size >= 0 { Some(huge_pool_size as usize) } else { None },
-        xlarge_pool_size: if xlarge_pool_size >= 0 { Some(xlarge_pool_size as usize) } else { None },
-    };
-    initialize_memory_manager_with_config(config)
+    panic_guard::guard(|| {
+        let config = PoolConfig {
+            tiny_pool_size: if tiny_pool_size >= 0 { Some(tiny_pool_size as usize) } else { None },
+            small_pool_size: if small_pool_size >= 0 { Some(small_pool_size as usize) } else { None },
+            medium_pool_size: if medium_pool_size >= 0 { Some(medium_pool_size as usize) } else { None },
+            large_pool_size: if large_pool_size >= 0 { Some(large_pool_size as usize) } else { None },
+            huge_pool_size: if huge_pool_size >= 0 { Some(huge_pool_size as usize) } else { None },
+            xlarge_pool_size: if xlarge_pool_size >= 0 { Some(xlarge_pool_size as usize) } else { None },
+        };
+        initialize_memory_manager_with_config(config)
+    })
 }
 
 #[unsafe(no_mangle)]
 pub extern "C" fn dart_destroy_memory_manager() -> bool {
-    destroy_memory_manager()
+    panic_guard::guard(|| {
+        destroy_memory_manager()
+    })
 }
 
 #[unsafe(no_mangle)]
 pub extern "C" fn dart_is_memory_manager_available() -> bool {
-    is_memory_manager_available()
+    panic_guard::guard(|| {
+        is_memory_manager_available()
+    })
 }
 
 // ============================================
@@ -437,16 +672,20 @@ pub extern "C" fn dart_is_memory_manager_available() -> bool {
 
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_transport_config_default(result: *mut QuicFfiResult) -> i32 {
-    if result.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
-    
-    unsafe { (*result).write_result(Ok(quic::QuicFfiTransportConfig::default())) }
+    panic_guard::guard(|| {
+        if result.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
+
+        unsafe { (*result).write_result(Ok(quic::QuicFfiTransportConfig::default())) }
+    })
 }
 
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_transport_config_free(config: *mut quic::QuicFfiTransportConfig) {
-    if !config.is_null() {
-        unsafe { let _ = Box::from_raw(config); }
-    }
+    panic_guard::guard(|| {
+        if !config.is_null() {
+            unsafe { let _ = Box::from_raw(config); }
+        }
+    })
 }
@@ -0,0 +1,321 @@
+//! Logging Module
+//!
+//! Forwards `tracing` events from this crate, quinn and rustls (via the `log` bridge) to
+//! Dart through a native port (`dart_quic_log_set_port`), or to native code through a
+//! synchronous callback (`dart_quic_log_set_callback`). The subscriber is installed on first
+//! use of any `dart_quic_log_*` function; the level filter can be changed at any time.
+//!
+//! Each record carries:
+//! - `level` / `target`: event metadata (`target` is the module path, e.g. `quinn_proto::connection`)
+//! - `message`: the formatted event message
+//! - `fields`: the remaining event fields as `key=value` pairs, space separated
+//! - `spans`: the enclosing spans, root first, as `name{key=value ...}` joined by `:`
+//!
+//! Port messages are 5-element lists: `[level: int, target: String, message: String, fields: String, spans: String]`.
+
+use std::fmt::{self, Write};
+use std::sync::OnceLock;
+
+use allo_isolate::{IntoDart, Isolate};
+use parking_lot::RwLock;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Subscriber};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::{LookupSpan, Registry};
+use tracing_subscriber::reload;
+
+use crate::panic_guard;
+use crate::types::QuicResult;
+
+// ============================================
+// Log Records
+// ============================================
+
+/// Log level (also used as the filter value for `dart_quic_log_set_level`)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuicLogLevel {
+    /// Filter only: disable all records
+    Off = 0,
+    Error = 1,
+    Warn = 2,
+    Info = 3,
+    Debug = 4,
+    Trace = 5,
+}
+
+impl From<&Level> for QuicLogLevel {
+    fn from(level: &Level) -> Self {
+        match *level {
+            Level::ERROR => Self::Error,
+            Level::WARN => Self::Warn,
+            Level::INFO => Self::Info,
+            Level::DEBUG => Self::Debug,
+            Level::TRACE => Self::Trace,
+        }
+    }
+}
+
+impl TryFrom<i32> for QuicLogLevel {
+    type Error = ();
+
+    fn try_from(value: i32) -> Result<Self, ()> {
+        match value {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::Error),
+            2 => Ok(Self::Warn),
+            3 => Ok(Self::Info),
+            4 => Ok(Self::Debug),
+            5 => Ok(Self::Trace),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<QuicLogLevel> for LevelFilter {
+    fn from(level: QuicLogLevel) -> Self {
+        match level {
+            QuicLogLevel::Off => LevelFilter::OFF,
+            QuicLogLevel::Error => LevelFilter::ERROR,
+            QuicLogLevel::Warn => LevelFilter::WARN,
+            QuicLogLevel::Info => LevelFilter::INFO,
+            QuicLogLevel::Debug => LevelFilter::DEBUG,
+            QuicLogLevel::Trace => LevelFilter::TRACE,
+        }
+    }
+}
+
+/// Log record passed to `QuicLogCallback` (C-compatible)
+///
+/// Strings are UTF-8, not null-terminated, and only valid during the callback.
+#[repr(C)]
+pub struct QuicFfiLogRecord {
+    pub level: QuicLogLevel,
+    pub target_ptr: *const u8,
+    pub target_len: usize,
+    pub message_ptr: *const u8,
+    pub message_len: usize,
+    pub fields_ptr: *const u8,
+    pub fields_len: usize,
+    pub spans_ptr: *const u8,
+    pub spans_len: usize,
+}
+
+/// Callback receiving log records, invoked synchronously on the logging thread (nullable)
+pub type QuicLogCallback = Option<extern "C" fn(record: *const QuicFfiLogRecord)>;
+
+/// Where records are delivered
+#[derive(Clone, Copy)]
+enum LogSink {
+    Callback(extern "C" fn(record: *const QuicFfiLogRecord)),
+    Port(i64),
+}
+
+static SINK: RwLock<Option<LogSink>> = RwLock::new(None);
+
+/// Level filter handle of the installed subscriber
+static FILTER: OnceLock<Result<reload::Handle<LevelFilter, Registry>, String>> = OnceLock::new();
+
+/// Level used until `dart_quic_log_set_level` is called
+const DEFAULT_LEVEL: LevelFilter = LevelFilter::INFO;
+
+// ============================================
+// Subscriber
+// ============================================
+
+/// Install the global subscriber (once), returning its level filter handle
+fn install() -> Result<&'static reload::Handle<LevelFilter, Registry>, String> {
+    FILTER
+        .get_or_init(|| {
+            let (filter, handle) = reload::Layer::new(DEFAULT_LEVEL);
+            let subscriber = Registry::default().with(filter).with(DartLogLayer);
+            tracing::subscriber::set_global_default(subscriber)
+                .map_err(|e| format!("Failed to install log subscriber: {}", e))?;
+            tracing_log::LogTracer::init()
+                .map_err(|e| format!("Failed to install log bridge: {}", e))?;
+            Ok(handle)
+        })
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+/// Formatted fields of a span, stored in its extensions
+struct SpanFields(String);
+
+/// Collects the `message` field and formats the others as `key=value`
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => self.message = format!("{:?}", value),
+            // Metadata of bridged `log` records, already in the normalized metadata
+            name if name.starts_with("log.") => {}
+            name => {
+                if !self.fields.is_empty() {
+                    self.fields.push(' ');
+                }
+                let _ = write!(self.fields, "{}={:?}", name, value);
+            }
+        }
+    }
+}
+
+/// Layer that forwards events to the registered sink
+struct DartLogLayer;
+
+impl<S> Layer<S> for DartLogLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        span.extensions_mut().insert(SpanFields(visitor.fields));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut extensions = span.extensions_mut();
+        if let Some(SpanFields(fields)) = extensions.get_mut::<SpanFields>() {
+            let mut visitor = FieldVisitor { message: String::new(), fields: std::mem::take(fields) };
+            values.record(&mut visitor);
+            *fields = visitor.fields;
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let Some(sink) = *SINK.read() else { return };
+
+        let normalized = event.normalized_metadata();
+        let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+
+        let mut spans = String::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                if !spans.is_empty() {
+                    spans.push(':');
+                }
+                spans.push_str(span.name());
+                if let Some(SpanFields(fields)) = span.extensions().get::<SpanFields>()
+                    && !fields.is_empty()
+                {
+                    let _ = write!(spans, "{{{}}}", fields);
+                }
+            }
+        }
+
+        let level = QuicLogLevel::from(metadata.level());
+        let target = metadata.target();
+        match sink {
+            LogSink::Callback(callback) => {
+                let record = QuicFfiLogRecord {
+                    level,
+                    target_ptr: target.as_ptr(),
+                    target_len: target.len(),
+                    message_ptr: visitor.message.as_ptr(),
+                    message_len: visitor.message.len(),
+                    fields_ptr: visitor.fields.as_ptr(),
+                    fields_len: visitor.fields.len(),
+                    spans_ptr: spans.as_ptr(),
+                    spans_len: spans.len(),
+                };
+                callback(&record);
+            }
+            LogSink::Port(port) => {
+                Isolate::new(port).post(vec![
+                    (level as i32).into_dart(),
+                    target.to_string().into_dart(),
+                    visitor.message.into_dart(),
+                    visitor.fields.into_dart(),
+                    spans.into_dart(),
+                ]);
+            }
+        }
+    }
+}
+
+/// Install the subscriber and replace the sink
+fn set_sink(sink: Option<LogSink>) -> i32 {
+    match install() {
+        Ok(_) => {
+            *SINK.write() = sink;
+            QuicResult::Success as i32
+        }
+        Err(_) => QuicResult::RuntimeError as i32,
+    }
+}
+
+// ============================================
+// Logging FFI
+// ============================================
+
+/// Forward log records to `callback` (sync)
+///
+/// Intended for native consumers: the callback is invoked synchronously on whichever
+/// thread emitted the record, and the record strings are only valid until it returns.
+/// Dart code should use `dart_quic_log_set_port` instead, since a `NativeCallable.listener`
+/// runs after the callback has returned and would read freed strings.
+///
+/// Replaces any previously registered callback or port; pass null to stop forwarding.
+/// Records below the level set with
+/// `dart_quic_log_set_level` (default Info) are dropped.
+///
+/// Returns RuntimeError if the log subscriber could not be installed.
+#[unsafe(no_mangle)]
+pub extern "C" fn dart_quic_log_set_callback(callback: QuicLogCallback) -> i32 {
+    panic_guard::guard(|| set_sink(callback.map(LogSink::Callback)))
+}
+
+/// Forward log records to a Dart native port (sync)
+///
+/// This is the supported way to receive logs in Dart; records are copied into the message.
+/// Replaces any previously registered callback or port; pass 0 to stop forwarding.
+/// Requires `dart_quic_port_init`. See the module docs for the message format.
+///
+/// Returns RuntimeError if the log subscriber could not be installed.
+#[unsafe(no_mangle)]
+pub extern "C" fn dart_quic_log_set_port(port: i64) -> i32 {
+    panic_guard::guard(|| set_sink((port != 0).then_some(LogSink::Port(port))))
+}
+
+/// Set the maximum level of forwarded records (sync)
+///
+/// `level` is a `QuicLogLevel` value (0 = Off ... 5 = Trace). Takes effect immediately,
+/// including for quinn and rustls.
+///
+/// Returns InvalidParameter for an unknown level, RuntimeError if the log subscriber
+/// could not be installed.
+#[unsafe(no_mangle)]
+pub extern "C" fn dart_quic_log_set_level(level: i32) -> i32 {
+    panic_guard::guard(|| {
+        let Ok(level) = QuicLogLevel::try_from(level) else {
+            return QuicResult::InvalidParameter as i32;
+        };
+        let Ok(handle) = install() else {
+            return QuicResult::RuntimeError as i32;
+        };
+        match handle.reload(LevelFilter::from(level)) {
+            Ok(()) => QuicResult::Success as i32,
+            Err(_) => QuicResult::RuntimeError as i32,
+        }
+    })
+}
@@ -0,0 +1,191 @@
+//! Panic Guard Module
+//!
+//! A panic unwinding out of an `extern "C"` function aborts the process, and a panic in a
+//! spawned task drops its completion callback so Dart awaits forever. Every entry point
+//! therefore runs inside [`guard`] / [`guard_with`], and every executor task inside
+//! [`guard_future`], which convert a caught panic into a `QuicResult::InternalError`:
+//! - Entry points with a callback or port fail it with `[InternalError] Panic: <message>`
+//! - Other entry points return their [`PanicDefault`] value (`InternalError`, null, 0 or false)
+//!
+//! Panics are additionally reported to the callback registered with
+//! `dart_quic_set_panic_callback`, including location, for crash reporting.
+
+use std::any::Any;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use futures::FutureExt;
+use parking_lot::RwLock;
+
+use crate::error::QuicError;
+use crate::quic_executor::{BoolCallback, BytesCallback, SendableCallback, UsizeCallback, VoidCallback};
+use crate::types::QuicResult;
+
+// ============================================
+// Panic Reporting
+// ============================================
+
+/// Callback receiving a panic description ("panicked at <file>:<line>:<col>:\n<message>")
+///
+/// Invoked on the panicking thread; the message is only valid during the call.
+/// Nullable: pass null to unregister.
+pub type PanicCallback = Option<extern "C" fn(message: *const u8, message_len: usize)>;
+
+static PANIC_CALLBACK: RwLock<PanicCallback> = RwLock::new(None);
+static INSTALL_HOOK: Once = Once::new();
+
+/// Install the global panic hook (once)
+///
+/// Panics go to the registered callback; without one, to the previous hook (stderr).
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| match *PANIC_CALLBACK.read() {
+            Some(callback) => {
+                let message = info.to_string();
+                callback(message.as_ptr(), message.len());
+            }
+            None => previous(info),
+        }));
+    });
+}
+
+/// Register a callback for every panic inside the library (sync)
+///
+/// Pass null to unregister; panics are then printed to stderr. Caught panics are
+/// still converted into `InternalError` results either way.
+#[unsafe(no_mangle)]
+pub extern "C" fn dart_quic_set_panic_callback(callback: PanicCallback) {
+    guard(|| {
+        install_hook();
+        *PANIC_CALLBACK.write() = callback;
+    })
+}
+
+/// Extract the message from a panic payload
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+/// `InternalError` describing a caught panic
+pub fn panic_error(payload: &(dyn Any + Send)) -> QuicError {
+    QuicError::with_message(QuicResult::InternalError, format!("Panic: {}", panic_message(payload)))
+}
+
+// ============================================
+// Guards
+// ============================================
+
+/// Value returned by an entry point whose body panicked
+pub trait PanicDefault {
+    fn panic_default() -> Self;
+}
+
+impl PanicDefault for () {
+    fn panic_default() -> Self {}
+}
+
+impl PanicDefault for i32 {
+    fn panic_default() -> Self {
+        QuicResult::InternalError as i32
+    }
+}
+
+impl PanicDefault for bool {
+    fn panic_default() -> Self {
+        false
+    }
+}
+
+macro_rules! impl_panic_default_zero {
+    ($($ty:ty),*) => {
+        $(impl PanicDefault for $ty {
+            fn panic_default() -> Self {
+                0
+            }
+        })*
+    };
+}
+
+impl_panic_default_zero!(u16, u64, usize);
+
+impl<T> PanicDefault for *mut T {
+    fn panic_default() -> Self {
+        std::ptr::null_mut()
+    }
+}
+
+impl<T> PanicDefault for *const T {
+    fn panic_default() -> Self {
+        std::ptr::null()
+    }
+}
+
+/// Completion target that can fail an operation with a caught panic
+pub trait PanicReport {
+    fn report_panic(&self, err: &QuicError);
+}
+
+impl<T: PanicReport> PanicReport for SendableCallback<T> {
+    fn report_panic(&self, err: &QuicError) {
+        self.0.report_panic(err);
+    }
+}
+
+impl PanicReport for VoidCallback {
+    fn report_panic(&self, err: &QuicError) {
+        let err = crate::FfiErrBuf::new(err.to_string());
+        self(false, err.as_ptr(), err.len());
+    }
+}
+
+impl PanicReport for BoolCallback {
+    fn report_panic(&self, err: &QuicError) {
+        let err = crate::FfiErrBuf::new(err.to_string());
+        self(false, false, err.as_ptr(), err.len());
+    }
+}
+
+impl PanicReport for UsizeCallback {
+    fn report_panic(&self, err: &QuicError) {
+        let err = crate::FfiErrBuf::new(err.to_string());
+        self(false, 0, err.as_ptr(), err.len());
+    }
+}
+
+impl PanicReport for BytesCallback {
+    fn report_panic(&self, err: &QuicError) {
+        let err = crate::FfiErrBuf::new(err.to_string());
+        self(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+    }
+}
+
+/// Run an entry point body, returning [`PanicDefault`] if it panics
+pub fn guard<R: PanicDefault>(body: impl FnOnce() -> R) -> R {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|_| R::panic_default())
+}
+
+/// Run an entry point body, failing `report` and returning [`PanicDefault`] if it panics
+pub fn guard_with<R: PanicDefault>(report: impl PanicReport, body: impl FnOnce() -> R) -> R {
+    panic::catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|payload| {
+        report.report_panic(&panic_error(&*payload));
+        R::panic_default()
+    })
+}
+
+/// Run a task, failing `report` if it panics
+pub async fn guard_future<F>(report: impl PanicReport, future: F)
+where
+    F: Future<Output = ()>,
+{
+    if let Err(payload) = AssertUnwindSafe(future).catch_unwind().await {
+        report.report_panic(&panic_error(&*payload));
+    }
+}
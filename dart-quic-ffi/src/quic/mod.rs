@@ -7,6 +7,13 @@ mod quic_client;
 mod quic_server;
 mod quic_connection;
 mod quic_endpoint;
+mod quic_session_cache;
+mod quic_resolver;
+mod quic_qlog;
+mod quic_keylog;
+mod quic_alpn_router;
+mod quic_sni;
+mod quic_cert_reload;
 
 pub use quic_config::{
     QuicTransportConfig, MtuDiscoveryConfig, AckFrequencyConfig,
@@ -14,8 +21,30 @@ pub use quic_config::{
     QuicClientConfigBuilder, QuicServerConfigBuilder,
 };
 pub use quic_client::{
-    QuicClient, QuicFfiClientConfig, QuicFfiTrustMode, QuicFfiClientCertMode,
+    QuicClient, QuicClientHandle, QuicFfiClientConfig, QuicFfiTrustMode,
+    QuicFfiClientCertMode, QuicFfiSessionCacheMode,
 };
-pub use quic_server::{QuicServer, QuicServerHandle, QuicFfiServerConfig};
-pub use quic_connection::{QuicConnection, QuicConnectionHandle};
-pub use quic_endpoint::{QuicEndpoint, QuicEndpointBuilder, QuicEndpointMode, QuicFfiEndpointConfig};
+pub use quic_server::{QuicServer, QuicServerHandle, QuicFfiServerConfig, QuicFfiSniCert};
+pub use quic_connection::{
+    QuicConnection, QuicConnectionHandle, QuicFfiConnectionStats,
+    QuicFfiPathStats, QuicFfiFrameStats, QuicFfiUdpStats,
+    QuicZeroRttHandshake, QuicZeroRttStatus,
+    QuicCloseKind, QuicCloseReason, QuicFfiCloseReason,
+    QuicHandshakeData, QuicFfiHandshakeData,
+};
+pub use quic_endpoint::{
+    QuicEndpoint, QuicEndpointHandle, QuicEndpointBuilder, QuicEndpointMode,
+    QuicFfiEndpointConfig, QuicFfiEndpointStats, QuicFfiSocketAddr, QuicFfiAddrFamily,
+    QUIC_ADDR_FAMILY_IPV4, QUIC_ADDR_FAMILY_IPV6,
+};
+pub use quic_session_cache::{
+    QuicLruSessionCache, DEFAULT_SESSION_CACHE_CAPACITY,
+};
+pub use quic_resolver::{
+    QuicResolver, QuicSystemResolver, QuicStaticResolver, happy_eyeballs_order,
+    CONNECTION_ATTEMPT_DELAY,
+};
+pub use quic_qlog::QuicQlog;
+pub use quic_keylog::{QuicKeyLogFile, QuicFfiKeyLogMode, key_log_from_env};
+pub use quic_sni::QuicSniResolver;
+pub use quic_cert_reload::{QuicCertWatcher, watch_cert_files, DEFAULT_CERT_WATCH_INTERVAL};
@@ -0,0 +1,177 @@
+//! ALPN Connection Routing
+//!
+//! Demultiplexes the connections accepted by a `QuicServer` by their negotiated ALPN
+//! protocol, for servers running several protocols on one UDP port.
+//!
+//! Register a queue per protocol (and optionally a fallback queue for connections whose
+//! protocol has none), then accept from a specific queue:
+//!
+//! ```rust
+//! server.route_alpn(Some(b"h3"))?;
+//! server.route_alpn(Some(b"my-proto"))?;
+//! server.route_alpn(None)?; // fallback
+//!
+//! let conn = server.accept_alpn(Some(b"my-proto")).await;
+//! ```
+//!
+//! The first registered route starts a background task on the server's runtime that
+//! accepts connections off the endpoint and queues them by protocol, until the endpoint
+//! closes or the last server clone is dropped. `accept_alpn` only reads its queue, so it
+//! can be cancelled or time out without losing connections meant for other protocols.
+//!
+//! Connections whose protocol has neither a queue nor a fallback, or whose queue already
+//! holds `ROUTE_QUEUE_CAPACITY` connections, are closed with application error code 0.
+//! Once routes are registered, accept only through `accept_alpn`: a plain `accept` takes
+//! connections off the endpoint without routing them.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
+
+use parking_lot::RwLock;
+use tokio::runtime::Handle;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::AbortHandle;
+
+use crate::error::QuicError;
+use crate::types::QuicResult;
+use super::quic_connection::QuicConnection;
+use super::quic_endpoint::QuicEndpoint;
+
+/// Maximum number of routed connections waiting in one queue
+const ROUTE_QUEUE_CAPACITY: usize = 64;
+
+/// Queued connections of one protocol
+type RouteQueue = Arc<Mutex<mpsc::Receiver<QuicConnection>>>;
+
+/// Connection queues by protocol (None = fallback)
+#[derive(Default)]
+struct RouteTable {
+    /// Sending halves, dropped once the endpoint closes so that accepts drain and end
+    senders: HashMap<Option<Vec<u8>>, mpsc::Sender<QuicConnection>>,
+    queues: HashMap<Option<Vec<u8>>, RouteQueue>,
+    closed: bool,
+}
+
+/// Per-ALPN connection queues of a server
+pub(crate) struct AlpnRouter {
+    routes: Arc<RwLock<RouteTable>>,
+    /// Runtime the server was created on, for the routing task
+    runtime: Option<Handle>,
+    /// Routing task, started by the first `register`
+    task: OnceLock<AbortHandle>,
+}
+
+impl AlpnRouter {
+    /// Create a router (call from within the server's runtime)
+    pub(crate) fn new() -> Self {
+        Self {
+            routes: Arc::new(RwLock::new(RouteTable::default())),
+            runtime: Handle::try_current().ok(),
+            task: OnceLock::new(),
+        }
+    }
+
+    /// Register the queue for `alpn` (None = fallback); no-op if it exists
+    ///
+    /// Starts routing the connections of `endpoint` on the first call.
+    pub(crate) fn register(&self, endpoint: &QuicEndpoint, alpn: Option<&[u8]>) -> Result<(), QuicError> {
+        let Some(runtime) = self.runtime.as_ref() else {
+            return Err(QuicError::with_message(
+                QuicResult::RuntimeError,
+                "ALPN routing requires a server created within a runtime",
+            ));
+        };
+
+        {
+            let mut routes = self.routes.write();
+            let key = alpn.map(<[u8]>::to_vec);
+            if !routes.queues.contains_key(&key) {
+                let (tx, rx) = mpsc::channel(ROUTE_QUEUE_CAPACITY);
+                // After the endpoint closed the queue starts out finished
+                if !routes.closed {
+                    routes.senders.insert(key.clone(), tx);
+                }
+                routes.queues.insert(key, Arc::new(Mutex::new(rx)));
+            }
+        }
+
+        self.task.get_or_init(|| {
+            runtime
+                .spawn(route_connections(endpoint.clone(), self.routes.clone()))
+                .abort_handle()
+        });
+        Ok(())
+    }
+
+    /// Accept the next connection routed to the queue of `alpn` (None = fallback)
+    ///
+    /// Returns `None` once the endpoint is closed and the queue is drained.
+    /// Cancel-safe: a cancelled accept leaves queued connections in place.
+    pub(crate) async fn accept(&self, alpn: Option<&[u8]>) -> Option<Result<QuicConnection, QuicError>> {
+        let queue = self.routes.read().queues.get(&alpn.map(<[u8]>::to_vec)).cloned();
+        let Some(queue) = queue else {
+            let message = match alpn {
+                Some(alpn) => format!("No route registered for ALPN {}", display_alpn(Some(alpn))),
+                None => "No fallback route registered".to_string(),
+            };
+            return Some(Err(QuicError::with_message(QuicResult::InvalidParameter, message)));
+        };
+
+        queue.lock().await.recv().await.map(Ok)
+    }
+}
+
+impl Drop for AlpnRouter {
+    fn drop(&mut self) {
+        // Last server clone gone: stop routing so the task releases the endpoint
+        if let Some(task) = self.task.get() {
+            task.abort();
+        }
+    }
+}
+
+/// Accept connections off `endpoint` and queue them by protocol until it closes
+async fn route_connections(endpoint: QuicEndpoint, routes: Arc<RwLock<RouteTable>>) {
+    while let Some(result) = endpoint.accept().await {
+        let conn = match result {
+            Ok(conn) => conn,
+            // Failed handshakes belong to no queue (already logged by the endpoint)
+            Err(_) => continue,
+        };
+
+        let protocol = conn.handshake_data().and_then(|data| data.alpn);
+        let target = {
+            let routes = routes.read();
+            routes
+                .senders
+                .get(&protocol)
+                .or_else(|| routes.senders.get(&None))
+                .cloned()
+        };
+
+        match target {
+            Some(target) => {
+                if let Err(mpsc::error::TrySendError::Full(conn)) = target.try_send(conn) {
+                    tracing::warn!(alpn = %display_alpn(protocol.as_deref()), "ALPN route queue full, closing connection");
+                    conn.close(0, b"connection queue full");
+                }
+            }
+            None => {
+                tracing::debug!(alpn = %display_alpn(protocol.as_deref()), "no ALPN route, closing connection");
+                conn.close(0, b"unsupported application protocol");
+            }
+        }
+    }
+
+    let mut routes = routes.write();
+    routes.closed = true;
+    routes.senders.clear();
+}
+
+fn display_alpn(alpn: Option<&[u8]>) -> Cow<'_, str> {
+    match alpn {
+        Some(alpn) => String::from_utf8_lossy(alpn),
+        None => Cow::Borrowed("<none>"),
+    }
+}
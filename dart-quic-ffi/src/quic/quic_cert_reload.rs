@@ -0,0 +1,123 @@
+//! Certificate Hot Reload
+//!
+//! Swaps the server certificate of a running endpoint without restarting it. New
+//! connections get the new certificate; established connections are unaffected.
+//!
+//! - On demand: build a new config and pass it to `QuicEndpoint::set_server_config`
+//!   (or `QuicServer::set_server_config`)
+//! - Automatically: [`watch_cert_files`] polls the PEM files of a `QuicServerConfigBuilder`
+//!   and rebuilds the config whenever they change
+//!
+//! Sessions cached for resumption under the old config are not carried over; resuming
+//! clients fall back to a full handshake.
+//!
+//! # Example
+//!
+//! ```rust
+//! let builder = QuicServer::builder().with_cert_pem_files("server.crt", "server.key")?;
+//! let server = builder.clone().bind("0.0.0.0:4433")?;
+//!
+//! let watcher = QuicCertWatcher::new(builder)?;
+//! let endpoint = server.as_endpoint().clone();
+//! tokio::spawn(watch_cert_files(watcher, DEFAULT_CERT_WATCH_INTERVAL, move || Some(endpoint.clone())));
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use tokio::time::MissedTickBehavior;
+
+use crate::error::QuicError;
+use crate::types::QuicResult;
+use super::quic_config::QuicServerConfigBuilder;
+use super::quic_endpoint::QuicEndpoint;
+
+/// Default interval between checks of the watched files
+pub const DEFAULT_CERT_WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Modification time and size of a file (None if it cannot be read)
+type FileStamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> FileStamp {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Detects changes to the certificate files of a server config
+pub struct QuicCertWatcher {
+    builder: QuicServerConfigBuilder,
+    files: Vec<PathBuf>,
+    stamps: Vec<FileStamp>,
+}
+
+impl QuicCertWatcher {
+    /// Watch the files returned by `QuicServerConfigBuilder::cert_files`
+    ///
+    /// The current file state is the baseline; only later changes trigger a reload.
+    ///
+    /// # Errors
+    /// - InvalidParameter if `builder` loads no certificate from files
+    pub fn new(builder: QuicServerConfigBuilder) -> Result<Self, QuicError> {
+        let files = builder.cert_files();
+        if files.is_empty() {
+            return Err(QuicError::with_message(
+                QuicResult::InvalidParameter,
+                "Server config has no certificate files to watch".to_string(),
+            ));
+        }
+        let stamps = files.iter().map(|file| stamp(file)).collect();
+        Ok(Self { builder, files, stamps })
+    }
+
+    /// Watched files
+    pub fn files(&self) -> &[PathBuf] {
+        &self.files
+    }
+
+    /// Rebuilt server config if any file changed since the last call
+    ///
+    /// A failed rebuild (e.g. the key was not rewritten yet) is retried on the next change.
+    pub fn poll(&mut self) -> Option<Result<quinn::ServerConfig, QuicError>> {
+        let stamps: Vec<FileStamp> = self.files.iter().map(|file| stamp(file)).collect();
+        if stamps == self.stamps {
+            return None;
+        }
+        self.stamps = stamps;
+        Some(self.builder.build_config())
+    }
+}
+
+/// Reload the server config whenever the watched certificate files change
+///
+/// Checks the files every `interval` and applies rebuilt configs to the endpoint returned
+/// by `endpoint`. Failed rebuilds keep the current config and log a warning.
+///
+/// Runs until `endpoint` returns `None` (e.g. once the endpoint's handle is released) or
+/// the future is dropped.
+pub async fn watch_cert_files(
+    mut watcher: QuicCertWatcher,
+    interval: Duration,
+    endpoint: impl Fn() -> Option<QuicEndpoint>,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    // The first tick completes immediately
+    ticker.tick().await;
+
+    loop {
+        ticker.tick().await;
+        let Some(endpoint) = endpoint() else {
+            return;
+        };
+        match watcher.poll() {
+            Some(Ok(config)) => {
+                endpoint.set_server_config(Some(config));
+                tracing::info!(files = ?watcher.files(), "server certificate reloaded");
+            }
+            Some(Err(e)) => {
+                tracing::warn!(files = ?watcher.files(), error = %e, "failed to reload server certificate");
+            }
+            None => {}
+        }
+    }
+}
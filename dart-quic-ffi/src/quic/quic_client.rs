@@ -44,11 +44,15 @@
 //! ```
 
 use std::net::SocketAddr;
+use std::sync::Arc;
 
 use crate::error::QuicError;
 use super::quic_config::{QuicClientConfigBuilder, QuicTransportConfig};
-use super::quic_connection::QuicConnection;
+use super::quic_qlog::QuicQlog;
+use super::quic_keylog::{ffi_key_log, QuicFfiKeyLogMode};
+use super::quic_connection::{QuicConnection, QuicZeroRttHandshake};
 use super::quic_endpoint::QuicEndpoint;
+use super::quic_session_cache::QuicLruSessionCache;
 
 // ============================================================================
 // Client Endpoint Main Class
@@ -135,7 +139,7 @@ impl QuicClient {
     /// Connect to a server
     ///
     /// # Parameters
-    /// - `server_addr`: Server address in "host:port" format
+    /// - `server_addr`: Server address in "host:port" format (host may be a host name)
     /// - `server_name`: Server name (used for TLS SNI and certificate verification)
     pub async fn connect(
         &self,
@@ -154,6 +158,18 @@ impl QuicClient {
         self.inner.connect_addr(server_addr, server_name).await
     }
 
+    /// Connect to a server, sending 0-RTT early data when a resumable session exists
+    ///
+    /// Requires early data to be enabled (`QuicClientConfigBuilder::with_early_data`).
+    /// See `QuicEndpoint::connect_0rtt` for details.
+    pub async fn connect_0rtt(
+        &self,
+        server_addr: &str,
+        server_name: &str,
+    ) -> Result<(QuicConnection, QuicZeroRttHandshake), QuicError> {
+        self.inner.connect_0rtt(server_addr, server_name).await
+    }
+
     // ========== Endpoint Information (delegated to inner) ==========
 
     /// Get the local bind address
@@ -220,6 +236,30 @@ impl QuicClient {
     }
 }
 
+/// FFI client handle
+///
+/// Returned by `dart_quic_client_new_async`; carries the registry handle of the
+/// new client. Free the structure with `dart_quic_client_handle_free` and the
+/// client with `dart_quic_client_free`.
+#[repr(C)]
+pub struct QuicClientHandle {
+    /// Client registry handle (pass to all subsequent client operations)
+    pub client: u64,
+    /// Local bind port
+    pub local_port: u16,
+}
+
+impl QuicClientHandle {
+    /// Register the client and describe it
+    pub fn new(client: QuicClient) -> Self {
+        let local_port = client.local_port();
+        Self {
+            client: crate::handle_registry::insert(client),
+            local_port,
+        }
+    }
+}
+
 // ============================================================================
 // FFI Client Configuration Structure (Unified Configuration)
 // ============================================================================
@@ -240,6 +280,20 @@ pub enum QuicFfiTrustMode {
     CustomCaDerFile = 4,
 }
 
+/// TLS session cache mode
+///
+/// Both modes keep sessions in memory only, so resumption and 0-RTT do not survive a
+/// process restart. A file-backed mode needs rustls to expose an encoding for TLS 1.3
+/// tickets (`Tls13ClientSessionValue` can only be built inside rustls 0.23).
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuicFfiSessionCacheMode {
+    /// rustls built-in in-memory cache
+    Default = 0,
+    /// Bounded in-memory LRU cache (`session_cache_capacity` servers)
+    Memory = 1,
+}
+
 /// Client certificate mode (mTLS)
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -339,9 +393,43 @@ pub struct QuicFfiClientConfig {
     // ===== Bind Address =====
     /// Local bind address (optional, NULL or empty string uses "0.0.0.0:0")
     pub bind_addr: *const std::os::raw::c_char,
+
+    // ===== Session Resumption =====
+    /// Enable 0-RTT early data on resumed sessions (used by `dart_quic_client_connect_0rtt`)
+    pub enable_early_data: bool,
+    /// Session cache mode
+    pub session_cache_mode: QuicFfiSessionCacheMode,
+    /// Maximum number of servers kept in the session cache (0 uses default 256)
+    pub session_cache_capacity: u32,
+
+    // ===== Debugging =====
+    /// TLS key log mode (for decrypting packet captures; default Disabled)
+    pub key_log_mode: QuicFfiKeyLogMode,
+    /// Key log file path (for File mode), UTF-8 encoded C string
+    pub key_log_path: *const std::os::raw::c_char,
 }
 
 impl QuicFfiClientConfig {
+    /// Create the session store selected by `session_cache_mode` (None for rustls default)
+    fn session_store(&self) -> Option<Arc<dyn rustls::client::ClientSessionStore>> {
+        match self.session_cache_mode {
+            QuicFfiSessionCacheMode::Default => None,
+            QuicFfiSessionCacheMode::Memory => {
+                Some(Arc::new(QuicLruSessionCache::new(self.session_cache_capacity as usize)))
+            }
+        }
+    }
+
+    /// qlog output configured in `transport_config`, if any
+    pub fn qlog(&self) -> Option<QuicQlog> {
+        if self.transport_config.is_null() {
+            return None;
+        }
+        // An invalid transport config fails build_quinn_config instead
+        let transport = unsafe { QuicTransportConfig::from_ffi_ptr(self.transport_config) }.ok()?;
+        QuicQlog::from_transport(&transport)
+    }
+
     /// Build Quinn ClientConfig from FFI configuration (without creating endpoint)
     ///
     /// This method builds only the `quinn::ClientConfig` which can be used
@@ -429,11 +517,21 @@ impl QuicFfiClientConfig {
         
         // Configure transport parameters
         if !self.transport_config.is_null() {
-            let ffi_config = unsafe { &*self.transport_config };
-            let config = QuicTransportConfig::from(ffi_config);
+            let config = unsafe { QuicTransportConfig::from_ffi_ptr(self.transport_config) }?;
             builder = builder.with_transport_config(config);
         }
         
+        // Configure 0-RTT early data
+        builder = builder.with_early_data(self.enable_early_data);
+        if let Some(store) = self.session_store() {
+            builder = builder.with_session_store(store);
+        }
+
+        // Configure TLS key logging
+        if let Some(key_log) = ffi_key_log(self.key_log_mode, self.key_log_path)? {
+            builder = builder.with_key_log(key_log);
+        }
+        
         // Build Quinn config (without binding to address)
         builder.build_config()
     }
@@ -522,11 +620,21 @@ impl QuicFfiClientConfig {
         
         // Configure transport parameters
         if !self.transport_config.is_null() {
-            let ffi_config = unsafe { &*self.transport_config };
-            let config = QuicTransportConfig::from(ffi_config);
+            let config = unsafe { QuicTransportConfig::from_ffi_ptr(self.transport_config) }?;
             builder = builder.with_transport_config(config);
         }
         
+        // Configure 0-RTT early data
+        builder = builder.with_early_data(self.enable_early_data);
+        if let Some(store) = self.session_store() {
+            builder = builder.with_session_store(store);
+        }
+
+        // Configure TLS key logging
+        if let Some(key_log) = ffi_key_log(self.key_log_mode, self.key_log_path)? {
+            builder = builder.with_key_log(key_log);
+        }
+        
         // Get bind address
         let bind_addr = if self.bind_addr.is_null() {
             "0.0.0.0:0"
@@ -557,6 +665,11 @@ impl Default for QuicFfiClientConfig {
             client_key_path: std::ptr::null(),
             transport_config: std::ptr::null(),
             bind_addr: std::ptr::null(),
+            enable_early_data: false,
+            session_cache_mode: QuicFfiSessionCacheMode::Default,
+            session_cache_capacity: 0,
+            key_log_mode: QuicFfiKeyLogMode::Disabled,
+            key_log_path: std::ptr::null(),
         }
     }
 }
@@ -4,7 +4,8 @@
 //! Design goals: simple, secure, and easy FFI binding.
 
 use std::fs;
-use std::path::Path;
+use std::ffi::{c_char, CStr};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -13,6 +14,8 @@ use rustls::RootCertStore;
 
 use crate::error::QuicError;
 use crate::types::QuicResult;
+use super::quic_qlog::QuicQlog;
+use super::quic_sni::QuicSniResolver;
 
 // ============================================================================
 // Certificate Source (Internal Use)
@@ -29,6 +32,27 @@ pub(crate) enum CertificateSource {
     FromMemory { cert_der: Vec<u8>, key_der: Vec<u8> },
 }
 
+impl CertificateSource {
+    /// Load (or generate) the certificate chain and private key
+    pub(crate) fn load(&self) -> Result<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>), QuicError> {
+        match self {
+            CertificateSource::SelfSigned { subject_alt_names } => {
+                generate_self_signed(subject_alt_names.clone())
+            }
+            CertificateSource::FromFile { cert_path, key_path } => {
+                let certs = load_certs_from_pem(Path::new(cert_path))?;
+                let key = load_key_from_pem(Path::new(key_path))?;
+                Ok((certs, key))
+            }
+            CertificateSource::FromMemory { cert_der, key_der } => {
+                let cert = load_cert_from_der(cert_der.clone());
+                let key = load_key_from_der(key_der.clone());
+                Ok((vec![cert], key))
+            }
+        }
+    }
+}
+
 /// Trust source (for clients)
 #[derive(Clone)]
 pub(crate) enum TrustSource {
@@ -221,6 +245,8 @@ pub struct QuicTransportConfig {
     pub allow_spin: bool,
     /// Whether to enable GSO (default true)
     pub enable_segmentation_offload: bool,
+    /// Directory for per-connection qlog traces (default None, disabled)
+    pub qlog_dir: Option<PathBuf>,
 
     // ===== ACK Frequency (Optional) =====
     /// ACK frequency configuration (requires peer support for extension)
@@ -272,6 +298,7 @@ impl Default for QuicTransportConfig {
             // Privacy and debugging
             allow_spin: true,
             enable_segmentation_offload: true,
+            qlog_dir: None,
 
             // ACK frequency
             ack_frequency: None,
@@ -421,6 +448,14 @@ impl QuicTransportConfig {
         self
     }
 
+    /// Write a qlog trace per connection into `dir` (None disables)
+    ///
+    /// See the `quic_qlog` module for file naming.
+    pub fn with_qlog_dir(mut self, dir: Option<impl Into<PathBuf>>) -> Self {
+        self.qlog_dir = dir.map(Into::into);
+        self
+    }
+
     // ===== ACK Frequency =====
 
     /// Set ACK frequency configuration
@@ -699,6 +734,10 @@ pub struct QuicClientConfigBuilder {
     transport_config: QuicTransportConfig,
     client_cert: Option<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)>,
     bind_addr: std::net::SocketAddr,
+    enable_early_data: bool,
+    session_store: Option<Arc<dyn rustls::client::ClientSessionStore>>,
+    resolver: Option<Arc<dyn super::quic_resolver::QuicResolver>>,
+    key_log: Option<Arc<dyn rustls::KeyLog>>,
 }
 
 impl QuicClientConfigBuilder {
@@ -709,6 +748,10 @@ impl QuicClientConfigBuilder {
             transport_config: QuicTransportConfig::default(),
             client_cert: None,
             bind_addr: "0.0.0.0:0".parse().unwrap(),
+            enable_early_data: false,
+            session_store: None,
+            resolver: None,
+            key_log: None,
         }
     }
 
@@ -857,6 +900,50 @@ impl QuicClientConfigBuilder {
         self
     }
 
+    // ========== Session Resumption ==========
+
+    /// Enable sending 0-RTT early data on resumed sessions (default false)
+    ///
+    /// Required for `connect_0rtt` to skip the handshake round trip.
+    /// TLS session tickets received from servers are cached for resumption.
+    ///
+    /// ⚠️ 0-RTT data is replayable; only send idempotent requests as early data.
+    pub fn with_early_data(mut self, enabled: bool) -> Self {
+        self.enable_early_data = enabled;
+        self
+    }
+
+    /// Use a custom TLS session store for resumption (default: rustls in-memory cache)
+    ///
+    /// See `QuicLruSessionCache`. Share one store between clients to let them resume
+    /// each other's sessions.
+    pub fn with_session_store(mut self, store: Arc<dyn rustls::client::ClientSessionStore>) -> Self {
+        self.session_store = Some(store);
+        self
+    }
+
+    // ========== Debugging ==========
+
+    /// Log TLS secrets for decrypting packet captures (default: disabled)
+    ///
+    /// See `key_log_from_env` and `QuicKeyLogFile`.
+    ///
+    /// ⚠️ Anyone holding the key log can decrypt the logged connections.
+    pub fn with_key_log(mut self, key_log: Arc<dyn rustls::KeyLog>) -> Self {
+        self.key_log = Some(key_log);
+        self
+    }
+
+    // ========== Host Name Resolution ==========
+
+    /// Set the host name resolver used by `connect()` (default: system resolver)
+    ///
+    /// See `QuicStaticResolver` for a fixed host table.
+    pub fn with_resolver(mut self, resolver: Arc<dyn super::quic_resolver::QuicResolver>) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
     // ========== Bind Address ==========
 
     /// Set local bind address
@@ -921,6 +1008,13 @@ impl QuicClientConfigBuilder {
 
         let mut crypto_config = crypto_config;
         crypto_config.alpn_protocols = self.transport_config.alpn_protocols.clone();
+        crypto_config.enable_early_data = self.enable_early_data;
+        if let Some(store) = &self.session_store {
+            crypto_config.resumption = rustls::client::Resumption::store(store.clone());
+        }
+        if let Some(key_log) = &self.key_log {
+            crypto_config.key_log = key_log.clone();
+        }
 
         let quic_config = quinn::crypto::rustls::QuicClientConfig::try_from(crypto_config)
             .map_err(|e| QuicError::unknown(format!("QUIC config error: {}", e)))?;
@@ -965,9 +1059,14 @@ impl QuicClientConfigBuilder {
         
         let client_config = self.build_config()?;
         
-        let inner = QuicEndpoint::builder()
-            .with_client_config(client_config)
-            .bind_addr(addr)?;
+        let mut builder = QuicEndpoint::builder().with_client_config(client_config);
+        if let Some(resolver) = self.resolver {
+            builder = builder.with_resolver(resolver);
+        }
+        if let Some(qlog) = QuicQlog::from_transport(&self.transport_config) {
+            builder = builder.with_client_qlog(qlog);
+        }
+        let inner = builder.bind_addr(addr)?;
 
         Ok(super::quic_client::QuicClient::from_endpoint(inner))
     }
@@ -1002,19 +1101,37 @@ impl Default for QuicClientConfigBuilder {
 ///     .with_cert_pem_files("cert.pem", "key.pem")?
 ///     .bind("0.0.0.0:4433")?;
 ///
+/// // Multiple domains: per-name certificates, the one above as default
+/// let server = QuicServer::builder()
+///     .with_cert_pem_files("cert.pem", "key.pem")?
+///     .with_sni_cert_pem_files("*.example.com", "example.pem", "example.key")?
+///     .bind("0.0.0.0:4433")?;
+///
 /// // If only ServerConfig is needed (for QuicEndpoint)
 /// let config = QuicServer::builder()
 ///     .with_self_signed(&["localhost"])
 ///     .build_config()?;
 /// ```
+#[derive(Clone)]
 pub struct QuicServerConfigBuilder {
     cert_source: CertificateSource,
     transport_config: QuicTransportConfig,
     client_cert_mode: ClientCertMode,
     /// Whether certificate is configured (server must configure certificate)
     cert_configured: bool,
+    /// Whether to accept 0-RTT early data from resuming clients
+    zero_rtt: bool,
+    /// TLS secret log for decrypting packet captures
+    key_log: Option<Arc<dyn rustls::KeyLog>>,
+    /// Per-server-name certificates (SNI mode when non-empty)
+    sni_certs: Vec<(String, CertificateSource)>,
+    /// In SNI mode, fail handshakes for unknown names instead of serving `cert_source`
+    sni_reject_unknown: bool,
 }
 
+/// Number of TLS sessions kept for resumption when 0-RTT is enabled
+const SERVER_SESSION_CACHE_SIZE: usize = 1024;
+
 /// Client certificate verification mode
 #[derive(Clone)]
 enum ClientCertMode {
@@ -1036,6 +1153,10 @@ impl QuicServerConfigBuilder {
             transport_config: QuicTransportConfig::default(),
             client_cert_mode: ClientCertMode::NoClientAuth,
             cert_configured: true, // Default self-signed certificate
+            zero_rtt: false,
+            key_log: None,
+            sni_certs: Vec::new(),
+            sni_reject_unknown: false,
         }
     }
 
@@ -1074,6 +1195,55 @@ impl QuicServerConfigBuilder {
         self
     }
 
+    // ========== SNI Certificates ==========
+
+    /// Serve the certificate from PEM files to clients requesting `server_name`
+    ///
+    /// Enables SNI mode: the certificate is chosen by the client's requested server name,
+    /// and the single certificate configured above (`with_cert_*` / `with_self_signed`)
+    /// becomes the default for unknown names. `server_name` may be a wildcard such as
+    /// `*.example.com`; see `QuicSniResolver` for the matching rules.
+    pub fn with_sni_cert_pem_files(
+        mut self,
+        server_name: &str,
+        cert_path: &str,
+        key_path: &str,
+    ) -> Result<Self, QuicError> {
+        if !std::path::Path::new(cert_path).exists() {
+            return Err(QuicError::unknown(format!("Certificate file not found: {}", cert_path)));
+        }
+        if !std::path::Path::new(key_path).exists() {
+            return Err(QuicError::unknown(format!("Key file not found: {}", key_path)));
+        }
+        self.sni_certs.push((
+            server_name.to_string(),
+            CertificateSource::FromFile {
+                cert_path: cert_path.to_string(),
+                key_path: key_path.to_string(),
+            },
+        ));
+        Ok(self)
+    }
+
+    /// Serve the certificate from DER bytes to clients requesting `server_name`
+    ///
+    /// See `with_sni_cert_pem_files`.
+    pub fn with_sni_cert_der(mut self, server_name: &str, cert_der: Vec<u8>, key_der: Vec<u8>) -> Self {
+        self.sni_certs.push((
+            server_name.to_string(),
+            CertificateSource::FromMemory { cert_der, key_der },
+        ));
+        self
+    }
+
+    /// In SNI mode, fail handshakes for unknown or missing server names (default false)
+    ///
+    /// By default such clients get the builder's single certificate.
+    pub fn with_sni_reject_unknown(mut self, reject: bool) -> Self {
+        self.sni_reject_unknown = reject;
+        self
+    }
+
     // ========== Client Certificate Verification (mTLS) ==========
 
     /// Require client to provide certificate (enable mTLS)
@@ -1097,6 +1267,31 @@ impl QuicServerConfigBuilder {
         self
     }
 
+    // ========== Session Resumption ==========
+
+    /// Accept 0-RTT early data from resuming clients (default false)
+    ///
+    /// Enables TLS session ticket storage so clients can resume, and allows
+    /// 0.5-RTT data to be sent before the handshake completes.
+    ///
+    /// ⚠️ 0-RTT data is replayable; only process idempotent requests received as early data.
+    pub fn with_zero_rtt(mut self, enabled: bool) -> Self {
+        self.zero_rtt = enabled;
+        self
+    }
+
+    // ========== Debugging ==========
+
+    /// Log TLS secrets for decrypting packet captures (default: disabled)
+    ///
+    /// See `key_log_from_env` and `QuicKeyLogFile`.
+    ///
+    /// ⚠️ Anyone holding the key log can decrypt the logged connections.
+    pub fn with_key_log(mut self, key_log: Arc<dyn rustls::KeyLog>) -> Self {
+        self.key_log = Some(key_log);
+        self
+    }
+
     // ========== Transport Configuration ==========
 
     /// Set transport layer configuration
@@ -1111,34 +1306,15 @@ impl QuicServerConfigBuilder {
     ///
     /// Use this method if you need to directly obtain `quinn::ServerConfig` for `QuicEndpoint`.
     pub fn build_config(&self) -> Result<quinn::ServerConfig, QuicError> {
-        // Load or generate certificate
-        let (certs, key) = match &self.cert_source {
-            CertificateSource::SelfSigned { subject_alt_names } => {
-                generate_self_signed(subject_alt_names.clone())?
-            }
-            CertificateSource::FromFile { cert_path, key_path } => {
-                let certs = load_certs_from_pem(std::path::Path::new(cert_path))?;
-                let key = load_key_from_pem(std::path::Path::new(key_path))?;
-                (certs, key)
-            }
-            CertificateSource::FromMemory { cert_der, key_der } => {
-                let cert = load_cert_from_der(cert_der.clone());
-                let key = load_key_from_der(key_der.clone());
-                (vec![cert], key)
-            }
-        };
-
         // Build rustls ServerConfig (based on client certificate verification mode)
         let provider = Arc::new(rustls::crypto::ring::default_provider());
         
-        let crypto_config = match &self.client_cert_mode {
+        let crypto_builder = match &self.client_cert_mode {
             ClientCertMode::NoClientAuth => {
                 rustls::ServerConfig::builder_with_provider(provider)
                     .with_protocol_versions(&[&rustls::version::TLS13])
                     .map_err(|e| QuicError::unknown(format!("TLS config error: {}", e)))?
                     .with_no_client_auth()
-                    .with_single_cert(certs, key)
-                    .map_err(|e| QuicError::unknown(format!("Invalid certificate: {}", e)))?
             }
             ClientCertMode::Required { trusted_ca_der } => {
                 let ca_cert = load_cert_from_der(trusted_ca_der.clone());
@@ -1153,8 +1329,6 @@ impl QuicServerConfigBuilder {
                     .with_protocol_versions(&[&rustls::version::TLS13])
                     .map_err(|e| QuicError::unknown(format!("TLS config error: {}", e)))?
                     .with_client_cert_verifier(verifier)
-                    .with_single_cert(certs, key)
-                    .map_err(|e| QuicError::unknown(format!("Invalid certificate: {}", e)))?
             }
             ClientCertMode::Optional { trusted_ca_der } => {
                 let ca_cert = load_cert_from_der(trusted_ca_der.clone());
@@ -1170,13 +1344,29 @@ impl QuicServerConfigBuilder {
                     .with_protocol_versions(&[&rustls::version::TLS13])
                     .map_err(|e| QuicError::unknown(format!("TLS config error: {}", e)))?
                     .with_client_cert_verifier(verifier)
-                    .with_single_cert(certs, key)
-                    .map_err(|e| QuicError::unknown(format!("Invalid certificate: {}", e)))?
             }
         };
 
-        let mut crypto_config = crypto_config;
+        // Load or generate certificates (one, or one per server name in SNI mode)
+        let mut crypto_config = if self.sni_certs.is_empty() {
+            let (certs, key) = self.cert_source.load()?;
+            crypto_builder
+                .with_single_cert(certs, key)
+                .map_err(|e| QuicError::unknown(format!("Invalid certificate: {}", e)))?
+        } else {
+            crypto_builder.with_cert_resolver(Arc::new(self.build_sni_resolver()?))
+        };
         crypto_config.alpn_protocols = self.transport_config.alpn_protocols.clone();
+        if self.zero_rtt {
+            // QUIC only permits 0 or u32::MAX for max_early_data_size
+            crypto_config.max_early_data_size = u32::MAX;
+            crypto_config.send_half_rtt_data = true;
+            crypto_config.session_storage =
+                rustls::server::ServerSessionMemoryCache::new(SERVER_SESSION_CACHE_SIZE);
+        }
+        if let Some(key_log) = &self.key_log {
+            crypto_config.key_log = key_log.clone();
+        }
 
         let quic_config = quinn::crypto::rustls::QuicServerConfig::try_from(crypto_config)
             .map_err(|e| QuicError::unknown(format!("QUIC config error: {}", e)))?;
@@ -1191,6 +1381,35 @@ impl QuicServerConfigBuilder {
         Ok(server_config)
     }
 
+    /// PEM files the certificates are loaded from (including SNI certificates)
+    pub fn cert_files(&self) -> Vec<PathBuf> {
+        let default = (self.sni_certs.is_empty() || !self.sni_reject_unknown).then_some(&self.cert_source);
+        default
+            .into_iter()
+            .chain(self.sni_certs.iter().map(|(_, source)| source))
+            .filter_map(|source| match source {
+                CertificateSource::FromFile { cert_path, key_path } => Some([cert_path, key_path]),
+                _ => None,
+            })
+            .flatten()
+            .map(PathBuf::from)
+            .collect()
+    }
+
+    /// SNI resolver with the per-name certificates and (unless rejected) the default
+    fn build_sni_resolver(&self) -> Result<QuicSniResolver, QuicError> {
+        let mut resolver = QuicSniResolver::new();
+        for (server_name, source) in &self.sni_certs {
+            let (certs, key) = source.load()?;
+            resolver.add(server_name, certs, key)?;
+        }
+        if !self.sni_reject_unknown {
+            let (certs, key) = self.cert_source.load()?;
+            resolver.set_default(certs, key)?;
+        }
+        Ok(resolver)
+    }
+
     /// Bind to specified address and build QuicServer
     ///
     /// # Parameters
@@ -1221,9 +1440,11 @@ impl QuicServerConfigBuilder {
 
         let server_config = self.build_config()?;
         
-        let inner = QuicEndpoint::builder()
-            .with_server_config(server_config)
-            .bind_addr(addr)?;
+        let mut builder = QuicEndpoint::builder().with_server_config(server_config);
+        if let Some(qlog) = QuicQlog::from_transport(&self.transport_config) {
+            builder = builder.with_server_qlog(qlog);
+        }
+        let inner = builder.bind_addr(addr)?;
 
         Ok(super::quic_server::QuicServer::from_endpoint(inner))
     }
@@ -1241,18 +1462,31 @@ impl Default for QuicServerConfigBuilder {
 
 /// FFI-friendly transport configuration
 ///
+/// `struct_size` must be set to `sizeof(QuicFfiTransportConfig)` as seen by the caller.
+/// Fields are only ever appended, so a caller built against an older header passes a
+/// smaller size and the fields it doesn't know keep their defaults.
+///
+/// Fields documented with "0 = default" use the `QuicTransportConfig` default when zero.
+///
 /// # C Language Usage Example
 ///
 /// ```c
+/// const char *alpn[] = { "h3" };
 /// QuicFfiTransportConfig transport = {
+///     .struct_size = sizeof(QuicFfiTransportConfig),
 ///     .max_idle_timeout_ms = 30000,
 ///     .keep_alive_interval_ms = 15000,
+///     .alpn_protocols = alpn,
+///     .alpn_protocols_count = 1,
 ///     // ...
 /// };
 /// ```
 #[repr(C)]
 #[derive(Clone)]
 pub struct QuicFfiTransportConfig {
+    // ABI versioning: sizeof(QuicFfiTransportConfig) as known to the caller
+    pub struct_size: u32,
+
     // Connection management
     pub max_idle_timeout_ms: u64,
     pub keep_alive_interval_ms: u64, // 0 = disabled
@@ -1281,6 +1515,43 @@ pub struct QuicFfiTransportConfig {
     // Privacy
     pub allow_spin: bool,
     pub enable_gso: bool,
+
+    // Debugging: directory for per-connection qlog traces (null = disabled)
+    pub qlog_dir: *const c_char,
+
+    // Flow control (continued)
+    pub receive_window: u64, // 0 = default (unlimited)
+    pub send_fairness: bool,
+
+    // Loss detection (0 = default)
+    pub packet_threshold: u32,
+    pub time_threshold: f32,
+    pub persistent_congestion_threshold: u32,
+
+    // Crypto buffer size in bytes (0 = default)
+    pub crypto_buffer_size: u32,
+
+    // MTU discovery tuning, used when enable_mtu_discovery is set (0 = default)
+    pub mtu_discovery_interval_ms: u64,
+    pub mtu_discovery_upper_bound: u16,
+    pub mtu_discovery_minimum_change: u16,
+    pub mtu_black_hole_cooldown_ms: u64,
+
+    // ACK frequency extension (requires peer support)
+    pub enable_ack_frequency: bool,
+    pub ack_eliciting_threshold: u32,
+    pub ack_max_delay_ms: u64, // 0 = peer's max_ack_delay
+    pub ack_reordering_threshold: u32,
+
+    // ALPN protocols as UTF-8 C strings (null = default "h3", "hq-29"; count 0 = no ALPN)
+    pub alpn_protocols: *const *const c_char,
+    pub alpn_protocols_count: u32,
+}
+
+impl QuicFfiTransportConfig {
+    /// Size of the first versioned layout; smaller `struct_size` values are rejected
+    const MIN_SIZE: usize = std::mem::offset_of!(QuicFfiTransportConfig, alpn_protocols_count)
+        + std::mem::size_of::<u32>();
 }
 
 impl Default for QuicFfiTransportConfig {
@@ -1292,7 +1563,10 @@ impl Default for QuicFfiTransportConfig {
 
 impl From<&QuicTransportConfig> for QuicFfiTransportConfig {
     fn from(config: &QuicTransportConfig) -> Self {
+        let mtu = config.mtu_discovery.clone().unwrap_or_default();
+        let ack = config.ack_frequency.clone();
         Self {
+            struct_size: std::mem::size_of::<Self>() as u32,
             max_idle_timeout_ms: config.max_idle_timeout.as_millis() as u64,
             keep_alive_interval_ms: config.keep_alive_interval.map(|d| d.as_millis() as u64).unwrap_or(0),
             max_concurrent_bi_streams: config.max_concurrent_bi_streams,
@@ -1312,12 +1586,34 @@ impl From<&QuicTransportConfig> for QuicFfiTransportConfig {
             },
             allow_spin: config.allow_spin,
             enable_gso: config.enable_segmentation_offload,
+            // Not representable without owned C strings
+            qlog_dir: std::ptr::null(),
+            receive_window: config.receive_window,
+            send_fairness: config.send_fairness,
+            packet_threshold: config.packet_threshold,
+            time_threshold: config.time_threshold,
+            persistent_congestion_threshold: config.persistent_congestion_threshold,
+            crypto_buffer_size: config.crypto_buffer_size as u32,
+            mtu_discovery_interval_ms: mtu.interval.as_millis() as u64,
+            mtu_discovery_upper_bound: mtu.upper_bound,
+            mtu_discovery_minimum_change: mtu.minimum_change,
+            mtu_black_hole_cooldown_ms: mtu.black_hole_cooldown.as_millis() as u64,
+            enable_ack_frequency: ack.is_some(),
+            ack_eliciting_threshold: ack.as_ref().map_or(1, |a| a.ack_eliciting_threshold),
+            ack_max_delay_ms: ack.as_ref().and_then(|a| a.max_ack_delay).map_or(0, |d| d.as_millis() as u64),
+            ack_reordering_threshold: ack.as_ref().map_or(2, |a| a.reordering_threshold),
+            alpn_protocols: std::ptr::null(),
+            alpn_protocols_count: 0,
         }
     }
 }
 
 impl From<&QuicFfiTransportConfig> for QuicTransportConfig {
     fn from(ffi: &QuicFfiTransportConfig) -> Self {
+        let defaults = QuicTransportConfig::default();
+        let non_zero = |value: u32, default: u32| if value > 0 { value } else { default };
+        let non_zero_ms = |ms: u64, default: Duration| if ms > 0 { Duration::from_millis(ms) } else { default };
+
         Self {
             max_idle_timeout: Duration::from_millis(ffi.max_idle_timeout_ms),
             keep_alive_interval: if ffi.keep_alive_interval_ms > 0 {
@@ -1328,17 +1624,34 @@ impl From<&QuicFfiTransportConfig> for QuicTransportConfig {
             max_concurrent_bi_streams: ffi.max_concurrent_bi_streams,
             max_concurrent_uni_streams: ffi.max_concurrent_uni_streams,
             stream_receive_window: ffi.stream_receive_window,
-            receive_window: u64::MAX,
+            receive_window: if ffi.receive_window > 0 { ffi.receive_window } else { defaults.receive_window },
             send_window: ffi.send_window,
-            send_fairness: true,
+            send_fairness: ffi.send_fairness,
             initial_rtt: Duration::from_millis(ffi.initial_rtt_ms),
-            packet_threshold: 3,
-            time_threshold: 9.0 / 8.0,
-            persistent_congestion_threshold: 3,
+            packet_threshold: non_zero(ffi.packet_threshold, defaults.packet_threshold),
+            time_threshold: if ffi.time_threshold > 0.0 { ffi.time_threshold } else { defaults.time_threshold },
+            persistent_congestion_threshold: non_zero(
+                ffi.persistent_congestion_threshold,
+                defaults.persistent_congestion_threshold,
+            ),
             initial_mtu: ffi.initial_mtu,
             min_mtu: ffi.min_mtu,
             mtu_discovery: if ffi.enable_mtu_discovery {
-                Some(MtuDiscoveryConfig::default())
+                let mtu = MtuDiscoveryConfig::default();
+                Some(MtuDiscoveryConfig {
+                    interval: non_zero_ms(ffi.mtu_discovery_interval_ms, mtu.interval),
+                    upper_bound: if ffi.mtu_discovery_upper_bound > 0 {
+                        ffi.mtu_discovery_upper_bound.min(65527)
+                    } else {
+                        mtu.upper_bound
+                    },
+                    black_hole_cooldown: non_zero_ms(ffi.mtu_black_hole_cooldown_ms, mtu.black_hole_cooldown),
+                    minimum_change: if ffi.mtu_discovery_minimum_change > 0 {
+                        ffi.mtu_discovery_minimum_change
+                    } else {
+                        mtu.minimum_change
+                    },
+                })
             } else {
                 None
             },
@@ -1348,8 +1661,17 @@ impl From<&QuicFfiTransportConfig> for QuicTransportConfig {
                 None
             },
             datagram_send_buffer_size: ffi.datagram_send_buffer_size as usize,
-            alpn_protocols: vec![b"h3".to_vec(), b"hq-29".to_vec()],
-            crypto_buffer_size: 16 * 1024,
+            alpn_protocols: if ffi.alpn_protocols.is_null() {
+                defaults.alpn_protocols
+            } else {
+                (0..ffi.alpn_protocols_count as usize)
+                    .map(|i| unsafe { *ffi.alpn_protocols.add(i) })
+                    .filter(|protocol| !protocol.is_null())
+                    .map(|protocol| unsafe { CStr::from_ptr(protocol) }.to_bytes().to_vec())
+                    .filter(|protocol| !protocol.is_empty())
+                    .collect()
+            },
+            crypto_buffer_size: non_zero(ffi.crypto_buffer_size, defaults.crypto_buffer_size as u32) as usize,
             congestion_controller: match ffi.congestion_controller {
                 0 => CongestionControllerType::Cubic,
                 1 => CongestionControllerType::NewReno,
@@ -1358,8 +1680,51 @@ impl From<&QuicFfiTransportConfig> for QuicTransportConfig {
             },
             allow_spin: ffi.allow_spin,
             enable_segmentation_offload: ffi.enable_gso,
-            ack_frequency: None,
+            qlog_dir: if ffi.qlog_dir.is_null() {
+                None
+            } else {
+                Some(PathBuf::from(unsafe { CStr::from_ptr(ffi.qlog_dir) }.to_string_lossy().into_owned()))
+            },
+            ack_frequency: ffi.enable_ack_frequency.then(|| AckFrequencyConfig {
+                ack_eliciting_threshold: ffi.ack_eliciting_threshold,
+                max_ack_delay: (ffi.ack_max_delay_ms > 0).then(|| Duration::from_millis(ffi.ack_max_delay_ms)),
+                reordering_threshold: ffi.ack_reordering_threshold,
+            }),
+        }
+    }
+}
+
+impl QuicTransportConfig {
+    /// Convert a caller-provided FFI config, honoring its `struct_size`
+    ///
+    /// Only the first `struct_size` bytes are read; later fields keep their defaults.
+    ///
+    /// # Safety
+    /// `config` must be non-null and point to at least `struct_size` readable bytes, with
+    /// valid C strings in its string fields.
+    pub(crate) unsafe fn from_ffi_ptr(config: *const QuicFfiTransportConfig) -> Result<Self, QuicError> {
+        let size = unsafe { config.cast::<u32>().read_unaligned() } as usize;
+        if size < QuicFfiTransportConfig::MIN_SIZE {
+            return Err(QuicError::with_message(
+                QuicResult::InvalidParameter,
+                format!(
+                    "Invalid QuicFfiTransportConfig struct_size {} (expected at least {})",
+                    size,
+                    QuicFfiTransportConfig::MIN_SIZE
+                ),
+            ));
+        }
+
+        let mut ffi = QuicFfiTransportConfig::default();
+        let len = size.min(std::mem::size_of::<QuicFfiTransportConfig>());
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                config.cast::<u8>(),
+                (&mut ffi as *mut QuicFfiTransportConfig).cast::<u8>(),
+                len,
+            );
         }
+        Ok(Self::from(&ffi))
     }
 }
 
@@ -2,14 +2,21 @@
 //!
 //! Standalone connection module, used by both client and server.
 
+use std::collections::HashMap;
+use std::future::Future;
 use std::net::SocketAddr;
+use std::sync::{Arc, Weak};
 
+use parking_lot::Mutex;
 use quinn::{Connection, RecvStream, SendStream};
 use rustls::pki_types::CertificateDer;
 
 use crate::error::QuicError;
 use crate::types::QuicResult;
 
+/// Error code and reason recorded by a local `close()` call
+type LocalClose = Arc<Mutex<Option<(u64, Vec<u8>)>>>;
+
 // ============================================================================
 // QUIC Connection
 // ============================================================================
@@ -22,13 +29,22 @@ use crate::types::QuicResult;
 /// # Thread Safety
 /// `QuicConnection` is thread-safe and can be safely used across multiple threads.
 pub struct QuicConnection {
-    inner: Connection,
+    /// Shared so the owning endpoint's registry can observe it via `Weak`
+    inner: Arc<Connection>,
+    /// Application code and reason passed to `close()` (quinn only reports `LocallyClosed`)
+    local_close: LocalClose,
+    /// Dropped with this wrapper; lets close watchers release the connection
+    alive: tokio::sync::watch::Sender<()>,
 }
 
 impl QuicConnection {
     /// Create from quinn::Connection
     pub(crate) fn new(connection: Connection) -> Self {
-        Self { inner: connection }
+        Self {
+            inner: Arc::new(connection),
+            local_close: Arc::new(Mutex::new(None)),
+            alive: tokio::sync::watch::Sender::new(()),
+        }
     }
 
     /// Get remote address
@@ -107,6 +123,13 @@ impl QuicConnection {
         self.inner.rtt()
     }
 
+    /// Get connection statistics snapshot
+    ///
+    /// Includes path (RTT, congestion window, loss), frame counters and UDP traffic counters.
+    pub fn stats(&self) -> quinn::ConnectionStats {
+        self.inner.stats()
+    }
+
     /// Get peer certificate (if available)
     ///
     /// In mutual authentication (mTLS) scenarios, servers can obtain client certificates.
@@ -117,12 +140,31 @@ impl QuicConnection {
             .map(|certs| (*certs).clone())
     }
 
+    /// Get the TLS handshake results
+    ///
+    /// Available once the handshake has progressed far enough (always for accepted
+    /// connections and completed `connect()` calls; may be `None` right after a 0-RTT connect).
+    pub fn handshake_data(&self) -> Option<QuicHandshakeData> {
+        let data = self
+            .inner
+            .handshake_data()?
+            .downcast::<quinn::crypto::rustls::HandshakeData>()
+            .ok()?;
+        Some(QuicHandshakeData {
+            alpn: data.protocol,
+            server_name: data.server_name,
+        })
+    }
+
     /// Close connection
     ///
     /// # Parameters
     /// - `error_code`: Application error code
     /// - `reason`: Close reason (UTF-8 string)
     pub fn close(&self, error_code: u32, reason: &[u8]) {
+        self.local_close
+            .lock()
+            .get_or_insert_with(|| (error_code as u64, reason.to_vec()));
         self.inner.close(error_code.into(), reason);
     }
 
@@ -131,6 +173,41 @@ impl QuicConnection {
         self.inner.closed().await
     }
 
+    /// Wait for the connection to close and describe why
+    pub async fn wait_close_reason(&self) -> QuicCloseReason {
+        let error = self.inner.closed().await;
+        QuicCloseReason::new(error, self.local_close.lock().as_ref())
+    }
+
+    /// Future resolving with the close reason, independent of `self`'s lifetime
+    ///
+    /// Does not keep the connection open: once this `QuicConnection` is dropped the
+    /// connection is closed with application code 0 (like dropping a `quinn::Connection`)
+    /// and the future resolves with `LocallyClosed`.
+    pub fn watch_close_reason(&self) -> impl Future<Output = QuicCloseReason> + Send + 'static {
+        let inner = self.inner.clone();
+        let local_close = self.local_close.clone();
+        let mut alive = self.alive.subscribe();
+
+        async move {
+            let error = tokio::select! {
+                error = inner.closed() => error,
+                // Never sent; only resolves when the owning `QuicConnection` is dropped
+                _ = alive.changed() => {
+                    inner.close(0u32.into(), b"");
+                    inner.closed().await
+                }
+            };
+            QuicCloseReason::new(error, local_close.lock().as_ref())
+        }
+    }
+
+    /// Why the connection closed, or `None` while it is still open
+    pub fn close_reason(&self) -> Option<QuicCloseReason> {
+        let error = self.inner.close_reason()?;
+        Some(QuicCloseReason::new(error, self.local_close.lock().as_ref()))
+    }
+
     /// Get internal quinn::Connection reference
     pub fn inner(&self) -> &Connection {
         &self.inner
@@ -138,7 +215,322 @@ impl QuicConnection {
 
     /// Consume and return internal quinn::Connection
     pub fn into_inner(self) -> Connection {
-        self.inner
+        Arc::try_unwrap(self.inner).unwrap_or_else(|shared| (*shared).clone())
+    }
+}
+
+// ============================================================================
+// Close Reason
+// ============================================================================
+
+/// How a connection was closed
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuicCloseKind {
+    /// Peer closed with a transport error code (CONNECTION_CLOSE 0x1c)
+    TransportClosed = 0,
+    /// Peer closed with an application error code (CONNECTION_CLOSE 0x1d)
+    ApplicationClosed = 1,
+    /// Closed locally via `close()`
+    LocallyClosed = 2,
+    /// Idle timeout expired
+    TimedOut = 3,
+    /// Peer sent a stateless reset
+    Reset = 4,
+    /// Peer does not support our QUIC version
+    VersionMismatch = 5,
+    /// Local transport error detected (protocol violation by the peer, TLS failure, ...)
+    TransportError = 6,
+    /// Ran out of connection IDs
+    CidsExhausted = 7,
+}
+
+/// Why a connection closed
+///
+/// Keeps the full `quinn::ConnectionError` alongside the flattened fields used over FFI.
+#[derive(Clone, Debug)]
+pub struct QuicCloseReason {
+    /// Close kind
+    pub kind: QuicCloseKind,
+    /// Transport error code (`TransportClosed` / `TransportError`) or application
+    /// error code (`ApplicationClosed`, or `LocallyClosed` via `close()`); 0 otherwise
+    pub error_code: u64,
+    /// Reason bytes sent with CONNECTION_CLOSE (or the local reason / error description)
+    pub reason: Vec<u8>,
+    /// Whether the remote peer initiated the close
+    pub remote: bool,
+    /// Original quinn error
+    pub error: quinn::ConnectionError,
+}
+
+impl QuicCloseReason {
+    fn new(error: quinn::ConnectionError, local_close: Option<&(u64, Vec<u8>)>) -> Self {
+        use quinn::ConnectionError as E;
+
+        let (kind, error_code, reason, remote) = match &error {
+            E::ConnectionClosed(close) => (
+                QuicCloseKind::TransportClosed,
+                u64::from(close.error_code),
+                close.reason.to_vec(),
+                true,
+            ),
+            E::ApplicationClosed(close) => (
+                QuicCloseKind::ApplicationClosed,
+                close.error_code.into_inner(),
+                close.reason.to_vec(),
+                true,
+            ),
+            E::LocallyClosed => {
+                let (code, reason) = local_close.cloned().unwrap_or_default();
+                (QuicCloseKind::LocallyClosed, code, reason, false)
+            }
+            E::TimedOut => (QuicCloseKind::TimedOut, 0, Vec::new(), false),
+            E::Reset => (QuicCloseKind::Reset, 0, Vec::new(), true),
+            E::VersionMismatch => (QuicCloseKind::VersionMismatch, 0, Vec::new(), true),
+            E::TransportError(e) => (
+                QuicCloseKind::TransportError,
+                u64::from(e.code),
+                e.reason.clone().into_bytes(),
+                false,
+            ),
+            E::CidsExhausted => (QuicCloseKind::CidsExhausted, 0, Vec::new(), false),
+        };
+
+        Self { kind, error_code, reason, remote, error }
+    }
+
+    /// Lossy result code (same mapping as `QuicError::from(quinn::ConnectionError)`)
+    pub fn result_code(&self) -> QuicResult {
+        QuicError::from(self.error.clone()).code()
+    }
+}
+
+/// Connection close reason (for C API)
+///
+/// Delivered by `dart_quic_connection_on_closed`; free with `dart_quic_close_reason_free`.
+#[repr(C)]
+pub struct QuicFfiCloseReason {
+    /// Close kind
+    pub kind: QuicCloseKind,
+    /// Whether the remote peer initiated the close
+    pub remote: bool,
+    /// Lossy `QuicResult` code for the close
+    pub result_code: i32,
+    /// Transport or application error code (see `QuicCloseReason::error_code`)
+    pub error_code: u64,
+    /// Reason bytes (null if empty, owned by this struct)
+    pub reason: *mut u8,
+    /// Reason length
+    pub reason_len: usize,
+}
+
+impl QuicFfiCloseReason {
+    /// Build from a close reason, copying the reason bytes into FFI memory
+    pub fn new(close: &QuicCloseReason) -> Self {
+        let (reason, reason_len) = if close.reason.is_empty() {
+            (std::ptr::null_mut(), 0)
+        } else {
+            let ptr = crate::allocate(close.reason.len());
+            if ptr.is_null() {
+                (std::ptr::null_mut(), 0)
+            } else {
+                unsafe {
+                    std::ptr::copy_nonoverlapping(close.reason.as_ptr(), ptr, close.reason.len());
+                }
+                (ptr, close.reason.len())
+            }
+        };
+
+        Self {
+            kind: close.kind,
+            remote: close.remote,
+            result_code: close.result_code() as i32,
+            error_code: close.error_code,
+            reason,
+            reason_len,
+        }
+    }
+}
+
+impl Drop for QuicFfiCloseReason {
+    fn drop(&mut self) {
+        if !self.reason.is_null() {
+            crate::deallocate(self.reason, self.reason_len);
+        }
+    }
+}
+
+// ============================================================================
+// TLS Handshake Data
+// ============================================================================
+
+/// TLS handshake results of a connection
+///
+/// quinn's rustls session does not expose the negotiated cipher suite or whether the
+/// session was resumed, so only ALPN and SNI are available.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QuicHandshakeData {
+    /// Negotiated application protocol (None if ALPN was not used)
+    pub alpn: Option<Vec<u8>>,
+    /// Server name (SNI) sent by the client; always None for outgoing connections
+    pub server_name: Option<String>,
+}
+
+/// TLS handshake results (for C API)
+///
+/// Strings are owned by this struct and null when absent.
+#[repr(C)]
+pub struct QuicFfiHandshakeData {
+    /// Negotiated ALPN protocol bytes
+    pub alpn: *mut u8,
+    /// ALPN protocol length
+    pub alpn_len: u32,
+    /// SNI server name (UTF-8, server side only)
+    pub server_name: *mut u8,
+    /// Server name length
+    pub server_name_len: u32,
+}
+
+impl QuicFfiHandshakeData {
+    /// Build from handshake data, copying the strings into FFI memory
+    pub fn new(data: Option<&QuicHandshakeData>) -> Self {
+        let (alpn, alpn_len) = copy_to_ffi(data.and_then(|d| d.alpn.as_deref()));
+        let (server_name, server_name_len) =
+            copy_to_ffi(data.and_then(|d| d.server_name.as_deref()).map(str::as_bytes));
+        Self { alpn, alpn_len, server_name, server_name_len }
+    }
+}
+
+impl Drop for QuicFfiHandshakeData {
+    fn drop(&mut self) {
+        if !self.alpn.is_null() {
+            crate::deallocate(self.alpn, self.alpn_len as usize);
+        }
+        if !self.server_name.is_null() {
+            crate::deallocate(self.server_name, self.server_name_len as usize);
+        }
+    }
+}
+
+/// Copy bytes into FFI memory (null for None, empty input or allocation failure)
+fn copy_to_ffi(bytes: Option<&[u8]>) -> (*mut u8, u32) {
+    let Some(bytes) = bytes.filter(|b| !b.is_empty()) else {
+        return (std::ptr::null_mut(), 0);
+    };
+    let ptr = crate::allocate(bytes.len());
+    if ptr.is_null() {
+        return (std::ptr::null_mut(), 0);
+    }
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr, bytes.len());
+    }
+    (ptr, bytes.len() as u32)
+}
+
+// ============================================================================
+// 0-RTT / 0.5-RTT Handshake Tracking
+// ============================================================================
+
+/// Outcome of an early data (0-RTT / 0.5-RTT) attempt
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuicZeroRttStatus {
+    /// Early data was not possible (no resumable session); the full handshake was awaited
+    NotAttempted = 0,
+    /// Handshake completed and early data was accepted by the peer
+    Accepted = 1,
+    /// Handshake completed but early data was rejected by the peer
+    ///
+    /// Streams opened and data sent before the handshake completed are lost;
+    /// operations on them return `ZeroRttRejected`.
+    Rejected = 2,
+}
+
+/// Pending completion of a 0-RTT / 0.5-RTT handshake
+///
+/// Returned alongside a connection by `QuicEndpoint::connect_0rtt` and `QuicEndpoint::accept_0rtt`.
+/// The connection is usable immediately; await `wait()` to learn whether early data was accepted.
+///
+/// For incoming connections (0.5-RTT) the outcome is always `Accepted`.
+pub struct QuicZeroRttHandshake {
+    accepted: Option<quinn::ZeroRttAccepted>,
+}
+
+impl QuicZeroRttHandshake {
+    /// Early data is in flight, outcome is known when the handshake completes
+    pub(crate) fn pending(accepted: quinn::ZeroRttAccepted) -> Self {
+        Self { accepted: Some(accepted) }
+    }
+
+    /// Early data was not possible, the handshake has already completed
+    pub(crate) fn not_attempted() -> Self {
+        Self { accepted: None }
+    }
+
+    /// Whether early data was attempted on this connection
+    pub fn is_attempted(&self) -> bool {
+        self.accepted.is_some()
+    }
+
+    /// Wait for the handshake to complete and report the early data outcome
+    pub async fn wait(self) -> QuicZeroRttStatus {
+        match self.accepted {
+            Some(accepted) => {
+                if accepted.await {
+                    QuicZeroRttStatus::Accepted
+                } else {
+                    QuicZeroRttStatus::Rejected
+                }
+            }
+            None => QuicZeroRttStatus::NotAttempted,
+        }
+    }
+}
+
+// ============================================================================
+// Connection Registry
+// ============================================================================
+
+/// Registry of connections created on an endpoint
+///
+/// Holds weak references only, so registering a connection never keeps it alive:
+/// once the owning `QuicConnection` is dropped (or the connection is closed),
+/// the entry is pruned on the next access.
+///
+/// Cloning shares the underlying registry (used by `QuicEndpoint` clones).
+#[derive(Clone, Default)]
+pub(crate) struct ConnectionRegistry {
+    connections: Arc<Mutex<HashMap<usize, Weak<Connection>>>>,
+}
+
+impl ConnectionRegistry {
+    /// Create an empty registry
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a connection
+    pub(crate) fn register(&self, connection: &QuicConnection) {
+        let mut connections = self.connections.lock();
+        Self::prune(&mut connections);
+        connections.insert(connection.stable_id(), Arc::downgrade(&connection.inner));
+    }
+
+    /// Get stable IDs of all live connections (sorted ascending)
+    pub(crate) fn stable_ids(&self) -> Vec<usize> {
+        let mut connections = self.connections.lock();
+        Self::prune(&mut connections);
+        let mut ids: Vec<usize> = connections.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Remove entries whose connection was dropped or closed
+    fn prune(connections: &mut HashMap<usize, Weak<Connection>>) {
+        connections.retain(|_, weak| {
+            weak.upgrade()
+                .is_some_and(|conn| conn.close_reason().is_none())
+        });
     }
 }
 
@@ -148,80 +540,248 @@ impl QuicConnection {
 
 /// Connection handle (for C API)
 ///
-/// FFI-friendly structure that wraps a QuicConnection pointer along with
-/// commonly used connection information. This allows the Dart layer to
+/// FFI-friendly structure carrying the registry handle of a new connection along
+/// with commonly used connection information. This allows the Dart layer to
 /// directly access connection info without additional FFI calls.
 ///
 /// # Memory Management
-/// - `connection`: Owned pointer, must be freed via `dart_quic_connection_handle_free`
-/// - `remote_addr`: Owned string pointer, freed together with handle
+/// - `connection`: Registry handle, released via `dart_quic_connection_free`
+/// - `remote_addr`, `handshake`: Owned strings, freed together with this structure
+///   by `dart_quic_connection_handle_free`
+///
+/// The structure and the connection have independent lifetimes: the structure
+/// can be freed as soon as its fields have been read.
 ///
 /// # C API Usage
 /// ```c
 /// QuicConnectionHandle* handle = ...;
 /// printf("Connected to: %.*s\n", (int)handle->remote_addr_len, handle->remote_addr);
-/// // Use handle->connection for stream operations
+/// printf("ALPN: %.*s\n", (int)handle->handshake.alpn_len, handle->handshake.alpn);
+/// uint64_t connection = handle->connection;
 /// dart_quic_connection_handle_free(handle);
+/// // Use connection for stream operations, then
+/// dart_quic_connection_free(connection);
 /// ```
 #[repr(C)]
 pub struct QuicConnectionHandle {
-    /// Connection pointer (for subsequent operations)
-    pub connection: *mut QuicConnection,
+    /// Connection registry handle (for subsequent operations)
+    pub connection: u64,
     /// Connection stable ID (unique identifier)
     pub stable_id: u64,
     /// Remote address string (IP:Port format, allocated memory)
     pub remote_addr: *mut u8,
     /// Remote address string length
     pub remote_addr_len: u32,
+    /// TLS handshake results at the time the handle was created
+    pub handshake: QuicFfiHandshakeData,
 }
 
 impl QuicConnectionHandle {
     /// Create a new connection handle from QuicConnection
     ///
-    /// Takes ownership of the connection and allocates remote address string.
+    /// Registers the connection and allocates remote address string.
     pub fn new(connection: QuicConnection) -> Self {
         let remote_addr_str = connection.remote_address().to_string();
         let stable_id = connection.stable_id() as u64;
         
         // Allocate and copy remote address string
-        let remote_addr_bytes = remote_addr_str.as_bytes();
-        let remote_addr = crate::allocate(remote_addr_bytes.len());
-        let remote_addr_len = if !remote_addr.is_null() {
-            unsafe {
-                std::ptr::copy_nonoverlapping(
-                    remote_addr_bytes.as_ptr(),
-                    remote_addr,
-                    remote_addr_bytes.len()
-                );
-            }
-            remote_addr_bytes.len() as u32
-        } else {
-            0
-        };
-        
-        // Box the connection and get raw pointer
-        let connection_ptr = Box::into_raw(Box::new(connection));
+        let (remote_addr, remote_addr_len) = copy_to_ffi(Some(remote_addr_str.as_bytes()));
+        let handshake = QuicFfiHandshakeData::new(connection.handshake_data().as_ref());
         
         Self {
-            connection: connection_ptr,
+            connection: crate::handle_registry::insert(connection),
             stable_id,
             remote_addr,
             remote_addr_len,
+            handshake,
         }
     }
     
     /// Create a null/invalid handle
     pub fn null() -> Self {
         Self {
-            connection: std::ptr::null_mut(),
+            connection: 0,
             stable_id: 0,
             remote_addr: std::ptr::null_mut(),
             remote_addr_len: 0,
+            handshake: QuicFfiHandshakeData::new(None),
         }
     }
     
     /// Check if handle is valid
     pub fn is_valid(&self) -> bool {
-        !self.connection.is_null()
+        self.connection != 0
+    }
+}
+
+// ============================================================================
+// FFI-Friendly Connection Statistics
+// ============================================================================
+
+/// UDP traffic statistics (for C API)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuicFfiUdpStats {
+    /// Number of UDP datagrams
+    pub datagrams: u64,
+    /// Total bytes carried inside UDP datagrams
+    pub bytes: u64,
+    /// Number of I/O operations (may be less than `datagrams` with GSO/GRO)
+    pub ios: u64,
+}
+
+impl From<quinn::UdpStats> for QuicFfiUdpStats {
+    fn from(stats: quinn::UdpStats) -> Self {
+        Self {
+            datagrams: stats.datagrams,
+            bytes: stats.bytes,
+            ios: stats.ios,
+        }
+    }
+}
+
+/// Frame counters per frame type (for C API)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuicFfiFrameStats {
+    pub acks: u64,
+    pub ack_frequency: u64,
+    pub crypto: u64,
+    pub connection_close: u64,
+    pub data_blocked: u64,
+    pub datagram: u64,
+    pub handshake_done: u64,
+    pub immediate_ack: u64,
+    pub max_data: u64,
+    pub max_stream_data: u64,
+    pub max_streams_bidi: u64,
+    pub max_streams_uni: u64,
+    pub new_connection_id: u64,
+    pub new_token: u64,
+    pub path_challenge: u64,
+    pub path_response: u64,
+    pub ping: u64,
+    pub reset_stream: u64,
+    pub retire_connection_id: u64,
+    pub stream_data_blocked: u64,
+    pub streams_blocked_bidi: u64,
+    pub streams_blocked_uni: u64,
+    pub stop_sending: u64,
+    pub stream: u64,
+}
+
+impl From<quinn::FrameStats> for QuicFfiFrameStats {
+    fn from(stats: quinn::FrameStats) -> Self {
+        Self {
+            acks: stats.acks,
+            ack_frequency: stats.ack_frequency,
+            crypto: stats.crypto,
+            connection_close: stats.connection_close,
+            data_blocked: stats.data_blocked,
+            datagram: stats.datagram,
+            handshake_done: stats.handshake_done as u64,
+            immediate_ack: stats.immediate_ack,
+            max_data: stats.max_data,
+            max_stream_data: stats.max_stream_data,
+            max_streams_bidi: stats.max_streams_bidi,
+            max_streams_uni: stats.max_streams_uni,
+            new_connection_id: stats.new_connection_id,
+            new_token: stats.new_token,
+            path_challenge: stats.path_challenge,
+            path_response: stats.path_response,
+            ping: stats.ping,
+            reset_stream: stats.reset_stream,
+            retire_connection_id: stats.retire_connection_id,
+            stream_data_blocked: stats.stream_data_blocked,
+            streams_blocked_bidi: stats.streams_blocked_bidi,
+            streams_blocked_uni: stats.streams_blocked_uni,
+            stop_sending: stats.stop_sending,
+            stream: stats.stream,
+        }
+    }
+}
+
+/// Transmission path statistics (for C API)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuicFfiPathStats {
+    /// Current RTT estimate (microseconds)
+    pub rtt_us: u64,
+    /// Minimum RTT observed, ignoring ack delay (microseconds)
+    pub min_rtt_us: u64,
+    /// Current congestion window (bytes)
+    pub cwnd: u64,
+    /// Number of congestion events
+    pub congestion_events: u64,
+    /// Number of packets lost
+    pub lost_packets: u64,
+    /// Number of bytes lost
+    pub lost_bytes: u64,
+    /// Number of packets sent
+    pub sent_packets: u64,
+    /// Number of PLPMTUD probes sent (also counted in `sent_packets`)
+    pub sent_plpmtud_probes: u64,
+    /// Number of PLPMTUD probes lost (not counted in `lost_packets`)
+    pub lost_plpmtud_probes: u64,
+    /// Number of black holes detected
+    pub black_holes_detected: u64,
+    /// Largest UDP payload size the path currently supports
+    pub current_mtu: u16,
+}
+
+impl From<quinn::PathStats> for QuicFfiPathStats {
+    fn from(stats: quinn::PathStats) -> Self {
+        Self {
+            rtt_us: stats.rtt.as_micros() as u64,
+            min_rtt_us: stats.min_rtt.as_micros() as u64,
+            cwnd: stats.cwnd,
+            congestion_events: stats.congestion_events,
+            lost_packets: stats.lost_packets,
+            lost_bytes: stats.lost_bytes,
+            sent_packets: stats.sent_packets,
+            sent_plpmtud_probes: stats.sent_plpmtud_probes,
+            lost_plpmtud_probes: stats.lost_plpmtud_probes,
+            black_holes_detected: stats.black_holes_detected,
+            current_mtu: stats.current_mtu,
+        }
+    }
+}
+
+/// Connection statistics snapshot (for C API)
+///
+/// Filled by `dart_quic_connection_stats` from `quinn::Connection::stats()`.
+/// The structure is plain data and can be allocated by the caller.
+///
+/// # C API Usage
+/// ```c
+/// QuicFfiConnectionStats stats;
+/// if (dart_quic_connection_stats(handle, &stats) == 0) {
+///     printf("rtt=%lluus lost=%llu\n", stats.path.rtt_us, stats.path.lost_packets);
+/// }
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuicFfiConnectionStats {
+    /// Path statistics (RTT, congestion window, loss)
+    pub path: QuicFfiPathStats,
+    /// Frames transmitted
+    pub frame_tx: QuicFfiFrameStats,
+    /// Frames received
+    pub frame_rx: QuicFfiFrameStats,
+    /// UDP datagrams transmitted
+    pub udp_tx: QuicFfiUdpStats,
+    /// UDP datagrams received
+    pub udp_rx: QuicFfiUdpStats,
+}
+
+impl From<quinn::ConnectionStats> for QuicFfiConnectionStats {
+    fn from(stats: quinn::ConnectionStats) -> Self {
+        Self {
+            path: stats.path.into(),
+            frame_tx: stats.frame_tx.into(),
+            frame_rx: stats.frame_rx.into(),
+            udp_tx: stats.udp_tx.into(),
+            udp_rx: stats.udp_rx.into(),
+        }
     }
 }
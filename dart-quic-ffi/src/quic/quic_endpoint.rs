@@ -80,12 +80,17 @@
 //!     .build()?;
 //! ```
 
-use std::net::SocketAddr;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::sync::Arc;
 
-use quinn::{ClientConfig, Endpoint, ServerConfig};
+use futures::stream::{FuturesUnordered, StreamExt};
+use quinn::{ClientConfig, Endpoint, EndpointConfig, ServerConfig};
 
 use crate::error::QuicError;
-use super::quic_connection::QuicConnection;
+use crate::types::QuicResult;
+use super::quic_connection::{ConnectionRegistry, QuicConnection, QuicZeroRttHandshake};
+use super::quic_resolver::{self, QuicResolver, QuicSystemResolver, CONNECTION_ATTEMPT_DELAY};
+use super::quic_qlog::{ClientQlog, QuicQlog, ServerQlog};
 
 // ============================================================================
 // Endpoint Builder
@@ -97,6 +102,10 @@ use super::quic_connection::QuicConnection;
 pub struct QuicEndpointBuilder {
     client_config: Option<ClientConfig>,
     server_config: Option<ServerConfig>,
+    resolver: Option<Arc<dyn QuicResolver>>,
+    dual_stack: Option<bool>,
+    client_qlog: Option<QuicQlog>,
+    server_qlog: Option<QuicQlog>,
 }
 
 impl QuicEndpointBuilder {
@@ -105,6 +114,10 @@ impl QuicEndpointBuilder {
         Self {
             client_config: None,
             server_config: None,
+            resolver: None,
+            dual_stack: None,
+            client_qlog: None,
+            server_qlog: None,
         }
     }
 
@@ -124,6 +137,35 @@ impl QuicEndpointBuilder {
         self
     }
 
+    /// Set the host name resolver used by `connect()` (default: system resolver)
+    pub fn with_resolver(mut self, resolver: Arc<dyn QuicResolver>) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
+    /// Write a qlog trace per outgoing connection (requires a client config)
+    pub fn with_client_qlog(mut self, qlog: QuicQlog) -> Self {
+        self.client_qlog = Some(qlog);
+        self
+    }
+
+    /// Write a qlog trace per incoming connection (requires a server config)
+    pub fn with_server_qlog(mut self, qlog: QuicQlog) -> Self {
+        self.server_qlog = Some(qlog);
+        self
+    }
+
+    /// Control IPV6_V6ONLY for IPv6 bind addresses (default: OS default)
+    ///
+    /// - `true`: dual-stack, one socket serves both IPv6 and IPv4 (as IPv4-mapped addresses)
+    /// - `false`: IPv6 only
+    ///
+    /// Binding an IPv4 address with dual-stack enabled is an error.
+    pub fn with_dual_stack(mut self, enabled: bool) -> Self {
+        self.dual_stack = Some(enabled);
+        self
+    }
+
     /// Bind to the specified address and create endpoint
     ///
     /// # Parameters
@@ -150,7 +192,31 @@ impl QuicEndpointBuilder {
             ));
         }
 
-        let endpoint = if let Some(server_config) = self.server_config {
+        // qlog needs the configs to derive per-connection copies from
+        let client_qlog = self
+            .client_qlog
+            .zip(self.client_config.clone())
+            .map(|(qlog, config)| Arc::new(ClientQlog::new(qlog, config)));
+        let server_qlog = self
+            .server_qlog
+            .zip(self.server_config.clone())
+            .map(|(qlog, config)| Arc::new(ServerQlog::new(qlog, config)));
+
+        let endpoint = if let Some(dual_stack) = self.dual_stack {
+            // Explicit IPV6_V6ONLY: create the socket ourselves
+            let socket = bind_udp_socket(addr, dual_stack)?;
+            let runtime = quinn::default_runtime().ok_or_else(|| {
+                QuicError::unknown("No async runtime found for endpoint".to_string())
+            })?;
+            let mut ep = Endpoint::new(EndpointConfig::default(), self.server_config, socket, runtime)
+                .map_err(|e| {
+                    QuicError::unknown(format!("Failed to create endpoint on {}: {}", addr, e))
+                })?;
+            if let Some(client_config) = self.client_config {
+                ep.set_default_client_config(client_config);
+            }
+            ep
+        } else if let Some(server_config) = self.server_config {
             // Use server() when server config is present
             let mut ep = Endpoint::server(server_config, addr).map_err(|e| {
                 QuicError::unknown(format!("Failed to create server endpoint on {}: {}", addr, e))
@@ -175,16 +241,46 @@ impl QuicEndpointBuilder {
         let local_addr = endpoint.local_addr().map_err(|e| {
             QuicError::unknown(format!("Failed to get local address: {}", e))
         })?;
+        tracing::debug!(%local_addr, client = has_client, server = has_server, "endpoint bound");
 
         Ok(QuicEndpoint {
             inner: endpoint,
             local_addr,
             has_client_config: has_client,
             has_server_config: has_server,
+            connections: ConnectionRegistry::new(),
+            resolver: self.resolver.unwrap_or_else(|| Arc::new(QuicSystemResolver)),
+            client_qlog,
+            server_qlog,
         })
     }
 }
 
+/// Create a UDP socket bound to `addr` with IPV6_V6ONLY set according to `dual_stack`
+fn bind_udp_socket(addr: SocketAddr, dual_stack: bool) -> Result<std::net::UdpSocket, QuicError> {
+    use socket2::{Domain, Protocol, Socket, Type};
+
+    if dual_stack && !addr.is_ipv6() {
+        return Err(QuicError::with_message(
+            QuicResult::InvalidParameter,
+            format!("Dual-stack requires an IPv6 bind address, got {}", addr),
+        ));
+    }
+
+    let socket = Socket::new(Domain::for_address(addr), Type::DGRAM, Some(Protocol::UDP))
+        .map_err(|e| QuicError::unknown(format!("Failed to create socket: {}", e)))?;
+    if addr.is_ipv6() {
+        socket.set_only_v6(!dual_stack).map_err(|e| {
+            QuicError::unknown(format!("Failed to set IPV6_V6ONLY: {}", e))
+        })?;
+    }
+    socket
+        .bind(&addr.into())
+        .map_err(|e| QuicError::unknown(format!("Failed to bind {}: {}", addr, e)))?;
+
+    Ok(socket.into())
+}
+
 impl Default for QuicEndpointBuilder {
     fn default() -> Self {
         Self::new()
@@ -214,9 +310,36 @@ pub struct QuicEndpoint {
     local_addr: SocketAddr,
     has_client_config: bool,
     has_server_config: bool,
+    /// Live connections created on this endpoint (shared between clones)
+    connections: ConnectionRegistry,
+    /// Host name resolver for `connect()`
+    resolver: Arc<dyn QuicResolver>,
+    /// Per-connection qlog output, if enabled
+    client_qlog: Option<Arc<ClientQlog>>,
+    server_qlog: Option<Arc<ServerQlog>>,
 }
 
 impl QuicEndpoint {
+    /// Start an outgoing connection with the default client config (plus qlog, if enabled)
+    fn start_connect(&self, addr: SocketAddr, server_name: &str) -> Result<quinn::Connecting, quinn::ConnectError> {
+        match &self.client_qlog {
+            Some(qlog) => self.inner.connect_with(qlog.connection_config(), addr, server_name),
+            None => self.inner.connect(addr, server_name),
+        }
+    }
+
+    /// Accept an incoming connection with the current server config (plus qlog, if enabled)
+    fn accept_incoming(&self, incoming: quinn::Incoming) -> Result<quinn::Connecting, quinn::ConnectionError> {
+        let config = self
+            .server_qlog
+            .as_ref()
+            .and_then(|qlog| qlog.connection_config(&incoming.orig_dst_cid()));
+        match config {
+            Some(config) => incoming.accept_with(config),
+            None => incoming.accept(),
+        }
+    }
+
     /// Create an endpoint builder
     pub fn builder() -> QuicEndpointBuilder {
         QuicEndpointBuilder::new()
@@ -229,11 +352,13 @@ impl QuicEndpoint {
     /// Requires `ClientConfig` to be provided during construction.
     ///
     /// # Parameters
-    /// - `server_addr`: Server address in "host:port" format
+    /// - `server_addr`: Server address in "host:port" format; `host` may be an IP literal
+    ///   or a host name resolved with the endpoint's resolver (Happy Eyeballs across results)
     /// - `server_name`: Server name (for TLS SNI and certificate verification)
     ///
     /// # Errors
     /// - Returns error if endpoint has no ClientConfig configured
+    /// - Returns `HostResolutionFailed` if the host name cannot be resolved
     /// - Returns error if connection fails
     pub async fn connect(
         &self,
@@ -246,11 +371,58 @@ impl QuicEndpoint {
             ));
         }
 
-        let addr: SocketAddr = server_addr.parse().map_err(|e| {
-            QuicError::unknown(format!("Invalid server address '{}': {}", server_addr, e))
-        })?;
+        let candidates = quic_resolver::resolve_server_addr(self.resolver.as_ref(), server_addr).await?;
+        let connection = self.connect_candidates(candidates, server_name).await?;
 
-        self.connect_addr(addr, server_name).await
+        Ok(self.track(QuicConnection::new(connection)))
+    }
+
+    /// Race connection attempts to `candidates` (Happy Eyeballs, RFC 8305 §5)
+    ///
+    /// Attempts start in order; the next one starts when the previous fails or has not
+    /// completed within `CONNECTION_ATTEMPT_DELAY`. The first established connection wins
+    /// and the remaining attempts are dropped (which closes them).
+    async fn connect_candidates(
+        &self,
+        candidates: Vec<SocketAddr>,
+        server_name: &str,
+    ) -> Result<quinn::Connection, QuicError> {
+        let mut candidates = candidates.into_iter();
+        let mut attempts = FuturesUnordered::new();
+        let mut last_error = None;
+
+        loop {
+            if let Some(addr) = candidates.next() {
+                match self.start_connect(addr, server_name) {
+                    Ok(connecting) => attempts.push(async move { (addr, connecting.await) }),
+                    Err(e) => {
+                        // e.g. IPv6 address on an IPv4-only socket: try the next one immediately
+                        tracing::debug!(%addr, error = %e, "failed to initiate connection");
+                        last_error = Some(QuicError::unknown(format!(
+                            "Failed to initiate connection to {}: {}",
+                            addr, e
+                        )));
+                        continue;
+                    }
+                }
+            }
+
+            if attempts.is_empty() {
+                return Err(last_error.unwrap_or_else(|| QuicError::unknown("No address to connect to")));
+            }
+
+            let more_candidates = candidates.len() > 0;
+            tokio::select! {
+                Some((addr, result)) = attempts.next() => match result {
+                    Ok(connection) => return Ok(connection),
+                    Err(e) => {
+                        tracing::debug!(%addr, error = %e, "connection attempt failed");
+                        last_error = Some(QuicError::unknown(format!("Connection to {} failed: {}", addr, e)));
+                    }
+                },
+                _ = tokio::time::sleep(CONNECTION_ATTEMPT_DELAY), if more_candidates => {}
+            }
+        }
     }
 
     /// Connect to a remote server using SocketAddr
@@ -265,7 +437,7 @@ impl QuicEndpoint {
             ));
         }
 
-        let connecting = self.inner.connect(server_addr, server_name).map_err(|e| {
+        let connecting = self.start_connect(server_addr, server_name).map_err(|e| {
             QuicError::unknown(format!("Failed to initiate connection to {}: {}", server_addr, e))
         })?;
 
@@ -273,7 +445,72 @@ impl QuicEndpoint {
             QuicError::unknown(format!("Connection to {} failed: {}", server_addr, e))
         })?;
 
-        Ok(QuicConnection::new(connection))
+        Ok(self.track(QuicConnection::new(connection)))
+    }
+
+    /// Connect to a remote server, sending 0-RTT early data when possible
+    ///
+    /// If a resumable TLS session for the server exists and the client config enables
+    /// early data, returns immediately after sending the first flight; the connection can be
+    /// used right away. Otherwise falls back to awaiting the full handshake.
+    ///
+    /// ⚠️ 0-RTT data is replayable; only send idempotent requests before the handshake completes.
+    ///
+    /// # Parameters
+    /// - `server_addr`: Server address in "host:port" format
+    /// - `server_name`: Server name (for TLS SNI and certificate verification)
+    ///
+    /// # Returns
+    /// The connection plus a `QuicZeroRttHandshake` reporting whether early data was accepted.
+    pub async fn connect_0rtt(
+        &self,
+        server_addr: &str,
+        server_name: &str,
+    ) -> Result<(QuicConnection, QuicZeroRttHandshake), QuicError> {
+        let candidates = quic_resolver::resolve_server_addr(self.resolver.as_ref(), server_addr).await?;
+
+        // Early data is bound to a single path, so no racing: use the first usable address
+        let mut last_error = None;
+        for addr in candidates {
+            match self.connect_0rtt_addr(addr, server_name).await {
+                Ok(result) => return Ok(result),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| QuicError::unknown("No address to connect to")))
+    }
+
+    /// Connect to a remote server using SocketAddr, sending 0-RTT early data when possible
+    pub async fn connect_0rtt_addr(
+        &self,
+        server_addr: SocketAddr,
+        server_name: &str,
+    ) -> Result<(QuicConnection, QuicZeroRttHandshake), QuicError> {
+        if !self.has_client_config {
+            return Err(QuicError::unknown(
+                "Endpoint has no client configuration".to_string(),
+            ));
+        }
+
+        let connecting = self.start_connect(server_addr, server_name).map_err(|e| {
+            QuicError::unknown(format!("Failed to initiate connection to {}: {}", server_addr, e))
+        })?;
+
+        match connecting.into_0rtt() {
+            Ok((connection, accepted)) => Ok((
+                self.track(QuicConnection::new(connection)),
+                QuicZeroRttHandshake::pending(accepted),
+            )),
+            Err(connecting) => {
+                let connection = connecting.await.map_err(|e| {
+                    QuicError::unknown(format!("Connection to {} failed: {}", server_addr, e))
+                })?;
+                Ok((
+                    self.track(QuicConnection::new(connection)),
+                    QuicZeroRttHandshake::not_attempted(),
+                ))
+            }
+        }
     }
 
     /// Connect using a custom ClientConfig
@@ -296,7 +533,7 @@ impl QuicEndpoint {
             QuicError::unknown(format!("Connection failed: {}", e))
         })?;
 
-        Ok(QuicConnection::new(connection))
+        Ok(self.track(QuicConnection::new(connection)))
     }
 
     // ========== Server Capabilities ==========
@@ -316,18 +553,66 @@ impl QuicEndpoint {
         }
 
         let incoming = self.inner.accept().await?;
+        let remote = incoming.remote_address();
+        let connecting = match self.accept_incoming(incoming) {
+            Ok(connecting) => connecting,
+            Err(e) => return Some(Err(QuicError::unknown(format!("Accept failed: {}", e)))),
+        };
         Some(
-            incoming
+            connecting
                 .await
-                .map(QuicConnection::new)
-                .map_err(|e| QuicError::unknown(format!("Accept failed: {}", e))),
+                .map(|connection| self.track(QuicConnection::new(connection)))
+                .map_err(|e| {
+                    tracing::debug!(%remote, error = %e, "incoming handshake failed");
+                    QuicError::unknown(format!("Accept failed: {}", e))
+                }),
         )
     }
 
+    /// Accept incoming connections with 0.5-RTT
+    ///
+    /// Returns the connection as soon as the handshake starts, so the server can send
+    /// data before the client's handshake completes. Early (0-RTT) data from the client is
+    /// only accepted when the server config enables it (`QuicServerConfigBuilder::with_zero_rtt`).
+    ///
+    /// ⚠️ 0.5-RTT data is sent before client authentication; don't use it with mTLS-protected data.
+    ///
+    /// Returns `None` if the endpoint is closed.
+    pub async fn accept_0rtt(&self) -> Option<Result<(QuicConnection, QuicZeroRttHandshake), QuicError>> {
+        if !self.has_server_config {
+            return Some(Err(QuicError::unknown(
+                "Endpoint has no server configuration. Use builder().with_server_config() to enable incoming connections.".to_string(),
+            )));
+        }
+
+        let incoming = self.inner.accept().await?;
+        let connecting = match self.accept_incoming(incoming) {
+            Ok(connecting) => connecting,
+            Err(e) => return Some(Err(QuicError::unknown(format!("Accept failed: {}", e)))),
+        };
+
+        // Incoming connections always convert to 0.5-RTT
+        Some(match connecting.into_0rtt() {
+            Ok((connection, accepted)) => Ok((
+                self.track(QuicConnection::new(connection)),
+                QuicZeroRttHandshake::pending(accepted),
+            )),
+            Err(connecting) => connecting
+                .await
+                .map(|connection| {
+                    (self.track(QuicConnection::new(connection)), QuicZeroRttHandshake::not_attempted())
+                })
+                .map_err(|e| QuicError::unknown(format!("Accept failed: {}", e))),
+        })
+    }
+
     /// Update server configuration
     ///
     /// Used for hot-reloading certificates and similar scenarios. Only affects new connections.
     pub fn set_server_config(&self, config: Option<ServerConfig>) {
+        if let Some(qlog) = &self.server_qlog {
+            qlog.set_config(config.clone());
+        }
         self.inner.set_server_config(config);
     }
 
@@ -368,6 +653,19 @@ impl QuicEndpoint {
         self.inner.stats()
     }
 
+    /// Get stable IDs of live connections created on this endpoint
+    ///
+    /// Only connections whose `QuicConnection` is still held and not yet closed are listed.
+    pub fn connection_ids(&self) -> Vec<usize> {
+        self.connections.stable_ids()
+    }
+
+    /// Record a newly established connection in the registry
+    fn track(&self, connection: QuicConnection) -> QuicConnection {
+        self.connections.register(&connection);
+        connection
+    }
+
     // ========== Lifecycle Management ==========
 
     /// Close endpoint and all connections
@@ -395,6 +693,34 @@ impl Clone for QuicEndpoint {
             local_addr: self.local_addr,
             has_client_config: self.has_client_config,
             has_server_config: self.has_server_config,
+            connections: self.connections.clone(),
+            resolver: self.resolver.clone(),
+            client_qlog: self.client_qlog.clone(),
+            server_qlog: self.server_qlog.clone(),
+        }
+    }
+}
+
+/// FFI endpoint handle
+///
+/// Returned by `dart_quic_endpoint_create`; carries the registry handle of the
+/// new endpoint. Free the structure with `dart_quic_endpoint_handle_free` and the
+/// endpoint with `dart_quic_endpoint_free`.
+#[repr(C)]
+pub struct QuicEndpointHandle {
+    /// Endpoint registry handle (pass to all subsequent endpoint operations)
+    pub endpoint: u64,
+    /// Local bind port
+    pub local_port: u16,
+}
+
+impl QuicEndpointHandle {
+    /// Register the endpoint and describe it
+    pub fn new(endpoint: QuicEndpoint) -> Self {
+        let local_port = endpoint.local_port();
+        Self {
+            endpoint: crate::handle_registry::insert(endpoint),
+            local_port,
         }
     }
 }
@@ -413,10 +739,9 @@ impl Clone for QuicEndpoint {
 ///
 /// ```c
 /// // C API example
-/// FfiEndpointConfig config = {
+/// QuicFfiEndpointConfig config = {
 ///     .mode = QuicEndpointMode_ClientOnly,  // Client-only
-///     .bind_ip = 0,      // INADDR_ANY
-///     .bind_port = 0,    // System-assigned port
+///     .bind_addr = { .family = QUIC_ADDR_FAMILY_IPV4, .port = 0 },  // 0.0.0.0, system-assigned port
 /// };
 /// ```
 #[repr(u8)]
@@ -450,21 +775,135 @@ pub enum QuicEndpointMode {
     Bidirectional = 2,
 }
 
+/// `QuicFfiSocketAddr::family` value for IPv4
+pub const QUIC_ADDR_FAMILY_IPV4: u8 = 4;
+/// `QuicFfiSocketAddr::family` value for IPv6
+pub const QUIC_ADDR_FAMILY_IPV6: u8 = 6;
+
+/// Address family of `QuicFfiSocketAddr`
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuicFfiAddrFamily {
+    /// IPv4 (`ip[0..4]` used)
+    Ipv4 = QUIC_ADDR_FAMILY_IPV4,
+    /// IPv6 (all 16 bytes of `ip` used)
+    Ipv6 = QUIC_ADDR_FAMILY_IPV6,
+}
+
+impl TryFrom<u8> for QuicFfiAddrFamily {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, ()> {
+        match value {
+            QUIC_ADDR_FAMILY_IPV4 => Ok(Self::Ipv4),
+            QUIC_ADDR_FAMILY_IPV6 => Ok(Self::Ipv6),
+            _ => Err(()),
+        }
+    }
+}
+
+/// FFI socket address supporting IPv4 and IPv6 (for C API)
+///
+/// `family` is `QUIC_ADDR_FAMILY_IPV4` or `QUIC_ADDR_FAMILY_IPV6`; anything else is rejected.
+/// `ip` holds the address octets in network byte order; IPv4 uses the first 4 bytes.
+/// `flowinfo` and `scope_id` are only meaningful for IPv6 (`scope_id` selects the
+/// interface for link-local addresses such as `fe80::1%eth0`).
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct QuicFfiSocketAddr {
+    /// Address family (`QUIC_ADDR_FAMILY_IPV4` / `QUIC_ADDR_FAMILY_IPV6`)
+    pub family: u8,
+    /// Address octets (network byte order)
+    pub ip: [u8; 16],
+    /// Port (host byte order, 0 means system-assigned when binding)
+    pub port: u16,
+    /// IPv6 flow information
+    pub flowinfo: u32,
+    /// IPv6 scope ID (interface index)
+    pub scope_id: u32,
+}
+
+impl QuicFfiSocketAddr {
+    /// IPv4 wildcard address (0.0.0.0) with the given port
+    pub fn ipv4_unspecified(port: u16) -> Self {
+        SocketAddr::from((Ipv4Addr::UNSPECIFIED, port)).into()
+    }
+
+    /// IPv6 wildcard address (::) with the given port
+    pub fn ipv6_unspecified(port: u16) -> Self {
+        SocketAddr::from((Ipv6Addr::UNSPECIFIED, port)).into()
+    }
+
+    /// Convert to `SocketAddr`
+    ///
+    /// Returns `None` if `family` is not a valid `QuicFfiAddrFamily`.
+    pub fn to_socket_addr(&self) -> Option<SocketAddr> {
+        let family = QuicFfiAddrFamily::try_from(self.family).ok()?;
+        let addr = match family {
+            QuicFfiAddrFamily::Ipv4 => {
+                let octets = [self.ip[0], self.ip[1], self.ip[2], self.ip[3]];
+                SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::from(octets), self.port))
+            }
+            QuicFfiAddrFamily::Ipv6 => SocketAddr::V6(SocketAddrV6::new(
+                Ipv6Addr::from(self.ip),
+                self.port,
+                self.flowinfo,
+                self.scope_id,
+            )),
+        };
+        Some(addr)
+    }
+}
+
+impl From<SocketAddr> for QuicFfiSocketAddr {
+    fn from(addr: SocketAddr) -> Self {
+        let mut ip = [0u8; 16];
+        match addr {
+            SocketAddr::V4(v4) => {
+                ip[..4].copy_from_slice(&v4.ip().octets());
+                Self {
+                    family: QuicFfiAddrFamily::Ipv4 as u8,
+                    ip,
+                    port: v4.port(),
+                    flowinfo: 0,
+                    scope_id: 0,
+                }
+            }
+            SocketAddr::V6(v6) => {
+                ip.copy_from_slice(&v6.ip().octets());
+                Self {
+                    family: QuicFfiAddrFamily::Ipv6 as u8,
+                    ip,
+                    port: v6.port(),
+                    flowinfo: v6.flowinfo(),
+                    scope_id: v6.scope_id(),
+                }
+            }
+        }
+    }
+}
+
+impl Default for QuicFfiSocketAddr {
+    fn default() -> Self {
+        Self::ipv4_unspecified(0)
+    }
+}
+
 /// FFI endpoint configuration (for C API)
 ///
 /// # Field Descriptions
 ///
 /// - `mode`: Endpoint operation mode, determines what operations the endpoint can perform
-/// - `bind_ip`: Local IP address (network byte order), 0 means bind to all interfaces
-/// - `bind_port`: Local port number (host byte order), 0 means system auto-assign
+/// - `bind_addr`: Local address (IPv4 or IPv6); the unspecified address binds all interfaces
+/// - `dual_stack`: For IPv6 `bind_addr`, clear IPV6_V6ONLY so the endpoint also serves IPv4
 ///
 /// # C Language Usage Example
 ///
 /// ```c
 /// QuicFfiEndpointConfig config = {
-///     .mode = QuicEndpointMode_ClientOnly,
-///     .bind_ip = 0,      // INADDR_ANY
-///     .bind_port = 0,    // System-assigned port
+///     .mode = QuicEndpointMode_Bidirectional,
+///     .bind_addr = { .family = QUIC_ADDR_FAMILY_IPV6, .port = 4433 },  // [::]:4433
+///     .dual_stack = true,  // Accept IPv4 peers too
 /// };
 ///
 /// QuicEndpoint* endpoint = quic_endpoint_create(&config);
@@ -473,19 +912,56 @@ pub enum QuicEndpointMode {
 pub struct QuicFfiEndpointConfig {
     /// Endpoint operation mode
     pub mode: QuicEndpointMode,
-    /// Local bind IP (network byte order, 0 means INADDR_ANY)
-    pub bind_ip: u32,
-    /// Local bind port (host byte order, 0 means system-assigned)
-    pub bind_port: u16,
+    /// Local bind address
+    pub bind_addr: QuicFfiSocketAddr,
+    /// Dual-stack IPv6 socket (IPV6_V6ONLY off); IPv6-only when false. Ignored for IPv4.
+    pub dual_stack: bool,
 }
 
 impl Default for QuicFfiEndpointConfig {
     fn default() -> Self {
         Self {
             mode: QuicEndpointMode::ClientOnly,
-            bind_ip: 0,   // INADDR_ANY
-            bind_port: 0, // System-assigned
+            bind_addr: QuicFfiSocketAddr::default(), // 0.0.0.0:0
+            dual_stack: false,
         }
     }
 }
 
+
+// ============================================================================
+// FFI-Friendly Endpoint Statistics
+// ============================================================================
+
+/// Endpoint statistics snapshot (for C API)
+///
+/// Filled by `dart_quic_endpoint_stats` (and the server/client variants)
+/// from `quinn::Endpoint::stats()`. Handshake counters are cumulative.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuicFfiEndpointStats {
+    /// Incoming handshakes accepted
+    pub accepted_handshakes: u64,
+    /// Outgoing handshakes initiated
+    pub outgoing_handshakes: u64,
+    /// Incoming handshakes refused (e.g. server config removed or connection limit reached)
+    pub refused_handshakes: u64,
+    /// Incoming handshakes ignored (dropped without response)
+    pub ignored_handshakes: u64,
+    /// Currently open connections
+    pub open_connections: u64,
+}
+
+impl QuicFfiEndpointStats {
+    /// Build snapshot from an endpoint
+    pub fn from_endpoint(endpoint: &QuicEndpoint) -> Self {
+        let stats = endpoint.stats();
+        Self {
+            accepted_handshakes: stats.accepted_handshakes,
+            outgoing_handshakes: stats.outgoing_handshakes,
+            refused_handshakes: stats.refused_handshakes,
+            ignored_handshakes: stats.ignored_handshakes,
+            open_connections: endpoint.open_connections() as u64,
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! TLS Key Logging
+//!
+//! Writes TLS secrets in the NSS key log format so packet captures can be decrypted
+//! (Wireshark: Preferences → Protocols → TLS → (Pre)-Master-Secret log filename).
+//!
+//! Disabled by default. Enable it on a client or server builder with either:
+//! - [`key_log_from_env`]: append to the file named by `SSLKEYLOGFILE` (no-op if unset)
+//! - [`QuicKeyLogFile`]: append to an explicit path
+//!
+//! ⚠️ Anyone holding the key log can decrypt the logged connections; use only in test rigs.
+//!
+//! # Example
+//!
+//! ```rust
+//! let client = QuicClient::builder()
+//!     .with_skip_verification()
+//!     .with_key_log(key_log_from_env())
+//!     .bind("0.0.0.0:0")?;
+//! ```
+
+use std::ffi::{c_char, CStr};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use rustls::KeyLog;
+
+use crate::error::QuicError;
+use crate::types::QuicResult;
+
+/// Key log writing to the file named by the `SSLKEYLOGFILE` environment variable
+///
+/// The variable is read once, when the key log is created.
+pub fn key_log_from_env() -> Arc<dyn KeyLog> {
+    tracing::warn!("TLS key logging enabled via SSLKEYLOGFILE");
+    Arc::new(rustls::KeyLogFile::new())
+}
+
+/// Key log appending to an explicit file
+#[derive(Debug)]
+pub struct QuicKeyLogFile {
+    file: Mutex<File>,
+}
+
+impl QuicKeyLogFile {
+    /// Open (or create) `path` for appending
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, QuicError> {
+        let path = path.as_ref();
+        let file = OpenOptions::new().create(true).append(true).open(path).map_err(|e| {
+            QuicError::unknown(format!("Failed to open key log file {}: {}", path.display(), e))
+        })?;
+        tracing::warn!(path = %path.display(), "TLS key logging enabled");
+        Ok(Self { file: Mutex::new(file) })
+    }
+}
+
+impl KeyLog for QuicKeyLogFile {
+    fn log(&self, label: &str, client_random: &[u8], secret: &[u8]) {
+        let mut line = String::with_capacity(label.len() + 2 * (client_random.len() + secret.len()) + 3);
+        line.push_str(label);
+        line.push(' ');
+        push_hex(&mut line, client_random);
+        line.push(' ');
+        push_hex(&mut line, secret);
+        line.push('\n');
+
+        // One write per line so concurrent writers to the same file don't interleave
+        if let Err(e) = self.file.lock().write_all(line.as_bytes()) {
+            tracing::warn!(error = %e, "failed to write key log");
+        }
+    }
+}
+
+fn push_hex(out: &mut String, bytes: &[u8]) {
+    use std::fmt::Write;
+    for byte in bytes {
+        let _ = write!(out, "{:02x}", byte);
+    }
+}
+
+// ============================================================================
+// FFI Configuration
+// ============================================================================
+
+/// TLS key log mode
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuicFfiKeyLogMode {
+    /// No key logging
+    Disabled = 0,
+    /// Append to the file named by the `SSLKEYLOGFILE` environment variable
+    Env = 1,
+    /// Append to `key_log_path`
+    File = 2,
+}
+
+/// Create the key log selected by `mode` (None when disabled)
+pub(crate) fn ffi_key_log(
+    mode: QuicFfiKeyLogMode,
+    path: *const c_char,
+) -> Result<Option<Arc<dyn KeyLog>>, QuicError> {
+    match mode {
+        QuicFfiKeyLogMode::Disabled => Ok(None),
+        QuicFfiKeyLogMode::Env => Ok(Some(key_log_from_env())),
+        QuicFfiKeyLogMode::File => {
+            if path.is_null() {
+                return Err(QuicError::with_message(
+                    QuicResult::InvalidParameter,
+                    "Key log path is required for File mode".to_string(),
+                ));
+            }
+            let path = unsafe { CStr::from_ptr(path) }
+                .to_str()
+                .map_err(|_| QuicError::unknown("Invalid key log path encoding".to_string()))?;
+            Ok(Some(Arc::new(QuicKeyLogFile::open(path)?)))
+        }
+    }
+}
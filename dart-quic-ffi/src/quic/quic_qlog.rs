@@ -0,0 +1,135 @@
+//! qlog Output
+//!
+//! Writes one qlog trace (JSON-SEQ, `.sqlog`) per connection into the directory set with
+//! `QuicTransportConfig::with_qlog_dir`, for analysis in qvis or other qlog tooling.
+//!
+//! Files are named `<odcid>_<client|server>.sqlog`, where `odcid` is the hex original
+//! destination connection ID chosen by the client. Both ends of a connection therefore
+//! share the ID, and the role suffix keeps local test pairs apart.
+//!
+//! quinn shares one qlog stream per transport config, so every connection gets its own
+//! copy of the client / server config with a fresh stream attached.
+
+use std::fs::{self, File};
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use quinn::{ClientConfig, ConnectionId, ServerConfig};
+
+use super::quic_config::QuicTransportConfig;
+
+/// Length of client-chosen original destination connection IDs
+const ODCID_LEN: usize = 16;
+
+// ============================================================================
+// qlog Output
+// ============================================================================
+
+/// Per-connection qlog output
+#[derive(Clone)]
+pub struct QuicQlog {
+    dir: PathBuf,
+    transport: QuicTransportConfig,
+}
+
+impl QuicQlog {
+    /// qlog output for connections using `transport`, or None if `qlog_dir` is unset
+    pub fn from_transport(transport: &QuicTransportConfig) -> Option<Self> {
+        let dir = transport.qlog_dir.clone()?;
+        Some(Self { dir, transport: transport.clone() })
+    }
+
+    /// Transport config tracing one connection into `<dir>/<odcid>_<role>.sqlog`
+    ///
+    /// Falls back to no tracing (with a warning) if the file cannot be created.
+    fn transport_config(&self, odcid: &ConnectionId, role: &str) -> Arc<quinn::TransportConfig> {
+        let mut transport = quinn::TransportConfig::default();
+        self.transport.apply_to_transport(&mut transport);
+        match self.create_file(odcid, role) {
+            Ok(file) => attach_stream(&mut transport, file, format!("{} {}", role, odcid)),
+            Err(e) => tracing::warn!(dir = %self.dir.display(), error = %e, "failed to create qlog file"),
+        }
+        Arc::new(transport)
+    }
+
+    fn create_file(&self, odcid: &ConnectionId, role: &str) -> io::Result<File> {
+        fs::create_dir_all(&self.dir)?;
+        File::create(self.dir.join(format!("{}_{}.sqlog", odcid, role)))
+    }
+}
+
+#[cfg(feature = "qlog")]
+fn attach_stream(transport: &mut quinn::TransportConfig, file: File, title: String) {
+    let mut qlog = quinn::QlogConfig::default();
+    qlog.writer(Box::new(io::BufWriter::new(file))).title(Some(title));
+    transport.qlog_stream(qlog.into_stream());
+}
+
+#[cfg(not(feature = "qlog"))]
+fn attach_stream(_transport: &mut quinn::TransportConfig, _file: File, _title: String) {
+    tracing::warn!("qlog_dir is set but dart-quic-ffi was built without the `qlog` feature");
+}
+
+/// Random original destination connection ID for an outgoing connection
+fn random_odcid() -> ConnectionId {
+    let mut bytes = [0u8; ODCID_LEN];
+    if rustls::crypto::ring::default_provider().secure_random.fill(&mut bytes).is_err() {
+        // Still unique enough to name the file; quinn's own CIDs stay random
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos());
+        bytes.copy_from_slice(&nanos.to_be_bytes());
+    }
+    ConnectionId::new(&bytes)
+}
+
+// ============================================================================
+// Endpoint Bindings
+// ============================================================================
+
+/// qlog output for outgoing connections, bound to the endpoint's client config
+pub(crate) struct ClientQlog {
+    qlog: QuicQlog,
+    config: ClientConfig,
+}
+
+impl ClientQlog {
+    pub(crate) fn new(qlog: QuicQlog, config: ClientConfig) -> Self {
+        Self { qlog, config }
+    }
+
+    /// Client config for one outgoing connection, with its original destination CID fixed
+    pub(crate) fn connection_config(&self) -> ClientConfig {
+        let odcid = random_odcid();
+        let mut config = self.config.clone();
+        config.transport_config(self.qlog.transport_config(&odcid, "client"));
+        config.initial_dst_cid_provider(Arc::new(move || odcid));
+        config
+    }
+}
+
+/// qlog output for incoming connections, following the endpoint's current server config
+pub(crate) struct ServerQlog {
+    qlog: QuicQlog,
+    config: RwLock<Option<ServerConfig>>,
+}
+
+impl ServerQlog {
+    pub(crate) fn new(qlog: QuicQlog, config: ServerConfig) -> Self {
+        Self { qlog, config: RwLock::new(Some(config)) }
+    }
+
+    /// Track `QuicEndpoint::set_server_config`
+    pub(crate) fn set_config(&self, config: Option<ServerConfig>) {
+        *self.config.write() = config;
+    }
+
+    /// Server config for the incoming connection with original destination CID `odcid`
+    pub(crate) fn connection_config(&self, odcid: &ConnectionId) -> Option<Arc<ServerConfig>> {
+        let mut config = self.config.read().clone()?;
+        config.transport_config(self.qlog.transport_config(odcid, "server"));
+        Some(Arc::new(config))
+    }
+}
@@ -0,0 +1,347 @@
+//! Host Name Resolution
+//!
+//! Resolves `host:port` strings to socket addresses for outgoing connections.
+//!
+//! # Resolvers
+//!
+//! - [`QuicSystemResolver`]: system resolver via `tokio::net::lookup_host` (default)
+//! - [`QuicStaticResolver`]: fixed host table, useful for tests and pinned deployments
+//!
+//! Custom resolvers (DNS-over-HTTPS, service discovery, ...) implement [`QuicResolver`]
+//! and are installed with `QuicEndpointBuilder::with_resolver` or
+//! `QuicClientConfigBuilder::with_resolver`.
+//!
+//! # Address Ordering
+//!
+//! Resolved addresses are ordered Happy-Eyeballs style (RFC 8305): IPv6 and IPv4
+//! addresses are interleaved, starting with IPv6. `QuicEndpoint::connect` starts the
+//! next attempt if the current one has not completed within [`CONNECTION_ATTEMPT_DELAY`].
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+
+use crate::error::QuicError;
+use crate::types::QuicResult;
+
+/// Delay before starting the next connection attempt (RFC 8305 recommends 250ms)
+pub const CONNECTION_ATTEMPT_DELAY: Duration = Duration::from_millis(250);
+
+// ============================================================================
+// Resolver Trait
+// ============================================================================
+
+/// Host name resolver
+///
+/// Implementations must be cheap to share across connections (`Send + Sync`).
+pub trait QuicResolver: Send + Sync {
+    /// Resolve `host` to socket addresses with the given port
+    ///
+    /// Returning an empty list is treated as a resolution failure.
+    fn resolve<'a>(&'a self, host: &'a str, port: u16) -> BoxFuture<'a, Result<Vec<SocketAddr>, QuicError>>;
+}
+
+// ============================================================================
+// System Resolver
+// ============================================================================
+
+/// Resolver backed by the operating system (`getaddrinfo`) via tokio
+#[derive(Clone, Copy, Debug, Default)]
+pub struct QuicSystemResolver;
+
+impl QuicResolver for QuicSystemResolver {
+    fn resolve<'a>(&'a self, host: &'a str, port: u16) -> BoxFuture<'a, Result<Vec<SocketAddr>, QuicError>> {
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((host, port)).await.map_err(|e| {
+                QuicError::with_message(
+                    QuicResult::HostResolutionFailed,
+                    format!("Failed to resolve '{}': {}", host, e),
+                )
+            })?;
+            Ok(addrs.collect())
+        })
+    }
+}
+
+// ============================================================================
+// Static Resolver
+// ============================================================================
+
+/// Resolver with a fixed host table
+///
+/// Host names are matched case-insensitively. Unknown hosts fail with
+/// `HostResolutionFailed`, unless a fallback resolver is set.
+///
+/// # Example
+///
+/// ```rust
+/// let resolver = QuicStaticResolver::new()
+///     .with_host("api.example.com", ["::1".parse()?, "127.0.0.1".parse()?]);
+/// let client = QuicClient::builder()
+///     .with_skip_verification()
+///     .with_resolver(Arc::new(resolver))
+///     .bind("[::]:0")?;
+/// ```
+#[derive(Default)]
+pub struct QuicStaticResolver {
+    hosts: HashMap<String, Vec<IpAddr>>,
+    fallback: Option<Box<dyn QuicResolver>>,
+}
+
+impl QuicStaticResolver {
+    /// Create an empty host table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map `host` to the given addresses (replaces previous entries for `host`)
+    pub fn with_host(mut self, host: &str, addrs: impl IntoIterator<Item = IpAddr>) -> Self {
+        self.hosts.insert(host.to_ascii_lowercase(), addrs.into_iter().collect());
+        self
+    }
+
+    /// Resolve hosts missing from the table with `resolver`
+    pub fn with_fallback(mut self, resolver: impl QuicResolver + 'static) -> Self {
+        self.fallback = Some(Box::new(resolver));
+        self
+    }
+}
+
+impl QuicResolver for QuicStaticResolver {
+    fn resolve<'a>(&'a self, host: &'a str, port: u16) -> BoxFuture<'a, Result<Vec<SocketAddr>, QuicError>> {
+        if let Some(ips) = self.hosts.get(&host.to_ascii_lowercase()) {
+            let addrs = ips.iter().map(|ip| SocketAddr::new(*ip, port)).collect();
+            return Box::pin(async move { Ok(addrs) });
+        }
+
+        match &self.fallback {
+            Some(fallback) => fallback.resolve(host, port),
+            None => Box::pin(async move {
+                Err(QuicError::with_message(
+                    QuicResult::HostResolutionFailed,
+                    format!("Host '{}' not found in static host table", host),
+                ))
+            }),
+        }
+    }
+}
+
+// ============================================================================
+// Address Helpers
+// ============================================================================
+
+/// Split "host:port" / "[v6]:port" into host and port
+pub(crate) fn split_host_port(server_addr: &str) -> Result<(&str, u16), QuicError> {
+    let invalid = || {
+        QuicError::with_message(
+            QuicResult::AddressParseError,
+            format!("Invalid server address '{}': expected host:port", server_addr),
+        )
+    };
+
+    let (host, port) = server_addr.rsplit_once(':').ok_or_else(invalid)?;
+    let host = host
+        .strip_prefix('[')
+        .and_then(|h| h.strip_suffix(']'))
+        .unwrap_or(host);
+    if host.is_empty() || (host.contains(':') && !server_addr.starts_with('[')) {
+        return Err(invalid());
+    }
+
+    let port = port.parse::<u16>().map_err(|_| invalid())?;
+    Ok((host, port))
+}
+
+/// Order addresses for connection attempts (RFC 8305 §4)
+///
+/// Removes duplicates and interleaves address families, starting with IPv6.
+pub fn happy_eyeballs_order(addrs: Vec<SocketAddr>) -> Vec<SocketAddr> {
+    let mut v6 = Vec::new();
+    let mut v4 = Vec::new();
+    for addr in addrs {
+        let family = if addr.is_ipv6() { &mut v6 } else { &mut v4 };
+        if !family.contains(&addr) {
+            family.push(addr);
+        }
+    }
+
+    let mut ordered = Vec::with_capacity(v6.len() + v4.len());
+    let mut v6 = v6.into_iter();
+    let mut v4 = v4.into_iter();
+    loop {
+        match (v6.next(), v4.next()) {
+            (None, None) => break,
+            (a, b) => ordered.extend(a.into_iter().chain(b)),
+        }
+    }
+    ordered
+}
+
+/// Resolve `server_addr` into ordered candidate addresses
+///
+/// Literal socket addresses are returned as-is without consulting the resolver.
+pub(crate) async fn resolve_server_addr(
+    resolver: &dyn QuicResolver,
+    server_addr: &str,
+) -> Result<Vec<SocketAddr>, QuicError> {
+    if let Ok(addr) = server_addr.parse::<SocketAddr>() {
+        return Ok(vec![addr]);
+    }
+
+    let (host, port) = split_host_port(server_addr)?;
+    let addrs = happy_eyeballs_order(resolver.resolve(host, port).await?);
+    if addrs.is_empty() {
+        return Err(QuicError::with_message(
+            QuicResult::HostResolutionFailed,
+            format!("No addresses found for '{}'", host),
+        ));
+    }
+    Ok(addrs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    fn addr(s: &str) -> SocketAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn split_host_port_name_and_ipv4() {
+        assert_eq!(split_host_port("example.com:443").unwrap(), ("example.com", 443));
+        assert_eq!(split_host_port("127.0.0.1:4433").unwrap(), ("127.0.0.1", 4433));
+    }
+
+    #[test]
+    fn split_host_port_bracketed_ipv6() {
+        assert_eq!(split_host_port("[::1]:443").unwrap(), ("::1", 443));
+        assert_eq!(split_host_port("[fe80::1%eth0]:8443").unwrap(), ("fe80::1%eth0", 8443));
+    }
+
+    #[test]
+    fn split_host_port_rejects_unbracketed_ipv6() {
+        let err = split_host_port("::1:443").unwrap_err();
+        assert_eq!(err.code(), QuicResult::AddressParseError);
+    }
+
+    #[test]
+    fn split_host_port_rejects_missing_port() {
+        for s in ["example.com", "example.com:", "[::1]"] {
+            let err = split_host_port(s).unwrap_err();
+            assert_eq!(err.code(), QuicResult::AddressParseError, "{}", s);
+        }
+    }
+
+    #[test]
+    fn split_host_port_rejects_bad_port() {
+        for s in ["example.com:https", "example.com:65536", "example.com:-1", ":443", "[]:443"] {
+            let err = split_host_port(s).unwrap_err();
+            assert_eq!(err.code(), QuicResult::AddressParseError, "{}", s);
+        }
+    }
+
+    #[test]
+    fn happy_eyeballs_interleaves_starting_with_ipv6() {
+        let ordered = happy_eyeballs_order(vec![
+            addr("10.0.0.1:443"),
+            addr("10.0.0.2:443"),
+            addr("10.0.0.3:443"),
+            addr("[2001:db8::1]:443"),
+            addr("[2001:db8::2]:443"),
+        ]);
+        assert_eq!(
+            ordered,
+            vec![
+                addr("[2001:db8::1]:443"),
+                addr("10.0.0.1:443"),
+                addr("[2001:db8::2]:443"),
+                addr("10.0.0.2:443"),
+                addr("10.0.0.3:443"),
+            ]
+        );
+    }
+
+    #[test]
+    fn happy_eyeballs_single_family_keeps_order() {
+        let v4 = vec![addr("10.0.0.2:443"), addr("10.0.0.1:443")];
+        assert_eq!(happy_eyeballs_order(v4.clone()), v4);
+
+        let v6 = vec![addr("[2001:db8::2]:443"), addr("[2001:db8::1]:443")];
+        assert_eq!(happy_eyeballs_order(v6.clone()), v6);
+    }
+
+    #[test]
+    fn happy_eyeballs_removes_duplicates() {
+        let ordered = happy_eyeballs_order(vec![
+            addr("10.0.0.1:443"),
+            addr("10.0.0.1:443"),
+            addr("[::1]:443"),
+            addr("[::1]:443"),
+        ]);
+        assert_eq!(ordered, vec![addr("[::1]:443"), addr("10.0.0.1:443")]);
+        assert!(happy_eyeballs_order(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn static_resolver_matches_case_insensitively() {
+        let resolver = QuicStaticResolver::new()
+            .with_host("API.example.com", ["::1".parse().unwrap(), "127.0.0.1".parse().unwrap()]);
+        let addrs = block_on(resolver.resolve("api.EXAMPLE.com", 8443)).unwrap();
+        assert_eq!(addrs, vec![addr("[::1]:8443"), addr("127.0.0.1:8443")]);
+    }
+
+    #[test]
+    fn static_resolver_with_host_replaces_entries() {
+        let resolver = QuicStaticResolver::new()
+            .with_host("a.test", ["10.0.0.1".parse().unwrap()])
+            .with_host("a.test", ["10.0.0.2".parse().unwrap()]);
+        let addrs = block_on(resolver.resolve("a.test", 1)).unwrap();
+        assert_eq!(addrs, vec![addr("10.0.0.2:1")]);
+    }
+
+    #[test]
+    fn static_resolver_unknown_host_fails() {
+        let resolver = QuicStaticResolver::new();
+        let err = block_on(resolver.resolve("missing.test", 443)).unwrap_err();
+        assert_eq!(err.code(), QuicResult::HostResolutionFailed);
+    }
+
+    #[test]
+    fn static_resolver_uses_fallback() {
+        let fallback = QuicStaticResolver::new().with_host("b.test", ["10.0.0.9".parse().unwrap()]);
+        let resolver = QuicStaticResolver::new()
+            .with_host("a.test", ["10.0.0.1".parse().unwrap()])
+            .with_fallback(fallback);
+        assert_eq!(block_on(resolver.resolve("a.test", 1)).unwrap(), vec![addr("10.0.0.1:1")]);
+        assert_eq!(block_on(resolver.resolve("b.test", 2)).unwrap(), vec![addr("10.0.0.9:2")]);
+        assert!(block_on(resolver.resolve("c.test", 3)).is_err());
+    }
+
+    #[test]
+    fn resolve_server_addr_skips_resolver_for_literals() {
+        let resolver = QuicStaticResolver::new();
+        let addrs = block_on(resolve_server_addr(&resolver, "[::1]:443")).unwrap();
+        assert_eq!(addrs, vec![addr("[::1]:443")]);
+    }
+
+    #[test]
+    fn resolve_server_addr_orders_resolved_addresses() {
+        let resolver = QuicStaticResolver::new().with_host(
+            "dual.test",
+            ["10.0.0.1".parse().unwrap(), "10.0.0.2".parse().unwrap(), "::1".parse().unwrap()],
+        );
+        let addrs = block_on(resolve_server_addr(&resolver, "dual.test:443")).unwrap();
+        assert_eq!(addrs, vec![addr("[::1]:443"), addr("10.0.0.1:443"), addr("10.0.0.2:443")]);
+    }
+
+    #[test]
+    fn resolve_server_addr_fails_on_empty_result() {
+        let resolver = QuicStaticResolver::new().with_host("empty.test", []);
+        let err = block_on(resolve_server_addr(&resolver, "empty.test:443")).unwrap_err();
+        assert_eq!(err.code(), QuicResult::HostResolutionFailed);
+    }
+}
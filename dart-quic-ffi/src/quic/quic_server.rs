@@ -22,6 +22,7 @@
 //!
 //! - **One server, multiple connections**: A single server can handle multiple client connections simultaneously
 //! - **Hot certificate reload**: Supports updating certificates at runtime without affecting existing connections
+//! - **ALPN routing**: Connections can be queued per negotiated protocol (`route_alpn` / `accept_alpn`)
 //!
 //! # Examples
 //!
@@ -48,11 +49,15 @@
 //! ```
 
 use std::net::SocketAddr;
+use std::sync::Arc;
 
 use crate::error::QuicError;
 use super::quic_config::{QuicServerConfigBuilder, QuicTransportConfig};
-use super::quic_connection::QuicConnection;
+use super::quic_qlog::QuicQlog;
+use super::quic_keylog::{ffi_key_log, QuicFfiKeyLogMode};
+use super::quic_connection::{QuicConnection, QuicZeroRttHandshake};
 use super::quic_endpoint::QuicEndpoint;
+use super::quic_alpn_router::AlpnRouter;
 
 // ============================================================================
 // Server Endpoint Main Class
@@ -65,7 +70,8 @@ use super::quic_endpoint::QuicEndpoint;
 /// # Internal Structure
 /// ```text
 /// QuicServer
-///   └── inner: QuicEndpoint  // Low-level unified endpoint
+///   ├── inner: QuicEndpoint  // Low-level unified endpoint
+///   └── router: AlpnRouter   // Per-ALPN connection queues
 /// ```
 /// 
 /// # Core Concepts
@@ -80,6 +86,8 @@ use super::quic_endpoint::QuicEndpoint;
 pub struct QuicServer {
     /// Underlying QuicEndpoint
     inner: QuicEndpoint,
+    /// Per-ALPN connection queues (shared by clones)
+    router: Arc<AlpnRouter>,
 }
 
 impl QuicServer {
@@ -99,7 +107,7 @@ impl QuicServer {
 
     /// Create from QuicEndpoint (internal use)
     pub(crate) fn from_endpoint(endpoint: QuicEndpoint) -> Self {
-        Self { inner: endpoint }
+        Self { inner: endpoint, router: Arc::new(AlpnRouter::new()) }
     }
 
     /// Create a server with self-signed certificate (testing only!)
@@ -143,6 +151,63 @@ impl QuicServer {
         self.inner.accept().await
     }
 
+    /// Accept incoming connections with 0.5-RTT
+    ///
+    /// See `QuicEndpoint::accept_0rtt` for details.
+    pub async fn accept_0rtt(&self) -> Option<Result<(QuicConnection, QuicZeroRttHandshake), QuicError>> {
+        self.inner.accept_0rtt().await
+    }
+
+    // ========== ALPN Routing ==========
+
+    /// Register a connection queue for ALPN protocol `alpn`
+    ///
+    /// `None` registers the fallback queue, which receives connections whose protocol
+    /// has no queue of its own (or that negotiated none). Registering twice is a no-op.
+    ///
+    /// The first call starts routing incoming connections in the background; from then
+    /// on, accept connections only through `accept_alpn`.
+    ///
+    /// # Example
+    /// ```rust
+    /// server.route_alpn(Some(b"h3"))?;
+    /// server.route_alpn(Some(b"my-proto"))?;
+    ///
+    /// while let Some(conn) = server.accept_alpn(Some(b"my-proto")).await {
+    ///     // Only connections that negotiated "my-proto"
+    /// }
+    /// ```
+    pub fn route_alpn(&self, alpn: Option<&[u8]>) -> Result<(), QuicError> {
+        self.router.register(&self.inner, alpn)
+    }
+
+    /// Accept the next connection routed to the queue of `alpn` (None = fallback)
+    ///
+    /// Returns `None` if the endpoint is closed, and an `InvalidParameter` error if
+    /// no queue is registered for `alpn`. Cancel-safe. See `quic_alpn_router` for the
+    /// routing rules.
+    pub async fn accept_alpn(&self, alpn: Option<&[u8]>) -> Option<Result<QuicConnection, QuicError>> {
+        self.router.accept(alpn).await
+    }
+
+    // ========== Certificate Reload (delegated to inner) ==========
+
+    /// Replace the server configuration (hot certificate reload)
+    ///
+    /// Only affects new connections. See `quic_cert_reload` for reloading automatically
+    /// when the certificate files change.
+    ///
+    /// # Example
+    /// ```rust
+    /// let config = QuicServer::builder()
+    ///     .with_cert_pem_files("renewed.crt", "renewed.key")?
+    ///     .build_config()?;
+    /// server.set_server_config(config);
+    /// ```
+    pub fn set_server_config(&self, config: quinn::ServerConfig) {
+        self.inner.set_server_config(Some(config));
+    }
+
     // ========== Endpoint Information (delegated to inner) ==========
 
     /// Get local bind address
@@ -215,27 +280,29 @@ impl QuicServer {
 
 /// FFI server handle
 ///
-/// FFI-friendly structure that wraps a QuicServer pointer along with
-/// commonly used server information. This allows the Dart layer to
+/// FFI-friendly structure carrying the registry handle of a new server along
+/// with commonly used server information. This allows the Dart layer to
 /// directly access server info without additional FFI calls.
 ///
 /// # Memory Management
 /// - Allocated and owned entirely by Rust via `Box::into_raw`
-/// - `server`: owned QuicServer pointer
+/// - `server`: registry handle, released via `dart_quic_server_free`
 /// - `local_addr_ptr`: owned string pointer, allocated via `crate::allocate`
-/// - Free the entire handle with `dart_quic_server_handle_free`
+/// - Free the structure with `dart_quic_server_handle_free` (does not free the server)
 ///
 /// # C API Usage
 /// ```c
 /// QuicServerHandle* handle = ...;
 /// printf("Listening on: %.*s\n", (int)handle->local_addr_len, handle->local_addr_ptr);
-/// // Use handle->server for server operations
+/// uint64_t server = handle->server;
 /// dart_quic_server_handle_free(handle);
+/// // Use server for server operations, then
+/// dart_quic_server_free(server);
 /// ```
 #[repr(C)]
 pub struct QuicServerHandle {
-    /// Server pointer (pass to all subsequent server operations)
-    pub server: *mut QuicServer,
+    /// Server registry handle (pass to all subsequent server operations)
+    pub server: u64,
     /// Local bind port
     pub local_port: u16,
     /// Local address string length
@@ -247,7 +314,7 @@ pub struct QuicServerHandle {
 impl QuicServerHandle {
     /// Create a new server handle from QuicServer
     ///
-    /// Takes ownership of the server and allocates local address string.
+    /// Registers the server and allocates local address string.
     pub fn new(server: QuicServer) -> Self {
         let local_addr_str = server.local_addr().to_string();
         let local_port = server.local_port();
@@ -268,11 +335,8 @@ impl QuicServerHandle {
             0
         };
 
-        // Box the server and get raw pointer
-        let server_ptr = Box::into_raw(Box::new(server));
-
         Self {
-            server: server_ptr,
+            server: crate::handle_registry::insert(server),
             local_port,
             local_addr_len,
             local_addr_ptr,
@@ -282,7 +346,7 @@ impl QuicServerHandle {
     /// Create a null/invalid handle
     pub fn null() -> Self {
         Self {
-            server: std::ptr::null_mut(),
+            server: 0,
             local_port: 0,
             local_addr_len: 0,
             local_addr_ptr: std::ptr::null_mut(),
@@ -332,6 +396,19 @@ pub struct QuicFfiServerConfig {
     pub client_ca_len: u32,
     /// Transport configuration (optional, null uses default)
     pub transport: *const super::quic_config::QuicFfiTransportConfig,
+    /// Accept 0-RTT early data from resuming clients (enables session tickets)
+    pub enable_0rtt: bool,
+    /// TLS key log mode (for decrypting packet captures; default Disabled)
+    pub key_log_mode: QuicFfiKeyLogMode,
+    /// Key log file path (used when key_log_mode = File)
+    pub key_log_path: *const std::os::raw::c_char,
+    /// Per-server-name certificates (optional; enables SNI mode, in which the certificate
+    /// selected by `cert_mode` is the default for unknown names)
+    pub sni_certs: *const QuicFfiSniCert,
+    /// SNI certificate count
+    pub sni_cert_count: u32,
+    /// In SNI mode, fail handshakes for unknown names instead of using the default certificate
+    pub sni_reject_unknown: bool,
 }
 
 impl Default for QuicFfiServerConfig {
@@ -350,27 +427,69 @@ impl Default for QuicFfiServerConfig {
             client_ca_ptr: std::ptr::null(),
             client_ca_len: 0,
             transport: std::ptr::null(),
+            enable_0rtt: false,
+            key_log_mode: QuicFfiKeyLogMode::Disabled,
+            key_log_path: std::ptr::null(),
+            sni_certs: std::ptr::null(),
+            sni_cert_count: 0,
+            sni_reject_unknown: false,
         }
     }
 }
 
-impl QuicFfiServerConfig {
-    /// Build Quinn ServerConfig from FFI configuration (without creating endpoint)
-    ///
-    /// This method builds only the `quinn::ServerConfig` which can be used
-    /// to create a unified endpoint or for other advanced use cases.
-    pub fn build_quinn_config(&self) -> Result<quinn::ServerConfig, QuicError> {
+/// FFI certificate for one server name (entry of `QuicFfiServerConfig::sni_certs`)
+///
+/// # C Language Usage Example
+///
+/// ```c
+/// QuicFfiSniCert sni[] = {
+///     { .server_name = "api.example.com", .cert_mode = 0,
+///       .cert_path_ptr = "api.crt", .key_path_ptr = "api.key" },
+///     { .server_name = "*.example.org", .cert_mode = 0,
+///       .cert_path_ptr = "org.crt", .key_path_ptr = "org.key" },
+/// };
+/// config.sni_certs = sni;
+/// config.sni_cert_count = 2;
+/// ```
+#[repr(C)]
+pub struct QuicFfiSniCert {
+    /// Server name, optionally with a leading `*.` wildcard label
+    pub server_name: *const std::os::raw::c_char,
+    /// Certificate mode: 0 = file, 1 = memory
+    pub cert_mode: u32,
+    /// Certificate file path (used when cert_mode = 0)
+    pub cert_path_ptr: *const std::os::raw::c_char,
+    /// Private key file path (used when cert_mode = 0)
+    pub key_path_ptr: *const std::os::raw::c_char,
+    /// Certificate DER data (used when cert_mode = 1)
+    pub cert_der_ptr: *const u8,
+    /// Certificate DER data length (bytes)
+    pub cert_der_len: u32,
+    /// Private key DER data (used when cert_mode = 1)
+    pub key_der_ptr: *const u8,
+    /// Private key DER data length (bytes)
+    pub key_der_len: u32,
+}
+
+impl QuicFfiSniCert {
+    /// Add this certificate to `builder`
+    fn apply(&self, builder: QuicServerConfigBuilder) -> Result<QuicServerConfigBuilder, QuicError> {
         use std::ffi::CStr;
-        use super::quic_config::QuicServerConfigBuilder;
-        
-        let mut builder = QuicServerConfigBuilder::new();
-        
-        // Configure certificate based on mode
-        builder = match self.cert_mode {
+
+        if self.server_name.is_null() {
+            return Err(QuicError::unknown("SNI server name is required".to_string()));
+        }
+        let server_name = unsafe { CStr::from_ptr(self.server_name) }
+            .to_str()
+            .map_err(|_| QuicError::unknown("Invalid SNI server name encoding".to_string()))?;
+
+        match self.cert_mode {
             0 => {
                 // File mode
                 if self.cert_path_ptr.is_null() || self.key_path_ptr.is_null() {
-                    return Err(QuicError::unknown("Certificate and key paths are required for file mode".to_string()));
+                    return Err(QuicError::unknown(format!(
+                        "Certificate and key paths are required for SNI name {}", server_name
+                    )));
                 }
                 let cert_path = unsafe { CStr::from_ptr(self.cert_path_ptr) }
                     .to_str()
@@ -378,76 +497,54 @@ impl QuicFfiServerConfig {
                 let key_path = unsafe { CStr::from_ptr(self.key_path_ptr) }
                     .to_str()
                     .map_err(|_| QuicError::unknown("Invalid key path encoding".to_string()))?;
-                builder.with_cert_pem_files(cert_path, key_path)?
+                builder.with_sni_cert_pem_files(server_name, cert_path, key_path)
             }
             1 => {
                 // Memory mode
-                if self.cert_der_ptr.is_null() || self.cert_der_len == 0 ||
-                   self.key_der_ptr.is_null() || self.key_der_len == 0 {
-                    return Err(QuicError::unknown("Certificate and key data are required for memory mode".to_string()));
+                if self.cert_der_ptr.is_null() || self.cert_der_len == 0
+                    || self.key_der_ptr.is_null() || self.key_der_len == 0
+                {
+                    return Err(QuicError::unknown(format!(
+                        "Certificate and key data are required for SNI name {}", server_name
+                    )));
                 }
                 let cert_der = unsafe { std::slice::from_raw_parts(self.cert_der_ptr, self.cert_der_len as usize) }.to_vec();
                 let key_der = unsafe { std::slice::from_raw_parts(self.key_der_ptr, self.key_der_len as usize) }.to_vec();
-                builder.with_cert_der(cert_der, key_der)
+                Ok(builder.with_sni_cert_der(server_name, cert_der, key_der))
             }
-            2 => {
-                // Self-signed mode
-                let san_list: Vec<String> = if !self.san_ptr.is_null() && self.san_count > 0 {
-                    let san_ptrs = unsafe { std::slice::from_raw_parts(self.san_ptr, self.san_count as usize) };
-                    san_ptrs.iter()
-                        .filter_map(|&ptr| {
-                            if !ptr.is_null() {
-                                unsafe { CStr::from_ptr(ptr) }.to_str().ok().map(|s| s.to_string())
-                            } else {
-                                None
-                            }
-                        })
-                        .collect()
-                } else {
-                    vec![]
-                };
-                let san_refs: Vec<&str> = san_list.iter().map(|s| s.as_str()).collect();
-                builder.with_self_signed(&san_refs)
-            }
-            _ => return Err(QuicError::unknown(format!("Invalid cert mode: {}", self.cert_mode))),
-        };
-        
-        // Configure client authentication (mTLS)
-        if self.client_auth_mode > 0 {
-            if self.client_ca_ptr.is_null() || self.client_ca_len == 0 {
-                return Err(QuicError::unknown("Client CA certificate data is required when client auth is enabled".to_string()));
-            }
-            let client_ca_der = unsafe { std::slice::from_raw_parts(self.client_ca_ptr, self.client_ca_len as usize) }.to_vec();
-            builder = if self.client_auth_mode == 1 {
-                // Required
-                builder.require_client_cert(client_ca_der)
-            } else {
-                // Optional
-                builder.optional_client_cert(client_ca_der)
-            };
+            _ => Err(QuicError::unknown(format!("Invalid SNI cert mode: {}", self.cert_mode))),
         }
-        
-        // Configure transport parameters (null means use defaults)
-        if !self.transport.is_null() {
-            let transport_config = super::quic_config::QuicTransportConfig::from(unsafe { &*self.transport });
-            builder = builder.with_transport_config(transport_config);
+    }
+}
+
+impl QuicFfiServerConfig {
+    /// Add the SNI certificates (if any) to `builder`
+    fn apply_sni(&self, mut builder: QuicServerConfigBuilder) -> Result<QuicServerConfigBuilder, QuicError> {
+        if !self.sni_certs.is_null() && self.sni_cert_count > 0 {
+            let sni_certs = unsafe { std::slice::from_raw_parts(self.sni_certs, self.sni_cert_count as usize) };
+            for sni_cert in sni_certs {
+                builder = sni_cert.apply(builder)?;
+            }
         }
+        Ok(builder.with_sni_reject_unknown(self.sni_reject_unknown))
+    }
 
-        // Build Quinn config
-        builder.build_config()
+    /// qlog output configured in `transport`, if any
+    pub fn qlog(&self) -> Option<QuicQlog> {
+        if self.transport.is_null() {
+            return None;
+        }
+        // An invalid transport config fails build_quinn_config instead
+        let transport = unsafe { QuicTransportConfig::from_ffi_ptr(self.transport) }.ok()?;
+        QuicQlog::from_transport(&transport)
     }
 
-    /// Build QuicServer from FFI configuration
+    /// Server config builder for this FFI configuration
     ///
-    /// This is the primary entry point for creating a `QuicServer` from an FFI config.
-    /// Delegates certificate parsing and builder construction to `QuicServerConfigBuilder`,
-    /// then internally calls `bind()` to create the server endpoint.
-    ///
-    /// # Parameters
-    /// - `bind_addr`: Address to bind, e.g. "0.0.0.0:4433"
-    pub fn build(&self, bind_addr: &str) -> Result<QuicServer, QuicError> {
+    /// Shared by `build_quinn_config` and `build`; also kept by certificate file watchers
+    /// to rebuild the config when the files change.
+    pub fn builder(&self) -> Result<QuicServerConfigBuilder, QuicError> {
         use std::ffi::CStr;
-        use super::quic_config::QuicServerConfigBuilder;
 
         let mut builder = QuicServerConfigBuilder::new();
 
@@ -500,6 +597,9 @@ impl QuicFfiServerConfig {
             _ => return Err(QuicError::unknown(format!("Invalid cert mode: {}", self.cert_mode))),
         };
 
+        // Configure per-server-name certificates (SNI mode)
+        builder = self.apply_sni(builder)?;
+
         // Configure client authentication (mTLS)
         if self.client_auth_mode > 0 {
             if self.client_ca_ptr.is_null() || self.client_ca_len == 0 {
@@ -520,12 +620,40 @@ impl QuicFfiServerConfig {
 
         // Configure transport parameters (null means use defaults)
         if !self.transport.is_null() {
-            let transport_config = super::quic_config::QuicTransportConfig::from(unsafe { &*self.transport });
+            let transport_config = unsafe { QuicTransportConfig::from_ffi_ptr(self.transport) }?;
             builder = builder.with_transport_config(transport_config);
         }
 
+        // Configure 0-RTT acceptance
+        builder = builder.with_zero_rtt(self.enable_0rtt);
+
+        // Configure TLS key logging
+        if let Some(key_log) = ffi_key_log(self.key_log_mode, self.key_log_path)? {
+            builder = builder.with_key_log(key_log);
+        }
+
+        Ok(builder)
+    }
+
+    /// Build Quinn ServerConfig from FFI configuration (without creating endpoint)
+    ///
+    /// This method builds only the `quinn::ServerConfig` which can be used
+    /// to create a unified endpoint or for other advanced use cases.
+    pub fn build_quinn_config(&self) -> Result<quinn::ServerConfig, QuicError> {
+        self.builder()?.build_config()
+    }
+
+    /// Build QuicServer from FFI configuration
+    ///
+    /// This is the primary entry point for creating a `QuicServer` from an FFI config.
+    /// Delegates certificate parsing and builder construction to `QuicServerConfigBuilder`,
+    /// then internally calls `bind()` to create the server endpoint.
+    ///
+    /// # Parameters
+    /// - `bind_addr`: Address to bind, e.g. "0.0.0.0:4433"
+    pub fn build(&self, bind_addr: &str) -> Result<QuicServer, QuicError> {
         // Bind and create server
-        builder.bind(bind_addr)
+        self.builder()?.bind(bind_addr)
     }
 }
 
@@ -0,0 +1,181 @@
+//! TLS Session Resumption Cache
+//!
+//! Pluggable client-side session stores used for TLS session resumption and 0-RTT.
+//!
+//! [`QuicLruSessionCache`] is a bounded in-memory cache that evicts the least recently
+//! used server.
+//!
+//! # Persistence
+//!
+//! Sessions are not persisted across process restarts: rustls 0.23 keeps the construction
+//! of `Tls13ClientSessionValue` and its ticket and secret accessors crate-private, so a
+//! stored ticket cannot be encoded or rebuilt outside rustls.
+//!
+//! # Example
+//!
+//! ```rust
+//! let cache = QuicLruSessionCache::new(256);
+//! let client = QuicClient::builder()
+//!     .with_system_roots()
+//!     .with_session_store(Arc::new(cache))
+//!     .with_early_data(true)
+//!     .bind("0.0.0.0:0")?;
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use parking_lot::Mutex;
+use rustls::client::{ClientSessionStore, Tls12ClientSessionValue, Tls13ClientSessionValue};
+use rustls::pki_types::ServerName;
+use rustls::NamedGroup;
+
+/// Default number of servers remembered by a session cache
+pub const DEFAULT_SESSION_CACHE_CAPACITY: usize = 256;
+
+/// Maximum TLS 1.3 tickets kept per server (matches rustls' in-memory cache)
+const MAX_TLS13_TICKETS_PER_SERVER: usize = 8;
+
+// ============================================================================
+// In-Memory LRU Cache
+// ============================================================================
+
+/// Cached resumption data for a single server
+#[derive(Default)]
+struct ServerSessionEntry {
+    kx_hint: Option<NamedGroup>,
+    tls12: Option<Tls12ClientSessionValue>,
+    tls13: VecDeque<Tls13ClientSessionValue>,
+    last_used: u64,
+}
+
+/// Bounded in-memory session cache with least-recently-used eviction
+///
+/// Each server (keyed by SNI name or IP) keeps its key exchange hint,
+/// one TLS 1.2 session and up to 8 TLS 1.3 tickets.
+pub struct QuicLruSessionCache {
+    servers: Mutex<HashMap<ServerName<'static>, ServerSessionEntry>>,
+    capacity: usize,
+    clock: AtomicU64,
+}
+
+impl QuicLruSessionCache {
+    /// Create a cache remembering at most `capacity` servers
+    ///
+    /// A capacity of 0 uses [`DEFAULT_SESSION_CACHE_CAPACITY`].
+    pub fn new(capacity: usize) -> Self {
+        let capacity = if capacity == 0 { DEFAULT_SESSION_CACHE_CAPACITY } else { capacity };
+        Self {
+            servers: Mutex::new(HashMap::new()),
+            capacity,
+            clock: AtomicU64::new(0),
+        }
+    }
+
+    /// Number of servers currently cached
+    pub fn len(&self) -> usize {
+        self.servers.lock().len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.servers.lock().is_empty()
+    }
+
+    /// Forget all cached sessions
+    pub fn clear(&self) {
+        self.servers.lock().clear();
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Run `f` on the entry for `server_name`, creating it (and evicting if full) when missing
+    fn with_entry<R>(
+        &self,
+        server_name: ServerName<'static>,
+        f: impl FnOnce(&mut ServerSessionEntry) -> R,
+    ) -> R {
+        let now = self.tick();
+        let mut servers = self.servers.lock();
+
+        if !servers.contains_key(&server_name) && servers.len() >= self.capacity {
+            let oldest = servers
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(name, _)| name.clone());
+            if let Some(oldest) = oldest {
+                servers.remove(&oldest);
+            }
+        }
+
+        let entry = servers.entry(server_name).or_default();
+        entry.last_used = now;
+        f(entry)
+    }
+
+    /// Run `f` on the entry for `server_name` if it exists
+    fn with_existing<R>(
+        &self,
+        server_name: &ServerName<'_>,
+        f: impl FnOnce(&mut ServerSessionEntry) -> Option<R>,
+    ) -> Option<R> {
+        let now = self.tick();
+        let mut servers = self.servers.lock();
+        let entry = servers.get_mut(&server_name.to_owned())?;
+        entry.last_used = now;
+        f(entry)
+    }
+}
+
+impl Default for QuicLruSessionCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_SESSION_CACHE_CAPACITY)
+    }
+}
+
+impl std::fmt::Debug for QuicLruSessionCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("QuicLruSessionCache")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl ClientSessionStore for QuicLruSessionCache {
+    fn set_kx_hint(&self, server_name: ServerName<'static>, group: NamedGroup) {
+        self.with_entry(server_name, |entry| entry.kx_hint = Some(group));
+    }
+
+    fn kx_hint(&self, server_name: &ServerName<'_>) -> Option<NamedGroup> {
+        self.with_existing(server_name, |entry| entry.kx_hint)
+    }
+
+    fn set_tls12_session(&self, server_name: ServerName<'static>, value: Tls12ClientSessionValue) {
+        self.with_entry(server_name, |entry| entry.tls12 = Some(value));
+    }
+
+    fn tls12_session(&self, server_name: &ServerName<'_>) -> Option<Tls12ClientSessionValue> {
+        self.with_existing(server_name, |entry| entry.tls12.clone())
+    }
+
+    fn remove_tls12_session(&self, server_name: &ServerName<'static>) {
+        self.with_existing(server_name, |entry| entry.tls12.take());
+    }
+
+    fn insert_tls13_ticket(&self, server_name: ServerName<'static>, value: Tls13ClientSessionValue) {
+        self.with_entry(server_name, |entry| {
+            if entry.tls13.len() >= MAX_TLS13_TICKETS_PER_SERVER {
+                entry.tls13.pop_front();
+            }
+            entry.tls13.push_back(value);
+        });
+    }
+
+    fn take_tls13_ticket(&self, server_name: &ServerName<'static>) -> Option<Tls13ClientSessionValue> {
+        // Newest ticket first; each ticket is handed out at most once
+        self.with_existing(server_name, |entry| entry.tls13.pop_back())
+    }
+}
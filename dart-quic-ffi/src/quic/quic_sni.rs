@@ -0,0 +1,236 @@
+//! SNI Certificate Resolution
+//!
+//! Selects the server certificate by the server name the client requests (SNI), so one
+//! endpoint can host several domains.
+//!
+//! Names match case-insensitively, ignoring a trailing dot. A wildcard name such as
+//! `*.example.com` matches exactly one extra label (`a.example.com`, but neither
+//! `example.com` nor `a.b.example.com`), and exact names take precedence over wildcards.
+//! Clients sending no SNI (e.g. connecting by IP address) or an unknown name get the
+//! default certificate; without one, their handshakes fail.
+//!
+//! # Example
+//!
+//! ```rust
+//! let server = QuicServer::builder()
+//!     .with_cert_pem_files("default.crt", "default.key")?
+//!     .with_sni_cert_pem_files("api.example.com", "api.crt", "api.key")?
+//!     .with_sni_cert_pem_files("*.example.org", "org.crt", "org.key")?
+//!     .bind("0.0.0.0:4433")?;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use rustls::pki_types::{CertificateDer, DnsName, PrivateKeyDer};
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+
+use crate::error::QuicError;
+use crate::types::QuicResult;
+
+/// Server certificate resolver keyed by SNI server name
+#[derive(Debug, Default)]
+pub struct QuicSniResolver {
+    /// Certificates by exact (normalized) name
+    exact: HashMap<String, Arc<CertifiedKey>>,
+    /// Wildcard certificates by the name following `*.`
+    wildcard: HashMap<String, Arc<CertifiedKey>>,
+    /// Certificate for clients whose name matches no entry
+    default: Option<Arc<CertifiedKey>>,
+}
+
+impl QuicSniResolver {
+    /// Create an empty resolver (no names, no default)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve `certs` / `key` to clients requesting `server_name`
+    ///
+    /// `server_name` is a DNS name, optionally with a leading `*.` label. Adding a name
+    /// twice replaces its certificate.
+    ///
+    /// # Errors
+    /// - InvalidParameter if `server_name` is not a valid (wildcard) DNS name
+    /// - If the key is unsupported or doesn't match the certificate
+    pub fn add(
+        &mut self,
+        server_name: &str,
+        certs: Vec<CertificateDer<'static>>,
+        key: PrivateKeyDer<'static>,
+    ) -> Result<(), QuicError> {
+        let name = normalize(server_name);
+        let (table, name) = match name.strip_prefix("*.") {
+            Some(suffix) => (&mut self.wildcard, suffix.to_string()),
+            None => (&mut self.exact, name),
+        };
+        if DnsName::try_from(name.as_str()).is_err() {
+            return Err(QuicError::with_message(
+                QuicResult::InvalidParameter,
+                format!("Invalid SNI server name: {}", server_name),
+            ));
+        }
+        table.insert(name, certified_key(certs, key, server_name)?);
+        Ok(())
+    }
+
+    /// Serve `certs` / `key` to clients whose server name matches no entry
+    pub fn set_default(
+        &mut self,
+        certs: Vec<CertificateDer<'static>>,
+        key: PrivateKeyDer<'static>,
+    ) -> Result<(), QuicError> {
+        self.default = Some(certified_key(certs, key, "default certificate")?);
+        Ok(())
+    }
+
+    /// Number of named entries (exact and wildcard)
+    pub fn len(&self) -> usize {
+        self.exact.len() + self.wildcard.len()
+    }
+
+    /// Whether no names are configured
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Certificate for `server_name` (None = no SNI)
+    fn lookup(&self, server_name: Option<&str>) -> Option<Arc<CertifiedKey>> {
+        if let Some(name) = server_name.map(normalize) {
+            if let Some(key) = self.exact.get(&name) {
+                return Some(key.clone());
+            }
+            if let Some(key) = name.split_once('.').and_then(|(_, parent)| self.wildcard.get(parent)) {
+                return Some(key.clone());
+            }
+        }
+        self.default.clone()
+    }
+}
+
+impl ResolvesServerCert for QuicSniResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        let server_name = client_hello.server_name();
+        let key = self.lookup(server_name);
+        if key.is_none() {
+            tracing::debug!(server_name = server_name.unwrap_or("<none>"), "no certificate for SNI");
+        }
+        key
+    }
+}
+
+/// Lowercase `name` and drop a trailing dot
+fn normalize(name: &str) -> String {
+    name.strip_suffix('.').unwrap_or(name).to_ascii_lowercase()
+}
+
+/// Check that `key` matches `certs` and prepare it for signing
+fn certified_key(
+    certs: Vec<CertificateDer<'static>>,
+    key: PrivateKeyDer<'static>,
+    label: &str,
+) -> Result<Arc<CertifiedKey>, QuicError> {
+    CertifiedKey::from_der(certs, key, &rustls::crypto::ring::default_provider())
+        .map(Arc::new)
+        .map_err(|e| QuicError::unknown(format!("Invalid certificate for {}: {}", label, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quic::quic_config::generate_self_signed;
+
+    /// Add a fresh certificate for `name` and return its DER bytes
+    fn add(resolver: &mut QuicSniResolver, name: &str) -> CertificateDer<'static> {
+        let (certs, key) = generate_self_signed(vec!["localhost".to_string()]).unwrap();
+        let der = certs[0].clone();
+        resolver.add(name, certs, key).unwrap();
+        der
+    }
+
+    fn set_default(resolver: &mut QuicSniResolver) -> CertificateDer<'static> {
+        let (certs, key) = generate_self_signed(vec!["localhost".to_string()]).unwrap();
+        let der = certs[0].clone();
+        resolver.set_default(certs, key).unwrap();
+        der
+    }
+
+    /// Leaf certificate served for `server_name`
+    fn served(resolver: &QuicSniResolver, server_name: Option<&str>) -> Option<CertificateDer<'static>> {
+        resolver.lookup(server_name).map(|key| key.cert[0].clone())
+    }
+
+    #[test]
+    fn wildcard_matches_exactly_one_label() {
+        let mut resolver = QuicSniResolver::new();
+        let org = add(&mut resolver, "*.example.org");
+
+        assert_eq!(served(&resolver, Some("a.example.org")), Some(org.clone()));
+        assert_eq!(served(&resolver, Some("www.example.org")), Some(org));
+        assert_eq!(served(&resolver, Some("example.org")), None);
+        assert_eq!(served(&resolver, Some("a.b.example.org")), None);
+        assert_eq!(served(&resolver, Some("a.example.com")), None);
+    }
+
+    #[test]
+    fn exact_name_beats_wildcard() {
+        let mut resolver = QuicSniResolver::new();
+        let wildcard = add(&mut resolver, "*.example.com");
+        let api = add(&mut resolver, "api.example.com");
+
+        assert_eq!(served(&resolver, Some("api.example.com")), Some(api));
+        assert_eq!(served(&resolver, Some("www.example.com")), Some(wildcard));
+    }
+
+    #[test]
+    fn names_are_normalized() {
+        let mut resolver = QuicSniResolver::new();
+        let api = add(&mut resolver, "API.Example.COM.");
+        let org = add(&mut resolver, "*.Example.ORG");
+
+        assert_eq!(served(&resolver, Some("api.example.com")), Some(api.clone()));
+        assert_eq!(served(&resolver, Some("Api.Example.Com.")), Some(api));
+        assert_eq!(served(&resolver, Some("WWW.EXAMPLE.ORG.")), Some(org));
+    }
+
+    #[test]
+    fn adding_a_name_twice_replaces_it() {
+        let mut resolver = QuicSniResolver::new();
+        add(&mut resolver, "api.example.com");
+        let second = add(&mut resolver, "API.example.com");
+
+        assert_eq!(resolver.len(), 1);
+        assert_eq!(served(&resolver, Some("api.example.com")), Some(second));
+    }
+
+    #[test]
+    fn no_sni_and_unknown_names_use_default() {
+        let mut resolver = QuicSniResolver::new();
+        add(&mut resolver, "api.example.com");
+        let default = set_default(&mut resolver);
+
+        assert_eq!(served(&resolver, None), Some(default.clone()));
+        assert_eq!(served(&resolver, Some("other.example.com")), Some(default));
+    }
+
+    #[test]
+    fn no_sni_without_default_is_rejected() {
+        let mut resolver = QuicSniResolver::new();
+        add(&mut resolver, "api.example.com");
+
+        assert_eq!(served(&resolver, None), None);
+        assert_eq!(served(&resolver, Some("other.example.com")), None);
+    }
+
+    #[test]
+    fn invalid_names_are_rejected() {
+        let mut resolver = QuicSniResolver::new();
+        for name in ["", "*.", "bad name.com", "a.*.example.com"] {
+            let (certs, key) = generate_self_signed(vec!["localhost".to_string()]).unwrap();
+            let err = resolver.add(name, certs, key).unwrap_err();
+            assert_eq!(err.code(), QuicResult::InvalidParameter, "{}", name);
+        }
+        assert!(resolver.is_empty());
+    }
+}
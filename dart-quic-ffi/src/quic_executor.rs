@@ -1,14 +1,27 @@
 /// Simplified QUIC async executor
 /// 
 /// Manages tokio runtime only. Provides a simple `submit_async` method for async tasks.
-/// Resources (clients, connections, streams) are owned by Dart via raw pointers.
+/// Resources (clients, connections, streams) are referenced by Dart via registry handles.
 
+use std::collections::HashMap;
 use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
-use once_cell::sync::OnceCell;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+use tokio::task::AbortHandle;
 
-use crate::runtime_manager::RuntimeManager;
+use crate::error::QuicError;
+use crate::handle_registry::{self, QuicHandleType};
+use crate::panic_guard::{self, PanicReport};
+use crate::quic::{QuicClient, QuicEndpoint, QuicServer};
+use crate::runtime_manager::{RuntimeConfig, RuntimeManager};
+use crate::types::QuicResult;
+use crate::ERR_NOT_RUNNING;
 
 // ============================================
 // Callback Types
@@ -36,42 +49,279 @@ pub struct SendableCallback<T>(pub T);
 unsafe impl<T> Send for SendableCallback<T> {}
 unsafe impl<T> Sync for SendableCallback<T> {}
 
+// ============================================
+// Operation Timeouts
+// ============================================
+
+/// Await `future`, giving up after `timeout_ms` milliseconds
+///
+/// `timeout_ms == 0` waits forever. On timeout the future is dropped and
+/// `QuicResult::Timeout` is returned; otherwise the future's own output.
+pub async fn with_timeout<F: Future>(timeout_ms: u64, future: F) -> Result<F::Output, QuicError> {
+    if timeout_ms == 0 {
+        return Ok(future.await);
+    }
+    tokio::time::timeout(Duration::from_millis(timeout_ms), future)
+        .await
+        .map_err(|_| {
+            QuicError::with_message(
+                QuicResult::Timeout,
+                format!("Operation timed out after {} ms", timeout_ms),
+            )
+        })
+}
+
+// ============================================
+// Cancellable Operations
+// ============================================
+
+/// In-flight cancellable operations, keyed by operation ID
+type OperationTable = Arc<Mutex<HashMap<u64, AbortHandle>>>;
+
+/// Task wrapper that delivers exactly one of completion or cancellation
+///
+/// The operation future resolves to a completion closure (which fires the Dart callback).
+/// Whoever removes the entry from the table first wins: the task on completion, or
+/// `QuicExecutor::cancel`. A cancelled task fires `on_cancel` only after the operation
+/// future has been dropped, so the resources it borrowed (streams, buffers) are no longer
+/// in use when Dart sees the cancellation. A panic counts as completion and is delivered
+/// through `report`.
+struct CancellableOperation<F> {
+    id: u64,
+    operations: OperationTable,
+    future: Option<Pin<Box<F>>>,
+    on_cancel: Option<Box<dyn FnOnce() + Send>>,
+    report: Box<dyn PanicReport + Send>,
+}
+
+impl<F> CancellableOperation<F> {
+    /// Fire `on_cancel` if `QuicExecutor::cancel` claimed the operation
+    ///
+    /// If the entry is still present the task was dropped with the runtime; no callback fires.
+    fn finish_cancelled(&mut self) {
+        if self.operations.lock().remove(&self.id).is_none()
+            && let Some(on_cancel) = self.on_cancel.take()
+        {
+            on_cancel();
+        }
+    }
+}
+
+impl<F, D> Future for CancellableOperation<F>
+where
+    F: Future<Output = D>,
+    D: FnOnce(),
+{
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let Some(future) = self.future.as_mut() else {
+            return Poll::Ready(());
+        };
+
+        let complete = match panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+            Ok(Poll::Pending) => return Poll::Pending,
+            Ok(Poll::Ready(complete)) => Ok(complete),
+            Err(payload) => Err(payload),
+        };
+        self.future = None;
+        if self.operations.lock().remove(&self.id).is_some() {
+            self.on_cancel = None;
+            if let Err(payload) = complete.and_then(|complete| panic::catch_unwind(AssertUnwindSafe(complete))) {
+                self.report.report_panic(&panic_guard::panic_error(&*payload));
+            }
+        } else {
+            self.finish_cancelled();
+        }
+        Poll::Ready(())
+    }
+}
+
+impl<F> Drop for CancellableOperation<F> {
+    fn drop(&mut self) {
+        // Aborted before completing: release the operation first, then notify
+        if let Some(future) = self.future.take() {
+            drop(future);
+            self.finish_cancelled();
+        }
+    }
+}
+
+// ============================================
+// Task Tracking
+// ============================================
+
+/// Tasks spawned through the executor, drained or aborted on shutdown
+#[derive(Default)]
+struct TaskSet {
+    tasks: Mutex<HashMap<u64, AbortHandle>>,
+    last_id: AtomicU64,
+    /// Notified when the last task finishes
+    idle: Notify,
+}
+
+impl TaskSet {
+    /// Spawn `future` on `manager` and track it until it finishes or is dropped
+    fn spawn<F>(self: &Arc<Self>, manager: &RuntimeManager, future: F) -> AbortHandle
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let id = self.last_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let guard = TaskGuard { tasks: Arc::clone(self), id };
+
+        // Hold the lock so the task cannot finish before it is registered
+        let mut tasks = self.tasks.lock();
+        let handle = manager
+            .spawn(async move {
+                let _guard = guard;
+                future.await;
+            })
+            .abort_handle();
+        tasks.insert(id, handle.clone());
+        handle
+    }
+
+    /// Wait until no tracked task is left
+    async fn wait_idle(&self) {
+        loop {
+            // Registered before the check, so a concurrent notify is not missed
+            let idle = self.idle.notified();
+            if self.tasks.lock().is_empty() {
+                return;
+            }
+            idle.await;
+        }
+    }
+
+    /// Abort every tracked task
+    fn abort_all(&self) {
+        let tasks = std::mem::take(&mut *self.tasks.lock());
+        for handle in tasks.values() {
+            handle.abort();
+        }
+    }
+}
+
+/// Untracks a task when its future completes or is dropped (including on abort)
+struct TaskGuard {
+    tasks: Arc<TaskSet>,
+    id: u64,
+}
+
+impl Drop for TaskGuard {
+    fn drop(&mut self) {
+        let mut tasks = self.tasks.tasks.lock();
+        tasks.remove(&self.id);
+        if tasks.is_empty() {
+            self.tasks.idle.notify_waiters();
+        }
+    }
+}
+
+/// Client and server handles created on an executor, closed when it shuts down
+///
+/// Only handles are kept, so tracking does not extend an endpoint's lifetime. Endpoints
+/// from `dart_quic_endpoint_create` are not created on an executor and are not tracked.
+#[derive(Clone, Default)]
+pub struct EndpointTracker(Arc<Mutex<Vec<u64>>>);
+
+impl EndpointTracker {
+    /// Track a client or server handle
+    pub fn track(&self, handle: u64) {
+        let mut handles = self.0.lock();
+        handles.retain(|&handle| tracked_endpoint(handle).is_some());
+        handles.push(handle);
+    }
+
+    /// Close every tracked endpoint that has not been freed yet
+    fn close_all(&self, error_code: u32, reason: &[u8]) {
+        let handles = std::mem::take(&mut *self.0.lock());
+        for endpoint in handles.into_iter().filter_map(tracked_endpoint) {
+            endpoint.close(error_code, reason);
+        }
+    }
+}
+
+/// Endpoint behind a live client or server handle
+fn tracked_endpoint(handle: u64) -> Option<QuicEndpoint> {
+    match handle_registry::handle_type(handle)? {
+        QuicHandleType::Client => handle_registry::get::<QuicClient>(handle).map(|client| client.as_endpoint().clone()),
+        QuicHandleType::Server => handle_registry::get::<QuicServer>(handle).map(|server| server.as_endpoint().clone()),
+        _ => None,
+    }
+}
+
 // ============================================
 // QUIC Executor
 // ============================================
 
 /// Simplified QUIC executor - only manages tokio runtime
 pub struct QuicExecutor {
-    /// Tokio runtime manager
-    runtime: Arc<OnceCell<RuntimeManager>>,
+    /// Tokio runtime manager (None before init and after shutdown)
+    runtime: Arc<Mutex<Option<RuntimeManager>>>,
     /// Running state
     running: Arc<AtomicBool>,
+    /// In-flight cancellable operations
+    operations: OperationTable,
+    /// Last issued operation ID (0 is never issued)
+    last_operation_id: AtomicU64,
+    /// Tasks spawned on the runtime
+    tasks: Arc<TaskSet>,
+    /// Endpoints closed by `shutdown_graceful`
+    endpoints: EndpointTracker,
 }
 
 impl QuicExecutor {
     /// Create a new executor
     pub fn new() -> Self {
         Self {
-            runtime: Arc::new(OnceCell::new()),
+            runtime: Arc::new(Mutex::new(None)),
             running: Arc::new(AtomicBool::new(false)),
+            operations: Arc::new(Mutex::new(HashMap::new())),
+            last_operation_id: AtomicU64::new(0),
+            tasks: Arc::new(TaskSet::default()),
+            endpoints: EndpointTracker::default(),
         }
     }
     
     /// Initialize the tokio runtime
+    ///
+    /// Fails if a runtime is already running or still shutting down; after
+    /// `shutdown_graceful` completes the executor can be initialized again.
     pub fn init_runtime(&self, threads: usize, callback: BoolCallback) {
+        self.init_runtime_with_config(RuntimeConfig::with_threads(threads), callback);
+    }
+
+    /// Initialize the tokio runtime with a full configuration
+    ///
+    /// Runtime build errors are reported through `callback`.
+    pub fn init_runtime_with_config(&self, config: RuntimeConfig, callback: BoolCallback) {
         let running = Arc::clone(&self.running);
         let runtime = Arc::clone(&self.runtime);
         let callback = SendableCallback(callback);
         
         std::thread::spawn(move || {
-            let manager = RuntimeManager::new(threads);
-            let success = runtime.set(manager).is_ok();
-            running.store(success, Ordering::Release);
-            if success {
-                (callback.0)(true, true, std::ptr::null(), 0);
-            } else {
+            let mut slot = runtime.lock();
+            if slot.is_some() {
+                drop(slot);
                 let err = b"Failed to initialize runtime";
                 (callback.0)(false, false, err.as_ptr(), err.len());
+                return;
+            }
+            match RuntimeManager::new(&config) {
+                Ok(manager) => {
+                    tracing::debug!(?config, "runtime initialized");
+                    *slot = Some(manager);
+                    running.store(true, Ordering::Release);
+                    drop(slot);
+                    (callback.0)(true, true, std::ptr::null(), 0);
+                }
+                Err(e) => {
+                    drop(slot);
+                    tracing::error!(error = %e, "failed to build runtime");
+                    let err = crate::FfiErrBuf::new(format!("Failed to initialize runtime: {}", e));
+                    (callback.0)(false, false, err.as_ptr(), err.len());
+                }
             }
         });
     }
@@ -81,27 +331,148 @@ impl QuicExecutor {
         self.running.load(Ordering::Acquire)
     }
     
-    /// Shutdown the executor
+    /// Stop accepting new tasks (the runtime is destroyed when the executor is dropped)
     pub fn shutdown(&self) {
         self.running.store(false, Ordering::Release);
     }
+
+    /// Tracker for endpoints that `shutdown_graceful` should close
+    pub fn endpoint_tracker(&self) -> EndpointTracker {
+        self.endpoints.clone()
+    }
+
+    /// Shut down the runtime, draining in-flight tasks first
+    ///
+    /// Runs on a separate thread:
+    /// 1. Stops accepting new tasks and closes all tracked endpoints
+    /// 2. Waits up to `grace_period` for tracked tasks to finish
+    /// 3. Aborts the remaining tasks (their callbacks do not fire)
+    /// 4. Destroys the runtime
+    ///
+    /// `callback` then fires with `value = true` if every task finished in time, or
+    /// `false` if some were aborted. Fails immediately if the executor is not running.
+    pub fn shutdown_graceful(&self, grace_period: Duration, callback: BoolCallback) {
+        if self.running.compare_exchange(true, false, Ordering::AcqRel, Ordering::Acquire).is_err() {
+            callback(false, false, ERR_NOT_RUNNING.as_ptr(), ERR_NOT_RUNNING.len());
+            return;
+        }
+
+        let runtime = Arc::clone(&self.runtime);
+        let operations = Arc::clone(&self.operations);
+        let tasks = Arc::clone(&self.tasks);
+        let endpoints = self.endpoints.clone();
+        let callback = SendableCallback(callback);
+
+        std::thread::spawn(move || {
+            endpoints.close_all(0, b"executor shutdown");
+
+            // Taking the lock after clearing `running` also waits out submits that
+            // passed their check, so every task spawned from here on is tracked
+            let rt = runtime.lock().as_ref().map(RuntimeManager::get_runtime);
+            let drained = match rt {
+                Some(rt) => rt.block_on(async {
+                    tokio::time::timeout(grace_period, tasks.wait_idle()).await.is_ok()
+                }),
+                None => true,
+            };
+            if !drained {
+                tracing::warn!(?grace_period, "tasks still running after grace period, aborting");
+                tasks.abort_all();
+            }
+
+            // Dropping the runtime drops every remaining task. Aborted cancellable
+            // operations are still registered, so they do not fire `on_cancel`.
+            let manager = runtime.lock().take();
+            drop(manager);
+            operations.lock().clear();
+
+            (callback.0)(true, drained, std::ptr::null(), 0);
+        });
+    }
     
     /// Submit an async task for execution
-    pub fn submit_async<F>(&self, future: F) -> bool
+    ///
+    /// If the task panics, `report` (the operation's completion callback) fails with
+    /// `QuicResult::InternalError`.
+    pub fn submit_async<F>(&self, report: impl PanicReport + Send + 'static, future: F) -> bool
     where
         F: Future<Output = ()> + Send + 'static,
     {
         if !self.running.load(Ordering::Acquire) {
             return false;
         }
-        
-        if let Some(manager) = self.runtime.get() {
-            manager.spawn(future);
+
+        let runtime = self.runtime.lock();
+        // Re-checked under the lock: `shutdown_graceful` may have started meanwhile
+        if !self.running.load(Ordering::Acquire) {
+            return false;
+        }
+        if let Some(manager) = runtime.as_ref() {
+            self.tasks.spawn(manager, panic_guard::guard_future(report, future));
             true
         } else {
             false
         }
     }
+
+    /// Submit a cancellable async task
+    ///
+    /// `future` resolves to a closure that fires the completion callback. If the operation
+    /// is cancelled with [`cancel`](Self::cancel) first, `on_cancel` fires instead; exactly
+    /// one of the two runs. If the operation panics, `report` fails with
+    /// `QuicResult::InternalError` instead of the completion callback firing.
+    ///
+    /// Returns the operation ID, or 0 if the executor is not running.
+    pub fn submit_cancellable<F, D>(
+        &self,
+        report: impl PanicReport + Send + 'static,
+        future: F,
+        on_cancel: impl FnOnce() + Send + 'static,
+    ) -> u64
+    where
+        F: Future<Output = D> + Send + 'static,
+        D: FnOnce(),
+    {
+        if !self.running.load(Ordering::Acquire) {
+            return 0;
+        }
+        let runtime = self.runtime.lock();
+        // Re-checked under the lock: `shutdown_graceful` may have started meanwhile
+        if !self.running.load(Ordering::Acquire) {
+            return 0;
+        }
+        let Some(manager) = runtime.as_ref() else {
+            return 0;
+        };
+
+        let id = self.last_operation_id.fetch_add(1, Ordering::Relaxed) + 1;
+        let operation = CancellableOperation {
+            id,
+            operations: Arc::clone(&self.operations),
+            future: Some(Box::pin(future)),
+            on_cancel: Some(Box::new(on_cancel)),
+            report: Box::new(report),
+        };
+
+        // Hold the lock so the task cannot complete before it is registered
+        let mut operations = self.operations.lock();
+        let handle = self.tasks.spawn(manager, operation);
+        operations.insert(id, handle);
+        id
+    }
+
+    /// Cancel an in-flight operation started with [`submit_cancellable`](Self::submit_cancellable)
+    ///
+    /// Returns false if the operation is unknown or has already completed.
+    pub fn cancel(&self, id: u64) -> bool {
+        match self.operations.lock().remove(&id) {
+            Some(handle) => {
+                handle.abort();
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 impl Default for QuicExecutor {
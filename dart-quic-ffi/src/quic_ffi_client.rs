@@ -5,15 +5,17 @@ use std::os::raw::c_char;
 
 use crate::ERR_NOT_RUNNING;
 use crate::quic_executor::{
-    UsizeCallback, QuicExecutor, SendableCallback, VoidCallback,
+    with_timeout, UsizeCallback, QuicExecutor, SendableCallback, VoidCallback,
 };
-use crate::{quic, types};
-use crate::quic::QuicConnectionHandle;
+use crate::{handle_registry, quic, types};
+use crate::quic::{QuicClient, QuicConnectionHandle};
+use crate::quic_ffi_conn::ZeroRttCallbacks;
 
 use crate::{
     check_executor_usize, check_executor_void,
-    check_ptr_usize, check_ptr_void,
+    check_handle_usize, check_handle_void, check_ptr_usize,
 };
+use crate::panic_guard;
 
 // ============================================
 // QUIC Client FFI
@@ -27,160 +29,294 @@ use crate::{
 /// # Safety
 /// The `config` pointer and all data it references must remain valid until the callback is invoked.
 ///
-/// Returns error code. Callback receives client pointer (as usize) on success.
+/// Returns error code. Callback receives a `QuicClientHandle*` pointer (as usize) on success;
+/// free it with `dart_quic_client_handle_free` and the client with `dart_quic_client_free`.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_client_new_async(
     executor: *mut QuicExecutor,
     config: *const quic::QuicFfiClientConfig,
     callback: UsizeCallback,
 ) -> i32 {
-    if executor.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
-    
-    let executor_ref = unsafe { &*executor };
-    if !executor_ref.is_running() {
-        return types::QuicResult::RuntimeError as i32;
-    }
-
-    if config.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
-
-    let config_ptr = config as usize;
-    let callback = SendableCallback(callback);
-    
-    executor_ref.submit_async(async move {
-        let ffi_config = unsafe { &*(config_ptr as *const quic::QuicFfiClientConfig) };
-        match ffi_config.build() {
-            Ok(client) => {
-                let ptr = Box::into_raw(Box::new(client)) as usize;
-                (callback.0)(true, ptr, std::ptr::null(), 0);
+    panic_guard::guard(|| {
+        if executor.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
+
+        let executor_ref = unsafe { &*executor };
+        if !executor_ref.is_running() {
+            return types::QuicResult::RuntimeError as i32;
+        }
+
+        if config.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
+
+        let config_ptr = config as usize;
+        let callback = SendableCallback(callback);
+        let endpoints = executor_ref.endpoint_tracker();
+
+        executor_ref.submit_async(callback, async move {
+            let ffi_config = unsafe { &*(config_ptr as *const quic::QuicFfiClientConfig) };
+            match ffi_config.build() {
+                Ok(client) => {
+                    let handle = quic::QuicClientHandle::new(client);
+                    endpoints.track(handle.client);
+                    let ptr = Box::into_raw(Box::new(handle)) as usize;
+                    (callback.0)(true, ptr, std::ptr::null(), 0);
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(e.to_string());
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
             }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(e.to_string());
-                (callback.0)(false, 0, err.as_ptr(), err.len());
+        });
+
+        types::QuicResult::Success as i32
+    })
+}
+
+/// Free a client handle structure (does not free the client)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_client_handle_free(handle: *mut quic::QuicClientHandle) {
+    panic_guard::guard(|| {
+        if !handle.is_null() {
+            unsafe {
+                let _ = Box::from_raw(handle);
             }
         }
-    });
-    
-    types::QuicResult::Success as i32
+    })
 }
 
 /// Free client
+///
+/// Returns InvalidParameter if the handle is not a live client (e.g. already freed).
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dart_quic_client_free(client: *mut quic::QuicClient) {
-    if !client.is_null() {
-        unsafe {
-            let _ = Box::from_raw(client);
+pub extern "C" fn dart_quic_client_free(client: u64) -> i32 {
+    panic_guard::guard(|| {
+        match handle_registry::remove::<QuicClient>(client) {
+            Some(_) => types::QuicResult::Success as i32,
+            None => types::QuicResult::InvalidParameter as i32,
         }
-    }
+    })
 }
 
 /// Close client (sync)
 /// Returns error code
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_client_close(
-    client: *mut quic::QuicClient,
+    client: u64,
     error_code: u32,
     reason: *const u8,
     reason_len: usize,
 ) -> i32 {
-    if client.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
-
-    let reason_bytes = if reason.is_null() || reason_len == 0 {
-        &[]
-    } else {
-        unsafe { std::slice::from_raw_parts(reason, reason_len) }
-    };
-
-    unsafe { (*client).close(error_code, reason_bytes) };
-    types::QuicResult::Success as i32
+    panic_guard::guard(|| {
+        let Some(client) = handle_registry::get::<QuicClient>(client) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+
+        let reason_bytes = if reason.is_null() || reason_len == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(reason, reason_len) }
+        };
+
+        client.close(error_code, reason_bytes);
+        types::QuicResult::Success as i32
+    })
 }
 
 /// Connect to server asynchronously
 ///
 /// Callback receives QuicConnectionHandle pointer on success.
 /// The handle contains:
-/// - connection pointer (for subsequent operations)
+/// - connection registry handle (for subsequent operations)
 /// - stable_id (connection ID)
 /// - remote_addr (remote address string)
 ///
-/// Use `dart_quic_connection_handle_free` to free the handle.
+/// `server_addr` is "host:port"; host names are resolved asynchronously. Resolution
+/// failures are reported with a `[HostResolutionFailed]` error message.
+///
+/// `timeout_ms` bounds resolution plus handshake; when exceeded the attempt is abandoned
+/// and the callback fails with a `[Timeout]` error (0 = no timeout).
+///
+/// Use `dart_quic_connection_handle_free` to free the handle structure and
+/// `dart_quic_connection_free` to release the connection.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_client_connect(
     executor: *mut QuicExecutor,
-    client: *mut quic::QuicClient,
+    client: u64,
     server_addr: *const c_char,
     server_name: *const c_char,
+    timeout_ms: u64,
     callback: UsizeCallback,
 ) {
-    check_executor_usize!(executor, callback);
-    check_ptr_usize!(client, callback);
-
-    // Parse addresses
-    let addr = match unsafe { CStr::from_ptr(server_addr).to_str() } {
-        Ok(s) => s.to_string(),
-        Err(_) => {
-            let err = b"Invalid server address";
-            callback(false, 0, err.as_ptr(), err.len());
-            return;
-        }
-    };
-
-    let name = match unsafe { CStr::from_ptr(server_name).to_str() } {
-        Ok(s) => s.to_string(),
-        Err(_) => {
-            let err = b"Invalid server name";
-            callback(false, 0, err.as_ptr(), err.len());
-            return;
+    panic_guard::guard_with(callback, || {
+        check_executor_usize!(executor, callback);
+        let client = check_handle_usize!(QuicClient, client, callback);
+
+        // Parse addresses
+        let addr = match unsafe { CStr::from_ptr(server_addr).to_str() } {
+            Ok(s) => s.to_string(),
+            Err(_) => {
+                let err = b"Invalid server address";
+                callback(false, 0, err.as_ptr(), err.len());
+                return;
+            }
+        };
+
+        let name = match unsafe { CStr::from_ptr(server_name).to_str() } {
+            Ok(s) => s.to_string(),
+            Err(_) => {
+                let err = b"Invalid server name";
+                callback(false, 0, err.as_ptr(), err.len());
+                return;
+            }
+        };
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            match with_timeout(timeout_ms, client.connect(&addr, &name)).await.and_then(|r| r) {
+                Ok(conn) => {
+                    // Create handle with connection info
+                    let handle = QuicConnectionHandle::new(conn);
+                    let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
+                    (callback.0)(true, handle_ptr, std::ptr::null(), 0);
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
+            }
+        }) {
+            callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    };
-
-    let client_ptr = client as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-
-    if !exec.submit_async(async move {
-        let client = unsafe { &*(client_ptr as *const quic::QuicClient) };
-        match client.connect(&addr, &name).await {
-            Ok(conn) => {
-                // Create handle with connection info
-                let handle = QuicConnectionHandle::new(conn);
-                let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
-                (callback.0)(true, handle_ptr, std::ptr::null(), 0);
+    })
+}
+
+/// Connect to server with 0-RTT early data (async)
+///
+/// If a resumable session exists and `enable_early_data` was set in the client config,
+/// `callback` fires immediately with a usable `QuicConnectionHandle*`, before the handshake
+/// completes. Otherwise it fires after the full handshake, like `dart_quic_client_connect`.
+///
+/// `zero_rtt_callback` fires once the handshake completes with a `QuicZeroRttStatus` value:
+/// - 0 (NotAttempted): no early data was sent
+/// - 1 (Accepted): early data was accepted by the server
+/// - 2 (Rejected): early data was rejected; streams opened before this point fail with `ZeroRttRejected`
+///
+/// On error, including invalid arguments, both callbacks receive the same error message.
+///
+/// ⚠️ Early data is replayable; only send idempotent requests before the handshake completes.
+///
+/// # Safety
+/// `executor` must be valid; `server_addr` and `server_name` must be null-terminated C strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_client_connect_0rtt(
+    executor: *mut QuicExecutor,
+    client: u64,
+    server_addr: *const c_char,
+    server_name: *const c_char,
+    callback: UsizeCallback,
+    zero_rtt_callback: UsizeCallback,
+) {
+    let callbacks = ZeroRttCallbacks::new(callback, zero_rtt_callback);
+    let fail = |success, value, error_ptr, error_len| callbacks.fail(success, value, error_ptr, error_len);
+    panic_guard::guard_with(callbacks.clone(), || {
+        check_executor_usize!(executor, fail);
+        let client = check_handle_usize!(QuicClient, client, fail);
+
+        check_ptr_usize!(server_addr, fail);
+        check_ptr_usize!(server_name, fail);
+
+        let addr = match unsafe { CStr::from_ptr(server_addr).to_str() } {
+            Ok(s) => s.to_string(),
+            Err(_) => {
+                callbacks.fail_with("Invalid server address");
+                return;
             }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, 0, err.as_ptr(), err.len());
+        };
+
+        let name = match unsafe { CStr::from_ptr(server_name).to_str() } {
+            Ok(s) => s.to_string(),
+            Err(_) => {
+                callbacks.fail_with("Invalid server name");
+                return;
             }
+        };
+
+        let exec = unsafe { &*executor };
+        let task_callbacks = callbacks.clone();
+
+        if !exec.submit_async(callbacks.clone(), async move {
+            let result = client.connect_0rtt(&addr, &name).await;
+            task_callbacks.complete(Some(result)).await;
+        }) {
+            callbacks.fail_with(ERR_NOT_RUNNING);
         }
-    }) {
-        callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+    })
 }
 
 /// Wait for client to become idle
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_client_wait_idle(
     executor: *mut QuicExecutor,
-    client: *mut quic::QuicClient,
+    client: u64,
     callback: VoidCallback,
 ) {
-    check_executor_void!(executor, callback);
-    check_ptr_void!(client, callback);
-
-    let client_ptr = client as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-
-    if !exec.submit_async(async move {
-        let client = unsafe { &*(client_ptr as *const quic::QuicClient) };
-        client.wait_idle().await;
-        (callback.0)(true, std::ptr::null(), 0);
-    }) {
-        callback.0(false, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+    panic_guard::guard_with(callback, || {
+        check_executor_void!(executor, callback);
+        let client = check_handle_void!(QuicClient, client, callback);
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            client.wait_idle().await;
+            (callback.0)(true, std::ptr::null(), 0);
+        }) {
+            callback.0(false, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
+        }
+    })
+}
+
+/// Get client endpoint statistics (sync)
+///
+/// See `dart_quic_endpoint_stats`.
+///
+/// # Safety
+/// out_stats must point to writable memory for a `QuicFfiEndpointStats`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_client_stats(
+    client: u64,
+    out_stats: *mut quic::QuicFfiEndpointStats,
+) -> i32 {
+    panic_guard::guard(|| {
+        let Some(client) = handle_registry::get::<QuicClient>(client) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+        unsafe { crate::quic_ffi_endpoint::write_endpoint_stats(client.as_endpoint(), out_stats) }
+    })
+}
+
+/// Enumerate stable IDs of live connections opened by the client (sync)
+///
+/// See `dart_quic_endpoint_connection_ids`. Free the array with
+/// `dart_free_memory(ids, count * sizeof(uint64_t))`.
+///
+/// # Safety
+/// out_ids and out_count must be valid pointers
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_client_connection_ids(
+    client: u64,
+    out_ids: *mut *mut u64,
+    out_count: *mut usize,
+) -> i32 {
+    panic_guard::guard(|| {
+        let Some(client) = handle_registry::get::<QuicClient>(client) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+        unsafe { crate::quic_ffi_endpoint::write_connection_ids(client.as_endpoint(), out_ids, out_count) }
+    })
 }
@@ -1,54 +1,290 @@
 //! QUIC Connection FFI - Connection and stream operations
 
-use crate::ERR_NOT_RUNNING;
+use crate::{ERR_CANCELLED, ERR_NOT_RUNNING};
 use crate::quic_executor::{
-    BytesCallback, UsizeCallback, QuicExecutor, SendableCallback,
+    with_timeout, BytesCallback, UsizeCallback, QuicExecutor, SendableCallback,
 };
 use crate::quic_ffi_stream_result::QuicFfiStreamPair;
-use crate::{allocate, deallocate, quic, types};
-use crate::quic::QuicConnectionHandle;
+use crate::{allocate, deallocate, handle_registry, quic, types};
+use crate::error::QuicError;
+use crate::quic::{QuicConnection, QuicConnectionHandle};
 
 use crate::{
     check_executor_bytes, check_executor_usize,
-    check_ptr_bytes, check_ptr_usize,
+    check_handle_bytes, check_handle_usize,
 };
+use crate::panic_guard;
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 // ============================================
 // QUIC Connection Handle FFI
 // ============================================
 
-/// Free connection handle and its resources
+/// Free a connection handle structure
 ///
 /// This frees:
-/// - The connection itself
 /// - The remote address string
+/// - The handshake data strings
 /// - The handle structure
+///
+/// The connection itself stays open; release it with `dart_quic_connection_free`.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_connection_handle_free(handle: *mut QuicConnectionHandle) {
-    if handle.is_null() {
-        return;
-    }
+    panic_guard::guard(|| {
+        if handle.is_null() {
+            return;
+        }
+
+        let handle_ref = unsafe { &mut *handle };
 
-    let handle_ref = unsafe { &mut *handle };
+        // Free the remote address string
+        if !handle_ref.remote_addr.is_null() && handle_ref.remote_addr_len > 0 {
+            deallocate(handle_ref.remote_addr, handle_ref.remote_addr_len as usize);
+        }
 
-    // Free the connection
-    if !handle_ref.connection.is_null() {
+        // Free the handle itself
         unsafe {
-            let _ = Box::from_raw(handle_ref.connection);
+            let _ = Box::from_raw(handle);
+        }
+    })
+}
+
+/// Release a connection
+///
+/// The connection is dropped (and implicitly closed with code 0 once its streams are
+/// gone) after in-flight operations on it complete.
+///
+/// # Returns
+/// - 0 (Success) on success
+/// - InvalidParameter if the handle is not a live connection (e.g. already freed)
+#[unsafe(no_mangle)]
+pub extern "C" fn dart_quic_connection_free(connection: u64) -> i32 {
+    panic_guard::guard(|| {
+        match handle_registry::remove::<QuicConnection>(connection) {
+            Some(_) => types::QuicResult::Success as i32,
+            None => types::QuicResult::InvalidParameter as i32,
+        }
+    })
+}
+
+/// Callback pair of a 0-RTT connect / 0.5-RTT accept
+///
+/// Both callbacks fire exactly once:
+/// - `callback` first, with the `QuicConnectionHandle*` (0 if the endpoint closed)
+/// - `zero_rtt_callback` after the handshake completes, with a `QuicZeroRttStatus` value
+///   (0 = NotAttempted, 1 = Accepted, 2 = Rejected)
+///
+/// Argument errors, submit failures and panics fail whichever callbacks have not fired
+/// yet, so the pair is also the panic reporter of the entry point and its task.
+#[derive(Clone)]
+pub(crate) struct ZeroRttCallbacks {
+    callback: SendableCallback<UsizeCallback>,
+    zero_rtt_callback: SendableCallback<UsizeCallback>,
+    connected: Arc<AtomicBool>,
+}
+
+impl ZeroRttCallbacks {
+    pub(crate) fn new(callback: UsizeCallback, zero_rtt_callback: UsizeCallback) -> Self {
+        Self {
+            callback: SendableCallback(callback),
+            zero_rtt_callback: SendableCallback(zero_rtt_callback),
+            connected: Arc::new(AtomicBool::new(false)),
         }
     }
 
-    // Free the remote address string
-    if !handle_ref.remote_addr.is_null() && handle_ref.remote_addr_len > 0 {
-        deallocate(handle_ref.remote_addr, handle_ref.remote_addr_len as usize);
+    /// Fail both callbacks (`callback` only if it has not fired yet)
+    ///
+    /// Takes the `UsizeCallback` arguments so it can stand in for a callback in the
+    /// `check_*_usize!` macros.
+    pub(crate) fn fail(&self, success: bool, value: usize, error_ptr: *const u8, error_len: usize) {
+        if !self.connected.swap(true, Ordering::AcqRel) {
+            (self.callback.0)(success, value, error_ptr, error_len);
+        }
+        (self.zero_rtt_callback.0)(success, value, error_ptr, error_len);
+    }
+
+    /// Fail both callbacks with `message`
+    pub(crate) fn fail_with(&self, message: &str) {
+        self.fail(false, 0, message.as_ptr(), message.len());
+    }
+
+    /// Deliver the result of the operation
+    ///
+    /// On error both callbacks receive the same error message.
+    pub(crate) async fn complete(
+        self,
+        result: Option<Result<(quic::QuicConnection, quic::QuicZeroRttHandshake), QuicError>>,
+    ) {
+        match result {
+            Some(Ok((conn, handshake))) => {
+                let handle = QuicConnectionHandle::new(conn);
+                let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
+                self.connected.store(true, Ordering::Release);
+                (self.callback.0)(true, handle_ptr, std::ptr::null(), 0);
+
+                let status = handshake.wait().await;
+                (self.zero_rtt_callback.0)(true, status as usize, std::ptr::null(), 0);
+            }
+            Some(Err(e)) => {
+                let err = crate::FfiErrBuf::new(format!("{}", e));
+                self.fail(false, 0, err.as_ptr(), err.len());
+            }
+            None => {
+                // Endpoint closed — signal completion with value=0
+                self.connected.store(true, Ordering::Release);
+                (self.callback.0)(true, 0, std::ptr::null(), 0);
+                (self.zero_rtt_callback.0)(true, quic::QuicZeroRttStatus::NotAttempted as usize, std::ptr::null(), 0);
+            }
+        }
     }
+}
 
-    // Free the handle itself
-    unsafe {
-        let _ = Box::from_raw(handle);
+impl panic_guard::PanicReport for ZeroRttCallbacks {
+    fn report_panic(&self, err: &QuicError) {
+        let err = crate::FfiErrBuf::new(err.to_string());
+        self.fail(false, 0, err.as_ptr(), err.len());
     }
 }
 
+/// Subscribe to the connection close event (async)
+///
+/// `callback` fires exactly once, when the connection closes for any reason, with a
+/// `QuicFfiCloseReason*` (free with `dart_quic_close_reason_free`). If the connection
+/// is already closed it fires immediately.
+///
+/// The subscription does not keep the connection alive: once the handle is freed and
+/// in-flight operations finish, the connection closes and the callback reports
+/// `LocallyClosed` with code 0.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_connection_on_closed(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    callback: UsizeCallback,
+) {
+    panic_guard::guard_with(callback, || {
+        check_executor_usize!(executor, callback);
+        let conn = check_handle_usize!(QuicConnection, handle, callback);
+
+        let close_reason = conn.watch_close_reason();
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            let reason = close_reason.await;
+            let reason_ptr = Box::into_raw(Box::new(quic::QuicFfiCloseReason::new(&reason))) as usize;
+            (callback.0)(true, reason_ptr, std::ptr::null(), 0);
+        }) {
+            callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
+        }
+    })
+}
+
+/// Get the close reason if the connection is already closed (sync)
+///
+/// # Parameters
+/// - `handle`: Connection handle
+/// - `out_reason`: Receives a `QuicFfiCloseReason*` (free with `dart_quic_close_reason_free`),
+///   or null while the connection is still open
+///
+/// # Returns
+/// - 0 (Success), InvalidParameter if the handle is stale or `out_reason` is null
+///
+/// # Safety
+/// `out_reason` must be a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_connection_close_reason(
+    handle: u64,
+    out_reason: *mut *mut quic::QuicFfiCloseReason,
+) -> i32 {
+    panic_guard::guard(|| {
+        if out_reason.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
+        let Some(conn) = handle_registry::get::<QuicConnection>(handle) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+
+        let reason = conn
+            .close_reason()
+            .map_or(std::ptr::null_mut(), |reason| {
+                Box::into_raw(Box::new(quic::QuicFfiCloseReason::new(&reason)))
+            });
+        unsafe { *out_reason = reason };
+        types::QuicResult::Success as i32
+    })
+}
+
+/// Get the TLS handshake results of a connection (sync)
+///
+/// Use this for connections whose handle was created before the handshake data was
+/// available (0-RTT connects); otherwise `QuicConnectionHandle::handshake` has it already.
+///
+/// # Parameters
+/// - `handle`: Connection handle
+/// - `out_data`: Receives a `QuicFfiHandshakeData*` (free with `dart_quic_handshake_data_free`),
+///   or null while the handshake data is not available yet
+///
+/// # Returns
+/// - 0 (Success), InvalidParameter if the handle is stale or `out_data` is null
+///
+/// # Safety
+/// `out_data` must be a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_connection_handshake_data(
+    handle: u64,
+    out_data: *mut *mut quic::QuicFfiHandshakeData,
+) -> i32 {
+    panic_guard::guard(|| {
+        if out_data.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
+        let Some(conn) = handle_registry::get::<QuicConnection>(handle) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+
+        let data = conn.handshake_data().map_or(std::ptr::null_mut(), |data| {
+            Box::into_raw(Box::new(quic::QuicFfiHandshakeData::new(Some(&data))))
+        });
+        unsafe { *out_data = data };
+        types::QuicResult::Success as i32
+    })
+}
+
+/// Free handshake data returned by `dart_quic_connection_handshake_data`
+///
+/// # Safety
+/// `data` must be null or a pointer returned by that function, freed only once.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_handshake_data_free(data: *mut quic::QuicFfiHandshakeData) {
+    panic_guard::guard(|| {
+        if !data.is_null() {
+            unsafe {
+                let _ = Box::from_raw(data);
+            }
+        }
+    })
+}
+
+/// Free a close reason returned by `dart_quic_connection_on_closed` / `dart_quic_connection_close_reason`
+///
+/// # Safety
+/// `reason` must be null or a pointer returned by those functions, freed only once.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_close_reason_free(reason: *mut quic::QuicFfiCloseReason) {
+    panic_guard::guard(|| {
+        if !reason.is_null() {
+            unsafe {
+                let _ = Box::from_raw(reason);
+            }
+        }
+    })
+}
+
 /// Close connection (sync)
 ///
 /// # Parameters
@@ -56,29 +292,30 @@ pub unsafe extern "C" fn dart_quic_connection_handle_free(handle: *mut QuicConne
 /// - `error_code`: Application error code
 /// - `reason`: Close reason bytes (nullable)
 /// - `reason_len`: Length of reason bytes
+///
+/// # Returns
+/// - 0 (Success), InvalidParameter if the handle is stale
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_connection_close(
-    handle: *mut QuicConnectionHandle,
+    handle: u64,
     error_code: u32,
     reason: *const u8,
     reason_len: usize,
-) {
-    if handle.is_null() {
-        return;
-    }
-
-    let handle_ref = unsafe { &*handle };
-    if handle_ref.connection.is_null() {
-        return;
-    }
-
-    let reason_bytes = if reason.is_null() || reason_len == 0 {
-        &[]
-    } else {
-        unsafe { std::slice::from_raw_parts(reason, reason_len) }
-    };
-
-    unsafe { (*handle_ref.connection).close(error_code, reason_bytes) };
+) -> i32 {
+    panic_guard::guard(|| {
+        let Some(conn) = handle_registry::get::<QuicConnection>(handle) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+
+        let reason_bytes = if reason.is_null() || reason_len == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(reason, reason_len) }
+        };
+
+        conn.close(error_code, reason_bytes);
+        types::QuicResult::Success as i32
+    })
 }
 
 /// Open bidirectional stream
@@ -88,162 +325,172 @@ pub unsafe extern "C" fn dart_quic_connection_close(
 /// # Parameters
 /// - `executor`: Executor for async operations
 /// - `handle`: Connection handle
+/// - `priority`: Initial send priority (higher is sent first, default 0)
+/// - `timeout_ms`: Fail with `[Timeout]` if no stream is available in time (0 = no timeout)
 /// - `callback`: Callback receiving stream pair pointer
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_connection_open_bi(
     executor: *mut QuicExecutor,
-    handle: *mut QuicConnectionHandle,
+    handle: u64,
+    priority: i32,
+    timeout_ms: u64,
     callback: UsizeCallback,
 ) {
-    check_executor_usize!(executor, callback);
-    check_ptr_usize!(handle, callback);
-
-    let conn_ptr = unsafe { (*handle).connection } as usize;
-    if conn_ptr == 0 {
-        let err = b"Invalid connection handle";
-        callback(false, 0, err.as_ptr(), err.len());
-        return;
-    }
-
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-
-    if !exec.submit_async(async move {
-        let conn = unsafe { &*(conn_ptr as *const quic::QuicConnection) };
-        match conn.open_bi().await {
-            Ok((send, recv)) => {
-                let pair = Box::new(QuicFfiStreamPair::bi(send, recv));
-                let pair_ptr = Box::into_raw(pair) as usize;
-                (callback.0)(true, pair_ptr, std::ptr::null(), 0);
-            }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, 0, err.as_ptr(), err.len());
+    panic_guard::guard_with(callback, || {
+        check_executor_usize!(executor, callback);
+        let conn = check_handle_usize!(QuicConnection, handle, callback);
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            let result = with_timeout(timeout_ms, conn.open_bi()).await.and_then(|r| r).and_then(|(send, recv)| {
+                send.set_priority(priority)?;
+                Ok((send, recv))
+            });
+            match result {
+                Ok((send, recv)) => {
+                    let pair = Box::new(QuicFfiStreamPair::bi(send, recv));
+                    let pair_ptr = Box::into_raw(pair) as usize;
+                    (callback.0)(true, pair_ptr, std::ptr::null(), 0);
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
             }
+        }) {
+            callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    }) {
-        callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+    })
 }
 
 /// Open unidirectional stream (send only)
 ///
 /// Returns QuicFfiStreamPair structure pointer via UsizeCallback.
+/// `priority` sets the initial send priority (higher is sent first, default 0).
+/// `timeout_ms` fails the open with `[Timeout]` if exceeded (0 = no timeout).
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_connection_open_uni(
     executor: *mut QuicExecutor,
-    handle: *mut QuicConnectionHandle,
+    handle: u64,
+    priority: i32,
+    timeout_ms: u64,
     callback: UsizeCallback,
 ) {
-    check_executor_usize!(executor, callback);
-    check_ptr_usize!(handle, callback);
-
-    let conn_ptr = unsafe { (*handle).connection } as usize;
-    if conn_ptr == 0 {
-        let err = b"Invalid connection handle";
-        callback(false, 0, err.as_ptr(), err.len());
-        return;
-    }
-
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-
-    if !exec.submit_async(async move {
-        let conn = unsafe { &*(conn_ptr as *const quic::QuicConnection) };
-        match conn.open_uni().await {
-            Ok(send) => {
-                let pair = Box::new(QuicFfiStreamPair::send_only(send));
-                let pair_ptr = Box::into_raw(pair) as usize;
-                (callback.0)(true, pair_ptr, std::ptr::null(), 0);
-            }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, 0, err.as_ptr(), err.len());
+    panic_guard::guard_with(callback, || {
+        check_executor_usize!(executor, callback);
+        let conn = check_handle_usize!(QuicConnection, handle, callback);
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            let result = with_timeout(timeout_ms, conn.open_uni()).await.and_then(|r| r).and_then(|send| {
+                send.set_priority(priority)?;
+                Ok(send)
+            });
+            match result {
+                Ok(send) => {
+                    let pair = Box::new(QuicFfiStreamPair::send_only(send));
+                    let pair_ptr = Box::into_raw(pair) as usize;
+                    (callback.0)(true, pair_ptr, std::ptr::null(), 0);
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
             }
+        }) {
+            callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    }) {
-        callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+    })
 }
 
 /// Accept bidirectional stream
 ///
 /// Returns QuicFfiStreamPair structure pointer via UsizeCallback.
+/// `timeout_ms` fails the accept with `[Timeout]` if exceeded (0 = no timeout).
+/// If cancelled, the callback fails with an error starting with `[Cancelled]`.
+///
+/// # Returns
+/// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+/// (the callback has then already been called with the error).
+///
+/// # Safety
+/// `executor` must be valid.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_connection_accept_bi(
     executor: *mut QuicExecutor,
-    handle: *mut QuicConnectionHandle,
+    handle: u64,
+    timeout_ms: u64,
     callback: UsizeCallback,
-) {
-    check_executor_usize!(executor, callback);
-    check_ptr_usize!(handle, callback);
-
-    let conn_ptr = unsafe { (*handle).connection } as usize;
-    if conn_ptr == 0 {
-        let err = b"Invalid connection handle";
-        callback(false, 0, err.as_ptr(), err.len());
-        return;
-    }
-
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-
-    if !exec.submit_async(async move {
-        let conn = unsafe { &*(conn_ptr as *const quic::QuicConnection) };
-        match conn.accept_bi().await {
-            Ok((send, recv)) => {
-                let pair = Box::new(QuicFfiStreamPair::bi(send, recv));
-                let pair_ptr = Box::into_raw(pair) as usize;
-                (callback.0)(true, pair_ptr, std::ptr::null(), 0);
-            }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, 0, err.as_ptr(), err.len());
+) -> u64 {
+    panic_guard::guard_with(callback, || {
+        check_executor_usize!(executor, callback, 0);
+        let conn = check_handle_usize!(QuicConnection, handle, callback, 0);
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        let operation_id = exec.submit_cancellable(callback, async move {
+            let result = with_timeout(timeout_ms, conn.accept_bi()).await.and_then(|r| r);
+            move || match result {
+                Ok((send, recv)) => {
+                    let pair = Box::new(QuicFfiStreamPair::bi(send, recv));
+                    let pair_ptr = Box::into_raw(pair) as usize;
+                    (callback.0)(true, pair_ptr, std::ptr::null(), 0);
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
             }
+        }, move || {
+            (callback.0)(false, 0, ERR_CANCELLED.as_ptr(), ERR_CANCELLED.len());
+        });
+
+        if operation_id == 0 {
+            callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    }) {
-        callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+        operation_id
+    })
 }
 
 /// Accept unidirectional stream (recv only)
 ///
 /// Returns QuicFfiStreamPair structure pointer via UsizeCallback.
+/// `timeout_ms` fails the accept with `[Timeout]` if exceeded (0 = no timeout).
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_connection_accept_uni(
     executor: *mut QuicExecutor,
-    handle: *mut QuicConnectionHandle,
+    handle: u64,
+    timeout_ms: u64,
     callback: UsizeCallback,
 ) {
-    check_executor_usize!(executor, callback);
-    check_ptr_usize!(handle, callback);
-
-    let conn_ptr = unsafe { (*handle).connection } as usize;
-    if conn_ptr == 0 {
-        let err = b"Invalid connection handle";
-        callback(false, 0, err.as_ptr(), err.len());
-        return;
-    }
-
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-
-    if !exec.submit_async(async move {
-        let conn = unsafe { &*(conn_ptr as *const quic::QuicConnection) };
-        match conn.accept_uni().await {
-            Ok(recv) => {
-                let pair = Box::new(QuicFfiStreamPair::recv_only(recv));
-                let pair_ptr = Box::into_raw(pair) as usize;
-                (callback.0)(true, pair_ptr, std::ptr::null(), 0);
-            }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, 0, err.as_ptr(), err.len());
+    panic_guard::guard_with(callback, || {
+        check_executor_usize!(executor, callback);
+        let conn = check_handle_usize!(QuicConnection, handle, callback);
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            match with_timeout(timeout_ms, conn.accept_uni()).await.and_then(|r| r) {
+                Ok(recv) => {
+                    let pair = Box::new(QuicFfiStreamPair::recv_only(recv));
+                    let pair_ptr = Box::into_raw(pair) as usize;
+                    (callback.0)(true, pair_ptr, std::ptr::null(), 0);
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
             }
+        }) {
+            callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    }) {
-        callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+    })
 }
 
 /// Send datagram (sync, unreliable)
@@ -258,25 +505,25 @@ pub unsafe extern "C" fn dart_quic_connection_accept_uni(
 /// - Error code on failure
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_connection_send_datagram(
-    handle: *mut QuicConnectionHandle,
+    handle: u64,
     data: *const u8,
     data_len: usize,
 ) -> i32 {
-    if handle.is_null() || data.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
-
-    let conn = unsafe { (*handle).connection };
-    if conn.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
+    panic_guard::guard(|| {
+        if data.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
+        let Some(conn) = handle_registry::get::<QuicConnection>(handle) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
 
-    let data_bytes = unsafe { std::slice::from_raw_parts(data, data_len) };
+        let data_bytes = unsafe { std::slice::from_raw_parts(data, data_len) };
 
-    match unsafe { (*conn).send_datagram(bytes::Bytes::copy_from_slice(data_bytes)) } {
-        Ok(_) => types::QuicResult::Success as i32,
-        Err(e) => e.code_value(),
-    }
+        match conn.send_datagram(bytes::Bytes::copy_from_slice(data_bytes)) {
+            Ok(_) => types::QuicResult::Success as i32,
+            Err(e) => e.code_value(),
+        }
+    })
 }
 
 /// Read datagram (async)
@@ -288,50 +535,77 @@ pub unsafe extern "C" fn dart_quic_connection_send_datagram(
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_connection_read_datagram(
     executor: *mut QuicExecutor,
-    handle: *mut QuicConnectionHandle,
+    handle: u64,
     callback: BytesCallback,
 ) {
-    check_executor_bytes!(executor, callback);
-    check_ptr_bytes!(handle, callback);
-
-    let conn_ptr = unsafe { (*handle).connection } as usize;
-    if conn_ptr == 0 {
-        let err = b"Invalid connection handle";
-        callback(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
-        return;
-    }
-
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-
-    if !exec.submit_async(async move {
-        let conn = unsafe { &*(conn_ptr as *const quic::QuicConnection) };
-        match conn.read_datagram().await {
-            Ok(data) => {
-                let len = data.len();
-                let ptr = allocate(len);
-                if !ptr.is_null() {
-                    unsafe {
-                        std::ptr::copy_nonoverlapping(data.as_ptr(), ptr, len);
+    panic_guard::guard_with(callback, || {
+        check_executor_bytes!(executor, callback);
+        let conn = check_handle_bytes!(QuicConnection, handle, callback);
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            match conn.read_datagram().await {
+                Ok(data) => {
+                    let len = data.len();
+                    let ptr = allocate(len);
+                    if !ptr.is_null() {
+                        unsafe {
+                            std::ptr::copy_nonoverlapping(data.as_ptr(), ptr, len);
+                        }
+                        (callback.0)(true, ptr, len, std::ptr::null(), 0);
+                    } else {
+                        let err = b"Allocation failed";
+                        (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
                     }
-                    (callback.0)(true, ptr, len, std::ptr::null(), 0);
-                } else {
-                    let err = b"Allocation failed";
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
                     (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
                 }
             }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
-            }
+        }) {
+            callback.0(
+                false,
+                std::ptr::null_mut(),
+                0,
+                ERR_NOT_RUNNING.as_bytes().as_ptr(),
+                ERR_NOT_RUNNING.len(),
+            );
         }
-    }) {
-        callback.0(
-            false,
-            std::ptr::null_mut(),
-            0,
-            ERR_NOT_RUNNING.as_bytes().as_ptr(),
-            ERR_NOT_RUNNING.len(),
-        );
-    }
+    })
+}
+
+/// Get connection statistics snapshot (sync)
+///
+/// Fills `out_stats` from `quinn::Connection::stats()`.
+///
+/// # Parameters
+/// - `handle`: Connection handle
+/// - `out_stats`: Output structure (caller allocated)
+///
+/// # Returns
+/// - QuicResult::Success on success
+/// - QuicResult::InvalidParameter if the handle is stale or `out_stats` is null
+///
+/// # Safety
+/// `out_stats` must point to writable memory for a `QuicFfiConnectionStats`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_connection_stats(
+    handle: u64,
+    out_stats: *mut quic::QuicFfiConnectionStats,
+) -> i32 {
+    panic_guard::guard(|| {
+        if out_stats.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
+        let Some(conn) = handle_registry::get::<QuicConnection>(handle) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+
+        let stats = conn.stats();
+        unsafe { *out_stats = quic::QuicFfiConnectionStats::from(stats) };
+        types::QuicResult::Success as i32
+    })
 }
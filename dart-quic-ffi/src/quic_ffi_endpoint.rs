@@ -5,24 +5,26 @@
 //! - Server-only mode (incoming connections)
 //! - Bidirectional mode (both outgoing and incoming)
 
-use std::net::{SocketAddr, Ipv4Addr};
 use std::ffi::CStr;
 
 use crate::{
     QuicFfiResult,
-    quic_executor::{QuicExecutor, UsizeCallback, VoidCallback, SendableCallback},
+    quic_executor::{with_timeout, QuicExecutor, UsizeCallback, VoidCallback, SendableCallback},
     quic,
-    quic::QuicConnectionHandle,
+    quic::{QuicConnectionHandle, QuicEndpoint},
+    quic_ffi_conn::ZeroRttCallbacks,
     error::QuicError,
+    handle_registry,
     types,
     allocate,
 };
 
 // Import check macros and error constants
 use crate::{
-    ERR_PTR_NULL,
-    check_executor_void, check_ptr_void, check_executor_usize, check_ptr_usize,
+    ERR_PTR_NULL, ERR_NOT_RUNNING, ERR_CANCELLED,
+    check_executor_void, check_ptr_void, check_handle_void, check_executor_usize, check_handle_usize, check_ptr_usize,
 };
+use crate::panic_guard;
 
 // ============================================
 // Endpoint Creation and Configuration
@@ -34,10 +36,11 @@ use crate::{
 /// - `config`: Endpoint configuration (mode, bind IP, bind port)
 /// - `client_config`: Client config handle (nullable based on mode)
 /// - `server_config`: Server config handle (nullable based on mode)
-/// - `result`: Output parameter for endpoint pointer or error
+/// - `result`: Output parameter for endpoint handle or error
 ///
 /// # Returns
-/// - 0 on success (endpoint pointer in result)
+/// - 0 on success (`QuicEndpointHandle*` in result; free it with `dart_quic_endpoint_handle_free`
+///   and the endpoint with `dart_quic_endpoint_free`)
 /// - Non-zero error code on failure
 ///
 /// # Mode Requirements
@@ -57,7 +60,7 @@ use crate::{
 /// - `result`: Result output structure
 ///
 /// # Returns
-/// - 0 on success (result.data contains endpoint pointer)
+/// - 0 on success (result.data contains a `QuicEndpointHandle*`)
 /// - Error code on failure (result.error contains error details)
 ///
 /// # Mode Requirements
@@ -76,111 +79,139 @@ pub unsafe extern "C" fn dart_quic_endpoint_create(
     server_config: *const quic::QuicFfiServerConfig,
     result: *mut QuicFfiResult,
 ) -> i32 {
-    if result.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
-
-    // Validate config
-    if config.is_null() {
-        unsafe {
-            (*result).write_error_str("Endpoint config is null");
+    panic_guard::guard(|| {
+        if result.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
         }
-        return types::QuicResult::InvalidParameter as i32;
-    }
 
-    let config_ref = unsafe { &*config };
-
-    // Validate config based on mode
-    let validation_error = match config_ref.mode {
-        quic::QuicEndpointMode::ClientOnly => {
-            if client_config.is_null() {
-                Some("ClientOnly mode requires client_config")
-            } else if !server_config.is_null() {
-                Some("ClientOnly mode must not have server_config")
-            } else {
-                None
+        // Validate config
+        if config.is_null() {
+            unsafe {
+                (*result).write_error_str("Endpoint config is null");
             }
+            return types::QuicResult::InvalidParameter as i32;
         }
-        quic::QuicEndpointMode::ServerOnly => {
-            if server_config.is_null() {
-                Some("ServerOnly mode requires server_config")
-            } else if !client_config.is_null() {
-                Some("ServerOnly mode must not have client_config")
-            } else {
-                None
+
+        let config_ref = unsafe { &*config };
+
+        // Validate config based on mode
+        let validation_error = match config_ref.mode {
+            quic::QuicEndpointMode::ClientOnly => {
+                if client_config.is_null() {
+                    Some("ClientOnly mode requires client_config")
+                } else if !server_config.is_null() {
+                    Some("ClientOnly mode must not have server_config")
+                } else {
+                    None
+                }
             }
-        }
-        quic::QuicEndpointMode::Bidirectional => {
-            if client_config.is_null() {
-                Some("Bidirectional mode requires client_config")
-            } else if server_config.is_null() {
-                Some("Bidirectional mode requires server_config")
-            } else {
-                None
+            quic::QuicEndpointMode::ServerOnly => {
+                if server_config.is_null() {
+                    Some("ServerOnly mode requires server_config")
+                } else if !client_config.is_null() {
+                    Some("ServerOnly mode must not have client_config")
+                } else {
+                    None
+                }
             }
-        }
-    };
+            quic::QuicEndpointMode::Bidirectional => {
+                if client_config.is_null() {
+                    Some("Bidirectional mode requires client_config")
+                } else if server_config.is_null() {
+                    Some("Bidirectional mode requires server_config")
+                } else {
+                    None
+                }
+            }
+        };
 
-    if let Some(error) = validation_error {
-        unsafe {
-            (*result).write_error_str(error);
+        if let Some(error) = validation_error {
+            unsafe {
+                (*result).write_error_str(error);
+            }
+            return types::QuicResult::InvalidParameter as i32;
         }
-        return types::QuicResult::InvalidParameter as i32;
-    }
 
-    // Parse bind address
-    let bind_ip = if config_ref.bind_ip == 0 {
-        Ipv4Addr::UNSPECIFIED
-    } else {
-        Ipv4Addr::from(u32::from_be(config_ref.bind_ip))
-    };
-    let bind_addr = SocketAddr::from((bind_ip, config_ref.bind_port));
+        let Some(bind_addr) = config_ref.bind_addr.to_socket_addr() else {
+            unsafe {
+                (*result).write_error_str("bind_addr.family must be 4 (IPv4) or 6 (IPv6)");
+            }
+            return types::QuicResult::InvalidParameter as i32;
+        };
 
-    // Build endpoint
-    let endpoint_result = (|| -> Result<*mut quic::QuicEndpoint, QuicError> {
-        let mut builder = quic::QuicEndpoint::builder();
+        // Build endpoint
+        let endpoint_result = (|| -> Result<quic::QuicEndpointHandle, QuicError> {
+            let mut builder = quic::QuicEndpoint::builder();
 
-        // Build and add client config if present
-        if !client_config.is_null() {
-            let client_ffi_cfg = unsafe { &*client_config };
-            let quinn_client_config = client_ffi_cfg.build_quinn_config()?;
-            builder = builder.with_client_config(quinn_client_config);
-        }
+            // IPv6 sockets get an explicit IPV6_V6ONLY setting so behavior is the same on all platforms
+            if bind_addr.is_ipv6() {
+                builder = builder.with_dual_stack(config_ref.dual_stack);
+            }
 
-        // Build and add server config if present  
-        if !server_config.is_null() {
-            let server_ffi_cfg = unsafe { &*server_config };
-            let quinn_server_config = server_ffi_cfg.build_quinn_config()?;
-            builder = builder.with_server_config(quinn_server_config);
-        }
+            // Build and add client config if present
+            if !client_config.is_null() {
+                let client_ffi_cfg = unsafe { &*client_config };
+                let quinn_client_config = client_ffi_cfg.build_quinn_config()?;
+                builder = builder.with_client_config(quinn_client_config);
+                if let Some(qlog) = client_ffi_cfg.qlog() {
+                    builder = builder.with_client_qlog(qlog);
+                }
+            }
 
-        // Create endpoint
-        let endpoint = builder.bind_addr(bind_addr)
-            .map_err(|e| QuicError::unknown(format!("Failed to bind endpoint: {}", e)))?;
-        
-        Ok(Box::into_raw(Box::new(endpoint)))
-    })();
+            // Build and add server config if present  
+            if !server_config.is_null() {
+                let server_ffi_cfg = unsafe { &*server_config };
+                let quinn_server_config = server_ffi_cfg.build_quinn_config()?;
+                builder = builder.with_server_config(quinn_server_config);
+                if let Some(qlog) = server_ffi_cfg.qlog() {
+                    builder = builder.with_server_qlog(qlog);
+                }
+            }
 
-    unsafe {
-        (*result).write_result(endpoint_result)
-    }
+            // Create endpoint
+            let endpoint = builder.bind_addr(bind_addr)
+                .map_err(|e| QuicError::unknown(format!("Failed to bind endpoint: {}", e)))?;
+
+            Ok(quic::QuicEndpointHandle::new(endpoint))
+        })();
+
+        unsafe {
+            (*result).write_result(endpoint_result)
+        }
+    })
+}
+
+/// Free an endpoint handle structure (does not free the endpoint)
+///
+/// # Safety
+/// - handle must be null or a pointer returned by dart_quic_endpoint_create, freed only once
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_handle_free(handle: *mut quic::QuicEndpointHandle) {
+    panic_guard::guard(|| {
+        if !handle.is_null() {
+            unsafe {
+                let _ = Box::from_raw(handle);
+            }
+        }
+    })
 }
 
 /// Free an endpoint and close all connections
 ///
 /// # Parameters
-/// - `endpoint`: Endpoint pointer to free
+/// - `endpoint`: Endpoint handle to free
 ///
-/// # Safety
-/// - endpoint must be a valid pointer created by dart_quic_endpoint_create
-/// - Must not be used after this call
+/// # Returns
+/// - 0 on success
+/// - InvalidParameter if the handle is not a live endpoint (e.g. already freed)
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dart_quic_endpoint_free(endpoint: *mut quic::QuicEndpoint) {
-    if !endpoint.is_null() {
-        unsafe {
-            let _ = Box::from_raw(endpoint);
+pub extern "C" fn dart_quic_endpoint_free(endpoint: u64) -> i32 {
+    panic_guard::guard(|| {
+        match handle_registry::remove::<QuicEndpoint>(endpoint) {
+            Some(_) => types::QuicResult::Success as i32,
+            None => types::QuicResult::InvalidParameter as i32,
         }
-    }
+    })
 }
 
 // ============================================
@@ -191,116 +222,174 @@ pub unsafe extern "C" fn dart_quic_endpoint_free(endpoint: *mut quic::QuicEndpoi
 ///
 /// # Parameters
 /// - `executor`: Executor pointer for async operations
-/// - `endpoint`: Endpoint pointer
+/// - `endpoint`: Endpoint handle
 /// - `server_addr`: Server address string (e.g., "127.0.0.1:4433")
 /// - `server_name`: Server name for SNI (e.g., "localhost")
+/// - `timeout_ms`: Abandon resolution + handshake after this long with a `[Timeout]` error
+///   (0 = no timeout)
 /// - `callback`: Callback invoked with connection pointer (or 0 on error)
 ///
 /// # Safety
-/// - All pointers must be valid
+/// - executor must be valid
 /// - Endpoint must have client capability (ClientOnly or Bidirectional mode)
 /// - server_addr and server_name must be valid null-terminated C strings
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_endpoint_connect(
     executor: *mut QuicExecutor,
-    endpoint: *mut quic::QuicEndpoint,
+    endpoint: u64,
     server_addr: *const i8,
     server_name: *const i8,
+    timeout_ms: u64,
     callback: UsizeCallback,
 ) {
-    check_executor_usize!(executor, callback);
-    check_ptr_usize!(endpoint, callback);
-
-    if server_addr.is_null() {
-        let error_msg = allocate(ERR_PTR_NULL.len());
-        if !error_msg.is_null() {
-            unsafe {
-                std::ptr::copy_nonoverlapping(
-                    ERR_PTR_NULL.as_ptr(),
-                    error_msg,
-                    ERR_PTR_NULL.len()
-                );
-            }
-        }
-        let callback = SendableCallback(callback);
-        callback.0(false, 0, error_msg, ERR_PTR_NULL.len());
-        return;
-    }
-
-    if server_name.is_null() {
-        let error_msg = allocate(ERR_PTR_NULL.len());
-        if !error_msg.is_null() {
-            unsafe {
-                std::ptr::copy_nonoverlapping(
-                    ERR_PTR_NULL.as_ptr(),
-                    error_msg,
-                    ERR_PTR_NULL.len()
-                );
-            }
-        }
-        let callback = SendableCallback(callback);
-        callback.0(false, 0, error_msg, ERR_PTR_NULL.len());
-        return;
-    }
-
-    let endpoint_ptr = endpoint as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
+    panic_guard::guard_with(callback, || {
+        check_executor_usize!(executor, callback);
+        let endpoint = check_handle_usize!(QuicEndpoint, endpoint, callback);
 
-    // Convert C strings to Rust strings
-    let addr_str = match unsafe { CStr::from_ptr(server_addr) }.to_str() {
-        Ok(s) => s.to_string(),
-        Err(_) => {
-            let error_msg = b"Invalid server_addr encoding\0";
-            let ptr = allocate(error_msg.len());
-            if !ptr.is_null() {
+        if server_addr.is_null() {
+            let error_msg = allocate(ERR_PTR_NULL.len());
+            if !error_msg.is_null() {
                 unsafe {
-                    std::ptr::copy_nonoverlapping(error_msg.as_ptr(), ptr, error_msg.len());
+                    std::ptr::copy_nonoverlapping(
+                        ERR_PTR_NULL.as_ptr(),
+                        error_msg,
+                        ERR_PTR_NULL.len()
+                    );
                 }
             }
-            callback.0(false, 0, ptr, error_msg.len());
+            let callback = SendableCallback(callback);
+            callback.0(false, 0, error_msg, ERR_PTR_NULL.len());
             return;
         }
-    };
 
-    let name_str = match unsafe { CStr::from_ptr(server_name) }.to_str() {
-        Ok(s) => s.to_string(),
-        Err(_) => {
-            let error_msg = b"Invalid server_name encoding\0";
-            let ptr = allocate(error_msg.len());
-            if !ptr.is_null() {
+        if server_name.is_null() {
+            let error_msg = allocate(ERR_PTR_NULL.len());
+            if !error_msg.is_null() {
                 unsafe {
-                    std::ptr::copy_nonoverlapping(error_msg.as_ptr(), ptr, error_msg.len());
+                    std::ptr::copy_nonoverlapping(
+                        ERR_PTR_NULL.as_ptr(),
+                        error_msg,
+                        ERR_PTR_NULL.len()
+                    );
                 }
             }
-            callback.0(false, 0, ptr, error_msg.len());
+            let callback = SendableCallback(callback);
+            callback.0(false, 0, error_msg, ERR_PTR_NULL.len());
             return;
         }
-    };
 
-    // Submit async connect task
-    exec.submit_async(async move {
-        let endpoint = unsafe { &*(endpoint_ptr as *const quic::QuicEndpoint) };
-        match endpoint.connect(&addr_str, &name_str).await {
-            Ok(connection) => {
-                // Create handle with connection info
-                let handle = QuicConnectionHandle::new(connection);
-                let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
-                callback.0(true, handle_ptr, std::ptr::null_mut(), 0);
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        // Convert C strings to Rust strings
+        let addr_str = match unsafe { CStr::from_ptr(server_addr) }.to_str() {
+            Ok(s) => s.to_string(),
+            Err(_) => {
+                let error_msg = b"Invalid server_addr encoding\0";
+                let ptr = allocate(error_msg.len());
+                if !ptr.is_null() {
+                    unsafe {
+                        std::ptr::copy_nonoverlapping(error_msg.as_ptr(), ptr, error_msg.len());
+                    }
+                }
+                callback.0(false, 0, ptr, error_msg.len());
+                return;
             }
-            Err(e) => {
-                let error_msg = format!("Connection failed: {}\0", e);
-                let error_bytes = error_msg.into_bytes();
-                let ptr = allocate(error_bytes.len());
+        };
+
+        let name_str = match unsafe { CStr::from_ptr(server_name) }.to_str() {
+            Ok(s) => s.to_string(),
+            Err(_) => {
+                let error_msg = b"Invalid server_name encoding\0";
+                let ptr = allocate(error_msg.len());
                 if !ptr.is_null() {
                     unsafe {
-                        std::ptr::copy_nonoverlapping(error_bytes.as_ptr(), ptr, error_bytes.len());
+                        std::ptr::copy_nonoverlapping(error_msg.as_ptr(), ptr, error_msg.len());
                     }
                 }
-                callback.0(false, 0, ptr, error_bytes.len());
+                callback.0(false, 0, ptr, error_msg.len());
+                return;
+            }
+        };
+
+        // Submit async connect task
+        exec.submit_async(callback, async move {
+            match with_timeout(timeout_ms, endpoint.connect(&addr_str, &name_str)).await.and_then(|r| r) {
+                Ok(connection) => {
+                    // Create handle with connection info
+                    let handle = QuicConnectionHandle::new(connection);
+                    let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
+                    callback.0(true, handle_ptr, std::ptr::null_mut(), 0);
+                }
+                Err(e) => {
+                    let error_msg = format!("Connection failed: {}\0", e);
+                    let error_bytes = error_msg.into_bytes();
+                    let ptr = allocate(error_bytes.len());
+                    if !ptr.is_null() {
+                        unsafe {
+                            std::ptr::copy_nonoverlapping(error_bytes.as_ptr(), ptr, error_bytes.len());
+                        }
+                    }
+                    callback.0(false, 0, ptr, error_bytes.len());
+                }
+            }
+        });
+    })
+}
+
+/// Connect to a remote server with 0-RTT early data (async)
+///
+/// Same semantics as `dart_quic_client_connect_0rtt`: `callback` receives the
+/// `QuicConnectionHandle*` (immediately when early data is possible), and
+/// `zero_rtt_callback` receives the `QuicZeroRttStatus` once the handshake completes.
+///
+/// # Safety
+/// - executor must be valid
+/// - Endpoint must have client capability (ClientOnly or Bidirectional mode)
+/// - server_addr and server_name must be valid null-terminated C strings
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_connect_0rtt(
+    executor: *mut QuicExecutor,
+    endpoint: u64,
+    server_addr: *const i8,
+    server_name: *const i8,
+    callback: UsizeCallback,
+    zero_rtt_callback: UsizeCallback,
+) {
+    let callbacks = ZeroRttCallbacks::new(callback, zero_rtt_callback);
+    let fail = |success, value, error_ptr, error_len| callbacks.fail(success, value, error_ptr, error_len);
+    panic_guard::guard_with(callbacks.clone(), || {
+        check_executor_usize!(executor, fail);
+        let endpoint = check_handle_usize!(QuicEndpoint, endpoint, fail);
+        check_ptr_usize!(server_addr, fail);
+        check_ptr_usize!(server_name, fail);
+
+        let addr_str = match unsafe { CStr::from_ptr(server_addr) }.to_str() {
+            Ok(s) => s.to_string(),
+            Err(_) => {
+                callbacks.fail_with("Invalid server_addr encoding");
+                return;
             }
+        };
+
+        let name_str = match unsafe { CStr::from_ptr(server_name) }.to_str() {
+            Ok(s) => s.to_string(),
+            Err(_) => {
+                callbacks.fail_with("Invalid server_name encoding");
+                return;
+            }
+        };
+
+        let exec = unsafe { &*executor };
+        let task_callbacks = callbacks.clone();
+
+        if !exec.submit_async(callbacks.clone(), async move {
+            let result = endpoint.connect_0rtt(&addr_str, &name_str).await;
+            task_callbacks.complete(Some(result)).await;
+        }) {
+            callbacks.fail_with(ERR_NOT_RUNNING);
         }
-    });
+    })
 }
 
 // ============================================
@@ -311,7 +400,8 @@ pub unsafe extern "C" fn dart_quic_endpoint_connect(
 ///
 /// # Parameters
 /// - `executor`: Executor pointer for async operations
-/// - `endpoint`: Endpoint pointer
+/// - `endpoint`: Endpoint handle
+/// - `timeout_ms`: Fail with a `[Timeout]` error if no connection arrives in time (0 = no timeout)
 /// - `callback`: Callback invoked with connection pointer (or 0 on error/close)
 ///
 /// # Returns
@@ -319,67 +409,237 @@ pub unsafe extern "C" fn dart_quic_endpoint_connect(
 /// - 0 if endpoint is closing or on error
 ///
 /// # Safety
-/// - All pointers must be valid
+/// - executor must be valid
 /// - Endpoint must have server capability (ServerOnly or Bidirectional mode)
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_endpoint_accept(
     executor: *mut QuicExecutor,
-    endpoint: *mut quic::QuicEndpoint,
+    endpoint: u64,
+    timeout_ms: u64,
     callback: UsizeCallback,
 ) {
-    check_executor_usize!(executor, callback);
-    check_ptr_usize!(endpoint, callback);
+    panic_guard::guard_with(callback, || {
+        check_executor_usize!(executor, callback);
+        let endpoint = check_handle_usize!(QuicEndpoint, endpoint, callback);
 
-    let endpoint_ptr = endpoint as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-
-    // Submit async accept task
-    exec.submit_async(async move {
-        let endpoint = unsafe { &*(endpoint_ptr as *const quic::QuicEndpoint) };
-        match endpoint.accept().await {
-            Some(Ok(connection)) => {
-                // Create handle with connection info
-                let handle = QuicConnectionHandle::new(connection);
-                let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
-                callback.0(true, handle_ptr, std::ptr::null_mut(), 0);
-            }
-            Some(Err(e)) => {
-                let error_msg = format!("Accept failed: {}\0", e);
-                let error_bytes = error_msg.into_bytes();
-                let ptr = allocate(error_bytes.len());
-                if !ptr.is_null() {
-                    unsafe {
-                        std::ptr::copy_nonoverlapping(error_bytes.as_ptr(), ptr, error_bytes.len());
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        // Submit async accept task
+        exec.submit_async(callback, async move {
+            match with_timeout(timeout_ms, endpoint.accept()).await.unwrap_or_else(|e| Some(Err(e))) {
+                Some(Ok(connection)) => {
+                    // Create handle with connection info
+                    let handle = QuicConnectionHandle::new(connection);
+                    let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
+                    callback.0(true, handle_ptr, std::ptr::null_mut(), 0);
+                }
+                Some(Err(e)) => {
+                    let error_msg = format!("Accept failed: {}\0", e);
+                    let error_bytes = error_msg.into_bytes();
+                    let ptr = allocate(error_bytes.len());
+                    if !ptr.is_null() {
+                        unsafe {
+                            std::ptr::copy_nonoverlapping(error_bytes.as_ptr(), ptr, error_bytes.len());
+                        }
                     }
+                    callback.0(false, 0, ptr, error_bytes.len());
                 }
-                callback.0(false, 0, ptr, error_bytes.len());
-            }
-            None => {
-                // Endpoint is closing
-                let error_msg = b"Endpoint closed\0";
-                let ptr = allocate(error_msg.len());
-                if !ptr.is_null() {
-                    unsafe {
-                        std::ptr::copy_nonoverlapping(error_msg.as_ptr(), ptr, error_msg.len());
+                None => {
+                    // Endpoint is closing
+                    let error_msg = b"Endpoint closed\0";
+                    let ptr = allocate(error_msg.len());
+                    if !ptr.is_null() {
+                        unsafe {
+                            std::ptr::copy_nonoverlapping(error_msg.as_ptr(), ptr, error_msg.len());
+                        }
                     }
+                    callback.0(false, 0, ptr, error_msg.len());
                 }
-                callback.0(false, 0, ptr, error_msg.len());
             }
+        });
+    })
+}
+
+/// Accept an incoming connection with 0.5-RTT (async)
+///
+/// Same semantics as `dart_quic_server_accept_0rtt`.
+///
+/// # Safety
+/// - executor must be valid
+/// - Endpoint must have server capability (ServerOnly or Bidirectional mode)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_accept_0rtt(
+    executor: *mut QuicExecutor,
+    endpoint: u64,
+    callback: UsizeCallback,
+    zero_rtt_callback: UsizeCallback,
+) {
+    let callbacks = ZeroRttCallbacks::new(callback, zero_rtt_callback);
+    let fail = |success, value, error_ptr, error_len| callbacks.fail(success, value, error_ptr, error_len);
+    panic_guard::guard_with(callbacks.clone(), || {
+        check_executor_usize!(executor, fail);
+        let endpoint = check_handle_usize!(QuicEndpoint, endpoint, fail);
+
+        let exec = unsafe { &*executor };
+        let task_callbacks = callbacks.clone();
+
+        if !exec.submit_async(callbacks.clone(), async move {
+            let result = endpoint.accept_0rtt().await;
+            task_callbacks.complete(result).await;
+        }) {
+            callbacks.fail_with(ERR_NOT_RUNNING);
         }
+    })
+}
+
+// ============================================
+// Endpoint Certificate Reload
+// ============================================
+
+/// Build a server configuration from `config` and apply it to `endpoint`
+///
+/// Shared by the endpoint and server set-config entry points.
+///
+/// # Safety
+/// `config` must be null or valid; `result` must be valid.
+pub(crate) unsafe fn apply_server_config(
+    endpoint: &QuicEndpoint,
+    config: *const quic::QuicFfiServerConfig,
+    result: &mut QuicFfiResult,
+) -> i32 {
+    if config.is_null() {
+        result.write_error_str(crate::ERR_CONFIG_REQUIRED);
+        return types::QuicResult::InvalidParameter as i32;
+    }
+    let status = unsafe { &*config }.build_quinn_config().map(|server_config| {
+        endpoint.set_server_config(Some(server_config));
+        tracing::info!(local_addr = %endpoint.local_addr(), "server certificate replaced");
     });
+    result.write_status(status)
+}
+
+/// Start watching the certificate files of `config` on `executor`
+///
+/// Shared by the endpoint and server watch entry points. `endpoint` is polled on every
+/// check; the watch ends with success once it returns None.
+///
+/// # Safety
+/// `executor` must be valid; `config` must be null or valid.
+pub(crate) unsafe fn submit_cert_watch(
+    executor: *mut QuicExecutor,
+    config: *const quic::QuicFfiServerConfig,
+    interval_ms: u64,
+    callback: VoidCallback,
+    endpoint: impl Fn() -> Option<QuicEndpoint> + Send + 'static,
+) -> u64 {
+    check_executor_void!(executor, callback, 0);
+    check_ptr_void!(config, callback, 0);
+
+    let watcher = match unsafe { &*config }.builder().and_then(quic::QuicCertWatcher::new) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            let err = crate::FfiErrBuf::new(format!("{}", e));
+            callback(false, err.as_ptr(), err.len());
+            return 0;
+        }
+    };
+    let interval = if interval_ms == 0 {
+        quic::DEFAULT_CERT_WATCH_INTERVAL
+    } else {
+        std::time::Duration::from_millis(interval_ms)
+    };
+
+    let callback = SendableCallback(callback);
+    let exec = unsafe { &*executor };
+
+    let operation_id = exec.submit_cancellable(callback, async move {
+        quic::watch_cert_files(watcher, interval, endpoint).await;
+        move || (callback.0)(true, std::ptr::null(), 0)
+    }, move || {
+        (callback.0)(false, ERR_CANCELLED.as_ptr(), ERR_CANCELLED.len());
+    });
+
+    if operation_id == 0 {
+        callback.0(false, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
+    }
+    operation_id
+}
+
+/// Replace the endpoint's server configuration (sync, hot certificate reload)
+///
+/// See `dart_quic_server_set_config`.
+///
+/// # Returns
+/// - 0 on success
+/// - InvalidParameter if the handle is stale or the endpoint has no server capability
+/// - Error code on failure (`result` carries the message; the current configuration stays)
+///
+/// # Safety
+/// `config` and `result` must be valid pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_set_server_config(
+    endpoint: u64,
+    config: *const quic::QuicFfiServerConfig,
+    result: *mut QuicFfiResult,
+) -> i32 {
+    panic_guard::guard(|| {
+        if result.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
+        let result = unsafe { &mut *result };
+        let Some(endpoint) = handle_registry::get::<QuicEndpoint>(endpoint) else {
+            result.write_error_str(crate::ERR_INVALID_HANDLE);
+            return types::QuicResult::InvalidParameter as i32;
+        };
+        if !endpoint.can_accept() {
+            result.write_error_str("Endpoint has no server capability");
+            return types::QuicResult::InvalidParameter as i32;
+        }
+        unsafe { apply_server_config(&endpoint, config, result) }
+    })
+}
+
+/// Reload the endpoint's server certificate whenever its PEM files change (async)
+///
+/// See `dart_quic_server_watch_certs`. The watch ends with success once the endpoint
+/// handle is released.
+///
+/// # Safety
+/// - executor must be valid
+/// - Endpoint must have server capability (ServerOnly or Bidirectional mode)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_watch_certs(
+    executor: *mut QuicExecutor,
+    endpoint: u64,
+    config: *const quic::QuicFfiServerConfig,
+    interval_ms: u64,
+    callback: VoidCallback,
+) -> u64 {
+    panic_guard::guard_with(callback, || {
+        let endpoint_ref = check_handle_void!(QuicEndpoint, endpoint, callback, 0);
+        if !endpoint_ref.can_accept() {
+            let err = b"[InvalidParameter] Endpoint has no server capability";
+            callback(false, err.as_ptr(), err.len());
+            return 0;
+        }
+        unsafe {
+            submit_cert_watch(executor, config, interval_ms, callback, move || {
+                handle_registry::get::<QuicEndpoint>(endpoint).map(|endpoint| (*endpoint).clone())
+            })
+        }
+    })
 }
 
 // ============================================
 // Endpoint Information and Control
 // ============================================
 
-/// Get the local bound address of the endpoint
+/// Get the local bound address of the endpoint (IPv4 or IPv6)
 ///
 /// # Parameters
-/// - `endpoint`: Endpoint pointer
-/// - `out_ip`: Output buffer for IP address (network byte order)
-/// - `out_port`: Output buffer for port (host byte order)
+/// - `endpoint`: Endpoint handle
+/// - `out_addr`: Receives the local address
 ///
 /// # Returns
 /// - 0 on success
@@ -387,155 +647,253 @@ pub unsafe extern "C" fn dart_quic_endpoint_accept(
 ///
 /// # Safety
 /// - endpoint must be a valid pointer
-/// - out_ip and out_port must be valid pointers
+/// - out_addr must be a valid pointer
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_endpoint_local_addr(
-    endpoint: *mut quic::QuicEndpoint,
-    out_ip: *mut u32,
-    out_port: *mut u16,
+    endpoint: u64,
+    out_addr: *mut quic::QuicFfiSocketAddr,
 ) -> i32 {
-    if endpoint.is_null() || out_ip.is_null() || out_port.is_null() {
-        return -1;
-    }
-
-    let endpoint_ref = unsafe { &*endpoint };
-    let addr = endpoint_ref.local_addr();
-
-    match addr {
-        SocketAddr::V4(addr_v4) => {
-            unsafe {
-                *out_ip = u32::from_be_bytes(addr_v4.ip().octets());
-                *out_port = addr_v4.port();
-            }
-            0
+    panic_guard::guard(|| {
+        if out_addr.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
         }
-        SocketAddr::V6(_) => {
-            // IPv6 not supported in this simple API
-            -2
-        }
-    }
+        let Some(endpoint) = handle_registry::get::<QuicEndpoint>(endpoint) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+
+        unsafe { *out_addr = endpoint.local_addr().into() };
+        types::QuicResult::Success as i32
+    })
 }
 
 /// Get the number of currently open connections
 ///
 /// # Parameters
-/// - `endpoint`: Endpoint pointer
+/// - `endpoint`: Endpoint handle
 ///
 /// # Returns
 /// - Number of open connections
-/// - 0 if endpoint is null
+/// - 0 if the endpoint handle is invalid
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dart_quic_endpoint_open_connections(
-    endpoint: *mut quic::QuicEndpoint,
-) -> usize {
-    if endpoint.is_null() {
-        return 0;
-    }
-
-    let endpoint_ref = unsafe { &*endpoint };
-    endpoint_ref.open_connections()
+pub extern "C" fn dart_quic_endpoint_open_connections(endpoint: u64) -> usize {
+    panic_guard::guard(|| {
+        handle_registry::get::<QuicEndpoint>(endpoint).map_or(0, |endpoint| endpoint.open_connections())
+    })
 }
 
 /// Check if endpoint has client capability
 ///
 /// # Parameters
-/// - `endpoint`: Endpoint pointer
+/// - `endpoint`: Endpoint handle
 ///
 /// # Returns
 /// - 1 if endpoint can connect to remote servers
 /// - 0 otherwise
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dart_quic_endpoint_can_connect(
-    endpoint: *mut quic::QuicEndpoint,
-) -> i32 {
-    if endpoint.is_null() {
-        return 0;
-    }
-
-    let endpoint_ref = unsafe { &*endpoint };
-    if endpoint_ref.can_connect() { 1 } else { 0 }
+pub extern "C" fn dart_quic_endpoint_can_connect(endpoint: u64) -> i32 {
+    panic_guard::guard(|| {
+        match handle_registry::get::<QuicEndpoint>(endpoint) {
+            Some(endpoint) if endpoint.can_connect() => 1,
+            _ => 0,
+        }
+    })
 }
 
 /// Check if endpoint has server capability
 ///
 /// # Parameters
-/// - `endpoint`: Endpoint pointer
+/// - `endpoint`: Endpoint handle
 ///
 /// # Returns
 /// - 1 if endpoint can accept incoming connections
 /// - 0 otherwise
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dart_quic_endpoint_can_accept(
-    endpoint: *mut quic::QuicEndpoint,
-) -> i32 {
-    if endpoint.is_null() {
-        return 0;
-    }
-
-    let endpoint_ref = unsafe { &*endpoint };
-    if endpoint_ref.can_accept() { 1 } else { 0 }
+pub extern "C" fn dart_quic_endpoint_can_accept(endpoint: u64) -> i32 {
+    panic_guard::guard(|| {
+        match handle_registry::get::<QuicEndpoint>(endpoint) {
+            Some(endpoint) if endpoint.can_accept() => 1,
+            _ => 0,
+        }
+    })
 }
 
 /// Close the endpoint and all connections gracefully
 ///
 /// # Parameters
-/// - `endpoint`: Endpoint pointer
+/// - `endpoint`: Endpoint handle
 /// - `error_code`: Application error code
 /// - `reason`: Reason bytes (nullable)
 /// - `reason_len`: Length of reason bytes
 ///
+/// # Returns
+/// - 0 on success
+/// - InvalidParameter if the endpoint handle is invalid
+///
 /// # Safety
-/// - endpoint must be a valid pointer
 /// - reason must be valid for reason_len bytes if not null
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_endpoint_close(
-    endpoint: *mut quic::QuicEndpoint,
+    endpoint: u64,
     error_code: u32,
     reason: *const u8,
     reason_len: usize,
-) {
-    if endpoint.is_null() {
-        return;
-    }
-
-    let endpoint_ref = unsafe { &*endpoint };
-
-    let reason_bytes = if reason.is_null() || reason_len == 0 {
-        &[]
-    } else {
-        unsafe { std::slice::from_raw_parts(reason, reason_len) }
-    };
-
-    endpoint_ref.close(error_code, reason_bytes);
+) -> i32 {
+    panic_guard::guard(|| {
+        let Some(endpoint) = handle_registry::get::<QuicEndpoint>(endpoint) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+
+        let reason_bytes = if reason.is_null() || reason_len == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(reason, reason_len) }
+        };
+
+        endpoint.close(error_code, reason_bytes);
+        types::QuicResult::Success as i32
+    })
 }
 
 /// Wait for all connections to close (async)
 ///
 /// # Parameters
 /// - `executor`: Executor pointer for async operations
-/// - `endpoint`: Endpoint pointer
+/// - `endpoint`: Endpoint handle
 /// - `callback`: Callback invoked when all connections are closed
 ///
 /// # Safety
-/// - All pointers must be valid
+/// - executor must be valid
 /// - Should be called after dart_quic_endpoint_close
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_endpoint_wait_idle(
     executor: *mut QuicExecutor,
-    endpoint: *mut quic::QuicEndpoint,
+    endpoint: u64,
     callback: VoidCallback,
 ) {
-    check_executor_void!(executor, callback);
-    check_ptr_void!(endpoint, callback);
+    panic_guard::guard_with(callback, || {
+        check_executor_void!(executor, callback);
+        let endpoint = check_handle_void!(QuicEndpoint, endpoint, callback);
 
-    let endpoint_ptr = endpoint as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        // Submit async wait_idle task
+        exec.submit_async(callback, async move {
+            endpoint.wait_idle().await;
+            callback.0(true, std::ptr::null_mut(), 0);
+        });
+    })
+}
 
-    // Submit async wait_idle task
-    exec.submit_async(async move {
-        let endpoint = unsafe { &*(endpoint_ptr as *const quic::QuicEndpoint) };
-        endpoint.wait_idle().await;
-        callback.0(true, std::ptr::null_mut(), 0);
-    });
+// ============================================
+// Endpoint Statistics and Connection Registry
+// ============================================
+
+/// Write endpoint statistics into a caller-provided structure
+///
+/// Shared by the endpoint, server and client stats entry points.
+///
+/// # Safety
+/// `out_stats` must be null or point to writable memory for a `QuicFfiEndpointStats`.
+pub(crate) unsafe fn write_endpoint_stats(
+    endpoint: &quic::QuicEndpoint,
+    out_stats: *mut quic::QuicFfiEndpointStats,
+) -> i32 {
+    if out_stats.is_null() {
+        return types::QuicResult::InvalidParameter as i32;
+    }
+    unsafe { *out_stats = quic::QuicFfiEndpointStats::from_endpoint(endpoint) };
+    types::QuicResult::Success as i32
+}
+
+/// Write live connection stable IDs into a newly allocated `u64` array
+///
+/// Shared by the endpoint, server and client connection enumeration entry points.
+/// An empty registry yields `*out_ids = NULL` and `*out_count = 0`.
+///
+/// # Safety
+/// `out_ids` and `out_count` must be null or valid writable pointers.
+pub(crate) unsafe fn write_connection_ids(
+    endpoint: &quic::QuicEndpoint,
+    out_ids: *mut *mut u64,
+    out_count: *mut usize,
+) -> i32 {
+    if out_ids.is_null() || out_count.is_null() {
+        return types::QuicResult::InvalidParameter as i32;
+    }
+
+    let ids: Vec<u64> = endpoint.connection_ids().into_iter().map(|id| id as u64).collect();
+    if ids.is_empty() {
+        unsafe {
+            *out_ids = std::ptr::null_mut();
+            *out_count = 0;
+        }
+        return types::QuicResult::Success as i32;
+    }
+
+    let byte_len = ids.len() * std::mem::size_of::<u64>();
+    let ptr = allocate(byte_len);
+    if ptr.is_null() {
+        return types::QuicResult::ResourceExhausted as i32;
+    }
+    unsafe {
+        std::ptr::copy_nonoverlapping(ids.as_ptr() as *const u8, ptr, byte_len);
+        *out_ids = ptr as *mut u64;
+        *out_count = ids.len();
+    }
+    types::QuicResult::Success as i32
+}
+
+/// Get endpoint statistics (sync)
+///
+/// # Parameters
+/// - `endpoint`: Endpoint handle
+/// - `out_stats`: Output structure (caller allocated)
+///
+/// # Returns
+/// - 0 (Success) on success
+/// - InvalidParameter if the handle is invalid or `out_stats` is null
+///
+/// # Safety
+/// - out_stats must point to writable memory for a `QuicFfiEndpointStats`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_stats(
+    endpoint: u64,
+    out_stats: *mut quic::QuicFfiEndpointStats,
+) -> i32 {
+    panic_guard::guard(|| {
+        let Some(endpoint) = handle_registry::get::<QuicEndpoint>(endpoint) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+        unsafe { write_endpoint_stats(&endpoint, out_stats) }
+    })
+}
+
+/// Enumerate stable IDs of live connections on the endpoint (sync)
+///
+/// IDs match `QuicConnectionHandle.stable_id`. Only connections whose handle
+/// has not been freed and which are not yet closed are listed.
+///
+/// # Parameters
+/// - `endpoint`: Endpoint handle
+/// - `out_ids`: Receives pointer to `u64` array (NULL when empty)
+/// - `out_count`: Receives number of IDs
+///
+/// # Memory
+/// Free the array with `dart_free_memory(ids, count * sizeof(uint64_t))`.
+///
+/// # Safety
+/// - out_ids and out_count must be valid pointers
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_connection_ids(
+    endpoint: u64,
+    out_ids: *mut *mut u64,
+    out_count: *mut usize,
+) -> i32 {
+    panic_guard::guard(|| {
+        let Some(endpoint) = handle_registry::get::<QuicEndpoint>(endpoint) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+        unsafe { write_connection_ids(&endpoint, out_ids, out_count) }
+    })
 }
@@ -0,0 +1,1229 @@
+//! QUIC Port FFI - Port-based completion for async operations
+//!
+//! Parallel to the callback-based async FFI: each `*_port` function takes a Dart native
+//! port (`ReceivePort.sendPort.nativePort`) instead of an `extern "C"` callback, and
+//! posts its result with `allo-isolate`. Results arrive on the isolate that owns the
+//! port, so no `NativeCallable.listener` plumbing is needed.
+//!
+//! Call `dart_quic_port_init(NativeApi.postCObject)` once before using these functions.
+//!
+//! # Message Format
+//!
+//! Every operation posts exactly one message per port, a 4-element list (0-RTT connects
+//! and 0.5-RTT accepts take a second port for the handshake outcome):
+//!
+//! `[ok: bool, code: int, message: String?, payload]`
+//!
+//! - `code`: `QuicResult` value (0 on success)
+//! - `message`: error description, null on success
+//! - `payload`: operation result (see each function), null on error
+//!
+//! Operations taking `timeout_ms` fail with `QuicResult::Timeout` when it is exceeded
+//! (0 = no timeout), with the same semantics as their callback counterparts.
+//!
+//! Bytes are posted as external `Uint8List`s owned by Dart; unlike the callback API
+//! they must not be freed with `dart_free_memory`. Handle structures (connections,
+//! stream pairs) are posted as `int` addresses and freed as usual. Objects are passed
+//! in as registry handles, as in the callback API.
+
+use std::ffi::CStr;
+use std::future::Future;
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use allo_isolate::ffi::{DartCObject, DartPostCObjectFnType};
+use allo_isolate::{IntoDart, Isolate, ZeroCopyBuffer};
+
+use crate::error::QuicError;
+use crate::handle_registry;
+use crate::panic_guard::{self, PanicReport};
+use crate::quic::{
+    self, QuicClient, QuicClientHandle, QuicConnection, QuicConnectionHandle, QuicEndpoint, QuicServer,
+    QuicServerHandle, QuicZeroRttHandshake, QuicZeroRttStatus,
+};
+use crate::quic_executor::{with_timeout, QuicExecutor};
+use crate::quic_ffi_client::{dart_quic_client_free, dart_quic_client_handle_free};
+use crate::quic_ffi_conn::{dart_quic_connection_free, dart_quic_connection_handle_free};
+use crate::quic_ffi_server::{dart_quic_server_free, dart_quic_server_handle_free};
+use crate::quic_ffi_stream_result::{
+    dart_quic_stream_free, dart_quic_stream_pair_free, stream_handle_error, FfiRecvStream, FfiSendStream,
+    QuicFfiStreamPair, QuicStreamType,
+};
+use crate::types::QuicResult;
+use crate::{ERR_CONFIG_REQUIRED, ERR_EXECUTOR_NULL, ERR_INVALID_HANDLE, ERR_NOT_RUNNING, ERR_PTR_NULL};
+
+// ============================================
+// Port Message Types
+// ============================================
+
+/// `Dart_PostCObject` function pointer (pass `NativeApi.postCObject` from Dart)
+pub type DartPostCObjectFn = unsafe extern "C" fn(port: i64, message: *mut std::ffi::c_void) -> bool;
+
+/// Message of a cancelled operation (the `[Cancelled]` prefix comes from the result code)
+const ERR_OPERATION_CANCELLED: &str = "Operation cancelled";
+
+/// Result payload posted to Dart
+pub(crate) enum QuicPortPayload {
+    /// `null`
+    Null,
+    /// `int` (counts, enum values)
+    Int(i64),
+    /// `int` address of a Rust-owned handle, with the function that frees it if undelivered
+    Handle(usize, unsafe fn(usize)),
+    /// `bool`
+    Bool(bool),
+    /// External `Uint8List`
+    Bytes(Vec<u8>),
+    /// `List` of payloads
+    List(Vec<QuicPortPayload>),
+}
+
+impl QuicPortPayload {
+    /// Post a `QuicConnectionHandle*` address
+    fn connection(handle: QuicConnectionHandle) -> Self {
+        Self::Handle(Box::into_raw(Box::new(handle)) as usize, release_connection_handle)
+    }
+
+    /// Post a `QuicFfiStreamPair*` address
+    fn stream_pair(pair: QuicFfiStreamPair) -> Self {
+        Self::Handle(Box::into_raw(Box::new(pair)) as usize, release_stream_pair)
+    }
+
+    /// Post a `QuicClientHandle*` address
+    fn client(handle: QuicClientHandle) -> Self {
+        Self::Handle(Box::into_raw(Box::new(handle)) as usize, release_client_handle)
+    }
+
+    /// Post a `QuicServerHandle*` address
+    fn server(handle: QuicServerHandle) -> Self {
+        Self::Handle(Box::into_raw(Box::new(handle)) as usize, release_server_handle)
+    }
+}
+
+/// Free an undelivered `QuicConnectionHandle*` and its registry entry
+unsafe fn release_connection_handle(addr: usize) {
+    let handle = addr as *mut QuicConnectionHandle;
+    dart_quic_connection_free(unsafe { (*handle).connection });
+    unsafe { dart_quic_connection_handle_free(handle) };
+}
+
+/// Free an undelivered `QuicFfiStreamPair*` and its streams' registry entries
+unsafe fn release_stream_pair(addr: usize) {
+    let pair = addr as *mut QuicFfiStreamPair;
+    for stream in unsafe { [(*pair).send_handle, (*pair).recv_handle] } {
+        if !stream.is_null() {
+            dart_quic_stream_free(unsafe { (*stream).stream });
+        }
+    }
+    unsafe { dart_quic_stream_pair_free(pair) };
+}
+
+/// Free an undelivered `QuicClientHandle*` and its registry entry
+unsafe fn release_client_handle(addr: usize) {
+    let handle = addr as *mut QuicClientHandle;
+    dart_quic_client_free(unsafe { (*handle).client });
+    unsafe { dart_quic_client_handle_free(handle) };
+}
+
+/// Free an undelivered `QuicServerHandle*` and its registry entry
+unsafe fn release_server_handle(addr: usize) {
+    let handle = addr as *mut QuicServerHandle;
+    dart_quic_server_free(unsafe { (*handle).server });
+    unsafe { dart_quic_server_handle_free(handle) };
+}
+
+impl IntoDart for QuicPortPayload {
+    fn into_dart(self) -> DartCObject {
+        match self {
+            Self::Null => ().into_dart(),
+            Self::Int(value) => value.into_dart(),
+            Self::Handle(addr, _) => (addr as i64).into_dart(),
+            Self::Bool(value) => value.into_dart(),
+            Self::Bytes(data) => ZeroCopyBuffer(data).into_dart(),
+            Self::List(items) => items.into_iter().map(IntoDart::into_dart).collect::<Vec<_>>().into_dart(),
+        }
+    }
+}
+
+/// Completion target for one async operation
+#[derive(Clone, Copy)]
+pub(crate) struct QuicPortCompletion(Isolate);
+
+impl QuicPortCompletion {
+    pub(crate) fn new(port: i64) -> Self {
+        Self(Isolate::new(port))
+    }
+
+    /// Post `[true, 0, null, payload]`
+    ///
+    /// If the port is closed, a handle payload is freed here since Dart will never see it.
+    pub(crate) fn ok(self, payload: QuicPortPayload) {
+        let undelivered = match payload {
+            QuicPortPayload::Handle(addr, release) => Some((addr, release)),
+            _ => None,
+        };
+        if !self.0.post((true, QuicResult::Success as i32, None::<String>, payload))
+            && let Some((addr, release)) = undelivered
+        {
+            unsafe { release(addr) };
+        }
+    }
+
+    /// Post `[false, code, message, null]`
+    pub(crate) fn error(self, err: &QuicError) {
+        self.0.post((false, err.code_value(), Some(format!("{}", err)), QuicPortPayload::Null));
+    }
+
+    pub(crate) fn complete(self, result: Result<QuicPortPayload, QuicError>) {
+        match result {
+            Ok(payload) => self.ok(payload),
+            Err(e) => self.error(&e),
+        }
+    }
+}
+
+impl PanicReport for QuicPortCompletion {
+    fn report_panic(&self, err: &QuicError) {
+        self.error(err);
+    }
+}
+
+/// Run `future` on the executor and post its result to `port`
+///
+/// Fails with `QuicResult::Timeout` after `timeout_ms` milliseconds (0 = no timeout).
+fn submit_port<F>(executor: *mut QuicExecutor, port: i64, timeout_ms: u64, future: F)
+where
+    F: Future<Output = Result<QuicPortPayload, QuicError>> + Send + 'static,
+{
+    let completion = QuicPortCompletion::new(port);
+    if executor.is_null() {
+        completion.error(&QuicError::with_message(QuicResult::InvalidParameter, ERR_EXECUTOR_NULL));
+        return;
+    }
+
+    let exec = unsafe { &*executor };
+    if !exec.submit_async(completion, async move {
+        completion.complete(with_timeout(timeout_ms, future).await.and_then(|r| r))
+    }) {
+        completion.error(&QuicError::with_message(QuicResult::RuntimeError, ERR_NOT_RUNNING));
+    }
+}
+
+/// Run a cancellable `future` on the executor and post its result to `port`
+///
+/// The result is converted with `payload` only when it is delivered, so a cancelled
+/// operation drops it without leaking handles. Cancelling with `dart_quic_operation_cancel`
+/// posts a `QuicResult::Cancelled` error instead.
+///
+/// Returns the operation ID, or 0 if the operation was not started (the error has then
+/// already been posted).
+fn submit_port_cancellable<F, T>(
+    executor: *mut QuicExecutor,
+    port: i64,
+    timeout_ms: u64,
+    future: F,
+    payload: fn(T) -> QuicPortPayload,
+) -> u64
+where
+    F: Future<Output = Result<T, QuicError>> + Send + 'static,
+    T: Send + 'static,
+{
+    let completion = QuicPortCompletion::new(port);
+    if executor.is_null() {
+        completion.error(&QuicError::with_message(QuicResult::InvalidParameter, ERR_EXECUTOR_NULL));
+        return 0;
+    }
+
+    let exec = unsafe { &*executor };
+    let operation_id = exec.submit_cancellable(completion, async move {
+        let result = with_timeout(timeout_ms, future).await.and_then(|r| r);
+        move || completion.complete(result.map(payload))
+    }, move || {
+        completion.error(&QuicError::with_message(QuicResult::Cancelled, ERR_OPERATION_CANCELLED));
+    });
+
+    if operation_id == 0 {
+        completion.error(&QuicError::with_message(QuicResult::RuntimeError, ERR_NOT_RUNNING));
+    }
+    operation_id
+}
+
+/// Post an argument validation error
+fn post_invalid(port: i64, msg: &str) {
+    QuicPortCompletion::new(port).error(&QuicError::with_message(QuicResult::InvalidParameter, msg));
+}
+
+/// Read a C string argument
+unsafe fn c_str_arg(ptr: *const c_char, name: &str) -> Result<String, String> {
+    if ptr.is_null() {
+        return Err(format!("{}: {}", ERR_PTR_NULL, name));
+    }
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map(str::to_string)
+        .map_err(|_| format!("Invalid {} encoding", name))
+}
+
+/// Resolve a registry handle of type `T`
+fn handle_arg<T: handle_registry::QuicHandleObject>(handle: u64) -> Result<Arc<T>, &'static str> {
+    handle_registry::get(handle).ok_or(ERR_INVALID_HANDLE)
+}
+
+/// Resolve a receive stream handle
+fn recv_stream_arg(handle: u64) -> Result<Arc<FfiRecvStream>, &'static str> {
+    handle_registry::get(handle).ok_or_else(|| stream_handle_error(handle, QuicStreamType::Recv))
+}
+
+/// Resolve a send stream handle
+fn send_stream_arg(handle: u64) -> Result<Arc<FfiSendStream>, &'static str> {
+    handle_registry::get(handle).ok_or_else(|| stream_handle_error(handle, QuicStreamType::Send))
+}
+
+/// Payload for a new connection: `QuicConnectionHandle*` address
+fn connection_payload(conn: quic::QuicConnection) -> QuicPortPayload {
+    QuicPortPayload::connection(QuicConnectionHandle::new(conn))
+}
+
+/// Payload for an accepted connection: address, or null once the server is closed
+fn accepted_payload(conn: Option<quic::QuicConnection>) -> QuicPortPayload {
+    conn.map_or(QuicPortPayload::Null, connection_payload)
+}
+
+/// Result of a 0-RTT connect or 0.5-RTT accept (None = endpoint closed)
+type ZeroRttResult = Option<Result<(QuicConnection, QuicZeroRttHandshake), QuicError>>;
+
+/// Completion targets of a 0-RTT connect or 0.5-RTT accept
+///
+/// Port counterpart of `ZeroRttCallbacks`: `connection` receives the connection and
+/// `zero_rtt` the `QuicZeroRttStatus` once the handshake completes. Errors and panics
+/// are posted to both (to `connection` only if it has not received the connection yet).
+#[derive(Clone)]
+struct ZeroRttPorts {
+    connection: QuicPortCompletion,
+    zero_rtt: QuicPortCompletion,
+    connected: Arc<AtomicBool>,
+}
+
+impl ZeroRttPorts {
+    fn new(port: i64, zero_rtt_port: i64) -> Self {
+        Self {
+            connection: QuicPortCompletion::new(port),
+            zero_rtt: QuicPortCompletion::new(zero_rtt_port),
+            connected: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    fn error(&self, err: &QuicError) {
+        if !self.connected.swap(true, Ordering::AcqRel) {
+            self.connection.error(err);
+        }
+        self.zero_rtt.error(err);
+    }
+
+    fn invalid(&self, msg: &str) {
+        self.error(&QuicError::with_message(QuicResult::InvalidParameter, msg));
+    }
+
+    /// Run `future` on the executor and post its outcome to both ports
+    fn submit<F>(self, executor: *mut QuicExecutor, future: F)
+    where
+        F: Future<Output = ZeroRttResult> + Send + 'static,
+    {
+        if executor.is_null() {
+            return self.invalid(ERR_EXECUTOR_NULL);
+        }
+
+        let exec = unsafe { &*executor };
+        let ports = self.clone();
+        if !exec.submit_async(self.clone(), async move { ports.complete(future.await).await }) {
+            self.error(&QuicError::with_message(QuicResult::RuntimeError, ERR_NOT_RUNNING));
+        }
+    }
+
+    async fn complete(self, result: ZeroRttResult) {
+        match result {
+            Some(Ok((conn, handshake))) => {
+                self.connected.store(true, Ordering::Release);
+                self.connection.ok(connection_payload(conn));
+                let status = handshake.wait().await;
+                self.zero_rtt.ok(QuicPortPayload::Int(status as i64));
+            }
+            Some(Err(e)) => self.error(&e),
+            None => {
+                self.connected.store(true, Ordering::Release);
+                self.connection.ok(QuicPortPayload::Null);
+                self.zero_rtt.ok(QuicPortPayload::Int(QuicZeroRttStatus::NotAttempted as i64));
+            }
+        }
+    }
+}
+
+impl PanicReport for ZeroRttPorts {
+    fn report_panic(&self, err: &QuicError) {
+        self.error(err);
+    }
+}
+
+// ============================================
+// Port Setup FFI
+// ============================================
+
+/// Register `Dart_PostCObject` for port-based completion
+///
+/// Call once per process with `NativeApi.postCObject` before any `*_port` function.
+/// Without it, results are silently dropped.
+///
+/// # Safety
+/// `post_cobject` must be Dart's `Dart_PostCObject` (valid for the process lifetime).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_port_init(post_cobject: DartPostCObjectFn) {
+    panic_guard::guard(|| {
+        // Same ABI; the message pointer is a `Dart_CObject*`
+        let post_cobject: DartPostCObjectFnType = unsafe { std::mem::transmute(post_cobject) };
+        unsafe { allo_isolate::store_dart_post_cobject(post_cobject) };
+    })
+}
+
+// ============================================
+// Endpoint / Client / Server Port FFI
+// ============================================
+
+/// Create a QUIC client (async, port)
+///
+/// Port variant of `dart_quic_client_new_async`. Payload: `QuicClientHandle*` address
+/// (free with `dart_quic_client_handle_free`, the client with `dart_quic_client_free`).
+///
+/// # Safety
+/// `executor` must be valid; `config` and all data it references must remain valid
+/// until the result is posted.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_client_new_async_port(
+    executor: *mut QuicExecutor,
+    config: *const quic::QuicFfiClientConfig,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        if executor.is_null() {
+            return post_invalid(port, ERR_EXECUTOR_NULL);
+        }
+        if config.is_null() {
+            return post_invalid(port, ERR_CONFIG_REQUIRED);
+        }
+
+        // Safety: the caller keeps the config alive until the result is posted
+        let config_ptr = config as usize;
+        let endpoints = unsafe { &*executor }.endpoint_tracker();
+        submit_port(executor, port, 0, async move {
+            let config = unsafe { &*(config_ptr as *const quic::QuicFfiClientConfig) };
+            let handle = QuicClientHandle::new(config.build()?);
+            endpoints.track(handle.client);
+            Ok(QuicPortPayload::client(handle))
+        });
+    })
+}
+
+/// Create a QUIC server (async, port)
+///
+/// Port variant of `dart_quic_server_new_async`. Payload: `QuicServerHandle*` address
+/// (free with `dart_quic_server_handle_free`, the server with `dart_quic_server_free`).
+///
+/// # Safety
+/// `executor` must be valid; `bind_addr` must be null-terminated; `config` and all data
+/// it references must remain valid until the result is posted.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_new_async_port(
+    executor: *mut QuicExecutor,
+    bind_addr: *const c_char,
+    config: *const quic::QuicFfiServerConfig,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        if executor.is_null() {
+            return post_invalid(port, ERR_EXECUTOR_NULL);
+        }
+        if config.is_null() {
+            return post_invalid(port, ERR_CONFIG_REQUIRED);
+        }
+        let bind_addr = match unsafe { c_str_arg(bind_addr, "bind_addr") } {
+            Ok(addr) => addr,
+            Err(e) => return post_invalid(port, &e),
+        };
+
+        // Safety: the caller keeps the config alive until the result is posted
+        let config_ptr = config as usize;
+        let endpoints = unsafe { &*executor }.endpoint_tracker();
+        submit_port(executor, port, 0, async move {
+            let config = unsafe { &*(config_ptr as *const quic::QuicFfiServerConfig) };
+            let handle = QuicServerHandle::new(config.build(&bind_addr)?);
+            endpoints.track(handle.server);
+            Ok(QuicPortPayload::server(handle))
+        });
+    })
+}
+
+/// Connect to server via client (async, port)
+///
+/// Payload: `QuicConnectionHandle*` address (free with `dart_quic_connection_handle_free`).
+///
+/// # Safety
+/// `executor` must be valid; strings must be null-terminated.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_client_connect_port(
+    executor: *mut QuicExecutor,
+    client: u64,
+    server_addr: *const c_char,
+    server_name: *const c_char,
+    timeout_ms: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let client = match handle_arg::<QuicClient>(client) {
+            Ok(client) => client,
+            Err(e) => return post_invalid(port, e),
+        };
+        let (addr, name) = match unsafe { (c_str_arg(server_addr, "server_addr"), c_str_arg(server_name, "server_name")) } {
+            (Ok(addr), Ok(name)) => (addr, name),
+            (Err(e), _) | (_, Err(e)) => return post_invalid(port, &e),
+        };
+
+        submit_port(executor, port, timeout_ms, async move {
+            client.connect(&addr, &name).await.map(connection_payload)
+        });
+    })
+}
+
+/// Accept incoming connection via server (async, port)
+///
+/// Payload: `QuicConnectionHandle*` address, or null when the server is closed.
+/// If cancelled, posts a `Cancelled` error.
+///
+/// # Returns
+/// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+/// (the error has then already been posted).
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_accept_port(
+    executor: *mut QuicExecutor,
+    server: u64,
+    timeout_ms: u64,
+    port: i64,
+) -> u64 {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let server = match handle_arg::<QuicServer>(server) {
+            Ok(server) => server,
+            Err(e) => {
+                post_invalid(port, e);
+                return 0;
+            }
+        };
+
+        submit_port_cancellable(
+            executor,
+            port,
+            timeout_ms,
+            async move { server.accept().await.transpose() },
+            accepted_payload,
+        )
+    })
+}
+
+/// Accept the next connection routed to an ALPN queue via server (async, port)
+///
+/// Payload: `QuicConnectionHandle*` address, or null when the server is closed.
+/// See `dart_quic_server_accept_alpn`. If cancelled, posts a `Cancelled` error.
+///
+/// # Returns
+/// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+/// (the error has then already been posted).
+///
+/// # Safety
+/// `executor` must be valid; `alpn` must be null or valid for `alpn_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_accept_alpn_port(
+    executor: *mut QuicExecutor,
+    server: u64,
+    alpn: *const u8,
+    alpn_len: usize,
+    timeout_ms: u64,
+    port: i64,
+) -> u64 {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let server = match handle_arg::<QuicServer>(server) {
+            Ok(server) => server,
+            Err(e) => {
+                post_invalid(port, e);
+                return 0;
+            }
+        };
+        let alpn = unsafe { crate::quic_ffi_server::alpn_arg(alpn, alpn_len) };
+
+        submit_port_cancellable(
+            executor,
+            port,
+            timeout_ms,
+            async move { server.accept_alpn(alpn.as_deref()).await.transpose() },
+            accepted_payload,
+        )
+    })
+}
+
+/// Connect to a remote server via endpoint (async, port)
+///
+/// Payload: `QuicConnectionHandle*` address.
+///
+/// # Safety
+/// `executor` must be valid; strings must be null-terminated.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_connect_port(
+    executor: *mut QuicExecutor,
+    endpoint: u64,
+    server_addr: *const c_char,
+    server_name: *const c_char,
+    timeout_ms: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let endpoint = match handle_arg::<QuicEndpoint>(endpoint) {
+            Ok(endpoint) => endpoint,
+            Err(e) => return post_invalid(port, e),
+        };
+        let (addr, name) = match unsafe { (c_str_arg(server_addr, "server_addr"), c_str_arg(server_name, "server_name")) } {
+            (Ok(addr), Ok(name)) => (addr, name),
+            (Err(e), _) | (_, Err(e)) => return post_invalid(port, &e),
+        };
+
+        submit_port(executor, port, timeout_ms, async move {
+            endpoint.connect(&addr, &name).await.map(connection_payload)
+        });
+    })
+}
+
+/// Accept incoming connection via endpoint (async, port)
+///
+/// Payload: `QuicConnectionHandle*` address. Fails with `EndpointClosed` when the
+/// endpoint is closed.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_accept_port(
+    executor: *mut QuicExecutor,
+    endpoint: u64,
+    timeout_ms: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let endpoint = match handle_arg::<QuicEndpoint>(endpoint) {
+            Ok(endpoint) => endpoint,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        submit_port(executor, port, timeout_ms, async move {
+            match endpoint.accept().await {
+                Some(result) => result.map(connection_payload),
+                None => Err(QuicError::from_code(QuicResult::EndpointClosed)),
+            }
+        });
+    })
+}
+
+/// Connect to server with 0-RTT early data via client (async, port)
+///
+/// Port variant of `dart_quic_client_connect_0rtt`. `port` receives the
+/// `QuicConnectionHandle*` address (immediately when early data is possible);
+/// `zero_rtt_port` receives the `QuicZeroRttStatus` as an `int` once the handshake
+/// completes. On error, including invalid arguments, both ports receive the error.
+///
+/// # Safety
+/// `executor` must be valid; strings must be null-terminated.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_client_connect_0rtt_port(
+    executor: *mut QuicExecutor,
+    client: u64,
+    server_addr: *const c_char,
+    server_name: *const c_char,
+    port: i64,
+    zero_rtt_port: i64,
+) {
+    let ports = ZeroRttPorts::new(port, zero_rtt_port);
+    panic_guard::guard_with(ports.clone(), || {
+        let client = match handle_arg::<QuicClient>(client) {
+            Ok(client) => client,
+            Err(e) => return ports.invalid(e),
+        };
+        let (addr, name) = match unsafe { (c_str_arg(server_addr, "server_addr"), c_str_arg(server_name, "server_name")) } {
+            (Ok(addr), Ok(name)) => (addr, name),
+            (Err(e), _) | (_, Err(e)) => return ports.invalid(&e),
+        };
+
+        ports.clone().submit(executor, async move { Some(client.connect_0rtt(&addr, &name).await) });
+    })
+}
+
+/// Accept incoming connection with 0.5-RTT via server (async, port)
+///
+/// Port variant of `dart_quic_server_accept_0rtt`. `port` receives the
+/// `QuicConnectionHandle*` address as soon as the handshake starts, or null when the
+/// server is closed; `zero_rtt_port` receives the `QuicZeroRttStatus` as an `int`.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_accept_0rtt_port(
+    executor: *mut QuicExecutor,
+    server: u64,
+    port: i64,
+    zero_rtt_port: i64,
+) {
+    let ports = ZeroRttPorts::new(port, zero_rtt_port);
+    panic_guard::guard_with(ports.clone(), || {
+        let server = match handle_arg::<QuicServer>(server) {
+            Ok(server) => server,
+            Err(e) => return ports.invalid(e),
+        };
+
+        ports.clone().submit(executor, async move { server.accept_0rtt().await });
+    })
+}
+
+/// Connect to a remote server with 0-RTT early data via endpoint (async, port)
+///
+/// Same semantics as `dart_quic_client_connect_0rtt_port`.
+///
+/// # Safety
+/// `executor` must be valid; strings must be null-terminated.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_connect_0rtt_port(
+    executor: *mut QuicExecutor,
+    endpoint: u64,
+    server_addr: *const c_char,
+    server_name: *const c_char,
+    port: i64,
+    zero_rtt_port: i64,
+) {
+    let ports = ZeroRttPorts::new(port, zero_rtt_port);
+    panic_guard::guard_with(ports.clone(), || {
+        let endpoint = match handle_arg::<QuicEndpoint>(endpoint) {
+            Ok(endpoint) => endpoint,
+            Err(e) => return ports.invalid(e),
+        };
+        let (addr, name) = match unsafe { (c_str_arg(server_addr, "server_addr"), c_str_arg(server_name, "server_name")) } {
+            (Ok(addr), Ok(name)) => (addr, name),
+            (Err(e), _) | (_, Err(e)) => return ports.invalid(&e),
+        };
+
+        ports.clone().submit(executor, async move { Some(endpoint.connect_0rtt(&addr, &name).await) });
+    })
+}
+
+/// Accept an incoming connection with 0.5-RTT via endpoint (async, port)
+///
+/// Same semantics as `dart_quic_server_accept_0rtt_port`.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_accept_0rtt_port(
+    executor: *mut QuicExecutor,
+    endpoint: u64,
+    port: i64,
+    zero_rtt_port: i64,
+) {
+    let ports = ZeroRttPorts::new(port, zero_rtt_port);
+    panic_guard::guard_with(ports.clone(), || {
+        let endpoint = match handle_arg::<QuicEndpoint>(endpoint) {
+            Ok(endpoint) => endpoint,
+            Err(e) => return ports.invalid(e),
+        };
+
+        ports.clone().submit(executor, async move { endpoint.accept_0rtt().await });
+    })
+}
+
+/// Wait for all client connections to become idle (async, port)
+///
+/// Port variant of `dart_quic_client_wait_idle`. Payload: null.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_client_wait_idle_port(
+    executor: *mut QuicExecutor,
+    client: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let client = match handle_arg::<QuicClient>(client) {
+            Ok(client) => client,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        submit_port(executor, port, 0, async move {
+            client.wait_idle().await;
+            Ok(QuicPortPayload::Null)
+        });
+    })
+}
+
+/// Wait for all server connections to become idle (async, port)
+///
+/// Port variant of `dart_quic_server_wait_idle`. Payload: null.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_wait_idle_port(
+    executor: *mut QuicExecutor,
+    server: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let server = match handle_arg::<QuicServer>(server) {
+            Ok(server) => server,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        submit_port(executor, port, 0, async move {
+            server.wait_idle().await;
+            Ok(QuicPortPayload::Null)
+        });
+    })
+}
+
+/// Wait for all endpoint connections to close (async, port)
+///
+/// Port variant of `dart_quic_endpoint_wait_idle`. Payload: null.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_endpoint_wait_idle_port(
+    executor: *mut QuicExecutor,
+    endpoint: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let endpoint = match handle_arg::<QuicEndpoint>(endpoint) {
+            Ok(endpoint) => endpoint,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        submit_port(executor, port, 0, async move {
+            endpoint.wait_idle().await;
+            Ok(QuicPortPayload::Null)
+        });
+    })
+}
+
+// ============================================
+// Connection Port FFI
+// ============================================
+
+/// Open bidirectional stream (async, port)
+///
+/// Payload: `QuicFfiStreamPair*` address (free with `dart_quic_stream_pair_free`).
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_connection_open_bi_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    priority: i32,
+    timeout_ms: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let conn = match handle_arg::<QuicConnection>(handle) {
+            Ok(conn) => conn,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        submit_port(executor, port, timeout_ms, async move {
+            let (send, recv) = conn.open_bi().await?;
+            send.set_priority(priority)?;
+            Ok(QuicPortPayload::stream_pair(QuicFfiStreamPair::bi(send, recv)))
+        });
+    })
+}
+
+/// Open unidirectional stream (async, port)
+///
+/// Payload: `QuicFfiStreamPair*` address with only `send_handle` set.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_connection_open_uni_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    priority: i32,
+    timeout_ms: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let conn = match handle_arg::<QuicConnection>(handle) {
+            Ok(conn) => conn,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        submit_port(executor, port, timeout_ms, async move {
+            let send = conn.open_uni().await?;
+            send.set_priority(priority)?;
+            Ok(QuicPortPayload::stream_pair(QuicFfiStreamPair::send_only(send)))
+        });
+    })
+}
+
+/// Accept bidirectional stream (async, port)
+///
+/// Payload: `QuicFfiStreamPair*` address. If cancelled, posts a `Cancelled` error.
+///
+/// # Returns
+/// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+/// (the error has then already been posted).
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_connection_accept_bi_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    timeout_ms: u64,
+    port: i64,
+) -> u64 {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let conn = match handle_arg::<QuicConnection>(handle) {
+            Ok(conn) => conn,
+            Err(e) => {
+                post_invalid(port, e);
+                return 0;
+            }
+        };
+
+        submit_port_cancellable(
+            executor,
+            port,
+            timeout_ms,
+            async move { conn.accept_bi().await },
+            |(send, recv)| QuicPortPayload::stream_pair(QuicFfiStreamPair::bi(send, recv)),
+        )
+    })
+}
+
+/// Accept unidirectional stream (async, port)
+///
+/// Payload: `QuicFfiStreamPair*` address with only `recv_handle` set.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_connection_accept_uni_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    timeout_ms: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let conn = match handle_arg::<QuicConnection>(handle) {
+            Ok(conn) => conn,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        submit_port(executor, port, timeout_ms, async move {
+            let recv = conn.accept_uni().await?;
+            Ok(QuicPortPayload::stream_pair(QuicFfiStreamPair::recv_only(recv)))
+        });
+    })
+}
+
+/// Read datagram (async, port)
+///
+/// Payload: `Uint8List` with the datagram.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_connection_read_datagram_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let conn = match handle_arg::<QuicConnection>(handle) {
+            Ok(conn) => conn,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        submit_port(executor, port, 0, async move {
+            let data = conn.read_datagram().await?;
+            Ok(QuicPortPayload::Bytes(data.to_vec()))
+        });
+    })
+}
+
+/// Subscribe to the connection close event (async, port)
+///
+/// Payload: `[kind: int, remote: bool, resultCode: int, errorCode: int, reason: Uint8List]`
+/// with the same meaning as the `QuicFfiCloseReason` fields. Like
+/// `dart_quic_connection_on_closed`, the subscription does not keep the connection alive.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_connection_on_closed_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let conn = match handle_arg::<QuicConnection>(handle) {
+            Ok(conn) => conn,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        let close_reason = conn.watch_close_reason();
+        submit_port(executor, port, 0, async move {
+            let reason = close_reason.await;
+            Ok(QuicPortPayload::List(vec![
+                QuicPortPayload::Int(reason.kind as i64),
+                QuicPortPayload::Bool(reason.remote),
+                QuicPortPayload::Int(reason.result_code() as i64),
+                // Codes are < 2^62 (QUIC varint), so they fit in a Dart int
+                QuicPortPayload::Int(reason.error_code as i64),
+                QuicPortPayload::Bytes(reason.reason),
+            ]))
+        });
+    })
+}
+
+// ============================================
+// Stream Port FFI
+// ============================================
+
+/// Read up to `max_len` bytes from the stream (async, port)
+///
+/// Payload: `Uint8List` with the bytes read, or null at end of stream. If cancelled,
+/// posts a `Cancelled` error.
+///
+/// # Returns
+/// Operation ID for `dart_quic_operation_cancel`, or 0 if the read was not started
+/// (the error has then already been posted).
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_recv_stream_read_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    max_len: usize,
+    timeout_ms: u64,
+    port: i64,
+) -> u64 {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let stream = match recv_stream_arg(handle) {
+            Ok(stream) => stream,
+            Err(e) => {
+                post_invalid(port, e);
+                return 0;
+            }
+        };
+        if max_len == 0 {
+            post_invalid(port, "Invalid max length");
+            return 0;
+        }
+
+        submit_port_cancellable(
+            executor,
+            port,
+            timeout_ms,
+            async move {
+                let mut buf = vec![0u8; max_len];
+                match stream.read(&mut buf).await {
+                    Ok(n) => Ok(n.map(|n| {
+                        buf.truncate(n);
+                        buf
+                    })),
+                    Err(e) => Err(QuicError::from(e)),
+                }
+                .inspect_err(|e| stream.record_peer_error(e))
+            },
+            |data| data.map_or(QuicPortPayload::Null, QuicPortPayload::Bytes),
+        )
+    })
+}
+
+/// Read exactly `exact_len` bytes from the stream (async, port)
+///
+/// Payload: `Uint8List` of `exact_len` bytes.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_recv_stream_read_exact_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    exact_len: usize,
+    timeout_ms: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let stream = match recv_stream_arg(handle) {
+            Ok(stream) => stream,
+            Err(e) => return post_invalid(port, e),
+        };
+        if exact_len == 0 {
+            return post_invalid(port, "Invalid buffer length");
+        }
+
+        submit_port(executor, port, timeout_ms, async move {
+            let mut buf = vec![0u8; exact_len];
+            stream
+                .read_exact(&mut buf)
+                .await
+                .map(|()| QuicPortPayload::Bytes(buf))
+                .map_err(QuicError::from)
+                .inspect_err(|e| stream.record_peer_error(e))
+        });
+    })
+}
+
+/// Read all remaining data from the stream, up to `size_limit` bytes (async, port)
+///
+/// Payload: `Uint8List` with the data (empty if the stream had none).
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_recv_stream_read_to_end_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    size_limit: usize,
+    timeout_ms: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let stream = match recv_stream_arg(handle) {
+            Ok(stream) => stream,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        submit_port(executor, port, timeout_ms, async move {
+            stream
+                .read_to_end(size_limit)
+                .await
+                .map(QuicPortPayload::Bytes)
+                .map_err(QuicError::from)
+                .inspect_err(|e| stream.record_peer_error(e))
+        });
+    })
+}
+
+/// Write bytes to the send stream (async, port)
+///
+/// The data is copied before returning. Payload: `int` number of bytes written.
+///
+/// # Safety
+/// `executor` must be valid; `data` must point to `data_len` readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_send_stream_write_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    data: *const u8,
+    data_len: usize,
+    timeout_ms: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let stream = match send_stream_arg(handle) {
+            Ok(stream) => stream,
+            Err(e) => return post_invalid(port, e),
+        };
+        if data.is_null() || data_len == 0 {
+            return post_invalid(port, "Invalid data");
+        }
+
+        let data_vec = unsafe { std::slice::from_raw_parts(data, data_len) }.to_vec();
+        submit_port(executor, port, timeout_ms, async move {
+            stream
+                .write(&data_vec)
+                .await
+                .map(|n| QuicPortPayload::Int(n as i64))
+                .map_err(QuicError::from)
+                .inspect_err(|e| stream.record_peer_error(e))
+        });
+    })
+}
+
+/// Write all bytes to the send stream (async, port)
+///
+/// The data is copied before returning. Payload: null.
+///
+/// # Safety
+/// `executor` must be valid; `data` must point to `data_len` readable bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_send_stream_write_all_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    data: *const u8,
+    data_len: usize,
+    timeout_ms: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let stream = match send_stream_arg(handle) {
+            Ok(stream) => stream,
+            Err(e) => return post_invalid(port, e),
+        };
+        if data.is_null() || data_len == 0 {
+            return post_invalid(port, "Invalid data");
+        }
+
+        let data_vec = unsafe { std::slice::from_raw_parts(data, data_len) }.to_vec();
+        submit_port(executor, port, timeout_ms, async move {
+            stream
+                .write_all(&data_vec)
+                .await
+                .map(|()| QuicPortPayload::Null)
+                .map_err(QuicError::from)
+                .inspect_err(|e| stream.record_peer_error(e))
+        });
+    })
+}
+
+/// Wait until the peer has received all data or stopped the stream (async, port)
+///
+/// Payload: `bool`, true if the peer sent STOP_SENDING (the code is recorded on the
+/// handle, see `dart_quic_stream_peer_error_code`).
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_send_stream_stopped_port(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    port: i64,
+) {
+    panic_guard::guard_with(QuicPortCompletion::new(port), || {
+        let stream = match send_stream_arg(handle) {
+            Ok(stream) => stream,
+            Err(e) => return post_invalid(port, e),
+        };
+
+        let stopped = stream.stopped();
+        submit_port(executor, port, 0, async move {
+            match stopped.await {
+                Ok(None) => Ok(QuicPortPayload::Bool(false)),
+                Ok(Some(code)) => {
+                    let err = QuicError::with_app_error_code(QuicResult::StreamStopped, code.into_inner());
+                    stream.record_peer_error(&err);
+                    Ok(QuicPortPayload::Bool(true))
+                }
+                Err(e) => Err(QuicError::from(e)),
+            }
+        });
+    })
+}
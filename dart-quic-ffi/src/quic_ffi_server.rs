@@ -3,18 +3,20 @@
 use std::ffi::CStr;
 use std::os::raw::c_char;
 
-use crate::quic_executor::{QuicExecutor, SendableCallback, UsizeCallback, VoidCallback};
+use crate::quic_executor::{with_timeout, QuicExecutor, SendableCallback, UsizeCallback, VoidCallback};
 use crate::error::QuicError;
-use crate::{quic, types, QuicFfiResult, allocate};
-use crate::quic::QuicConnectionHandle;
-use crate::{check_executor_usize, check_executor_void, check_ptr_usize, check_ptr_void, ERR_NOT_RUNNING};
+use crate::{handle_registry, quic, types, QuicFfiResult, allocate};
+use crate::quic::{QuicConnectionHandle, QuicServer};
+use crate::quic_ffi_conn::ZeroRttCallbacks;
+use crate::{check_executor_usize, check_executor_void, check_handle_usize, check_handle_void, ERR_CANCELLED, ERR_NOT_RUNNING};
+use crate::panic_guard;
 
 // ============================================
 // QUIC Server FFI
 // ============================================
 
 /// Create server with self-signed certificate (testing only!)
-/// Returns error code, result written to `result` parameter
+/// Returns error code, result written to `result` parameter (`QuicServerHandle*` on success)
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_server_new_self_signed(
     bind_addr: *const c_char,
@@ -23,51 +25,52 @@ pub unsafe extern "C" fn dart_quic_server_new_self_signed(
     transport_config: *const quic::QuicFfiTransportConfig,
     result: *mut QuicFfiResult,
 ) -> i32 {
-    if result.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
+    panic_guard::guard(|| {
+        if result.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
 
-    if bind_addr.is_null() {
-        unsafe { (*result).write_error_str("Bind address is required"); }
-        return types::QuicResult::InvalidParameter as i32;
-    }
+        if bind_addr.is_null() {
+            unsafe { (*result).write_error_str("Bind address is required"); }
+            return types::QuicResult::InvalidParameter as i32;
+        }
 
-    let server_result = (|| {
-        let bind_addr_str = unsafe { CStr::from_ptr(bind_addr) }
-            .to_str()
-            .map_err(|_| QuicError::unknown("Invalid bind address".to_string()))?;
-
-        let mut san_names = Vec::with_capacity(san_count);
-        if !san_list.is_null() && san_count > 0 {
-            for i in 0..san_count {
-                let san_ptr = unsafe { *san_list.add(i) };
-                if !san_ptr.is_null() {
-                    if let Ok(s) = unsafe { CStr::from_ptr(san_ptr) }.to_str() {
-                        san_names.push(s.to_string());
+        let server_result = (|| {
+            let bind_addr_str = unsafe { CStr::from_ptr(bind_addr) }
+                .to_str()
+                .map_err(|_| QuicError::unknown("Invalid bind address".to_string()))?;
+
+            let mut san_names = Vec::with_capacity(san_count);
+            if !san_list.is_null() && san_count > 0 {
+                for i in 0..san_count {
+                    let san_ptr = unsafe { *san_list.add(i) };
+                    if !san_ptr.is_null() {
+                        if let Ok(s) = unsafe { CStr::from_ptr(san_ptr) }.to_str() {
+                            san_names.push(s.to_string());
+                        }
                     }
                 }
             }
-        }
-        if san_names.is_empty() {
-            san_names.push("localhost".to_string());
-        }
+            if san_names.is_empty() {
+                san_names.push("localhost".to_string());
+            }
 
-        let san_refs: Vec<&str> = san_names.iter().map(|s| s.as_str()).collect();
-        let mut builder = quic::QuicServer::builder().with_self_signed(&san_refs);
+            let san_refs: Vec<&str> = san_names.iter().map(|s| s.as_str()).collect();
+            let mut builder = quic::QuicServer::builder().with_self_signed(&san_refs);
 
-        if !transport_config.is_null() {
-            let ffi_config = unsafe { &*transport_config };
-            builder = builder.with_transport_config(quic::QuicTransportConfig::from(ffi_config));
-        }
+            if !transport_config.is_null() {
+                builder = builder.with_transport_config(unsafe { quic::QuicTransportConfig::from_ffi_ptr(transport_config) }?);
+            }
 
-        builder.bind(bind_addr_str)
-    })();
+            builder.bind(bind_addr_str).map(quic::QuicServerHandle::new)
+        })();
 
-    unsafe { (*result).write_result(server_result) }
+        unsafe { (*result).write_result(server_result) }
+    })
 }
 
 /// Create server with PEM certificate files
-/// Returns error code, result written to `result` parameter
+/// Returns error code, result written to `result` parameter (`QuicServerHandle*` on success)
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_server_new_with_cert_files(
     bind_addr: *const c_char,
@@ -76,138 +79,314 @@ pub unsafe extern "C" fn dart_quic_server_new_with_cert_files(
     transport_config: *const quic::QuicFfiTransportConfig,
     result: *mut QuicFfiResult,
 ) -> i32 {
-    if result.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
-
-    if bind_addr.is_null() || cert_path.is_null() || key_path.is_null() {
-        unsafe { (*result).write_error_str("Bind address, cert path and key path are required"); }
-        return types::QuicResult::InvalidParameter as i32;
-    }
+    panic_guard::guard(|| {
+        if result.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
 
-    let server_result = (|| {
-        let bind_addr_str = unsafe { CStr::from_ptr(bind_addr) }
-            .to_str()
-            .map_err(|_| QuicError::unknown("Invalid bind address".to_string()))?;
-        let cert_path_str = unsafe { CStr::from_ptr(cert_path) }
-            .to_str()
-            .map_err(|_| QuicError::unknown("Invalid certificate path".to_string()))?;
-        let key_path_str = unsafe { CStr::from_ptr(key_path) }
-            .to_str()
-            .map_err(|_| QuicError::unknown("Invalid key path".to_string()))?;
-
-        let mut builder = quic::QuicServer::builder()
-            .with_cert_pem_files(cert_path_str, key_path_str)?;
-
-        if !transport_config.is_null() {
-            let ffi_config = unsafe { &*transport_config };
-            builder = builder.with_transport_config(quic::QuicTransportConfig::from(ffi_config));
+        if bind_addr.is_null() || cert_path.is_null() || key_path.is_null() {
+            unsafe { (*result).write_error_str("Bind address, cert path and key path are required"); }
+            return types::QuicResult::InvalidParameter as i32;
         }
 
-        builder.bind(bind_addr_str)
-    })();
+        let server_result = (|| {
+            let bind_addr_str = unsafe { CStr::from_ptr(bind_addr) }
+                .to_str()
+                .map_err(|_| QuicError::unknown("Invalid bind address".to_string()))?;
+            let cert_path_str = unsafe { CStr::from_ptr(cert_path) }
+                .to_str()
+                .map_err(|_| QuicError::unknown("Invalid certificate path".to_string()))?;
+            let key_path_str = unsafe { CStr::from_ptr(key_path) }
+                .to_str()
+                .map_err(|_| QuicError::unknown("Invalid key path".to_string()))?;
+
+            let mut builder = quic::QuicServer::builder()
+                .with_cert_pem_files(cert_path_str, key_path_str)?;
+
+            if !transport_config.is_null() {
+                builder = builder.with_transport_config(unsafe { quic::QuicTransportConfig::from_ffi_ptr(transport_config) }?);
+            }
+
+            builder.bind(bind_addr_str).map(quic::QuicServerHandle::new)
+        })();
 
-    unsafe { (*result).write_result(server_result) }
+        unsafe { (*result).write_result(server_result) }
+    })
 }
 
 /// Free server
+///
+/// Returns InvalidParameter if the handle is not a live server (e.g. already freed).
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dart_quic_server_free(server: *mut quic::QuicServer) {
-    if !server.is_null() {
-        unsafe { let _ = Box::from_raw(server); }
-    }
+pub extern "C" fn dart_quic_server_free(server: u64) -> i32 {
+    panic_guard::guard(|| {
+        match handle_registry::remove::<QuicServer>(server) {
+            Some(_) => types::QuicResult::Success as i32,
+            None => types::QuicResult::InvalidParameter as i32,
+        }
+    })
 }
 
 /// Close server
+/// Returns error code
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_server_close(
-    server: *mut quic::QuicServer,
+    server: u64,
     error_code: u32,
     reason: *const u8,
     reason_len: usize,
-) {
-    if server.is_null() {
-        return;
-    }
-    
-    let reason_bytes = if reason.is_null() || reason_len == 0 {
-        &[]
-    } else {
-        unsafe { std::slice::from_raw_parts(reason, reason_len) }
-    };
-    
-    unsafe { (*server).close(error_code, reason_bytes) };
+) -> i32 {
+    panic_guard::guard(|| {
+        let Some(server) = handle_registry::get::<QuicServer>(server) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+
+        let reason_bytes = if reason.is_null() || reason_len == 0 {
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(reason, reason_len) }
+        };
+
+        server.close(error_code, reason_bytes);
+        types::QuicResult::Success as i32
+    })
 }
 
 /// Get server local address
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_server_local_addr(
-    server: *mut quic::QuicServer,
+    server: u64,
     addr_out: *mut *mut u8,
     len_out: *mut usize,
 ) -> bool {
-    if server.is_null() || addr_out.is_null() || len_out.is_null() {
-        return false;
-    }
-    
-    let addr_str = unsafe { (*server).local_addr().to_string() };
-    let addr_bytes = addr_str.as_bytes();
-    
-    let ptr = allocate(addr_bytes.len());
-    if !ptr.is_null() {
-        unsafe {
-            std::ptr::copy_nonoverlapping(addr_bytes.as_ptr(), ptr, addr_bytes.len());
-            *addr_out = ptr;
-            *len_out = addr_bytes.len();
+    panic_guard::guard(|| {
+        if addr_out.is_null() || len_out.is_null() {
+            return false;
         }
-        true
-    } else {
-        false
-    }
+        let Some(server) = handle_registry::get::<QuicServer>(server) else {
+            return false;
+        };
+
+        let addr_str = server.local_addr().to_string();
+        let addr_bytes = addr_str.as_bytes();
+
+        let ptr = allocate(addr_bytes.len());
+        if !ptr.is_null() {
+            unsafe {
+                std::ptr::copy_nonoverlapping(addr_bytes.as_ptr(), ptr, addr_bytes.len());
+                *addr_out = ptr;
+                *len_out = addr_bytes.len();
+            }
+            true
+        } else {
+            false
+        }
+    })
 }
 
 /// Accept incoming connection
 ///
 /// Callback receives QuicConnectionHandle pointer on success.
 /// The handle contains:
-/// - connection pointer (for subsequent operations)
+/// - connection registry handle (for subsequent operations)
 /// - stable_id (connection ID)
 /// - remote_addr (remote address string)
 ///
-/// Use `dart_quic_connection_handle_free` to free the handle.
+/// Use `dart_quic_connection_handle_free` to free the handle structure and
+/// `dart_quic_connection_free` to release the connection.
+/// `timeout_ms` fails the accept with `[Timeout]` if no connection arrives in time
+/// (0 = no timeout). If cancelled, the callback fails with an error starting with `[Cancelled]`.
+///
+/// # Returns
+/// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+/// (the callback has then already been called with the error).
+///
+/// # Safety
+/// `executor` must be valid.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_server_accept(
     executor: *mut QuicExecutor,
-    server: *mut quic::QuicServer,
+    server: u64,
+    timeout_ms: u64,
     callback: UsizeCallback,
-) {
-    check_executor_usize!(executor, callback);
-    check_ptr_usize!(server, callback);
-
-    let server_ptr = server as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-
-    if !exec.submit_async(async move {
-        let server = unsafe { &*(server_ptr as *const quic::QuicServer) };
-        match server.accept().await {
-            Some(Ok(conn)) => {
-                let handle = QuicConnectionHandle::new(conn);
-                let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
-                (callback.0)(true, handle_ptr, std::ptr::null(), 0);
-            }
-            Some(Err(e)) => {
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, 0, err.as_ptr(), err.len());
-            }
-            None => {
-                // Server closed — signal completion with value=0
-                (callback.0)(true, 0, std::ptr::null(), 0);
+) -> u64 {
+    panic_guard::guard_with(callback, || {
+        check_executor_usize!(executor, callback, 0);
+        let server = check_handle_usize!(QuicServer, server, callback, 0);
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        let operation_id = exec.submit_cancellable(callback, async move {
+            let result = with_timeout(timeout_ms, server.accept()).await.unwrap_or_else(|e| Some(Err(e)));
+            move || match result {
+                Some(Ok(conn)) => {
+                    let handle = QuicConnectionHandle::new(conn);
+                    let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
+                    (callback.0)(true, handle_ptr, std::ptr::null(), 0);
+                }
+                Some(Err(e)) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
+                None => {
+                    // Server closed — signal completion with value=0
+                    (callback.0)(true, 0, std::ptr::null(), 0);
+                }
             }
+        }, move || {
+            (callback.0)(false, 0, ERR_CANCELLED.as_ptr(), ERR_CANCELLED.len());
+        });
+
+        if operation_id == 0 {
+            callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    }) {
-        callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
+        operation_id
+    })
+}
+
+/// Accept incoming connection with 0.5-RTT (async)
+///
+/// `callback` fires with a `QuicConnectionHandle*` as soon as the handshake starts, so
+/// the server can send data before the client completes the handshake.
+/// `callback` receives value=0 when the server is closed.
+///
+/// `zero_rtt_callback` fires once the handshake completes with a `QuicZeroRttStatus` value
+/// (always 1 = Accepted for incoming connections, 0 if the server closed).
+///
+/// ⚠️ 0.5-RTT data is sent before client authentication completes.
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_accept_0rtt(
+    executor: *mut QuicExecutor,
+    server: u64,
+    callback: UsizeCallback,
+    zero_rtt_callback: UsizeCallback,
+) {
+    let callbacks = ZeroRttCallbacks::new(callback, zero_rtt_callback);
+    let fail = |success, value, error_ptr, error_len| callbacks.fail(success, value, error_ptr, error_len);
+    panic_guard::guard_with(callbacks.clone(), || {
+        check_executor_usize!(executor, fail);
+        let server = check_handle_usize!(QuicServer, server, fail);
+
+        let exec = unsafe { &*executor };
+        let task_callbacks = callbacks.clone();
+
+        if !exec.submit_async(callbacks.clone(), async move {
+            let result = server.accept_0rtt().await;
+            task_callbacks.complete(result).await;
+        }) {
+            callbacks.fail_with(ERR_NOT_RUNNING);
+        }
+    })
+}
+
+/// Read an ALPN protocol argument (null or empty = fallback route)
+pub(crate) unsafe fn alpn_arg(alpn: *const u8, alpn_len: usize) -> Option<Vec<u8>> {
+    if alpn.is_null() || alpn_len == 0 {
+        return None;
     }
+    Some(unsafe { std::slice::from_raw_parts(alpn, alpn_len) }.to_vec())
+}
+
+/// Register a connection queue for an ALPN protocol (sync)
+///
+/// Connections are then accepted per protocol with `dart_quic_server_accept_alpn`
+/// instead of `dart_quic_server_accept`. Registering twice is a no-op.
+///
+/// The first call starts a background task that routes incoming connections to their
+/// queues until the server is closed or freed.
+///
+/// # Parameters
+/// - `server`: Server handle
+/// - `alpn`, `alpn_len`: Protocol identifier bytes (e.g. "h3"); null or empty registers
+///   the fallback queue, which receives connections whose protocol has no queue
+///
+/// # Returns
+/// - 0 (Success), InvalidParameter if the handle is stale
+/// - RuntimeError if the server was not created on a runtime
+///
+/// # Safety
+/// `alpn` must be null or valid for `alpn_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_route_alpn(
+    server: u64,
+    alpn: *const u8,
+    alpn_len: usize,
+) -> i32 {
+    panic_guard::guard(|| {
+        let Some(server) = handle_registry::get::<QuicServer>(server) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+        match server.route_alpn(unsafe { alpn_arg(alpn, alpn_len) }.as_deref()) {
+            Ok(()) => types::QuicResult::Success as i32,
+            Err(e) => e.code_value(),
+        }
+    })
+}
+
+/// Accept the next connection that negotiated an ALPN protocol (async)
+///
+/// Same callback semantics as `dart_quic_server_accept`, for connections routed to the
+/// queue registered with `dart_quic_server_route_alpn` (null or empty `alpn` = fallback).
+/// Fails with InvalidParameter if no queue is registered for `alpn`.
+///
+/// Only reads the queue: cancelling or timing out does not drop connections routed to
+/// other protocols. Connections with no matching queue and no fallback are closed.
+///
+/// # Returns
+/// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+/// (the callback has then already been called with the error).
+///
+/// # Safety
+/// `executor` must be valid; `alpn` must be null or valid for `alpn_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_accept_alpn(
+    executor: *mut QuicExecutor,
+    server: u64,
+    alpn: *const u8,
+    alpn_len: usize,
+    timeout_ms: u64,
+    callback: UsizeCallback,
+) -> u64 {
+    panic_guard::guard_with(callback, || {
+        check_executor_usize!(executor, callback, 0);
+        let server = check_handle_usize!(QuicServer, server, callback, 0);
+        let alpn = unsafe { alpn_arg(alpn, alpn_len) };
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        let operation_id = exec.submit_cancellable(callback, async move {
+            let result = with_timeout(timeout_ms, server.accept_alpn(alpn.as_deref()))
+                .await
+                .unwrap_or_else(|e| Some(Err(e)));
+            move || match result {
+                Some(Ok(conn)) => {
+                    let handle = QuicConnectionHandle::new(conn);
+                    let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
+                    (callback.0)(true, handle_ptr, std::ptr::null(), 0);
+                }
+                Some(Err(e)) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
+                None => {
+                    // Server closed — signal completion with value=0
+                    (callback.0)(true, 0, std::ptr::null(), 0);
+                }
+            }
+        }, move || {
+            (callback.0)(false, 0, ERR_CANCELLED.as_ptr(), ERR_CANCELLED.len());
+        });
+
+        if operation_id == 0 {
+            callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
+        }
+        operation_id
+    })
 }
 
 /// Wait for all server connections to become idle (async)
@@ -216,54 +395,48 @@ pub unsafe extern "C" fn dart_quic_server_accept(
 /// Should typically be called after `dart_quic_server_close` for a graceful shutdown.
 ///
 /// # Safety
-/// All pointers must be valid.
+/// `executor` must be valid.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_server_wait_idle(
     executor: *mut QuicExecutor,
-    server: *mut quic::QuicServer,
+    server: u64,
     callback: VoidCallback,
 ) {
-    check_executor_void!(executor, callback);
-    check_ptr_void!(server, callback);
-
-    let server_ptr = server as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-
-    if !exec.submit_async(async move {
-        let server = unsafe { &*(server_ptr as *const quic::QuicServer) };
-        server.wait_idle().await;
-        (callback.0)(true, std::ptr::null(), 0);
-    }) {
-        let err = ERR_NOT_RUNNING.as_bytes();
-        callback.0(false, err.as_ptr(), err.len());
-    }
+    panic_guard::guard_with(callback, || {
+        check_executor_void!(executor, callback);
+        let server = check_handle_void!(QuicServer, server, callback);
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            server.wait_idle().await;
+            (callback.0)(true, std::ptr::null(), 0);
+        }) {
+            let err = ERR_NOT_RUNNING.as_bytes();
+            callback.0(false, err.as_ptr(), err.len());
+        }
+    })
 }
 
 /// Get the number of currently open connections on the server
 ///
-/// Returns 0 if the server pointer is null.
+/// Returns 0 if the server handle is invalid.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dart_quic_server_open_connections(
-    server: *mut quic::QuicServer,
-) -> usize {
-    if server.is_null() {
-        return 0;
-    }
-    unsafe { (*server).open_connections() }
+pub extern "C" fn dart_quic_server_open_connections(server: u64) -> usize {
+    panic_guard::guard(|| {
+        handle_registry::get::<QuicServer>(server).map_or(0, |server| server.open_connections())
+    })
 }
 
 /// Get server local port
 ///
-/// Returns 0 if the server pointer is null.
+/// Returns 0 if the server handle is invalid.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dart_quic_server_local_port(
-    server: *mut quic::QuicServer,
-) -> u16 {
-    if server.is_null() {
-        return 0;
-    }
-    unsafe { (*server).local_port() }
+pub extern "C" fn dart_quic_server_local_port(server: u64) -> u16 {
+    panic_guard::guard(|| {
+        handle_registry::get::<QuicServer>(server).map_or(0, |server| server.local_port())
+    })
 }
 
 /// Create a QUIC server asynchronously using unified FFI configuration
@@ -272,7 +445,8 @@ pub unsafe extern "C" fn dart_quic_server_local_port(
 /// an active tokio runtime context when creating the endpoint.
 ///
 /// On success, the callback receives a `QuicServerHandle*` pointer (as usize).
-/// The handle is allocated and owned by Rust; free it with `dart_quic_server_handle_free`.
+/// The handle is allocated and owned by Rust; free it with `dart_quic_server_handle_free`
+/// and the server with `dart_quic_server_free`.
 ///
 /// # Safety
 /// - `config` and all data it references must remain valid until the callback fires
@@ -289,77 +463,190 @@ pub unsafe extern "C" fn dart_quic_server_new_async(
     config: *const quic::QuicFfiServerConfig,
     callback: UsizeCallback,
 ) -> i32 {
-    if executor.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
-
-    let executor_ref = unsafe { &*executor };
-    if !executor_ref.is_running() {
-        return types::QuicResult::RuntimeError as i32;
-    }
+    panic_guard::guard(|| {
+        if executor.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
 
-    if bind_addr.is_null() || config.is_null() {
-        return types::QuicResult::InvalidParameter as i32;
-    }
+        let executor_ref = unsafe { &*executor };
+        if !executor_ref.is_running() {
+            return types::QuicResult::RuntimeError as i32;
+        }
 
-    // Capture raw pointer values to send across the async boundary.
-    // Safety: caller guarantees all pointers remain valid until the callback fires.
-    let bind_addr_ptr = bind_addr as usize;
-    let config_ptr = config as usize;
-    let callback = SendableCallback(callback);
-
-    executor_ref.submit_async(async move {
-        let bind_addr_str = match unsafe { CStr::from_ptr(bind_addr_ptr as *const c_char) }.to_str() {
-            Ok(s) => s,
-            Err(_) => {
-                let err = b"Invalid bind address encoding";
-                (callback.0)(false, 0, err.as_ptr(), err.len());
-                return;
-            }
-        };
-        let ffi_config = unsafe { &*(config_ptr as *const quic::QuicFfiServerConfig) };
+        if bind_addr.is_null() || config.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
 
-        match ffi_config.build(bind_addr_str) {
-            Ok(server) => {
-                let handle = quic::QuicServerHandle::new(server);
-                let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
-                (callback.0)(true, handle_ptr, std::ptr::null(), 0);
-            }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(e.to_string());
-                (callback.0)(false, 0, err.as_ptr(), err.len());
+        // Capture raw pointer values to send across the async boundary.
+        // Safety: caller guarantees all pointers remain valid until the callback fires.
+        let bind_addr_ptr = bind_addr as usize;
+        let config_ptr = config as usize;
+        let callback = SendableCallback(callback);
+        let endpoints = executor_ref.endpoint_tracker();
+
+        executor_ref.submit_async(callback, async move {
+            let bind_addr_str = match unsafe { CStr::from_ptr(bind_addr_ptr as *const c_char) }.to_str() {
+                Ok(s) => s,
+                Err(_) => {
+                    let err = b"Invalid bind address encoding";
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                    return;
+                }
+            };
+            let ffi_config = unsafe { &*(config_ptr as *const quic::QuicFfiServerConfig) };
+
+            match ffi_config.build(bind_addr_str) {
+                Ok(server) => {
+                    let handle = quic::QuicServerHandle::new(server);
+                    endpoints.track(handle.server);
+                    let handle_ptr = Box::into_raw(Box::new(handle)) as usize;
+                    (callback.0)(true, handle_ptr, std::ptr::null(), 0);
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(e.to_string());
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
             }
-        }
-    });
+        });
 
-    types::QuicResult::Success as i32
+        types::QuicResult::Success as i32
+    })
 }
 
-/// Free server handle and all its resources
+/// Free a server handle structure
 ///
 /// This frees:
-/// - The server itself
 /// - The local_addr_ptr string
 /// - The handle structure
 ///
+/// The server itself stays open; release it with `dart_quic_server_free`.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_server_handle_free(handle: *mut quic::QuicServerHandle) {
-    if handle.is_null() {
-        return;
-    }
+    panic_guard::guard(|| {
+        if handle.is_null() {
+            return;
+        }
 
-    let handle_ref = unsafe { &mut *handle };
+        let handle_ref = unsafe { &mut *handle };
 
-    // Free the server itself
-    if !handle_ref.server.is_null() {
-        unsafe { let _ = Box::from_raw(handle_ref.server); }
-    }
+        // Free the local address string
+        if !handle_ref.local_addr_ptr.is_null() && handle_ref.local_addr_len > 0 {
+            crate::deallocate(handle_ref.local_addr_ptr, handle_ref.local_addr_len as usize);
+        }
 
-    // Free the local address string
-    if !handle_ref.local_addr_ptr.is_null() && handle_ref.local_addr_len > 0 {
-        crate::deallocate(handle_ref.local_addr_ptr, handle_ref.local_addr_len as usize);
-    }
+        // Free the handle struct itself
+        unsafe { let _ = Box::from_raw(handle); }
+    })
+}
 
-    // Free the handle struct itself
-    unsafe { let _ = Box::from_raw(handle); }
+/// Get server endpoint statistics (sync)
+///
+/// See `dart_quic_endpoint_stats`.
+///
+/// # Safety
+/// out_stats must point to writable memory for a `QuicFfiEndpointStats`
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_stats(
+    server: u64,
+    out_stats: *mut quic::QuicFfiEndpointStats,
+) -> i32 {
+    panic_guard::guard(|| {
+        let Some(server) = handle_registry::get::<QuicServer>(server) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+        unsafe { crate::quic_ffi_endpoint::write_endpoint_stats(server.as_endpoint(), out_stats) }
+    })
+}
+
+/// Enumerate stable IDs of live connections accepted by the server (sync)
+///
+/// See `dart_quic_endpoint_connection_ids`. Free the array with
+/// `dart_free_memory(ids, count * sizeof(uint64_t))`.
+///
+/// # Safety
+/// out_ids and out_count must be valid pointers
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_connection_ids(
+    server: u64,
+    out_ids: *mut *mut u64,
+    out_count: *mut usize,
+) -> i32 {
+    panic_guard::guard(|| {
+        let Some(server) = handle_registry::get::<QuicServer>(server) else {
+            return types::QuicResult::InvalidParameter as i32;
+        };
+        unsafe { crate::quic_ffi_endpoint::write_connection_ids(server.as_endpoint(), out_ids, out_count) }
+    })
+}
+
+/// Replace the server certificate (sync, hot reload)
+///
+/// Builds a new server configuration from `config`, exactly as `dart_quic_server_new_async`
+/// would (certificate from PEM files, DER memory or self-signed per `cert_mode`, plus SNI
+/// certificates, client authentication, transport and 0-RTT settings), and applies it to
+/// new connections. Established connections keep their certificate.
+///
+/// # Returns
+/// - 0 on success
+/// - InvalidParameter if the handle is stale
+/// - Error code on failure (`result` carries the message; the current configuration stays)
+///
+/// # Safety
+/// `config` and `result` must be valid pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_set_config(
+    server: u64,
+    config: *const quic::QuicFfiServerConfig,
+    result: *mut QuicFfiResult,
+) -> i32 {
+    panic_guard::guard(|| {
+        if result.is_null() {
+            return types::QuicResult::InvalidParameter as i32;
+        }
+        let result = unsafe { &mut *result };
+        let Some(server) = handle_registry::get::<QuicServer>(server) else {
+            result.write_error_str(crate::ERR_INVALID_HANDLE);
+            return types::QuicResult::InvalidParameter as i32;
+        };
+        unsafe { crate::quic_ffi_endpoint::apply_server_config(server.as_endpoint(), config, result) }
+    })
+}
+
+/// Reload the server certificate whenever its PEM files change (async)
+///
+/// Watches the certificate and key files of `config` (`cert_mode` = 0 and file-based SNI
+/// certificates), checking them every `interval_ms` (0 = 5000). After a change the
+/// configuration is rebuilt from `config` as by `dart_quic_server_set_config`. A failed
+/// rebuild (e.g. the key was not rewritten yet) keeps the current certificate, logs a
+/// warning, and is retried on the next change.
+///
+/// `config` is copied; it may be freed once this call returns.
+///
+/// `callback` fires once, when the watch ends:
+/// - success once the server handle is released (`dart_quic_server_free`)
+/// - an error starting with `[Cancelled]` when cancelled with `dart_quic_operation_cancel`
+/// - an error right away if `config` is invalid or has no certificate files
+///
+/// # Returns
+/// Operation ID for `dart_quic_operation_cancel`, or 0 if the watch was not started
+/// (the callback has then already been called with the error).
+///
+/// # Safety
+/// `executor` must be valid; `config` must be a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_server_watch_certs(
+    executor: *mut QuicExecutor,
+    server: u64,
+    config: *const quic::QuicFfiServerConfig,
+    interval_ms: u64,
+    callback: VoidCallback,
+) -> u64 {
+    panic_guard::guard_with(callback, || {
+        check_handle_void!(QuicServer, server, callback, 0);
+        unsafe {
+            crate::quic_ffi_endpoint::submit_cert_watch(executor, config, interval_ms, callback, move || {
+                handle_registry::get::<QuicServer>(server).map(|server| server.as_endpoint().clone())
+            })
+        }
+    })
 }
@@ -2,77 +2,112 @@
 //!
 //! C-compatible structures for returning stream handles from async operations.
 
-use crate::quic_executor::{QuicExecutor, SendableCallback, BytesCallback};
-use crate::{allocate, ERR_NOT_RUNNING, check_executor_bytes};
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use parking_lot::Mutex;
+use quinn::{ClosedStream, ReadError, ReadExactError, ReadToEndError, StoppedError, VarInt, WriteError};
+use tokio::io::ReadBuf;
+use tokio::sync::Notify;
+
+use crate::quic_executor::{with_timeout, QuicExecutor, SendableCallback, BytesCallback};
+use crate::handle_registry::{self, QuicHandleType};
+use crate::{allocate, ERR_CANCELLED, ERR_INVALID_HANDLE, ERR_NOT_RUNNING, check_executor_bytes};
+use crate::error::QuicError;
+use crate::panic_guard;
 
 // ============================================================================
 // Stream Type Checking Macros
 // ============================================================================
 
-/// Check if handle is a valid recv stream (for BytesCallback)
+/// Look up a recv stream handle (for BytesCallback)
 macro_rules! check_recv_stream_bytes {
     ($handle:expr, $callback:expr) => {
-        if $handle.is_null() {
-            let err = b"Stream handle is null";
-            $callback(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
-            return;
-        }
-        let stream_type = unsafe { (*$handle).stream_type };
-        if stream_type != QuicStreamType::Recv as u8 {
-            let err = b"Invalid stream type: expected Recv stream";
-            $callback(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
-            return;
+        check_recv_stream_bytes!($handle, $callback, ())
+    };
+    ($handle:expr, $callback:expr, $ret:expr) => {
+        match handle_registry::get::<FfiRecvStream>($handle) {
+            Some(stream) => stream,
+            None => {
+                let err = stream_handle_error($handle, QuicStreamType::Recv);
+                $callback(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+                return $ret;
+            }
         }
     };
 }
 
-/// Check if handle is a valid send stream (for UsizeCallback)
+/// Look up a send stream handle (for UsizeCallback)
 macro_rules! check_send_stream_usize {
     ($handle:expr, $callback:expr) => {
-        if $handle.is_null() {
-            let err = b"Stream handle is null";
-            $callback(false, 0, err.as_ptr(), err.len());
-            return;
+        match handle_registry::get::<FfiSendStream>($handle) {
+            Some(stream) => stream,
+            None => {
+                let err = stream_handle_error($handle, QuicStreamType::Send);
+                $callback(false, 0, err.as_ptr(), err.len());
+                return;
+            }
         }
-        let stream_type = unsafe { (*$handle).stream_type };
-        if stream_type != QuicStreamType::Send as u8 {
-            let err = b"Invalid stream type: expected Send stream";
-            $callback(false, 0, err.as_ptr(), err.len());
-            return;
+    };
+}
+
+/// Look up a send stream handle (for BoolCallback)
+macro_rules! check_send_stream_bool {
+    ($handle:expr, $callback:expr) => {
+        match handle_registry::get::<FfiSendStream>($handle) {
+            Some(stream) => stream,
+            None => {
+                let err = stream_handle_error($handle, QuicStreamType::Send);
+                $callback(false, false, err.as_ptr(), err.len());
+                return;
+            }
         }
     };
 }
 
-/// Check if handle is a valid send stream (for VoidCallback)
+/// Look up a send stream handle (for VoidCallback)
 macro_rules! check_send_stream_void {
     ($handle:expr, $callback:expr) => {
-        if $handle.is_null() {
-            let err = b"Stream handle is null";
-            $callback(false, err.as_ptr(), err.len());
-            return;
+        match handle_registry::get::<FfiSendStream>($handle) {
+            Some(stream) => stream,
+            None => {
+                let err = stream_handle_error($handle, QuicStreamType::Send);
+                $callback(false, err.as_ptr(), err.len());
+                return;
+            }
         }
-        let stream_type = unsafe { (*$handle).stream_type };
-        if stream_type != QuicStreamType::Send as u8 {
-            let err = b"Invalid stream type: expected Send stream";
-            $callback(false, err.as_ptr(), err.len());
-            return;
+    };
+}
+
+/// Look up a recv stream handle (for sync functions returning i32)
+macro_rules! check_recv_stream_sync {
+    ($handle:expr) => {
+        match handle_registry::get::<FfiRecvStream>($handle) {
+            Some(stream) => stream,
+            None => return crate::types::QuicResult::InvalidParameter as i32,
         }
     };
 }
 
-/// Check if handle is a valid send stream (for sync functions returning i32)
+/// Look up a send stream handle (for sync functions returning i32)
 macro_rules! check_send_stream_sync {
     ($handle:expr) => {
-        if $handle.is_null() {
-            return crate::types::QuicResult::InvalidParameter as i32;
-        }
-        let stream_type = unsafe { (*$handle).stream_type };
-        if stream_type != QuicStreamType::Send as u8 {
-            return crate::types::QuicResult::InvalidParameter as i32;
+        match handle_registry::get::<FfiSendStream>($handle) {
+            Some(stream) => stream,
+            None => return crate::types::QuicResult::InvalidParameter as i32,
         }
     };
 }
 
+/// Error message for a handle that is not a live stream of the expected type
+pub(crate) fn stream_handle_error(handle: u64, expected: QuicStreamType) -> &'static str {
+    match (handle_registry::handle_type(handle), expected) {
+        (Some(QuicHandleType::SendStream), QuicStreamType::Recv) => "Invalid stream type: expected Recv stream",
+        (Some(QuicHandleType::RecvStream), QuicStreamType::Send) => "Invalid stream type: expected Send stream",
+        _ => ERR_INVALID_HANDLE,
+    }
+}
+
 // ============================================================================
 // Stream Handle Structure
 // ============================================================================
@@ -87,14 +122,183 @@ pub enum QuicStreamType {
     Send = 1,
 }
 
-/// Unified stream handle with metadata
+/// Registry object behind a stream handle
+///
+/// Owns the quinn stream and records the application error code the peer reset or
+/// stopped it with.
+///
+/// The stream sits behind a mutex that async operations hold only while polling, never
+/// across an await, so sync calls (finish, reset, priority, ...) never wait on a pending
+/// read or write. A local reset/stop wakes pending operations, which then fail with
+/// `ClosedStream` as if they had been issued after it.
+pub struct QuicFfiStream<S> {
+    stream: Mutex<S>,
+    interrupted: Notify,
+    peer_error_code: Mutex<Option<u64>>,
+}
+
+/// Registry object behind a Send stream handle
+pub type FfiSendStream = QuicFfiStream<quinn::SendStream>;
+/// Registry object behind a Recv stream handle
+pub type FfiRecvStream = QuicFfiStream<quinn::RecvStream>;
+
+impl<S> QuicFfiStream<S> {
+    fn new(stream: S) -> Self {
+        Self {
+            stream: Mutex::new(stream),
+            interrupted: Notify::new(),
+            peer_error_code: Mutex::new(None),
+        }
+    }
+
+    /// Poll `poll` to completion, locking the stream for each poll
+    ///
+    /// Resolves to `None` if the stream is reset or stopped locally first.
+    async fn poll_locked<T>(&self, mut poll: impl FnMut(&mut S, &mut Context<'_>) -> Poll<T>) -> Option<T> {
+        let interrupted = self.interrupted.notified();
+        let operation = std::future::poll_fn(|cx| poll(&mut self.stream.lock(), cx));
+        tokio::select! {
+            biased;
+            result = operation => Some(result),
+            () = interrupted => None,
+        }
+    }
+
+    /// Reset or stop the stream, failing pending operations
+    fn interrupt<R>(&self, f: impl FnOnce(&mut S) -> R) -> R {
+        let result = f(&mut self.stream.lock());
+        self.interrupted.notify_waiters();
+        result
+    }
+
+    /// Record the peer's reset/stop application error code, if the error carries one
+    ///
+    /// Called from async tasks before the error callback fires, so Dart observes the code
+    /// once the callback runs.
+    pub(crate) fn record_peer_error(&self, err: &QuicError) {
+        if let Some(code) = err.app_error_code() {
+            *self.peer_error_code.lock() = Some(code);
+        }
+    }
+
+    fn peer_error_code(&self) -> Option<u64> {
+        *self.peer_error_code.lock()
+    }
+}
+
+/// Spare capacity reserved before each `read_to_end` read
+const READ_TO_END_CHUNK: usize = 64 * 1024;
+
+impl FfiRecvStream {
+    /// `quinn::RecvStream::read` (0 bytes read = end of stream)
+    pub(crate) async fn read(&self, buf: &mut [u8]) -> Result<Option<usize>, ReadError> {
+        self.poll_locked(|recv, cx| recv.poll_read(cx, buf))
+            .await
+            .unwrap_or(Err(ReadError::ClosedStream))
+            .map(|n| (n > 0).then_some(n))
+    }
+
+    /// `quinn::RecvStream::read_exact`
+    pub(crate) async fn read_exact(&self, buf: &mut [u8]) -> Result<(), ReadExactError> {
+        let mut filled = 0;
+        self.poll_locked(|recv, cx| {
+            while filled < buf.len() {
+                match ready!(recv.poll_read(cx, &mut buf[filled..])) {
+                    Ok(0) => return Poll::Ready(Err(ReadExactError::FinishedEarly(filled))),
+                    Ok(n) => filled += n,
+                    Err(e) => return Poll::Ready(Err(e.into())),
+                }
+            }
+            Poll::Ready(Ok(()))
+        })
+        .await
+        .unwrap_or(Err(ReadError::ClosedStream.into()))
+    }
+
+    /// `quinn::RecvStream::read_to_end`
+    pub(crate) async fn read_to_end(&self, size_limit: usize) -> Result<Vec<u8>, ReadToEndError> {
+        let mut data = Vec::new();
+        self.poll_locked(|recv, cx| loop {
+            data.reserve(READ_TO_END_CHUNK);
+            let mut buf = ReadBuf::uninit(data.spare_capacity_mut());
+            ready!(recv.poll_read_buf(cx, &mut buf))?;
+            let n = buf.filled().len();
+            if n == 0 {
+                return Poll::Ready(Ok(std::mem::take(&mut data)));
+            }
+            // SAFETY: `poll_read_buf` initialized the first `n` bytes of spare capacity
+            unsafe { data.set_len(data.len() + n) };
+            if data.len() > size_limit {
+                return Poll::Ready(Err(ReadToEndError::TooLong));
+            }
+        })
+        .await
+        .unwrap_or(Err(ReadError::ClosedStream.into()))
+    }
+
+    /// `quinn::RecvStream::stop`, failing pending reads
+    pub(crate) fn stop(&self, error_code: VarInt) -> Result<(), ClosedStream> {
+        self.interrupt(|recv| recv.stop(error_code))
+    }
+}
+
+impl FfiSendStream {
+    /// `quinn::SendStream::write`
+    pub(crate) async fn write(&self, buf: &[u8]) -> Result<usize, WriteError> {
+        self.poll_locked(|send, cx| Pin::new(send).poll_write(cx, buf))
+            .await
+            .unwrap_or(Err(WriteError::ClosedStream))
+    }
+
+    /// `quinn::SendStream::write_all`
+    pub(crate) async fn write_all(&self, buf: &[u8]) -> Result<(), WriteError> {
+        let mut written = 0;
+        self.poll_locked(|send, cx| {
+            while written < buf.len() {
+                written += ready!(Pin::new(&mut *send).poll_write(cx, &buf[written..]))?;
+            }
+            Poll::Ready(Ok(()))
+        })
+        .await
+        .unwrap_or(Err(WriteError::ClosedStream))
+    }
+
+    /// `quinn::SendStream::finish`
+    pub(crate) fn finish(&self) -> Result<(), ClosedStream> {
+        self.stream.lock().finish()
+    }
+
+    /// `quinn::SendStream::reset`, failing pending writes
+    pub(crate) fn reset(&self, error_code: VarInt) -> Result<(), ClosedStream> {
+        self.interrupt(|send| send.reset(error_code))
+    }
+
+    /// `quinn::SendStream::set_priority`
+    pub(crate) fn set_priority(&self, priority: i32) -> Result<(), ClosedStream> {
+        self.stream.lock().set_priority(priority)
+    }
+
+    /// `quinn::SendStream::priority`
+    pub(crate) fn priority(&self) -> Result<i32, ClosedStream> {
+        self.stream.lock().priority()
+    }
+
+    /// `quinn::SendStream::stopped` (the returned future does not borrow the stream)
+    pub(crate) fn stopped(&self) -> impl Future<Output = Result<Option<VarInt>, StoppedError>> + Send + 'static {
+        self.stream.lock().stopped()
+    }
+}
+
+/// Stream handle with metadata
 ///
-/// FFI-friendly structure wrapping a stream pointer, its ID, and type.
-/// The stream pointer can be either `SendStream*` or `RecvStream*` depending on `stream_type`.
+/// FFI-friendly structure carrying a stream's registry handle, its ID, and type.
+/// The handle refers to a Send or Recv stream depending on `stream_type`; release it
+/// with `dart_quic_stream_free`. The structure itself is freed together with its
+/// `QuicFfiStreamPair`.
 #[repr(C)]
 pub struct QuicFfiStreamHandle {
-    /// Stream pointer (cast to void* for FFI, actual type depends on stream_type)
-    pub stream: *mut std::ffi::c_void,
+    /// Stream registry handle (pass to all subsequent stream operations)
+    pub stream: u64,
     /// Stream ID
     pub stream_id: u64,
     /// Stream type (0 = Recv, 1 = Send)
@@ -106,7 +310,7 @@ impl QuicFfiStreamHandle {
     pub fn new_send(stream: quinn::SendStream) -> Self {
         let stream_id = stream.id().index();
         Self {
-            stream: Box::into_raw(Box::new(stream)) as *mut std::ffi::c_void,
+            stream: handle_registry::insert(FfiSendStream::new(stream)),
             stream_id,
             stream_type: QuicStreamType::Send as u8,
         }
@@ -116,13 +320,45 @@ impl QuicFfiStreamHandle {
     pub fn new_recv(stream: quinn::RecvStream) -> Self {
         let stream_id = stream.id().index();
         Self {
-            stream: Box::into_raw(Box::new(stream)) as *mut std::ffi::c_void,
+            stream: handle_registry::insert(FfiRecvStream::new(stream)),
             stream_id,
             stream_type: QuicStreamType::Recv as u8,
         }
     }
 }
 
+/// Read buffer from `allocate`, freed on drop unless handed to Dart
+///
+/// Keeps cancelled and failed reads from leaking the buffer.
+struct FfiReadBuf {
+    ptr: usize,
+    len: usize,
+}
+
+impl FfiReadBuf {
+    fn new(len: usize) -> Option<Self> {
+        let ptr = allocate(len);
+        (!ptr.is_null()).then_some(Self { ptr: ptr as usize, len })
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.ptr as *mut u8, self.len) }
+    }
+
+    /// Hand the buffer to Dart (freed with `dart_free_memory(ptr, len)`)
+    fn into_raw(self) -> *mut u8 {
+        let ptr = self.ptr as *mut u8;
+        std::mem::forget(self);
+        ptr
+    }
+}
+
+impl Drop for FfiReadBuf {
+    fn drop(&mut self) {
+        crate::deallocate(self.ptr as *mut u8, self.len);
+    }
+}
+
 /// C-compatible structure for stream pair
 /// Contains both send and recv stream handles (one or both may be null)
 #[repr(C)]
@@ -160,48 +396,57 @@ impl QuicFfiStreamPair {
 }
 
 /// Free stream pair structure
+///
+/// Frees the pair and its `QuicFfiStreamHandle` structures. The streams stay open
+/// until released with `dart_quic_stream_free`.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_stream_pair_free(pair: *mut QuicFfiStreamPair) {
-    if !pair.is_null() {
-        let stream_pair = unsafe { Box::from_raw(pair) };
-        // Free individual stream handles if they exist
-        if !stream_pair.send_handle.is_null() {
-            unsafe { dart_quic_stream_handle_free(stream_pair.send_handle); }
-        }
-        if !stream_pair.recv_handle.is_null() {
-            unsafe { dart_quic_stream_handle_free(stream_pair.recv_handle); }
+    panic_guard::guard(|| {
+        if !pair.is_null() {
+            let stream_pair = unsafe { Box::from_raw(pair) };
+            // Free individual stream handles if they exist
+            if !stream_pair.send_handle.is_null() {
+                unsafe { dart_quic_stream_handle_free(stream_pair.send_handle); }
+            }
+            if !stream_pair.recv_handle.is_null() {
+                unsafe { dart_quic_stream_handle_free(stream_pair.recv_handle); }
+            }
         }
-    }
+    })
 }
 
-/// Free stream handle (works for both send and recv streams)
+/// Free a stream handle structure (does not release the stream)
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_stream_handle_free(handle: *mut QuicFfiStreamHandle) {
-    if handle.is_null() {
-        return;
-    }
-    
-    let handle_ref = unsafe { Box::from_raw(handle) };
-    if !handle_ref.stream.is_null() {
-        // Free the stream based on its type
-        match handle_ref.stream_type {
-            0 => {
-                // Recv stream
-                unsafe {
-                    let _ = Box::from_raw(handle_ref.stream as *mut quinn::RecvStream);
-                }
-            }
-            1 => {
-                // Send stream
-                unsafe {
-                    let _ = Box::from_raw(handle_ref.stream as *mut quinn::SendStream);
-                }
-            }
-            _ => {
-                // Invalid type, skip freeing stream (already freed handle itself)
-            }
+    panic_guard::guard(|| {
+        if !handle.is_null() {
+            unsafe { let _ = Box::from_raw(handle); }
         }
-    }
+    })
+}
+
+/// Release a stream (works for both send and recv streams)
+///
+/// Dropping a send stream finishes it; dropping a recv stream stops it with code 0.
+/// In-flight operations keep the stream alive until they complete.
+///
+/// # Returns
+/// - 0 (Success) on success
+/// - InvalidParameter if the handle is not a live stream (e.g. already freed)
+#[unsafe(no_mangle)]
+pub extern "C" fn dart_quic_stream_free(stream: u64) -> i32 {
+    panic_guard::guard(|| {
+        let released = match handle_registry::handle_type(stream) {
+            Some(QuicHandleType::SendStream) => handle_registry::remove::<FfiSendStream>(stream).is_some(),
+            Some(QuicHandleType::RecvStream) => handle_registry::remove::<FfiRecvStream>(stream).is_some(),
+            _ => false,
+        };
+        if released {
+            crate::types::QuicResult::Success as i32
+        } else {
+            crate::types::QuicResult::InvalidParameter as i32
+        }
+    })
 }
 
 // ============================================
@@ -226,69 +471,74 @@ pub unsafe extern "C" fn dart_quic_stream_handle_free(handle: *mut QuicFfiStream
 /// - `executor`: QuicExecutor for async execution
 /// - `handle`: Stream handle (must be of type Recv)
 /// - `max_len`: Maximum bytes to read (will allocate this much memory)
+/// - `timeout_ms`: Fail with a `[Timeout]` error if no data arrives in time (0 = no timeout)
 /// - `callback`: Called with (success, data_ptr, data_len, error_ptr, error_len)
 ///   - On success: callback(true, buf, bytes_read, null, 0) where bytes_read <= max_len
 ///   - On EOF: callback(true, null, 0, null, 0)
 ///   - On error: callback(false, null, 0, error_ptr, error_len)
+///   - On cancel: callback(false, null, 0, "[Cancelled] ...", len)
+///
+/// # Returns
+/// Operation ID for `dart_quic_operation_cancel`, or 0 if the read was not started
+/// (the callback has then already been called with the error).
+///
+/// # Safety
+/// `executor` must be valid.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_recv_stream_read(
     executor: *mut QuicExecutor,
-    handle: *mut QuicFfiStreamHandle,
+    handle: u64,
     max_len: usize,
+    timeout_ms: u64,
     callback: BytesCallback,
-) {
-    check_executor_bytes!(executor, callback);
-    check_recv_stream_bytes!(handle, callback);
-    
-    if max_len == 0 {
-        let err = b"Invalid max length";
-        callback(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
-        return;
-    }
-    
-    let stream_ptr = unsafe { (*handle).stream } as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-    
-    if !exec.submit_async(async move {
-        let stream = unsafe { &mut *(stream_ptr as *mut quinn::RecvStream) };
-        
-        // Allocate max_len bytes directly for zero-copy reading
-        // Trade-off: May waste (max_len - n) bytes, but avoids memory copy
-        let out_ptr = allocate(max_len);
-        if out_ptr.is_null() {
-            let err = b"Allocation failed";
-            (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
-            return;
+) -> u64 {
+    panic_guard::guard_with(callback, || {
+        check_executor_bytes!(executor, callback, 0);
+        let stream = check_recv_stream_bytes!(handle, callback, 0);
+
+        if max_len == 0 {
+            let err = b"Invalid max length";
+            callback(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+            return 0;
         }
-        
-        // Convert to usize for Send safety across await
-        let ptr_addr = out_ptr as usize;
-        
-        // Create mutable slice from allocated memory
-        let buf = unsafe { std::slice::from_raw_parts_mut(ptr_addr as *mut u8, max_len) };
-        
-        match stream.read(buf).await {
-            Ok(Some(n)) => {
-                // Successfully read n bytes (n <= max_len)
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        let operation_id = exec.submit_cancellable(callback, async move {
+            // Allocate max_len bytes directly for zero-copy reading
+            // Trade-off: May waste (max_len - n) bytes, but avoids memory copy
+            let result = match FfiReadBuf::new(max_len) {
+                None => Err(crate::FfiErrBuf::new("Allocation failed".to_string())),
+                Some(mut buf) => match with_timeout(timeout_ms, stream.read(buf.as_mut_slice())).await {
+                    // Successfully read n bytes (n <= max_len)
+                    Ok(Ok(Some(n))) => Ok(Some((buf, n))),
+                    // EOF reached, unused memory is freed with `buf`
+                    Ok(Ok(None)) => Ok(None),
+                    Ok(Err(e)) => {
+                        let err = crate::FfiErrBuf::new(format!("{}", e));
+                        stream.record_peer_error(&QuicError::from(e));
+                        Err(err)
+                    }
+                    Err(e) => Err(crate::FfiErrBuf::new(format!("{}", e))),
+                },
+            };
+
+            move || match result {
                 // Caller receives ptr with n valid bytes, must free max_len bytes
-                (callback.0)(true, ptr_addr as *mut u8, n, std::ptr::null(), 0);
-            }
-            Ok(None) => {
-                // EOF reached, deallocate unused memory
-                crate::deallocate(ptr_addr as *mut u8, max_len);
-                (callback.0)(true, std::ptr::null_mut(), 0, std::ptr::null(), 0);
-            }
-            Err(e) => {
-                // Error occurred, deallocate unused memory
-                crate::deallocate(ptr_addr as *mut u8, max_len);
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+                Ok(Some((buf, n))) => (callback.0)(true, buf.into_raw(), n, std::ptr::null(), 0),
+                Ok(None) => (callback.0)(true, std::ptr::null_mut(), 0, std::ptr::null(), 0),
+                Err(err) => (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len()),
             }
+        }, move || {
+            (callback.0)(false, std::ptr::null_mut(), 0, ERR_CANCELLED.as_ptr(), ERR_CANCELLED.len());
+        });
+
+        if operation_id == 0 {
+            callback.0(false, std::ptr::null_mut(), 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    }) {
-        callback.0(false, std::ptr::null_mut(), 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+        operation_id
+    })
 }
 
 /// Read exact number of bytes from the stream
@@ -303,61 +553,69 @@ pub unsafe extern "C" fn dart_quic_recv_stream_read(
 /// - `executor`: QuicExecutor for async execution
 /// - `handle`: Stream handle (must be of type Recv)
 /// - `exact_len`: Exact number of bytes to read
+/// - `timeout_ms`: Fail with a `[Timeout]` error if all bytes do not arrive in time
+///   (0 = no timeout); bytes consumed before the timeout are lost
 /// - `callback`: Called with (success, data_ptr, data_len, error_ptr, error_len)
 ///   - On success: callback(true, buf, exact_len, null, 0)
 ///   - On error: callback(false, null, 0, error_ptr, error_len)
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_recv_stream_read_exact(
     executor: *mut QuicExecutor,
-    handle: *mut QuicFfiStreamHandle,
+    handle: u64,
     exact_len: usize,
+    timeout_ms: u64,
     callback: BytesCallback,
 ) {
-    check_executor_bytes!(executor, callback);
-    check_recv_stream_bytes!(handle, callback);
-    
-    if exact_len == 0 {
-        let err = b"Invalid buffer length";
-        callback(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
-        return;
-    }
-    
-    let stream_ptr = unsafe { (*handle).stream } as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-    
-    if !exec.submit_async(async move {
-        let stream = unsafe { &mut *(stream_ptr as *mut quinn::RecvStream) };
-        
-        // Allocate exact size needed (no waste)
-        let out_ptr = allocate(exact_len);
-        if out_ptr.is_null() {
-            let err = b"Allocation failed";
-            (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+    panic_guard::guard_with(callback, || {
+        check_executor_bytes!(executor, callback);
+        let stream = check_recv_stream_bytes!(handle, callback);
+
+        if exact_len == 0 {
+            let err = b"Invalid buffer length";
+            callback(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
             return;
         }
-        
-        // Convert to usize for Send safety across await
-        let ptr_addr = out_ptr as usize;
-        
-        // Create mutable slice from allocated memory
-        let buf = unsafe { std::slice::from_raw_parts_mut(ptr_addr as *mut u8, exact_len) };
-        
-        match stream.read_exact(buf).await {
-            Ok(()) => {
-                // Successfully read exact_len bytes
-                (callback.0)(true, ptr_addr as *mut u8, exact_len, std::ptr::null(), 0);
-            }
-            Err(e) => {
-                // Error occurred, deallocate
-                crate::deallocate(ptr_addr as *mut u8, exact_len);
-                let err = crate::FfiErrBuf::new(format!("{}", e));
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            // Allocate exact size needed (no waste)
+            let out_ptr = allocate(exact_len);
+            if out_ptr.is_null() {
+                let err = b"Allocation failed";
                 (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+                return;
             }
+
+            // Convert to usize for Send safety across await
+            let ptr_addr = out_ptr as usize;
+
+            // Create mutable slice from allocated memory
+            let buf = unsafe { std::slice::from_raw_parts_mut(ptr_addr as *mut u8, exact_len) };
+
+            match with_timeout(timeout_ms, stream.read_exact(buf)).await {
+                Ok(Ok(())) => {
+                    // Successfully read exact_len bytes
+                    (callback.0)(true, ptr_addr as *mut u8, exact_len, std::ptr::null(), 0);
+                }
+                Ok(Err(e)) => {
+                    // Error occurred, deallocate
+                    crate::deallocate(ptr_addr as *mut u8, exact_len);
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    stream.record_peer_error(&QuicError::from(e));
+                    (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+                }
+                Err(e) => {
+                    crate::deallocate(ptr_addr as *mut u8, exact_len);
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+                }
+            }
+        }) {
+            callback.0(false, std::ptr::null_mut(), 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    }) {
-        callback.0(false, std::ptr::null_mut(), 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+    })
 }
 
 /// Read all remaining data from the stream
@@ -368,51 +626,58 @@ pub unsafe extern "C" fn dart_quic_recv_stream_read_exact(
 /// - `executor`: QuicExecutor for async execution
 /// - `handle`: Stream handle (must be of type Recv)
 /// - `size_limit`: Maximum bytes to read (prevents memory exhaustion)
+/// - `timeout_ms`: Fail with a `[Timeout]` error if the stream does not finish in time
+///   (0 = no timeout); data consumed before the timeout is lost
 /// - `callback`: Called with (success, data_ptr, data_len, error_ptr, error_len)
 ///   - On success: callback(true, buf, total_bytes, null, 0)
 ///   - On error: callback(false, null, 0, error_ptr, error_len)
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_recv_stream_read_to_end(
     executor: *mut QuicExecutor,
-    handle: *mut QuicFfiStreamHandle,
+    handle: u64,
     size_limit: usize,
+    timeout_ms: u64,
     callback: BytesCallback,
 ) {
-    check_executor_bytes!(executor, callback);
-    check_recv_stream_bytes!(handle, callback);
-    
-    let stream_ptr = unsafe { (*handle).stream } as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-    
-    if !exec.submit_async(async move {
-        let stream = unsafe { &mut *(stream_ptr as *mut quinn::RecvStream) };
-        
-        match stream.read_to_end(size_limit).await {
-            Ok(data) => {
-                if data.is_empty() {
-                    (callback.0)(true, std::ptr::null_mut(), 0, std::ptr::null(), 0);
-                } else {
-                    let ptr = allocate(data.len());
-                    if !ptr.is_null() {
-                        unsafe {
-                            std::ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len());
-                        }
-                        (callback.0)(true, ptr, data.len(), std::ptr::null(), 0);
+    panic_guard::guard_with(callback, || {
+        check_executor_bytes!(executor, callback);
+        let stream = check_recv_stream_bytes!(handle, callback);
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            match with_timeout(timeout_ms, stream.read_to_end(size_limit)).await {
+                Ok(Ok(data)) => {
+                    if data.is_empty() {
+                        (callback.0)(true, std::ptr::null_mut(), 0, std::ptr::null(), 0);
                     } else {
-                        let err = b"Allocation failed";
-                        (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+                        let ptr = allocate(data.len());
+                        if !ptr.is_null() {
+                            unsafe {
+                                std::ptr::copy_nonoverlapping(data.as_ptr(), ptr, data.len());
+                            }
+                            (callback.0)(true, ptr, data.len(), std::ptr::null(), 0);
+                        } else {
+                            let err = b"Allocation failed";
+                            (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+                        }
                     }
                 }
+                Ok(Err(e)) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    stream.record_peer_error(&QuicError::from(e));
+                    (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
+                }
             }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, std::ptr::null_mut(), 0, err.as_ptr(), err.len());
-            }
+        }) {
+            callback.0(false, std::ptr::null_mut(), 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    }) {
-        callback.0(false, std::ptr::null_mut(), 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+    })
 }
 
 // ============================================
@@ -429,50 +694,57 @@ pub unsafe extern "C" fn dart_quic_recv_stream_read_to_end(
 /// - `handle`: Stream handle (must be of type Send)
 /// - `data`: Data to write
 /// - `data_len`: Data length
+/// - `timeout_ms`: Fail with a `[Timeout]` error if flow control blocks the write for this
+///   long (0 = no timeout)
 /// - `callback`: Called with (success, bytes_written, error_ptr, error_len)
 ///   - On success: callback(true, bytes_written, null, 0)
 ///   - On error: callback(false, 0, error_ptr, error_len)
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_send_stream_write(
     executor: *mut QuicExecutor,
-    handle: *mut QuicFfiStreamHandle,
+    handle: u64,
     data: *const u8,
     data_len: usize,
+    timeout_ms: u64,
     callback: crate::quic_executor::UsizeCallback,
 ) {
-    use crate::check_executor_usize;
-    
-    check_executor_usize!(executor, callback);
-    check_send_stream_usize!(handle, callback);
-    
-    if data.is_null() || data_len == 0 {
-        let err = b"Invalid data";
-        callback(false, 0, err.as_ptr(), err.len());
-        return;
-    }
-    
-    // Copy data for async use (caller's buffer may be freed)
-    let data_vec = unsafe { std::slice::from_raw_parts(data, data_len) }.to_vec();
-    
-    let stream_ptr = unsafe { (*handle).stream } as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-    
-    if !exec.submit_async(async move {
-        let stream = unsafe { &mut *(stream_ptr as *mut quinn::SendStream) };
-        
-        match stream.write(&data_vec).await {
-            Ok(n) => {
-                (callback.0)(true, n, std::ptr::null(), 0);
-            }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, 0, err.as_ptr(), err.len());
+    panic_guard::guard_with(callback, || {
+        use crate::check_executor_usize;
+
+        check_executor_usize!(executor, callback);
+        let stream = check_send_stream_usize!(handle, callback);
+
+        if data.is_null() || data_len == 0 {
+            let err = b"Invalid data";
+            callback(false, 0, err.as_ptr(), err.len());
+            return;
+        }
+
+        // Copy data for async use (caller's buffer may be freed)
+        let data_vec = unsafe { std::slice::from_raw_parts(data, data_len) }.to_vec();
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            match with_timeout(timeout_ms, stream.write(&data_vec)).await {
+                Ok(Ok(n)) => {
+                    (callback.0)(true, n, std::ptr::null(), 0);
+                }
+                Ok(Err(e)) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    stream.record_peer_error(&QuicError::from(e));
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, 0, err.as_ptr(), err.len());
+                }
             }
+        }) {
+            callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    }) {
-        callback.0(false, 0, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+    })
 }
 
 /// Write all bytes to the send stream
@@ -484,50 +756,57 @@ pub unsafe extern "C" fn dart_quic_send_stream_write(
 /// - `handle`: Stream handle (must be of type Send)
 /// - `data`: Data to write
 /// - `data_len`: Data length
+/// - `timeout_ms`: Fail with a `[Timeout]` error if not all data is written in time
+///   (0 = no timeout); a prefix of the data may already have been written
 /// - `callback`: Called with (success, error_ptr, error_len)
 ///   - On success: callback(true, null, 0)
 ///   - On error: callback(false, error_ptr, error_len)
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn dart_quic_send_stream_write_all(
     executor: *mut QuicExecutor,
-    handle: *mut QuicFfiStreamHandle,
+    handle: u64,
     data: *const u8,
     data_len: usize,
+    timeout_ms: u64,
     callback: crate::quic_executor::VoidCallback,
 ) {
-    use crate::check_executor_void;
-    
-    check_executor_void!(executor, callback);
-    check_send_stream_void!(handle, callback);
-    
-    if data.is_null() || data_len == 0 {
-        let err = b"Invalid data";
-        callback(false, err.as_ptr(), err.len());
-        return;
-    }
-    
-    // Copy data for async use
-    let data_vec = unsafe { std::slice::from_raw_parts(data, data_len) }.to_vec();
-    
-    let stream_ptr = unsafe { (*handle).stream } as usize;
-    let callback = SendableCallback(callback);
-    let exec = unsafe { &*executor };
-    
-    if !exec.submit_async(async move {
-        let stream = unsafe { &mut *(stream_ptr as *mut quinn::SendStream) };
-        
-        match stream.write_all(&data_vec).await {
-            Ok(()) => {
-                (callback.0)(true, std::ptr::null(), 0);
-            }
-            Err(e) => {
-                let err = crate::FfiErrBuf::new(format!("{}", e));
-                (callback.0)(false, err.as_ptr(), err.len());
+    panic_guard::guard_with(callback, || {
+        use crate::check_executor_void;
+
+        check_executor_void!(executor, callback);
+        let stream = check_send_stream_void!(handle, callback);
+
+        if data.is_null() || data_len == 0 {
+            let err = b"Invalid data";
+            callback(false, err.as_ptr(), err.len());
+            return;
+        }
+
+        // Copy data for async use
+        let data_vec = unsafe { std::slice::from_raw_parts(data, data_len) }.to_vec();
+
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            match with_timeout(timeout_ms, stream.write_all(&data_vec)).await {
+                Ok(Ok(())) => {
+                    (callback.0)(true, std::ptr::null(), 0);
+                }
+                Ok(Err(e)) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    stream.record_peer_error(&QuicError::from(e));
+                    (callback.0)(false, err.as_ptr(), err.len());
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, err.as_ptr(), err.len());
+                }
             }
+        }) {
+            callback.0(false, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
         }
-    }) {
-        callback.0(false, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
-    }
+    })
 }
 
 /// Notify the peer that no more data will be written to this stream (sync)
@@ -541,20 +820,243 @@ pub unsafe extern "C" fn dart_quic_send_stream_write_all(
 /// - 0 (Success) on success
 /// - Error code on failure
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn dart_quic_send_stream_finish(
-    handle: *mut QuicFfiStreamHandle,
+pub extern "C" fn dart_quic_send_stream_finish(
+    handle: u64,
 ) -> i32 {
-    check_send_stream_sync!(handle);
-    
-    let stream = unsafe { (*handle).stream } as *mut quinn::SendStream;
-    if stream.is_null() {
-        return crate::types::QuicResult::InvalidParameter as i32;
-    }
-    
-    match unsafe { (*stream).finish() } {
-        Ok(()) => crate::types::QuicResult::Success as i32,
-        Err(_) => crate::types::QuicResult::StreamClosed as i32,
-    }
+    panic_guard::guard(|| {
+        let stream = check_send_stream_sync!(handle);
+
+        match stream.finish() {
+            Ok(()) => crate::types::QuicResult::Success as i32,
+            Err(_) => crate::types::QuicResult::StreamClosed as i32,
+        }
+    })
+}
+
+/// Wait until the peer has received all data or stopped the stream
+///
+/// Typically called after `dart_quic_send_stream_finish` to confirm delivery.
+/// Writes may continue while waiting.
+///
+/// # Parameters
+/// - `executor`: QuicExecutor for async execution
+/// - `handle`: Stream handle (must be of type Send)
+/// - `callback`: Called with (success, stopped_by_peer, error_ptr, error_len)
+///   - All data acknowledged: callback(true, false, null, 0)
+///   - Peer sent STOP_SENDING: callback(true, true, null, 0); the stop code is recorded
+///     on the stream (see `dart_quic_stream_peer_error_code`)
+///   - On error (connection lost, 0-RTT rejected): callback(false, false, error_ptr, error_len)
+///
+/// # Safety
+/// `executor` must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_send_stream_stopped(
+    executor: *mut QuicExecutor,
+    handle: u64,
+    callback: crate::quic_executor::BoolCallback,
+) {
+    panic_guard::guard_with(callback, || {
+        use crate::check_executor_bool;
+
+        check_executor_bool!(executor, callback);
+        let stream = check_send_stream_bool!(handle, callback);
+
+        // `stopped()` does not borrow the stream, so concurrent writes are unaffected
+        let stopped = stream.stopped();
+        let callback = SendableCallback(callback);
+        let exec = unsafe { &*executor };
+
+        if !exec.submit_async(callback, async move {
+            match stopped.await {
+                Ok(None) => {
+                    (callback.0)(true, false, std::ptr::null(), 0);
+                }
+                Ok(Some(code)) => {
+                    let err = QuicError::with_app_error_code(
+                        crate::types::QuicResult::StreamStopped,
+                        code.into_inner(),
+                    );
+                    stream.record_peer_error(&err);
+                    (callback.0)(true, true, std::ptr::null(), 0);
+                }
+                Err(e) => {
+                    let err = crate::FfiErrBuf::new(format!("{}", e));
+                    (callback.0)(false, false, err.as_ptr(), err.len());
+                }
+            }
+        }) {
+            callback.0(false, false, ERR_NOT_RUNNING.as_bytes().as_ptr(), ERR_NOT_RUNNING.len());
+        }
+    })
+}
+
+/// Abruptly close the send stream with an application error code (sync)
+///
+/// Sends RESET_STREAM to the peer; data not yet acknowledged is discarded.
+/// Pending writes on this stream fail.
+///
+/// # Parameters
+/// - `handle`: Stream handle (must be of type Send)
+/// - `error_code`: Application error code (must be < 2^62)
+///
+/// # Returns
+/// - 0 (Success) on success
+/// - InvalidParameter if the handle is invalid or `error_code` is out of range
+/// - StreamClosed if the stream was already finished or reset
+#[unsafe(no_mangle)]
+pub extern "C" fn dart_quic_send_stream_reset(
+    handle: u64,
+    error_code: u64,
+) -> i32 {
+    panic_guard::guard(|| {
+        let stream = check_send_stream_sync!(handle);
+
+        let Ok(code) = quinn::VarInt::from_u64(error_code) else {
+            return crate::types::QuicResult::InvalidParameter as i32;
+        };
+
+        match stream.reset(code) {
+            Ok(()) => crate::types::QuicResult::Success as i32,
+            Err(_) => crate::types::QuicResult::StreamClosed as i32,
+        }
+    })
+}
+
+/// Ask the peer to stop sending on this stream with an application error code (sync)
+///
+/// Sends STOP_SENDING to the peer and discards data that arrives afterwards.
+/// Pending reads on this stream fail.
+///
+/// # Parameters
+/// - `handle`: Stream handle (must be of type Recv)
+/// - `error_code`: Application error code (must be < 2^62)
+///
+/// # Returns
+/// - 0 (Success) on success
+/// - InvalidParameter if the handle is invalid or `error_code` is out of range
+/// - StreamClosed if the stream was already stopped
+#[unsafe(no_mangle)]
+pub extern "C" fn dart_quic_recv_stream_stop(
+    handle: u64,
+    error_code: u64,
+) -> i32 {
+    panic_guard::guard(|| {
+        let stream = check_recv_stream_sync!(handle);
+
+        let Ok(code) = quinn::VarInt::from_u64(error_code) else {
+            return crate::types::QuicResult::InvalidParameter as i32;
+        };
+
+        match stream.stop(code) {
+            Ok(()) => crate::types::QuicResult::Success as i32,
+            Err(_) => crate::types::QuicResult::StreamClosed as i32,
+        }
+    })
+}
+
+/// Set the send priority of the stream (sync)
+///
+/// Streams with higher priority are sent before lower-priority streams on the same
+/// connection; streams of equal priority are interleaved round-robin. Default is 0.
+///
+/// # Parameters
+/// - `handle`: Stream handle (must be of type Send)
+/// - `priority`: New priority (higher is sent first, may be negative)
+///
+/// # Returns
+/// - 0 (Success) on success
+/// - InvalidParameter if the handle is invalid
+/// - StreamClosed if the stream was already finished or reset
+#[unsafe(no_mangle)]
+pub extern "C" fn dart_quic_send_stream_set_priority(
+    handle: u64,
+    priority: i32,
+) -> i32 {
+    panic_guard::guard(|| {
+        let stream = check_send_stream_sync!(handle);
+
+        match stream.set_priority(priority) {
+            Ok(()) => crate::types::QuicResult::Success as i32,
+            Err(_) => crate::types::QuicResult::StreamClosed as i32,
+        }
+    })
+}
+
+/// Get the send priority of the stream (sync)
+///
+/// # Parameters
+/// - `handle`: Stream handle (must be of type Send)
+/// - `out_priority`: Receives the current priority
+///
+/// # Returns
+/// - 0 (Success) on success
+/// - InvalidParameter if the handle or `out_priority` is invalid
+/// - StreamClosed if the stream was already finished or reset
+///
+/// # Safety
+/// `out_priority` must be a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_send_stream_priority(
+    handle: u64,
+    out_priority: *mut i32,
+) -> i32 {
+    panic_guard::guard(|| {
+        if out_priority.is_null() {
+            return crate::types::QuicResult::InvalidParameter as i32;
+        }
+        let stream = check_send_stream_sync!(handle);
+
+        match stream.priority() {
+            Ok(priority) => {
+                unsafe { *out_priority = priority };
+                crate::types::QuicResult::Success as i32
+            }
+            Err(_) => crate::types::QuicResult::StreamClosed as i32,
+        }
+    })
+}
+
+/// Get the application error code the peer used to reset or stop this stream (sync)
+///
+/// The code is recorded when a read fails with `StreamReset` (Recv handle) or a
+/// write fails with `StreamStopped` (Send handle).
+///
+/// # Parameters
+/// - `handle`: Stream handle (Send or Recv)
+/// - `out_code`: Receives the application error code
+///
+/// # Returns
+/// - 0 (Success) if a code was recorded
+/// - NoMoreData if the peer has not reset or stopped the stream (yet)
+/// - InvalidParameter if the handle is not a live stream or `out_code` is null
+///
+/// # Safety
+/// `out_code` must be a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn dart_quic_stream_peer_error_code(
+    handle: u64,
+    out_code: *mut u64,
+) -> i32 {
+    panic_guard::guard(|| {
+        if out_code.is_null() {
+            return crate::types::QuicResult::InvalidParameter as i32;
+        }
+
+        let code = match handle_registry::handle_type(handle) {
+            Some(QuicHandleType::SendStream) => handle_registry::get::<FfiSendStream>(handle).map(|s| s.peer_error_code()),
+            Some(QuicHandleType::RecvStream) => handle_registry::get::<FfiRecvStream>(handle).map(|s| s.peer_error_code()),
+            _ => None,
+        };
+        let Some(code) = code else {
+            return crate::types::QuicResult::InvalidParameter as i32;
+        };
+        let Some(code) = code else {
+            return crate::types::QuicResult::NoMoreData as i32;
+        };
+
+        unsafe { *out_code = code };
+        crate::types::QuicResult::Success as i32
+    })
 }
 
 // ============================================
@@ -563,3 +1065,51 @@ pub unsafe extern "C" fn dart_quic_send_stream_finish(
 // Note: Stream IDs are now directly accessible via QuicFfiStreamHandle.stream_id field
 // No separate functions needed
 
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+
+    use futures::executor::block_on;
+    use futures::FutureExt;
+
+    use super::*;
+
+    #[test]
+    fn pending_operation_releases_lock_between_polls() {
+        let stream = QuicFfiStream::new(0u32);
+        let mut operation = pin!(stream.poll_locked(|_, _| Poll::<()>::Pending));
+
+        assert!(operation.as_mut().now_or_never().is_none());
+        assert!(stream.stream.try_lock().is_some());
+    }
+
+    #[test]
+    fn interrupt_fails_pending_operations() {
+        let stream = QuicFfiStream::new(0u32);
+        let mut first = pin!(stream.poll_locked(|_, _| Poll::<()>::Pending));
+        let mut second = pin!(stream.poll_locked(|_, _| Poll::<()>::Pending));
+        assert!(first.as_mut().now_or_never().is_none());
+        assert!(second.as_mut().now_or_never().is_none());
+
+        assert_eq!(stream.interrupt(|value| std::mem::replace(value, 1)), 0);
+        assert_eq!(block_on(first), None);
+        assert_eq!(block_on(second), None);
+    }
+
+    #[test]
+    fn completed_operation_wins_over_interrupt() {
+        let stream = QuicFfiStream::new(7u32);
+        let operation = stream.poll_locked(|value, _| Poll::Ready(*value));
+
+        stream.interrupt(|_| ());
+        assert_eq!(block_on(operation), Some(7));
+    }
+
+    #[test]
+    fn operation_started_after_interrupt_runs() {
+        let stream = QuicFfiStream::new(7u32);
+        stream.interrupt(|value| *value = 8);
+
+        assert_eq!(block_on(stream.poll_locked(|value, _| Poll::Ready(*value))), Some(8));
+    }
+}
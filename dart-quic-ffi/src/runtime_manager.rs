@@ -3,7 +3,75 @@ unsafe impl Send for RuntimeManager {}
 unsafe impl Sync for RuntimeManager {}
 
 use tokio::runtime::{Builder, Runtime};
+use std::ffi::CStr;
+use std::os::raw::c_char;
 use std::sync::Arc;
+use std::time::Duration;
+
+/// Tokio runtime configuration (C-compatible)
+///
+/// Zero / null fields fall back to tokio's defaults, so a zero-initialized
+/// struct behaves like `dart_quic_executor_init` with `threads = 0`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct QuicFfiRuntimeConfig {
+    /// 0 = CPU cores, 1 = current-thread runtime, N = multi-thread with N workers
+    pub worker_threads: usize,
+    /// Maximum number of threads for blocking work (0 = default, 512)
+    pub max_blocking_threads: usize,
+    /// Thread name, null-terminated (null = "tokio-runtime-worker")
+    pub thread_name: *const c_char,
+    /// Thread stack size in bytes (0 = default)
+    pub thread_stack_size: usize,
+    /// Scheduler ticks between polls for I/O and timer events (0 = default, 61)
+    pub event_interval: u32,
+    /// How long idle blocking threads are kept alive, in ms (0 = default, 10s)
+    pub thread_keep_alive_ms: u64,
+}
+
+/// Tokio runtime configuration
+#[derive(Clone, Debug, Default)]
+pub struct RuntimeConfig {
+    /// 0 = CPU cores, 1 = current-thread runtime, N = multi-thread with N workers
+    pub worker_threads: usize,
+    pub max_blocking_threads: Option<usize>,
+    pub thread_name: Option<String>,
+    pub thread_stack_size: Option<usize>,
+    pub event_interval: Option<u32>,
+    pub thread_keep_alive: Option<Duration>,
+}
+
+impl RuntimeConfig {
+    /// Default configuration with the given worker thread count
+    pub fn with_threads(threads: usize) -> Self {
+        Self { worker_threads: threads, ..Self::default() }
+    }
+}
+
+impl TryFrom<&QuicFfiRuntimeConfig> for RuntimeConfig {
+    type Error = String;
+
+    fn try_from(ffi: &QuicFfiRuntimeConfig) -> Result<Self, Self::Error> {
+        let thread_name = if ffi.thread_name.is_null() {
+            None
+        } else {
+            let name = unsafe { CStr::from_ptr(ffi.thread_name) }
+                .to_str()
+                .map_err(|_| "Invalid thread name encoding".to_string())?;
+            Some(name.to_string())
+        };
+        let non_zero = |value: usize| (value > 0).then_some(value);
+
+        Ok(Self {
+            worker_threads: ffi.worker_threads,
+            max_blocking_threads: non_zero(ffi.max_blocking_threads),
+            thread_name,
+            thread_stack_size: non_zero(ffi.thread_stack_size),
+            event_interval: (ffi.event_interval > 0).then_some(ffi.event_interval),
+            thread_keep_alive: (ffi.thread_keep_alive_ms > 0).then(|| Duration::from_millis(ffi.thread_keep_alive_ms)),
+        })
+    }
+}
 
 /// Tokio Runtime Manager
 /// Each instance manages its own Tokio runtime (Arc for thread safety).
@@ -15,28 +83,38 @@ pub struct RuntimeManager {
 
 impl RuntimeManager {
     /// Create a new Tokio runtime manager
-    /// threads == 0: use default thread count (CPU cores)
-    /// threads == 1: current-thread runtime
-    /// threads > 1: multi-threaded runtime with specified thread count
-    pub fn new(threads: usize) -> Self {
-        let runtime = if threads == 0 {
-            Builder::new_multi_thread()
-                .enable_all()
-                .build()
-                .expect("Failed to create default multi-threaded Tokio runtime")
-        } else if threads == 1 {
-            Builder::new_current_thread()
-                .enable_all()
-                .build()
-                .expect("Failed to create current-thread Tokio runtime")
-        } else {
-            Builder::new_multi_thread()
-                .worker_threads(threads)
-                .enable_all()
-                .build()
-                .expect("Failed to create multi-threaded Tokio runtime")
+    /// worker_threads == 0: use default thread count (CPU cores)
+    /// worker_threads == 1: current-thread runtime
+    /// worker_threads > 1: multi-threaded runtime with specified thread count
+    pub fn new(config: &RuntimeConfig) -> std::io::Result<Self> {
+        let mut builder = match config.worker_threads {
+            0 => Builder::new_multi_thread(),
+            1 => Builder::new_current_thread(),
+            threads => {
+                let mut builder = Builder::new_multi_thread();
+                builder.worker_threads(threads);
+                builder
+            }
         };
-        Self { runtime: Arc::new(runtime) }
+        builder.enable_all();
+        if let Some(max) = config.max_blocking_threads {
+            builder.max_blocking_threads(max);
+        }
+        if let Some(name) = &config.thread_name {
+            builder.thread_name(name.clone());
+        }
+        if let Some(size) = config.thread_stack_size {
+            builder.thread_stack_size(size);
+        }
+        if let Some(interval) = config.event_interval {
+            builder.event_interval(interval);
+        }
+        if let Some(keep_alive) = config.thread_keep_alive {
+            builder.thread_keep_alive(keep_alive);
+        }
+
+        let runtime = builder.build()?;
+        Ok(Self { runtime: Arc::new(runtime) })
     }
 
     /// Get an Arc reference to the inner Tokio runtime (thread safe)
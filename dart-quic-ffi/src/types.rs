@@ -64,6 +64,8 @@ pub enum QuicResult {
     TlsError = 109,
     /// Certificate error
     CertificateError = 110,
+    /// Host name resolution failed
+    HostResolutionFailed = 111,
 
     // ===== Stream-related errors (200-299) =====
     /// Stream operation error
@@ -144,6 +146,7 @@ impl QuicResult {
             108 => Self::HandshakeFailed,
             109 => Self::TlsError,
             110 => Self::CertificateError,
+            111 => Self::HostResolutionFailed,
 
             200 => Self::StreamError,
             201 => Self::StreamClosed,
@@ -11,6 +11,36 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * `QuicFfiSocketAddr::family` value for IPv4
+ */
+#define QUIC_ADDR_FAMILY_IPV4 4
+
+/**
+ * `QuicFfiSocketAddr::family` value for IPv6
+ */
+#define QUIC_ADDR_FAMILY_IPV6 6
+
+/**
+ * Default number of servers remembered by a session cache
+ */
+#define DEFAULT_SESSION_CACHE_CAPACITY 256
+
+/**
+ * Log level (also used as the filter value for `dart_quic_log_set_level`)
+ */
+typedef enum QuicLogLevel {
+  /**
+   * Filter only: disable all records
+   */
+  Off = 0,
+  Error = 1,
+  Warn = 2,
+  Info = 3,
+  Debug = 4,
+  Trace = 5,
+} QuicLogLevel;
+
 /**
  * Endpoint operation mode (for C API)
  *
@@ -22,14 +52,17 @@
  *
  * ```c
  * // C API example
- * FfiEndpointConfig config = {
+ * QuicFfiEndpointConfig config = {
  *     .mode = QuicEndpointMode_ClientOnly,  // Client-only
- *     .bind_ip = 0,      // INADDR_ANY
- *     .bind_port = 0,    // System-assigned port
+ *     .bind_addr = { .family = QUIC_ADDR_FAMILY_IPV4, .port = 0 },  // 0.0.0.0, system-assigned port
  * };
  * ```
  */
-enum QuicEndpointMode {
+enum QuicEndpointMode
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
   /**
    * Client-only mode
    *
@@ -61,12 +94,55 @@ enum QuicEndpointMode {
    */
   Bidirectional = 2,
 };
+#if __STDC_VERSION__ >= 202311L
+typedef enum QuicEndpointMode QuicEndpointMode;
+#else
 typedef uint8_t QuicEndpointMode;
+#endif // __STDC_VERSION__ >= 202311L
+
+/**
+ * Trust mode
+ */
+enum QuicFfiTrustMode
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * Skip verification (testing only! dangerous!)
+   */
+  SkipVerification = 0,
+  /**
+   * Use system root certificates (recommended for production)
+   */
+  SystemRoots = 1,
+  /**
+   * Use custom CA (DER in memory)
+   */
+  CustomCaDer = 2,
+  /**
+   * Use custom CA (PEM file)
+   */
+  CustomCaPemFile = 3,
+  /**
+   * Use custom CA (DER file)
+   */
+  CustomCaDerFile = 4,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum QuicFfiTrustMode QuicFfiTrustMode;
+#else
+typedef uint8_t QuicFfiTrustMode;
+#endif // __STDC_VERSION__ >= 202311L
 
 /**
  * Client certificate mode (mTLS)
  */
-enum QuicFfiClientCertMode {
+enum QuicFfiClientCertMode
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
   /**
    * No client certificate
    */
@@ -84,124 +160,120 @@ enum QuicFfiClientCertMode {
    */
   DerFile = 3,
 };
+#if __STDC_VERSION__ >= 202311L
+typedef enum QuicFfiClientCertMode QuicFfiClientCertMode;
+#else
 typedef uint8_t QuicFfiClientCertMode;
+#endif // __STDC_VERSION__ >= 202311L
 
 /**
- * Trust mode
+ * TLS session cache mode
+ *
+ * Both modes keep sessions in memory only, so resumption and 0-RTT do not survive a
+ * process restart. A file-backed mode needs rustls to expose an encoding for TLS 1.3
+ * tickets (`Tls13ClientSessionValue` can only be built inside rustls 0.23).
  */
-enum QuicFfiTrustMode {
+enum QuicFfiSessionCacheMode
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
   /**
-   * Skip verification (testing only! dangerous!)
+   * rustls built-in in-memory cache
    */
-  SkipVerification = 0,
+  Default = 0,
   /**
-   * Use system root certificates (recommended for production)
+   * Bounded in-memory LRU cache (`session_cache_capacity` servers)
    */
-  SystemRoots = 1,
+  Memory = 1,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum QuicFfiSessionCacheMode QuicFfiSessionCacheMode;
+#else
+typedef uint8_t QuicFfiSessionCacheMode;
+#endif // __STDC_VERSION__ >= 202311L
+
+/**
+ * TLS key log mode
+ */
+enum QuicFfiKeyLogMode
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
   /**
-   * Use custom CA (DER in memory)
+   * No key logging
    */
-  CustomCaDer = 2,
+  Disabled = 0,
   /**
-   * Use custom CA (PEM file)
+   * Append to the file named by the `SSLKEYLOGFILE` environment variable
    */
-  CustomCaPemFile = 3,
+  Env = 1,
   /**
-   * Use custom CA (DER file)
+   * Append to `key_log_path`
    */
-  CustomCaDerFile = 4,
+  File = 2,
 };
-typedef uint8_t QuicFfiTrustMode;
-
-typedef struct MemoryStats MemoryStats;
-
-/**
- * QUIC Client
- *
- * A client-specific wrapper based on `QuicEndpoint`.
- * **A single client can maintain multiple connections to different servers simultaneously.**
- *
- * # Internal Structure
- * ```text
- * QuicClient
- *   └── inner: QuicEndpoint  // Low-level unified endpoint
- * ```
- *
- * # Multiple Connections Example
- * ```rust
- * let client = QuicClient::builder()
- *     .with_system_roots()
- *     .bind("0.0.0.0:0")?;
- *
- * // Connect to multiple servers from the same local port
- * let conn1 = client.connect("server1:443", "server1").await?;
- * let conn2 = client.connect("server2:443", "server2").await?;
- * let conn3 = client.connect("server3:443", "server3").await?;
- *
- * println!("All connections share local port: {}", client.local_port());
- * ```
- *
- * # Thread Safety
- * `QuicClient` is thread-safe and can be shared across multiple threads.
- */
-typedef struct QuicClient QuicClient;
+#if __STDC_VERSION__ >= 202311L
+typedef enum QuicFfiKeyLogMode QuicFfiKeyLogMode;
+#else
+typedef uint8_t QuicFfiKeyLogMode;
+#endif // __STDC_VERSION__ >= 202311L
 
 /**
- * QUIC Connection
- *
- * Wraps a single QUIC connection, providing stream operation APIs.
- * This type is used by both `QuicClient` and `QuicServer`.
- *
- * # Thread Safety
- * `QuicConnection` is thread-safe and can be safely used across multiple threads.
+ * How a connection was closed
  */
-typedef struct QuicConnection QuicConnection;
+enum QuicCloseKind
+#if __STDC_VERSION__ >= 202311L
+  : uint8_t
+#endif // __STDC_VERSION__ >= 202311L
+ {
+  /**
+   * Peer closed with a transport error code (CONNECTION_CLOSE 0x1c)
+   */
+  TransportClosed = 0,
+  /**
+   * Peer closed with an application error code (CONNECTION_CLOSE 0x1d)
+   */
+  ApplicationClosed = 1,
+  /**
+   * Closed locally via `close()`
+   */
+  LocallyClosed = 2,
+  /**
+   * Idle timeout expired
+   */
+  TimedOut = 3,
+  /**
+   * Peer sent a stateless reset
+   */
+  Reset = 4,
+  /**
+   * Peer does not support our QUIC version
+   */
+  VersionMismatch = 5,
+  /**
+   * Local transport error detected (protocol violation by the peer, TLS failure, ...)
+   */
+  TransportError = 6,
+  /**
+   * Ran out of connection IDs
+   */
+  CidsExhausted = 7,
+};
+#if __STDC_VERSION__ >= 202311L
+typedef enum QuicCloseKind QuicCloseKind;
+#else
+typedef uint8_t QuicCloseKind;
+#endif // __STDC_VERSION__ >= 202311L
 
-/**
- * QUIC Unified Endpoint
- *
- * Represents a QUIC endpoint bound to a local UDP port.
- * Depending on configuration, it can have client/server capabilities simultaneously or individually.
- *
- * # Capability Overview
- *
- * | Capability | Required Config | Corresponding Method |
- * |-----------|----------------|---------------------|
- * | Initiate connections | ClientConfig | `connect()` |
- * | Accept connections | ServerConfig | `accept()` |
- *
- * # Thread Safety
- * `QuicEndpoint` is thread-safe and can be safely shared across multiple threads.
- */
-typedef struct QuicEndpoint QuicEndpoint;
+typedef struct MemoryStats MemoryStats;
 
 /**
  * Simplified QUIC executor - only manages tokio runtime
  */
 typedef struct QuicExecutor QuicExecutor;
 
-/**
- * QUIC Server
- *
- * Server-specific wrapper based on `QuicEndpoint`.
- *
- * # Internal Structure
- * ```text
- * QuicServer
- *   └── inner: QuicEndpoint  // Low-level unified endpoint
- * ```
- *
- * # Core Concepts
- *
- * - One server can handle multiple client connections simultaneously
- * - Supports runtime certificate updates (doesn't affect existing connections)
- * - Supports mTLS mutual authentication
- *
- * # Thread Safety
- * `QuicServer` is thread-safe and can be safely shared across multiple threads.
- */
-typedef struct QuicServer QuicServer;
-
 /**
  * Generic FFI result structure for C API interop.
  * Used for sync operations that need to return both a handle and potential error.
@@ -213,24 +285,68 @@ typedef struct QuicFfiResult {
 } QuicFfiResult;
 
 /**
- * Callback for bool result
+ * Callback for bool result  
  */
 typedef void (*BoolCallback)(bool success, bool value, const uint8_t *error_ptr, uintptr_t error_len);
 
+/**
+ * Tokio runtime configuration (C-compatible)
+ *
+ * Zero / null fields fall back to tokio's defaults, so a zero-initialized
+ * struct behaves like `dart_quic_executor_init` with `threads = 0`.
+ */
+typedef struct QuicFfiRuntimeConfig {
+  /**
+   * 0 = CPU cores, 1 = current-thread runtime, N = multi-thread with N workers
+   */
+  uintptr_t worker_threads;
+  /**
+   * Maximum number of threads for blocking work (0 = default, 512)
+   */
+  uintptr_t max_blocking_threads;
+  /**
+   * Thread name, null-terminated (null = "tokio-runtime-worker")
+   */
+  const char *thread_name;
+  /**
+   * Thread stack size in bytes (0 = default)
+   */
+  uintptr_t thread_stack_size;
+  /**
+   * Scheduler ticks between polls for I/O and timer events (0 = default, 61)
+   */
+  uint32_t event_interval;
+  /**
+   * How long idle blocking threads are kept alive, in ms (0 = default, 10s)
+   */
+  uint64_t thread_keep_alive_ms;
+} QuicFfiRuntimeConfig;
+
 /**
  * FFI-friendly transport configuration
  *
+ * `struct_size` must be set to `sizeof(QuicFfiTransportConfig)` as seen by the caller.
+ * Fields are only ever appended, so a caller built against an older header passes a
+ * smaller size and the fields it doesn't know keep their defaults.
+ *
+ * Fields documented with "0 = default" use the `QuicTransportConfig` default when zero.
+ *
  * # C Language Usage Example
  *
  * ```c
+ * const char *alpn[] = { "h3" };
  * QuicFfiTransportConfig transport = {
+ *     .struct_size = sizeof(QuicFfiTransportConfig),
  *     .max_idle_timeout_ms = 30000,
  *     .keep_alive_interval_ms = 15000,
+ *     .alpn_protocols = alpn,
+ *     .alpn_protocols_count = 1,
  *     // ...
  * };
  * ```
  */
 typedef struct QuicFfiTransportConfig {
+  uint32_t struct_size;
   uint64_t max_idle_timeout_ms;
   uint64_t keep_alive_interval_ms;
   uint32_t max_concurrent_bi_streams;
@@ -246,19 +362,68 @@ typedef struct QuicFfiTransportConfig {
   uint8_t congestion_controller;
   bool allow_spin;
   bool enable_gso;
+  const char *qlog_dir;
+  uint64_t receive_window;
+  bool send_fairness;
+  uint32_t packet_threshold;
+  float time_threshold;
+  uint32_t persistent_congestion_threshold;
+  uint32_t crypto_buffer_size;
+  uint64_t mtu_discovery_interval_ms;
+  uint16_t mtu_discovery_upper_bound;
+  uint16_t mtu_discovery_minimum_change;
+  uint64_t mtu_black_hole_cooldown_ms;
+  bool enable_ack_frequency;
+  uint32_t ack_eliciting_threshold;
+  uint64_t ack_max_delay_ms;
+  uint32_t ack_reordering_threshold;
+  const char *const *alpn_protocols;
+  uint32_t alpn_protocols_count;
 } QuicFfiTransportConfig;
 
 /**
- * Unified stream handle with metadata
+ * Callback receiving a panic description ("panicked at <file>:<line>:<col>:\n<message>")
+ *
+ * Invoked on the panicking thread; the message is only valid during the call.
+ * Nullable: pass null to unregister.
+ */
+typedef void (*PanicCallback)(const uint8_t *message, uintptr_t message_len);
+
+/**
+ * Log record passed to `QuicLogCallback` (C-compatible)
  *
- * FFI-friendly structure wrapping a stream pointer, its ID, and type.
- * The stream pointer can be either `SendStream*` or `RecvStream*` depending on `stream_type`.
+ * Strings are UTF-8, not null-terminated, and only valid during the callback.
+ */
+typedef struct QuicFfiLogRecord {
+  enum QuicLogLevel level;
+  const uint8_t *target_ptr;
+  uintptr_t target_len;
+  const uint8_t *message_ptr;
+  uintptr_t message_len;
+  const uint8_t *fields_ptr;
+  uintptr_t fields_len;
+  const uint8_t *spans_ptr;
+  uintptr_t spans_len;
+} QuicFfiLogRecord;
+
+/**
+ * Callback receiving log records, invoked synchronously on the logging thread (nullable)
+ */
+typedef void (*QuicLogCallback)(const struct QuicFfiLogRecord *record);
+
+/**
+ * Stream handle with metadata
+ *
+ * FFI-friendly structure carrying a stream's registry handle, its ID, and type.
+ * The handle refers to a Send or Recv stream depending on `stream_type`; release it
+ * with `dart_quic_stream_free`. The structure itself is freed together with its
+ * `QuicFfiStreamPair`.
  */
 typedef struct QuicFfiStreamHandle {
   /**
-   * Stream pointer (cast to void* for FFI, actual type depends on stream_type)
+   * Stream registry handle (pass to all subsequent stream operations)
    */
-  void *stream;
+  uint64_t stream;
   /**
    * Stream ID
    */
@@ -306,22 +471,53 @@ typedef void (*UsizeCallback)(bool success,
  */
 typedef void (*VoidCallback)(bool success, const uint8_t *error_ptr, uintptr_t error_len);
 
+/**
+ * FFI socket address supporting IPv4 and IPv6 (for C API)
+ *
+ * `family` is `QUIC_ADDR_FAMILY_IPV4` or `QUIC_ADDR_FAMILY_IPV6`; anything else is rejected.
+ * `ip` holds the address octets in network byte order; IPv4 uses the first 4 bytes.
+ * `flowinfo` and `scope_id` are only meaningful for IPv6 (`scope_id` selects the
+ * interface for link-local addresses such as `fe80::1%eth0`).
+ */
+typedef struct QuicFfiSocketAddr {
+  /**
+   * Address family (`QUIC_ADDR_FAMILY_IPV4` / `QUIC_ADDR_FAMILY_IPV6`)
+   */
+  uint8_t family;
+  /**
+   * Address octets (network byte order)
+   */
+  uint8_t ip[16];
+  /**
+   * Port (host byte order, 0 means system-assigned when binding)
+   */
+  uint16_t port;
+  /**
+   * IPv6 flow information
+   */
+  uint32_t flowinfo;
+  /**
+   * IPv6 scope ID (interface index)
+   */
+  uint32_t scope_id;
+} QuicFfiSocketAddr;
+
 /**
  * FFI endpoint configuration (for C API)
  *
  * # Field Descriptions
  *
  * - `mode`: Endpoint operation mode, determines what operations the endpoint can perform
- * - `bind_ip`: Local IP address (network byte order), 0 means bind to all interfaces
- * - `bind_port`: Local port number (host byte order), 0 means system auto-assign
+ * - `bind_addr`: Local address (IPv4 or IPv6); the unspecified address binds all interfaces
+ * - `dual_stack`: For IPv6 `bind_addr`, clear IPV6_V6ONLY so the endpoint also serves IPv4
  *
  * # C Language Usage Example
  *
  * ```c
  * QuicFfiEndpointConfig config = {
- *     .mode = QuicEndpointMode_ClientOnly,
- *     .bind_ip = 0,      // INADDR_ANY
- *     .bind_port = 0,    // System-assigned port
+ *     .mode = QuicEndpointMode_Bidirectional,
+ *     .bind_addr = { .family = QUIC_ADDR_FAMILY_IPV6, .port = 4433 },  // [::]:4433
+ *     .dual_stack = true,  // Accept IPv4 peers too
  * };
  *
  * QuicEndpoint* endpoint = quic_endpoint_create(&config);
@@ -333,13 +529,13 @@ typedef struct QuicFfiEndpointConfig {
    */
   QuicEndpointMode mode;
   /**
-   * Local bind IP (network byte order, 0 means INADDR_ANY)
+   * Local bind address
    */
-  uint32_t bind_ip;
+  struct QuicFfiSocketAddr bind_addr;
   /**
-   * Local bind port (host byte order, 0 means system-assigned)
+   * Dual-stack IPv6 socket (IPV6_V6ONLY off); IPv6-only when false. Ignored for IPv4.
    */
-  uint16_t bind_port;
+  bool dual_stack;
 } QuicFfiEndpointConfig;
 
 /**
@@ -442,8 +638,79 @@ typedef struct QuicFfiClientConfig {
    * Local bind address (optional, NULL or empty string uses "0.0.0.0:0")
    */
   const char *bind_addr;
+  /**
+   * Enable 0-RTT early data on resumed sessions (used by `dart_quic_client_connect_0rtt`)
+   */
+  bool enable_early_data;
+  /**
+   * Session cache mode
+   */
+  QuicFfiSessionCacheMode session_cache_mode;
+  /**
+   * Maximum number of servers kept in the session cache (0 uses default 256)
+   */
+  uint32_t session_cache_capacity;
+  /**
+   * TLS key log mode (for decrypting packet captures; default Disabled)
+   */
+  QuicFfiKeyLogMode key_log_mode;
+  /**
+   * Key log file path (for File mode), UTF-8 encoded C string
+   */
+  const char *key_log_path;
 } QuicFfiClientConfig;
 
+/**
+ * FFI certificate for one server name (entry of `QuicFfiServerConfig::sni_certs`)
+ *
+ * # C Language Usage Example
+ *
+ * ```c
+ * QuicFfiSniCert sni[] = {
+ *     { .server_name = "api.example.com", .cert_mode = 0,
+ *       .cert_path_ptr = "api.crt", .key_path_ptr = "api.key" },
+ *     { .server_name = "*.example.org", .cert_mode = 0,
+ *       .cert_path_ptr = "org.crt", .key_path_ptr = "org.key" },
+ * };
+ * config.sni_certs = sni;
+ * config.sni_cert_count = 2;
+ * ```
+ */
+typedef struct QuicFfiSniCert {
+  /**
+   * Server name, optionally with a leading `*.` wildcard label
+   */
+  const char *server_name;
+  /**
+   * Certificate mode: 0 = file, 1 = memory
+   */
+  uint32_t cert_mode;
+  /**
+   * Certificate file path (used when cert_mode = 0)
+   */
+  const char *cert_path_ptr;
+  /**
+   * Private key file path (used when cert_mode = 0)
+   */
+  const char *key_path_ptr;
+  /**
+   * Certificate DER data (used when cert_mode = 1)
+   */
+  const uint8_t *cert_der_ptr;
+  /**
+   * Certificate DER data length (bytes)
+   */
+  uint32_t cert_der_len;
+  /**
+   * Private key DER data (used when cert_mode = 1)
+   */
+  const uint8_t *key_der_ptr;
+  /**
+   * Private key DER data length (bytes)
+   */
+  uint32_t key_der_len;
+} QuicFfiSniCert;
+
 /**
  * FFI server configuration (for C API)
  *
@@ -509,32 +776,153 @@ typedef struct QuicFfiServerConfig {
    * Transport configuration (optional, null uses default)
    */
   const struct QuicFfiTransportConfig *transport;
+  /**
+   * Accept 0-RTT early data from resuming clients (enables session tickets)
+   */
+  bool enable_0rtt;
+  /**
+   * TLS key log mode (for decrypting packet captures; default Disabled)
+   */
+  QuicFfiKeyLogMode key_log_mode;
+  /**
+   * Key log file path (used when key_log_mode = File)
+   */
+  const char *key_log_path;
+  /**
+   * Per-server-name certificates (optional; enables SNI mode, in which the certificate
+   * selected by `cert_mode` is the default for unknown names)
+   */
+  const struct QuicFfiSniCert *sni_certs;
+  /**
+   * SNI certificate count
+   */
+  uint32_t sni_cert_count;
+  /**
+   * In SNI mode, fail handshakes for unknown names instead of using the default certificate
+   */
+  bool sni_reject_unknown;
 } QuicFfiServerConfig;
 
+/**
+ * FFI endpoint handle
+ *
+ * Returned by `dart_quic_endpoint_create`; carries the registry handle of the
+ * new endpoint. Free the structure with `dart_quic_endpoint_handle_free` and the
+ * endpoint with `dart_quic_endpoint_free`.
+ */
+typedef struct QuicEndpointHandle {
+  /**
+   * Endpoint registry handle (pass to all subsequent endpoint operations)
+   */
+  uint64_t endpoint;
+  /**
+   * Local bind port
+   */
+  uint16_t local_port;
+} QuicEndpointHandle;
+
+/**
+ * Endpoint statistics snapshot (for C API)
+ *
+ * Filled by `dart_quic_endpoint_stats` (and the server/client variants)
+ * from `quinn::Endpoint::stats()`. Handshake counters are cumulative.
+ */
+typedef struct QuicFfiEndpointStats {
+  /**
+   * Incoming handshakes accepted
+   */
+  uint64_t accepted_handshakes;
+  /**
+   * Outgoing handshakes initiated
+   */
+  uint64_t outgoing_handshakes;
+  /**
+   * Incoming handshakes refused (e.g. server config removed or connection limit reached)
+   */
+  uint64_t refused_handshakes;
+  /**
+   * Incoming handshakes ignored (dropped without response)
+   */
+  uint64_t ignored_handshakes;
+  /**
+   * Currently open connections
+   */
+  uint64_t open_connections;
+} QuicFfiEndpointStats;
+
+/**
+ * FFI client handle
+ *
+ * Returned by `dart_quic_client_new_async`; carries the registry handle of the
+ * new client. Free the structure with `dart_quic_client_handle_free` and the
+ * client with `dart_quic_client_free`.
+ */
+typedef struct QuicClientHandle {
+  /**
+   * Client registry handle (pass to all subsequent client operations)
+   */
+  uint64_t client;
+  /**
+   * Local bind port
+   */
+  uint16_t local_port;
+} QuicClientHandle;
+
+/**
+ * TLS handshake results (for C API)
+ *
+ * Strings are owned by this struct and null when absent.
+ */
+typedef struct QuicFfiHandshakeData {
+  /**
+   * Negotiated ALPN protocol bytes
+   */
+  uint8_t *alpn;
+  /**
+   * ALPN protocol length
+   */
+  uint32_t alpn_len;
+  /**
+   * SNI server name (UTF-8, server side only)
+   */
+  uint8_t *server_name;
+  /**
+   * Server name length
+   */
+  uint32_t server_name_len;
+} QuicFfiHandshakeData;
+
 /**
  * Connection handle (for C API)
  *
- * FFI-friendly structure that wraps a QuicConnection pointer along with
- * commonly used connection information. This allows the Dart layer to
+ * FFI-friendly structure carrying the registry handle of a new connection along
+ * with commonly used connection information. This allows the Dart layer to
  * directly access connection info without additional FFI calls.
  *
  * # Memory Management
- * - `connection`: Owned pointer, must be freed via `dart_quic_connection_handle_free`
- * - `remote_addr`: Owned string pointer, freed together with handle
+ * - `connection`: Registry handle, released via `dart_quic_connection_free`
+ * - `remote_addr`, `handshake`: Owned strings, freed together with this structure
+ *   by `dart_quic_connection_handle_free`
+ *
+ * The structure and the connection have independent lifetimes: the structure
+ * can be freed as soon as its fields have been read.
  *
  * # C API Usage
  * ```c
  * QuicConnectionHandle* handle = ...;
  * printf("Connected to: %.*s\n", (int)handle->remote_addr_len, handle->remote_addr);
- * // Use handle->connection for stream operations
+ * printf("ALPN: %.*s\n", (int)handle->handshake.alpn_len, handle->handshake.alpn);
+ * uint64_t connection = handle->connection;
  * dart_quic_connection_handle_free(handle);
+ * // Use connection for stream operations, then
+ * dart_quic_connection_free(connection);
  * ```
  */
 typedef struct QuicConnectionHandle {
   /**
-   * Connection pointer (for subsequent operations)
+   * Connection registry handle (for subsequent operations)
    */
-  struct QuicConnection *connection;
+  uint64_t connection;
   /**
    * Connection stable ID (unique identifier)
    */
@@ -547,34 +935,207 @@ typedef struct QuicConnectionHandle {
    * Remote address string length
    */
   uint32_t remote_addr_len;
+  /**
+   * TLS handshake results at the time the handle was created
+   */
+  struct QuicFfiHandshakeData handshake;
 } QuicConnectionHandle;
 
+/**
+ * Connection close reason (for C API)
+ *
+ * Delivered by `dart_quic_connection_on_closed`; free with `dart_quic_close_reason_free`.
+ */
+typedef struct QuicFfiCloseReason {
+  /**
+   * Close kind
+   */
+  QuicCloseKind kind;
+  /**
+   * Whether the remote peer initiated the close
+   */
+  bool remote;
+  /**
+   * Lossy `QuicResult` code for the close
+   */
+  int32_t result_code;
+  /**
+   * Transport or application error code (see `QuicCloseReason::error_code`)
+   */
+  uint64_t error_code;
+  /**
+   * Reason bytes (null if empty, owned by this struct)
+   */
+  uint8_t *reason;
+  /**
+   * Reason length
+   */
+  uintptr_t reason_len;
+} QuicFfiCloseReason;
+
+/**
+ * Transmission path statistics (for C API)
+ */
+typedef struct QuicFfiPathStats {
+  /**
+   * Current RTT estimate (microseconds)
+   */
+  uint64_t rtt_us;
+  /**
+   * Minimum RTT observed, ignoring ack delay (microseconds)
+   */
+  uint64_t min_rtt_us;
+  /**
+   * Current congestion window (bytes)
+   */
+  uint64_t cwnd;
+  /**
+   * Number of congestion events
+   */
+  uint64_t congestion_events;
+  /**
+   * Number of packets lost
+   */
+  uint64_t lost_packets;
+  /**
+   * Number of bytes lost
+   */
+  uint64_t lost_bytes;
+  /**
+   * Number of packets sent
+   */
+  uint64_t sent_packets;
+  /**
+   * Number of PLPMTUD probes sent (also counted in `sent_packets`)
+   */
+  uint64_t sent_plpmtud_probes;
+  /**
+   * Number of PLPMTUD probes lost (not counted in `lost_packets`)
+   */
+  uint64_t lost_plpmtud_probes;
+  /**
+   * Number of black holes detected
+   */
+  uint64_t black_holes_detected;
+  /**
+   * Largest UDP payload size the path currently supports
+   */
+  uint16_t current_mtu;
+} QuicFfiPathStats;
+
+/**
+ * Frame counters per frame type (for C API)
+ */
+typedef struct QuicFfiFrameStats {
+  uint64_t acks;
+  uint64_t ack_frequency;
+  uint64_t crypto;
+  uint64_t connection_close;
+  uint64_t data_blocked;
+  uint64_t datagram;
+  uint64_t handshake_done;
+  uint64_t immediate_ack;
+  uint64_t max_data;
+  uint64_t max_stream_data;
+  uint64_t max_streams_bidi;
+  uint64_t max_streams_uni;
+  uint64_t new_connection_id;
+  uint64_t new_token;
+  uint64_t path_challenge;
+  uint64_t path_response;
+  uint64_t ping;
+  uint64_t reset_stream;
+  uint64_t retire_connection_id;
+  uint64_t stream_data_blocked;
+  uint64_t streams_blocked_bidi;
+  uint64_t streams_blocked_uni;
+  uint64_t stop_sending;
+  uint64_t stream;
+} QuicFfiFrameStats;
+
+/**
+ * UDP traffic statistics (for C API)
+ */
+typedef struct QuicFfiUdpStats {
+  /**
+   * Number of UDP datagrams
+   */
+  uint64_t datagrams;
+  /**
+   * Total bytes carried inside UDP datagrams
+   */
+  uint64_t bytes;
+  /**
+   * Number of I/O operations (may be less than `datagrams` with GSO/GRO)
+   */
+  uint64_t ios;
+} QuicFfiUdpStats;
+
+/**
+ * Connection statistics snapshot (for C API)
+ *
+ * Filled by `dart_quic_connection_stats` from `quinn::Connection::stats()`.
+ * The structure is plain data and can be allocated by the caller.
+ *
+ * # C API Usage
+ * ```c
+ * QuicFfiConnectionStats stats;
+ * if (dart_quic_connection_stats(handle, &stats) == 0) {
+ *     printf("rtt=%lluus lost=%llu\n", stats.path.rtt_us, stats.path.lost_packets);
+ * }
+ * ```
+ */
+typedef struct QuicFfiConnectionStats {
+  /**
+   * Path statistics (RTT, congestion window, loss)
+   */
+  struct QuicFfiPathStats path;
+  /**
+   * Frames transmitted
+   */
+  struct QuicFfiFrameStats frame_tx;
+  /**
+   * Frames received
+   */
+  struct QuicFfiFrameStats frame_rx;
+  /**
+   * UDP datagrams transmitted
+   */
+  struct QuicFfiUdpStats udp_tx;
+  /**
+   * UDP datagrams received
+   */
+  struct QuicFfiUdpStats udp_rx;
+} QuicFfiConnectionStats;
+
 /**
  * FFI server handle
  *
- * FFI-friendly structure that wraps a QuicServer pointer along with
- * commonly used server information. This allows the Dart layer to
+ * FFI-friendly structure carrying the registry handle of a new server along
+ * with commonly used server information. This allows the Dart layer to
  * directly access server info without additional FFI calls.
  *
  * # Memory Management
  * - Allocated and owned entirely by Rust via `Box::into_raw`
- * - `server`: owned QuicServer pointer
+ * - `server`: registry handle, released via `dart_quic_server_free`
  * - `local_addr_ptr`: owned string pointer, allocated via `crate::allocate`
- * - Free the entire handle with `dart_quic_server_handle_free`
+ * - Free the structure with `dart_quic_server_handle_free` (does not free the server)
  *
  * # C API Usage
  * ```c
  * QuicServerHandle* handle = ...;
  * printf("Listening on: %.*s\n", (int)handle->local_addr_len, handle->local_addr_ptr);
- * // Use handle->server for server operations
+ * uint64_t server = handle->server;
  * dart_quic_server_handle_free(handle);
+ * // Use server for server operations, then
+ * dart_quic_server_free(server);
  * ```
  */
 typedef struct QuicServerHandle {
   /**
-   * Server pointer (pass to all subsequent server operations)
+   * Server registry handle (pass to all subsequent server operations)
    */
-  struct QuicServer *server;
+  uint64_t server;
   /**
    * Local bind port
    */
@@ -589,6 +1150,11 @@ typedef struct QuicServerHandle {
   uint8_t *local_addr_ptr;
 } QuicServerHandle;
 
+/**
+ * `Dart_PostCObject` function pointer (pass `NativeApi.postCObject` from Dart)
+ */
+typedef bool (*DartPostCObjectFn)(int64_t port, void *message);
+
 /**
  * Free error message allocated by QuicFfiResult
  */
@@ -600,10 +1166,66 @@ void dart_quic_executor_init(struct QuicExecutor *executor,
                              uintptr_t threads,
                              BoolCallback callback);
 
+/**
+ * Initialize the executor with a runtime configuration (async)
+ *
+ * Like `dart_quic_executor_init`, but exposes the tokio runtime builder options.
+ * A null `config` uses the defaults. Invalid options or runtime build failures
+ * are reported through `callback`.
+ *
+ * # Safety
+ * `executor` must be null or a valid executor; `config` must be null or valid
+ * for the duration of the call (it is copied before returning).
+ */
+void dart_quic_executor_init_with_config(struct QuicExecutor *executor,
+                                         const struct QuicFfiRuntimeConfig *config,
+                                         BoolCallback callback);
+
 bool dart_quic_executor_is_running(struct QuicExecutor *executor);
 
 void dart_quic_executor_free(struct QuicExecutor *executor);
 
+/**
+ * Gracefully shut down the executor (async)
+ *
+ * Stops accepting new operations and closes every client / server created on the
+ * executor (pending accepts complete as closed), then waits up to `grace_period_ms`
+ * for in-flight operations to finish. Operations still running afterwards are aborted
+ * without invoking their callbacks, and the tokio runtime is destroyed.
+ *
+ * `callback` fires once the runtime is gone, with `value` = true if all operations
+ * finished within the grace period, false if some were aborted. It fails with
+ * "Executor not running" if the executor is not initialized or already shutting down.
+ *
+ * After the callback fires the executor can be initialized again with
+ * `dart_quic_executor_init`.
+ *
+ * # Safety
+ * `executor` must be null or a valid executor.
+ */
+void dart_quic_executor_shutdown(struct QuicExecutor *executor,
+                                 uint64_t grace_period_ms,
+                                 BoolCallback callback);
+
+/**
+ * Cancel an in-flight async operation (sync)
+ *
+ * Applies to functions that return an operation ID (`dart_quic_recv_stream_read`,
+ * `dart_quic_connection_accept_bi`, `dart_quic_server_accept`). The operation's
+ * callback then fails with an error starting with `[Cancelled]` (QuicResult::Cancelled),
+ * fired after the operation has stopped using its stream / connection.
+ *
+ * # Returns
+ * - 0 (Success) if the operation was cancelled
+ * - InvalidOperation if the ID is unknown or the operation already completed
+ *   (its callback fires with the real result)
+ * - InvalidParameter if executor is null
+ *
+ * # Safety
+ * `executor` must be null or a valid executor.
+ */
+int32_t dart_quic_operation_cancel(struct QuicExecutor *executor, uint64_t operation_id);
+
 uint8_t *dart_allocate_memory(uintptr_t size);
 
 void dart_free_memory(uint8_t *ptr, uintptr_t size);
@@ -629,21 +1251,82 @@ int32_t dart_quic_transport_config_default(struct QuicFfiResult *result);
 
 void dart_quic_transport_config_free(struct QuicFfiTransportConfig *config);
 
+/**
+ * Register a callback for every panic inside the library (sync)
+ *
+ * Pass null to unregister; panics are then printed to stderr. Caught panics are
+ * still converted into `InternalError` results either way.
+ */
+void dart_quic_set_panic_callback(PanicCallback callback);
+
+/**
+ * Forward log records to `callback` (sync)
+ *
+ * Intended for native consumers: the callback is invoked synchronously on whichever
+ * thread emitted the record, and the record strings are only valid until it returns.
+ * Dart code should use `dart_quic_log_set_port` instead, since a `NativeCallable.listener`
+ * runs after the callback has returned and would read freed strings.
+ *
+ * Replaces any previously registered callback or port; pass null to stop forwarding.
+ * Records below the level set with
+ * `dart_quic_log_set_level` (default Info) are dropped.
+ *
+ * Returns RuntimeError if the log subscriber could not be installed.
+ */
+int32_t dart_quic_log_set_callback(QuicLogCallback callback);
+
+/**
+ * Forward log records to a Dart native port (sync)
+ *
+ * This is the supported way to receive logs in Dart; records are copied into the message.
+ * Replaces any previously registered callback or port; pass 0 to stop forwarding.
+ * Requires `dart_quic_port_init`. See the module docs for the message format.
+ *
+ * Returns RuntimeError if the log subscriber could not be installed.
+ */
+int32_t dart_quic_log_set_port(int64_t port);
+
+/**
+ * Set the maximum level of forwarded records (sync)
+ *
+ * `level` is a `QuicLogLevel` value (0 = Off ... 5 = Trace). Takes effect immediately,
+ * including for quinn and rustls.
+ *
+ * Returns InvalidParameter for an unknown level, RuntimeError if the log subscriber
+ * could not be installed.
+ */
+int32_t dart_quic_log_set_level(int32_t level);
+
 /**
  * Free stream pair structure
+ *
+ * Frees the pair and its `QuicFfiStreamHandle` structures. The streams stay open
+ * until released with `dart_quic_stream_free`.
  */
 void dart_quic_stream_pair_free(struct QuicFfiStreamPair *pair);
 
 /**
- * Free stream handle (works for both send and recv streams)
+ * Free a stream handle structure (does not release the stream)
  */
 void dart_quic_stream_handle_free(struct QuicFfiStreamHandle *handle);
 
 /**
- * Read data contiguously from the stream
+ * Release a stream (works for both send and recv streams)
  *
- * Returns the number of bytes read via callback, or None (ptr=null, len=0) if stream is finished.
+ * Dropping a send stream finishes it; dropping a recv stream stops it with code 0.
+ * In-flight operations keep the stream alive until they complete.
  *
+ * # Returns
+ * - 0 (Success) on success
+ * - InvalidParameter if the handle is not a live stream (e.g. already freed)
+ */
+int32_t dart_quic_stream_free(uint64_t stream);
+
+/**
+ * Read data contiguously from the stream
+ * 
+ * Returns the number of bytes read via callback, or None (ptr=null, len=0) if stream is finished.
+ * 
  * **Memory Management Note:**
  * This function allocates `max_len` bytes upfront to avoid an extra memory copy.
  * The actual bytes read (n) may be less than `max_len`, meaning some allocated memory
@@ -651,117 +1334,249 @@ void dart_quic_stream_handle_free(struct QuicFfiStreamHandle *handle);
  * - Use reasonable `max_len` values (e.g., 4KB-64KB, not 1MB+)
  * - Only access the first `n` bytes returned in the callback
  * - Call `dart_free_memory(ptr, max_len)` to deallocate when done
- *
+ * 
  * This design prioritizes zero-copy performance over memory efficiency.
- *
+ * 
  * # Parameters
  * - `executor`: QuicExecutor for async execution
  * - `handle`: Stream handle (must be of type Recv)
  * - `max_len`: Maximum bytes to read (will allocate this much memory)
+ * - `timeout_ms`: Fail with a `[Timeout]` error if no data arrives in time (0 = no timeout)
  * - `callback`: Called with (success, data_ptr, data_len, error_ptr, error_len)
  *   - On success: callback(true, buf, bytes_read, null, 0) where bytes_read <= max_len
  *   - On EOF: callback(true, null, 0, null, 0)
  *   - On error: callback(false, null, 0, error_ptr, error_len)
+ *   - On cancel: callback(false, null, 0, "[Cancelled] ...", len)
+ *
+ * # Returns
+ * Operation ID for `dart_quic_operation_cancel`, or 0 if the read was not started
+ * (the callback has then already been called with the error).
+ *
+ * # Safety
+ * `executor` must be valid.
  */
-void dart_quic_recv_stream_read(struct QuicExecutor *executor,
-                                struct QuicFfiStreamHandle *handle,
-                                uintptr_t max_len,
-                                BytesCallback callback);
+uint64_t dart_quic_recv_stream_read(struct QuicExecutor *executor,
+                                    uint64_t handle,
+                                    uintptr_t max_len,
+                                    uint64_t timeout_ms,
+                                    BytesCallback callback);
 
 /**
  * Read exact number of bytes from the stream
- *
+ * 
  * Reads exactly `exact_len` bytes or fails.
- *
+ * 
  * **Memory Management Note:**
  * This function allocates exactly `exact_len` bytes since we know the exact size needed.
  * No memory waste occurs. Caller must free exactly `exact_len` bytes.
- *
+ * 
  * # Parameters
  * - `executor`: QuicExecutor for async execution
  * - `handle`: Stream handle (must be of type Recv)
  * - `exact_len`: Exact number of bytes to read
+ * - `timeout_ms`: Fail with a `[Timeout]` error if all bytes do not arrive in time
+ *   (0 = no timeout); bytes consumed before the timeout are lost
  * - `callback`: Called with (success, data_ptr, data_len, error_ptr, error_len)
  *   - On success: callback(true, buf, exact_len, null, 0)
  *   - On error: callback(false, null, 0, error_ptr, error_len)
  */
 void dart_quic_recv_stream_read_exact(struct QuicExecutor *executor,
-                                      struct QuicFfiStreamHandle *handle,
+                                      uint64_t handle,
                                       uintptr_t exact_len,
+                                      uint64_t timeout_ms,
                                       BytesCallback callback);
 
 /**
  * Read all remaining data from the stream
- *
+ * 
  * Reads until EOF, up to `size_limit` bytes.
- *
+ * 
  * # Parameters
  * - `executor`: QuicExecutor for async execution
  * - `handle`: Stream handle (must be of type Recv)
  * - `size_limit`: Maximum bytes to read (prevents memory exhaustion)
+ * - `timeout_ms`: Fail with a `[Timeout]` error if the stream does not finish in time
+ *   (0 = no timeout); data consumed before the timeout is lost
  * - `callback`: Called with (success, data_ptr, data_len, error_ptr, error_len)
  *   - On success: callback(true, buf, total_bytes, null, 0)
  *   - On error: callback(false, null, 0, error_ptr, error_len)
  */
 void dart_quic_recv_stream_read_to_end(struct QuicExecutor *executor,
-                                       struct QuicFfiStreamHandle *handle,
+                                       uint64_t handle,
                                        uintptr_t size_limit,
+                                       uint64_t timeout_ms,
                                        BytesCallback callback);
 
 /**
  * Write bytes to the send stream
- *
+ * 
  * Returns the number of bytes written. May write less than the full buffer due to
  * congestion and flow control.
- *
+ * 
  * # Parameters
  * - `executor`: QuicExecutor for async execution
  * - `handle`: Stream handle (must be of type Send)
  * - `data`: Data to write
  * - `data_len`: Data length
+ * - `timeout_ms`: Fail with a `[Timeout]` error if flow control blocks the write for this
+ *   long (0 = no timeout)
  * - `callback`: Called with (success, bytes_written, error_ptr, error_len)
  *   - On success: callback(true, bytes_written, null, 0)
  *   - On error: callback(false, 0, error_ptr, error_len)
  */
 void dart_quic_send_stream_write(struct QuicExecutor *executor,
-                                 struct QuicFfiStreamHandle *handle,
+                                 uint64_t handle,
                                  const uint8_t *data,
                                  uintptr_t data_len,
+                                 uint64_t timeout_ms,
                                  UsizeCallback callback);
 
 /**
  * Write all bytes to the send stream
- *
+ * 
  * Writes the entire buffer, looping internally if needed due to flow control.
- *
+ * 
  * # Parameters
  * - `executor`: QuicExecutor for async execution
  * - `handle`: Stream handle (must be of type Send)
  * - `data`: Data to write
  * - `data_len`: Data length
+ * - `timeout_ms`: Fail with a `[Timeout]` error if not all data is written in time
+ *   (0 = no timeout); a prefix of the data may already have been written
  * - `callback`: Called with (success, error_ptr, error_len)
  *   - On success: callback(true, null, 0)
  *   - On error: callback(false, error_ptr, error_len)
  */
 void dart_quic_send_stream_write_all(struct QuicExecutor *executor,
-                                     struct QuicFfiStreamHandle *handle,
+                                     uint64_t handle,
                                      const uint8_t *data,
                                      uintptr_t data_len,
+                                     uint64_t timeout_ms,
                                      VoidCallback callback);
 
 /**
  * Notify the peer that no more data will be written to this stream (sync)
- *
+ * 
  * It is an error to write to a stream after finishing it.
+ * 
+ * # Parameters
+ * - `handle`: Stream handle (must be of type Send)
+ * 
+ * # Returns
+ * - 0 (Success) on success
+ * - Error code on failure
+ */
+int32_t dart_quic_send_stream_finish(uint64_t handle);
+
+/**
+ * Wait until the peer has received all data or stopped the stream
+ *
+ * Typically called after `dart_quic_send_stream_finish` to confirm delivery.
+ * Writes may continue while waiting.
+ *
+ * # Parameters
+ * - `executor`: QuicExecutor for async execution
+ * - `handle`: Stream handle (must be of type Send)
+ * - `callback`: Called with (success, stopped_by_peer, error_ptr, error_len)
+ *   - All data acknowledged: callback(true, false, null, 0)
+ *   - Peer sent STOP_SENDING: callback(true, true, null, 0); the stop code is recorded
+ *     on the stream (see `dart_quic_stream_peer_error_code`)
+ *   - On error (connection lost, 0-RTT rejected): callback(false, false, error_ptr, error_len)
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_send_stream_stopped(struct QuicExecutor *executor,
+                                   uint64_t handle,
+                                   BoolCallback callback);
+
+/**
+ * Abruptly close the send stream with an application error code (sync)
+ *
+ * Sends RESET_STREAM to the peer; data not yet acknowledged is discarded.
+ * Pending writes on this stream fail.
  *
  * # Parameters
  * - `handle`: Stream handle (must be of type Send)
+ * - `error_code`: Application error code (must be < 2^62)
  *
  * # Returns
  * - 0 (Success) on success
- * - Error code on failure
+ * - InvalidParameter if the handle is invalid or `error_code` is out of range
+ * - StreamClosed if the stream was already finished or reset
+ */
+int32_t dart_quic_send_stream_reset(uint64_t handle, uint64_t error_code);
+
+/**
+ * Ask the peer to stop sending on this stream with an application error code (sync)
+ *
+ * Sends STOP_SENDING to the peer and discards data that arrives afterwards.
+ * Pending reads on this stream fail.
+ *
+ * # Parameters
+ * - `handle`: Stream handle (must be of type Recv)
+ * - `error_code`: Application error code (must be < 2^62)
+ *
+ * # Returns
+ * - 0 (Success) on success
+ * - InvalidParameter if the handle is invalid or `error_code` is out of range
+ * - StreamClosed if the stream was already stopped
+ */
+int32_t dart_quic_recv_stream_stop(uint64_t handle, uint64_t error_code);
+
+/**
+ * Set the send priority of the stream (sync)
+ *
+ * Streams with higher priority are sent before lower-priority streams on the same
+ * connection; streams of equal priority are interleaved round-robin. Default is 0.
+ *
+ * # Parameters
+ * - `handle`: Stream handle (must be of type Send)
+ * - `priority`: New priority (higher is sent first, may be negative)
+ *
+ * # Returns
+ * - 0 (Success) on success
+ * - InvalidParameter if the handle is invalid
+ * - StreamClosed if the stream was already finished or reset
+ */
+int32_t dart_quic_send_stream_set_priority(uint64_t handle, int32_t priority);
+
+/**
+ * Get the send priority of the stream (sync)
+ *
+ * # Parameters
+ * - `handle`: Stream handle (must be of type Send)
+ * - `out_priority`: Receives the current priority
+ *
+ * # Returns
+ * - 0 (Success) on success
+ * - InvalidParameter if the handle or `out_priority` is invalid
+ * - StreamClosed if the stream was already finished or reset
+ *
+ * # Safety
+ * `out_priority` must be a valid pointer.
+ */
+int32_t dart_quic_send_stream_priority(uint64_t handle, int32_t *out_priority);
+
+/**
+ * Get the application error code the peer used to reset or stop this stream (sync)
+ *
+ * The code is recorded when a read fails with `StreamReset` (Recv handle) or a
+ * write fails with `StreamStopped` (Send handle).
+ *
+ * # Parameters
+ * - `handle`: Stream handle (Send or Recv)
+ * - `out_code`: Receives the application error code
+ *
+ * # Returns
+ * - 0 (Success) if a code was recorded
+ * - NoMoreData if the peer has not reset or stopped the stream (yet)
+ * - InvalidParameter if the handle is not a live stream or `out_code` is null
+ *
+ * # Safety
+ * `out_code` must be a valid pointer.
  */
-int32_t dart_quic_send_stream_finish(struct QuicFfiStreamHandle *handle);
+int32_t dart_quic_stream_peer_error_code(uint64_t handle, uint64_t *out_code);
 
 /**
  * Create a QUIC endpoint with specified configuration
@@ -770,10 +1585,11 @@ int32_t dart_quic_send_stream_finish(struct QuicFfiStreamHandle *handle);
  * - `config`: Endpoint configuration (mode, bind IP, bind port)
  * - `client_config`: Client config handle (nullable based on mode)
  * - `server_config`: Server config handle (nullable based on mode)
- * - `result`: Output parameter for endpoint pointer or error
+ * - `result`: Output parameter for endpoint handle or error
  *
  * # Returns
- * - 0 on success (endpoint pointer in result)
+ * - 0 on success (`QuicEndpointHandle*` in result; free it with `dart_quic_endpoint_handle_free`
+ *   and the endpoint with `dart_quic_endpoint_free`)
  * - Non-zero error code on failure
  *
  * # Mode Requirements
@@ -793,7 +1609,7 @@ int32_t dart_quic_send_stream_finish(struct QuicFfiStreamHandle *handle);
  * - `result`: Result output structure
  *
  * # Returns
- * - 0 on success (result.data contains endpoint pointer)
+ * - 0 on success (result.data contains a `QuicEndpointHandle*`)
  * - Error code on failure (result.error contains error details)
  *
  * # Mode Requirements
@@ -811,45 +1627,76 @@ int32_t dart_quic_endpoint_create(const struct QuicFfiEndpointConfig *config,
                                   const struct QuicFfiServerConfig *server_config,
                                   struct QuicFfiResult *result);
 
+/**
+ * Free an endpoint handle structure (does not free the endpoint)
+ *
+ * # Safety
+ * - handle must be null or a pointer returned by dart_quic_endpoint_create, freed only once
+ */
+void dart_quic_endpoint_handle_free(struct QuicEndpointHandle *handle);
+
 /**
  * Free an endpoint and close all connections
  *
  * # Parameters
- * - `endpoint`: Endpoint pointer to free
+ * - `endpoint`: Endpoint handle to free
  *
- * # Safety
- * - endpoint must be a valid pointer created by dart_quic_endpoint_create
- * - Must not be used after this call
+ * # Returns
+ * - 0 on success
+ * - InvalidParameter if the handle is not a live endpoint (e.g. already freed)
  */
-void dart_quic_endpoint_free(struct QuicEndpoint *endpoint);
+int32_t dart_quic_endpoint_free(uint64_t endpoint);
 
 /**
  * Connect to a remote server (async)
  *
  * # Parameters
  * - `executor`: Executor pointer for async operations
- * - `endpoint`: Endpoint pointer
+ * - `endpoint`: Endpoint handle
  * - `server_addr`: Server address string (e.g., "127.0.0.1:4433")
  * - `server_name`: Server name for SNI (e.g., "localhost")
+ * - `timeout_ms`: Abandon resolution + handshake after this long with a `[Timeout]` error
+ *   (0 = no timeout)
  * - `callback`: Callback invoked with connection pointer (or 0 on error)
  *
  * # Safety
- * - All pointers must be valid
+ * - executor must be valid
  * - Endpoint must have client capability (ClientOnly or Bidirectional mode)
  * - server_addr and server_name must be valid null-terminated C strings
  */
 void dart_quic_endpoint_connect(struct QuicExecutor *executor,
-                                struct QuicEndpoint *endpoint,
+                                uint64_t endpoint,
                                 const int8_t *server_addr,
                                 const int8_t *server_name,
+                                uint64_t timeout_ms,
                                 UsizeCallback callback);
 
+/**
+ * Connect to a remote server with 0-RTT early data (async)
+ *
+ * Same semantics as `dart_quic_client_connect_0rtt`: `callback` receives the
+ * `QuicConnectionHandle*` (immediately when early data is possible), and
+ * `zero_rtt_callback` receives the `QuicZeroRttStatus` once the handshake completes.
+ *
+ * # Safety
+ * - executor must be valid
+ * - Endpoint must have client capability (ClientOnly or Bidirectional mode)
+ * - server_addr and server_name must be valid null-terminated C strings
+ */
+void dart_quic_endpoint_connect_0rtt(struct QuicExecutor *executor,
+                                     uint64_t endpoint,
+                                     const int8_t *server_addr,
+                                     const int8_t *server_name,
+                                     UsizeCallback callback,
+                                     UsizeCallback zero_rtt_callback);
+
 /**
  * Accept an incoming connection (async)
  *
  * # Parameters
  * - `executor`: Executor pointer for async operations
- * - `endpoint`: Endpoint pointer
+ * - `endpoint`: Endpoint handle
+ * - `timeout_ms`: Fail with a `[Timeout]` error if no connection arrives in time (0 = no timeout)
  * - `callback`: Callback invoked with connection pointer (or 0 on error/close)
  *
  * # Returns
@@ -857,20 +1704,67 @@ void dart_quic_endpoint_connect(struct QuicExecutor *executor,
  * - 0 if endpoint is closing or on error
  *
  * # Safety
- * - All pointers must be valid
+ * - executor must be valid
  * - Endpoint must have server capability (ServerOnly or Bidirectional mode)
  */
 void dart_quic_endpoint_accept(struct QuicExecutor *executor,
-                               struct QuicEndpoint *endpoint,
+                               uint64_t endpoint,
+                               uint64_t timeout_ms,
                                UsizeCallback callback);
 
 /**
- * Get the local bound address of the endpoint
+ * Accept an incoming connection with 0.5-RTT (async)
+ *
+ * Same semantics as `dart_quic_server_accept_0rtt`.
+ *
+ * # Safety
+ * - executor must be valid
+ * - Endpoint must have server capability (ServerOnly or Bidirectional mode)
+ */
+void dart_quic_endpoint_accept_0rtt(struct QuicExecutor *executor,
+                                    uint64_t endpoint,
+                                    UsizeCallback callback,
+                                    UsizeCallback zero_rtt_callback);
+
+/**
+ * Replace the endpoint's server configuration (sync, hot certificate reload)
+ *
+ * See `dart_quic_server_set_config`.
+ *
+ * # Returns
+ * - 0 on success
+ * - InvalidParameter if the handle is stale or the endpoint has no server capability
+ * - Error code on failure (`result` carries the message; the current configuration stays)
+ *
+ * # Safety
+ * `config` and `result` must be valid pointers.
+ */
+int32_t dart_quic_endpoint_set_server_config(uint64_t endpoint,
+                                             const struct QuicFfiServerConfig *config,
+                                             struct QuicFfiResult *result);
+
+/**
+ * Reload the endpoint's server certificate whenever its PEM files change (async)
+ *
+ * See `dart_quic_server_watch_certs`. The watch ends with success once the endpoint
+ * handle is released.
+ *
+ * # Safety
+ * - executor must be valid
+ * - Endpoint must have server capability (ServerOnly or Bidirectional mode)
+ */
+uint64_t dart_quic_endpoint_watch_certs(struct QuicExecutor *executor,
+                                        uint64_t endpoint,
+                                        const struct QuicFfiServerConfig *config,
+                                        uint64_t interval_ms,
+                                        VoidCallback callback);
+
+/**
+ * Get the local bound address of the endpoint (IPv4 or IPv6)
  *
  * # Parameters
- * - `endpoint`: Endpoint pointer
- * - `out_ip`: Output buffer for IP address (network byte order)
- * - `out_port`: Output buffer for port (host byte order)
+ * - `endpoint`: Endpoint handle
+ * - `out_addr`: Receives the local address
  *
  * # Returns
  * - 0 on success
@@ -878,107 +1772,153 @@ void dart_quic_endpoint_accept(struct QuicExecutor *executor,
  *
  * # Safety
  * - endpoint must be a valid pointer
- * - out_ip and out_port must be valid pointers
+ * - out_addr must be a valid pointer
  */
-int32_t dart_quic_endpoint_local_addr(struct QuicEndpoint *endpoint,
-                                      uint32_t *out_ip,
-                                      uint16_t *out_port);
+int32_t dart_quic_endpoint_local_addr(uint64_t endpoint, struct QuicFfiSocketAddr *out_addr);
 
 /**
  * Get the number of currently open connections
  *
  * # Parameters
- * - `endpoint`: Endpoint pointer
+ * - `endpoint`: Endpoint handle
  *
  * # Returns
  * - Number of open connections
- * - 0 if endpoint is null
+ * - 0 if the endpoint handle is invalid
  */
-uintptr_t dart_quic_endpoint_open_connections(struct QuicEndpoint *endpoint);
+uintptr_t dart_quic_endpoint_open_connections(uint64_t endpoint);
 
 /**
  * Check if endpoint has client capability
  *
  * # Parameters
- * - `endpoint`: Endpoint pointer
+ * - `endpoint`: Endpoint handle
  *
  * # Returns
  * - 1 if endpoint can connect to remote servers
  * - 0 otherwise
  */
-int32_t dart_quic_endpoint_can_connect(struct QuicEndpoint *endpoint);
+int32_t dart_quic_endpoint_can_connect(uint64_t endpoint);
 
 /**
  * Check if endpoint has server capability
  *
  * # Parameters
- * - `endpoint`: Endpoint pointer
+ * - `endpoint`: Endpoint handle
  *
  * # Returns
  * - 1 if endpoint can accept incoming connections
  * - 0 otherwise
  */
-int32_t dart_quic_endpoint_can_accept(struct QuicEndpoint *endpoint);
+int32_t dart_quic_endpoint_can_accept(uint64_t endpoint);
 
 /**
  * Close the endpoint and all connections gracefully
  *
  * # Parameters
- * - `endpoint`: Endpoint pointer
+ * - `endpoint`: Endpoint handle
  * - `error_code`: Application error code
  * - `reason`: Reason bytes (nullable)
  * - `reason_len`: Length of reason bytes
  *
+ * # Returns
+ * - 0 on success
+ * - InvalidParameter if the endpoint handle is invalid
+ *
  * # Safety
- * - endpoint must be a valid pointer
  * - reason must be valid for reason_len bytes if not null
  */
-void dart_quic_endpoint_close(struct QuicEndpoint *endpoint,
-                              uint32_t error_code,
-                              const uint8_t *reason,
-                              uintptr_t reason_len);
+int32_t dart_quic_endpoint_close(uint64_t endpoint,
+                                 uint32_t error_code,
+                                 const uint8_t *reason,
+                                 uintptr_t reason_len);
 
 /**
  * Wait for all connections to close (async)
  *
  * # Parameters
  * - `executor`: Executor pointer for async operations
- * - `endpoint`: Endpoint pointer
+ * - `endpoint`: Endpoint handle
  * - `callback`: Callback invoked when all connections are closed
  *
  * # Safety
- * - All pointers must be valid
+ * - executor must be valid
  * - Should be called after dart_quic_endpoint_close
  */
 void dart_quic_endpoint_wait_idle(struct QuicExecutor *executor,
-                                  struct QuicEndpoint *endpoint,
+                                  uint64_t endpoint,
                                   VoidCallback callback);
 
 /**
- * Create QUIC client asynchronously (required when tokio runtime is managed by executor)
+ * Get endpoint statistics (sync)
  *
- * This function must be called after `dart_quic_executor_init` because it needs
- * to run inside the tokio runtime context.
+ * # Parameters
+ * - `endpoint`: Endpoint handle
+ * - `out_stats`: Output structure (caller allocated)
  *
- * # Safety
- * The `config` pointer and all data it references must remain valid until the callback is invoked.
+ * # Returns
+ * - 0 (Success) on success
+ * - InvalidParameter if the handle is invalid or `out_stats` is null
  *
- * Returns error code. Callback receives client pointer (as usize) on success.
+ * # Safety
+ * - out_stats must point to writable memory for a `QuicFfiEndpointStats`
  */
-int32_t dart_quic_client_new_async(struct QuicExecutor *executor,
-                                   const struct QuicFfiClientConfig *config,
-                                   UsizeCallback callback);
+int32_t dart_quic_endpoint_stats(uint64_t endpoint, struct QuicFfiEndpointStats *out_stats);
 
 /**
- * Free client
+ * Enumerate stable IDs of live connections on the endpoint (sync)
+ *
+ * IDs match `QuicConnectionHandle.stable_id`. Only connections whose handle
+ * has not been freed and which are not yet closed are listed.
+ *
+ * # Parameters
+ * - `endpoint`: Endpoint handle
+ * - `out_ids`: Receives pointer to `u64` array (NULL when empty)
+ * - `out_count`: Receives number of IDs
+ *
+ * # Memory
+ * Free the array with `dart_free_memory(ids, count * sizeof(uint64_t))`.
+ *
+ * # Safety
+ * - out_ids and out_count must be valid pointers
+ */
+int32_t dart_quic_endpoint_connection_ids(uint64_t endpoint,
+                                          uint64_t **out_ids,
+                                          uintptr_t *out_count);
+
+/**
+ * Create QUIC client asynchronously (required when tokio runtime is managed by executor)
+ *
+ * This function must be called after `dart_quic_executor_init` because it needs
+ * to run inside the tokio runtime context.
+ *
+ * # Safety
+ * The `config` pointer and all data it references must remain valid until the callback is invoked.
+ *
+ * Returns error code. Callback receives a `QuicClientHandle*` pointer (as usize) on success;
+ * free it with `dart_quic_client_handle_free` and the client with `dart_quic_client_free`.
+ */
+int32_t dart_quic_client_new_async(struct QuicExecutor *executor,
+                                   const struct QuicFfiClientConfig *config,
+                                   UsizeCallback callback);
+
+/**
+ * Free a client handle structure (does not free the client)
  */
-void dart_quic_client_free(struct QuicClient *client);
+void dart_quic_client_handle_free(struct QuicClientHandle *handle);
+
+/**
+ * Free client
+ *
+ * Returns InvalidParameter if the handle is not a live client (e.g. already freed).
+ */
+int32_t dart_quic_client_free(uint64_t client);
 
 /**
  * Close client (sync)
  * Returns error code
  */
-int32_t dart_quic_client_close(struct QuicClient *client,
+int32_t dart_quic_client_close(uint64_t client,
                                uint32_t error_code,
                                const uint8_t *reason,
                                uintptr_t reason_len);
@@ -988,35 +1928,174 @@ int32_t dart_quic_client_close(struct QuicClient *client,
  *
  * Callback receives QuicConnectionHandle pointer on success.
  * The handle contains:
- * - connection pointer (for subsequent operations)
+ * - connection registry handle (for subsequent operations)
  * - stable_id (connection ID)
  * - remote_addr (remote address string)
  *
- * Use `dart_quic_connection_handle_free` to free the handle.
+ * `server_addr` is "host:port"; host names are resolved asynchronously. Resolution
+ * failures are reported with a `[HostResolutionFailed]` error message.
+ *
+ * `timeout_ms` bounds resolution plus handshake; when exceeded the attempt is abandoned
+ * and the callback fails with a `[Timeout]` error (0 = no timeout).
+ *
+ * Use `dart_quic_connection_handle_free` to free the handle structure and
+ * `dart_quic_connection_free` to release the connection.
  */
 void dart_quic_client_connect(struct QuicExecutor *executor,
-                              struct QuicClient *client,
+                              uint64_t client,
                               const char *server_addr,
                               const char *server_name,
+                              uint64_t timeout_ms,
                               UsizeCallback callback);
 
+/**
+ * Connect to server with 0-RTT early data (async)
+ *
+ * If a resumable session exists and `enable_early_data` was set in the client config,
+ * `callback` fires immediately with a usable `QuicConnectionHandle*`, before the handshake
+ * completes. Otherwise it fires after the full handshake, like `dart_quic_client_connect`.
+ *
+ * `zero_rtt_callback` fires once the handshake completes with a `QuicZeroRttStatus` value:
+ * - 0 (NotAttempted): no early data was sent
+ * - 1 (Accepted): early data was accepted by the server
+ * - 2 (Rejected): early data was rejected; streams opened before this point fail with `ZeroRttRejected`
+ *
+ * On error, including invalid arguments, both callbacks receive the same error message.
+ *
+ * ⚠️ Early data is replayable; only send idempotent requests before the handshake completes.
+ *
+ * # Safety
+ * `executor` must be valid; `server_addr` and `server_name` must be null-terminated C strings.
+ */
+void dart_quic_client_connect_0rtt(struct QuicExecutor *executor,
+                                   uint64_t client,
+                                   const char *server_addr,
+                                   const char *server_name,
+                                   UsizeCallback callback,
+                                   UsizeCallback zero_rtt_callback);
+
 /**
  * Wait for client to become idle
  */
 void dart_quic_client_wait_idle(struct QuicExecutor *executor,
-                                struct QuicClient *client,
+                                uint64_t client,
                                 VoidCallback callback);
 
 /**
- * Free connection handle and its resources
+ * Get client endpoint statistics (sync)
+ *
+ * See `dart_quic_endpoint_stats`.
+ *
+ * # Safety
+ * out_stats must point to writable memory for a `QuicFfiEndpointStats`
+ */
+int32_t dart_quic_client_stats(uint64_t client, struct QuicFfiEndpointStats *out_stats);
+
+/**
+ * Enumerate stable IDs of live connections opened by the client (sync)
+ *
+ * See `dart_quic_endpoint_connection_ids`. Free the array with
+ * `dart_free_memory(ids, count * sizeof(uint64_t))`.
+ *
+ * # Safety
+ * out_ids and out_count must be valid pointers
+ */
+int32_t dart_quic_client_connection_ids(uint64_t client, uint64_t **out_ids, uintptr_t *out_count);
+
+/**
+ * Free a connection handle structure
  *
  * This frees:
- * - The connection itself
  * - The remote address string
+ * - The handshake data strings
  * - The handle structure
+ *
+ * The connection itself stays open; release it with `dart_quic_connection_free`.
  */
 void dart_quic_connection_handle_free(struct QuicConnectionHandle *handle);
 
+/**
+ * Release a connection
+ *
+ * The connection is dropped (and implicitly closed with code 0 once its streams are
+ * gone) after in-flight operations on it complete.
+ *
+ * # Returns
+ * - 0 (Success) on success
+ * - InvalidParameter if the handle is not a live connection (e.g. already freed)
+ */
+int32_t dart_quic_connection_free(uint64_t connection);
+
+/**
+ * Subscribe to the connection close event (async)
+ *
+ * `callback` fires exactly once, when the connection closes for any reason, with a
+ * `QuicFfiCloseReason*` (free with `dart_quic_close_reason_free`). If the connection
+ * is already closed it fires immediately.
+ *
+ * The subscription does not keep the connection alive: once the handle is freed and
+ * in-flight operations finish, the connection closes and the callback reports
+ * `LocallyClosed` with code 0.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_connection_on_closed(struct QuicExecutor *executor,
+                                    uint64_t handle,
+                                    UsizeCallback callback);
+
+/**
+ * Get the close reason if the connection is already closed (sync)
+ *
+ * # Parameters
+ * - `handle`: Connection handle
+ * - `out_reason`: Receives a `QuicFfiCloseReason*` (free with `dart_quic_close_reason_free`),
+ *   or null while the connection is still open
+ *
+ * # Returns
+ * - 0 (Success), InvalidParameter if the handle is stale or `out_reason` is null
+ *
+ * # Safety
+ * `out_reason` must be a valid pointer.
+ */
+int32_t dart_quic_connection_close_reason(uint64_t handle, struct QuicFfiCloseReason **out_reason);
+
+/**
+ * Get the TLS handshake results of a connection (sync)
+ *
+ * Use this for connections whose handle was created before the handshake data was
+ * available (0-RTT connects); otherwise `QuicConnectionHandle::handshake` has it already.
+ *
+ * # Parameters
+ * - `handle`: Connection handle
+ * - `out_data`: Receives a `QuicFfiHandshakeData*` (free with `dart_quic_handshake_data_free`),
+ *   or null while the handshake data is not available yet
+ *
+ * # Returns
+ * - 0 (Success), InvalidParameter if the handle is stale or `out_data` is null
+ *
+ * # Safety
+ * `out_data` must be a valid pointer.
+ */
+int32_t dart_quic_connection_handshake_data(uint64_t handle,
+                                            struct QuicFfiHandshakeData **out_data);
+
+/**
+ * Free handshake data returned by `dart_quic_connection_handshake_data`
+ *
+ * # Safety
+ * `data` must be null or a pointer returned by that function, freed only once.
+ */
+void dart_quic_handshake_data_free(struct QuicFfiHandshakeData *data);
+
+/**
+ * Free a close reason returned by `dart_quic_connection_on_closed` / `dart_quic_connection_close_reason`
+ *
+ * # Safety
+ * `reason` must be null or a pointer returned by those functions, freed only once.
+ */
+void dart_quic_close_reason_free(struct QuicFfiCloseReason *reason);
+
 /**
  * Close connection (sync)
  *
@@ -1025,11 +2104,14 @@ void dart_quic_connection_handle_free(struct QuicConnectionHandle *handle);
  * - `error_code`: Application error code
  * - `reason`: Close reason bytes (nullable)
  * - `reason_len`: Length of reason bytes
+ *
+ * # Returns
+ * - 0 (Success), InvalidParameter if the handle is stale
  */
-void dart_quic_connection_close(struct QuicConnectionHandle *handle,
-                                uint32_t error_code,
-                                const uint8_t *reason,
-                                uintptr_t reason_len);
+int32_t dart_quic_connection_close(uint64_t handle,
+                                   uint32_t error_code,
+                                   const uint8_t *reason,
+                                   uintptr_t reason_len);
 
 /**
  * Open bidirectional stream
@@ -1039,37 +2121,57 @@ void dart_quic_connection_close(struct QuicConnectionHandle *handle,
  * # Parameters
  * - `executor`: Executor for async operations
  * - `handle`: Connection handle
+ * - `priority`: Initial send priority (higher is sent first, default 0)
+ * - `timeout_ms`: Fail with `[Timeout]` if no stream is available in time (0 = no timeout)
  * - `callback`: Callback receiving stream pair pointer
  */
 void dart_quic_connection_open_bi(struct QuicExecutor *executor,
-                                  struct QuicConnectionHandle *handle,
+                                  uint64_t handle,
+                                  int32_t priority,
+                                  uint64_t timeout_ms,
                                   UsizeCallback callback);
 
 /**
  * Open unidirectional stream (send only)
  *
  * Returns QuicFfiStreamPair structure pointer via UsizeCallback.
+ * `priority` sets the initial send priority (higher is sent first, default 0).
+ * `timeout_ms` fails the open with `[Timeout]` if exceeded (0 = no timeout).
  */
 void dart_quic_connection_open_uni(struct QuicExecutor *executor,
-                                   struct QuicConnectionHandle *handle,
+                                   uint64_t handle,
+                                   int32_t priority,
+                                   uint64_t timeout_ms,
                                    UsizeCallback callback);
 
 /**
  * Accept bidirectional stream
  *
  * Returns QuicFfiStreamPair structure pointer via UsizeCallback.
+ * `timeout_ms` fails the accept with `[Timeout]` if exceeded (0 = no timeout).
+ * If cancelled, the callback fails with an error starting with `[Cancelled]`.
+ *
+ * # Returns
+ * Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+ * (the callback has then already been called with the error).
+ *
+ * # Safety
+ * `executor` must be valid.
  */
-void dart_quic_connection_accept_bi(struct QuicExecutor *executor,
-                                    struct QuicConnectionHandle *handle,
-                                    UsizeCallback callback);
+uint64_t dart_quic_connection_accept_bi(struct QuicExecutor *executor,
+                                        uint64_t handle,
+                                        uint64_t timeout_ms,
+                                        UsizeCallback callback);
 
 /**
  * Accept unidirectional stream (recv only)
  *
  * Returns QuicFfiStreamPair structure pointer via UsizeCallback.
+ * `timeout_ms` fails the accept with `[Timeout]` if exceeded (0 = no timeout).
  */
 void dart_quic_connection_accept_uni(struct QuicExecutor *executor,
-                                     struct QuicConnectionHandle *handle,
+                                     uint64_t handle,
+                                     uint64_t timeout_ms,
                                      UsizeCallback callback);
 
 /**
@@ -1084,7 +2186,7 @@ void dart_quic_connection_accept_uni(struct QuicExecutor *executor,
  * - QuicResult::Success on success
  * - Error code on failure
  */
-int32_t dart_quic_connection_send_datagram(struct QuicConnectionHandle *handle,
+int32_t dart_quic_connection_send_datagram(uint64_t handle,
                                            const uint8_t *data,
                                            uintptr_t data_len);
 
@@ -1097,12 +2199,30 @@ int32_t dart_quic_connection_send_datagram(struct QuicConnectionHandle *handle,
  * - `callback`: Callback receiving datagram data
  */
 void dart_quic_connection_read_datagram(struct QuicExecutor *executor,
-                                        struct QuicConnectionHandle *handle,
+                                        uint64_t handle,
                                         BytesCallback callback);
 
+/**
+ * Get connection statistics snapshot (sync)
+ *
+ * Fills `out_stats` from `quinn::Connection::stats()`.
+ *
+ * # Parameters
+ * - `handle`: Connection handle
+ * - `out_stats`: Output structure (caller allocated)
+ *
+ * # Returns
+ * - QuicResult::Success on success
+ * - QuicResult::InvalidParameter if the handle is stale or `out_stats` is null
+ *
+ * # Safety
+ * `out_stats` must point to writable memory for a `QuicFfiConnectionStats`
+ */
+int32_t dart_quic_connection_stats(uint64_t handle, struct QuicFfiConnectionStats *out_stats);
+
 /**
  * Create server with self-signed certificate (testing only!)
- * Returns error code, result written to `result` parameter
+ * Returns error code, result written to `result` parameter (`QuicServerHandle*` on success)
  */
 int32_t dart_quic_server_new_self_signed(const char *bind_addr,
                                          const char *const *san_list,
@@ -1112,7 +2232,7 @@ int32_t dart_quic_server_new_self_signed(const char *bind_addr,
 
 /**
  * Create server with PEM certificate files
- * Returns error code, result written to `result` parameter
+ * Returns error code, result written to `result` parameter (`QuicServerHandle*` on success)
  */
 int32_t dart_quic_server_new_with_cert_files(const char *bind_addr,
                                              const char *cert_path,
@@ -1122,36 +2242,117 @@ int32_t dart_quic_server_new_with_cert_files(const char *bind_addr,
 
 /**
  * Free server
+ *
+ * Returns InvalidParameter if the handle is not a live server (e.g. already freed).
  */
-void dart_quic_server_free(struct QuicServer *server);
+int32_t dart_quic_server_free(uint64_t server);
 
 /**
  * Close server
+ * Returns error code
  */
-void dart_quic_server_close(struct QuicServer *server,
-                            uint32_t error_code,
-                            const uint8_t *reason,
-                            uintptr_t reason_len);
+int32_t dart_quic_server_close(uint64_t server,
+                               uint32_t error_code,
+                               const uint8_t *reason,
+                               uintptr_t reason_len);
 
 /**
  * Get server local address
  */
-bool dart_quic_server_local_addr(struct QuicServer *server, uint8_t **addr_out, uintptr_t *len_out);
+bool dart_quic_server_local_addr(uint64_t server, uint8_t **addr_out, uintptr_t *len_out);
 
 /**
  * Accept incoming connection
  *
  * Callback receives QuicConnectionHandle pointer on success.
  * The handle contains:
- * - connection pointer (for subsequent operations)
+ * - connection registry handle (for subsequent operations)
  * - stable_id (connection ID)
  * - remote_addr (remote address string)
  *
- * Use `dart_quic_connection_handle_free` to free the handle.
+ * Use `dart_quic_connection_handle_free` to free the handle structure and
+ * `dart_quic_connection_free` to release the connection.
+ * `timeout_ms` fails the accept with `[Timeout]` if no connection arrives in time
+ * (0 = no timeout). If cancelled, the callback fails with an error starting with `[Cancelled]`.
+ *
+ * # Returns
+ * Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+ * (the callback has then already been called with the error).
+ *
+ * # Safety
+ * `executor` must be valid.
  */
-void dart_quic_server_accept(struct QuicExecutor *executor,
-                             struct QuicServer *server,
-                             UsizeCallback callback);
+uint64_t dart_quic_server_accept(struct QuicExecutor *executor,
+                                 uint64_t server,
+                                 uint64_t timeout_ms,
+                                 UsizeCallback callback);
+
+/**
+ * Accept incoming connection with 0.5-RTT (async)
+ *
+ * `callback` fires with a `QuicConnectionHandle*` as soon as the handshake starts, so
+ * the server can send data before the client completes the handshake.
+ * `callback` receives value=0 when the server is closed.
+ *
+ * `zero_rtt_callback` fires once the handshake completes with a `QuicZeroRttStatus` value
+ * (always 1 = Accepted for incoming connections, 0 if the server closed).
+ *
+ * ⚠️ 0.5-RTT data is sent before client authentication completes.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_server_accept_0rtt(struct QuicExecutor *executor,
+                                  uint64_t server,
+                                  UsizeCallback callback,
+                                  UsizeCallback zero_rtt_callback);
+
+/**
+ * Register a connection queue for an ALPN protocol (sync)
+ *
+ * Connections are then accepted per protocol with `dart_quic_server_accept_alpn`
+ * instead of `dart_quic_server_accept`. Registering twice is a no-op.
+ *
+ * The first call starts a background task that routes incoming connections to their
+ * queues until the server is closed or freed.
+ *
+ * # Parameters
+ * - `server`: Server handle
+ * - `alpn`, `alpn_len`: Protocol identifier bytes (e.g. "h3"); null or empty registers
+ *   the fallback queue, which receives connections whose protocol has no queue
+ *
+ * # Returns
+ * - 0 (Success), InvalidParameter if the handle is stale
+ * - RuntimeError if the server was not created on a runtime
+ *
+ * # Safety
+ * `alpn` must be null or valid for `alpn_len` bytes.
+ */
+int32_t dart_quic_server_route_alpn(uint64_t server, const uint8_t *alpn, uintptr_t alpn_len);
+
+/**
+ * Accept the next connection that negotiated an ALPN protocol (async)
+ *
+ * Same callback semantics as `dart_quic_server_accept`, for connections routed to the
+ * queue registered with `dart_quic_server_route_alpn` (null or empty `alpn` = fallback).
+ * Fails with InvalidParameter if no queue is registered for `alpn`.
+ *
+ * Only reads the queue: cancelling or timing out does not drop connections routed to
+ * other protocols. Connections with no matching queue and no fallback are closed.
+ *
+ * # Returns
+ * Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+ * (the callback has then already been called with the error).
+ *
+ * # Safety
+ * `executor` must be valid; `alpn` must be null or valid for `alpn_len` bytes.
+ */
+uint64_t dart_quic_server_accept_alpn(struct QuicExecutor *executor,
+                                      uint64_t server,
+                                      const uint8_t *alpn,
+                                      uintptr_t alpn_len,
+                                      uint64_t timeout_ms,
+                                      UsizeCallback callback);
 
 /**
  * Wait for all server connections to become idle (async)
@@ -1160,25 +2361,25 @@ void dart_quic_server_accept(struct QuicExecutor *executor,
  * Should typically be called after `dart_quic_server_close` for a graceful shutdown.
  *
  * # Safety
- * All pointers must be valid.
+ * `executor` must be valid.
  */
 void dart_quic_server_wait_idle(struct QuicExecutor *executor,
-                                struct QuicServer *server,
+                                uint64_t server,
                                 VoidCallback callback);
 
 /**
  * Get the number of currently open connections on the server
  *
- * Returns 0 if the server pointer is null.
+ * Returns 0 if the server handle is invalid.
  */
-uintptr_t dart_quic_server_open_connections(struct QuicServer *server);
+uintptr_t dart_quic_server_open_connections(uint64_t server);
 
 /**
  * Get server local port
  *
- * Returns 0 if the server pointer is null.
+ * Returns 0 if the server handle is invalid.
  */
-uint16_t dart_quic_server_local_port(struct QuicServer *server);
+uint16_t dart_quic_server_local_port(uint64_t server);
 
 /**
  * Create a QUIC server asynchronously using unified FFI configuration
@@ -1187,7 +2388,8 @@ uint16_t dart_quic_server_local_port(struct QuicServer *server);
  * an active tokio runtime context when creating the endpoint.
  *
  * On success, the callback receives a `QuicServerHandle*` pointer (as usize).
- * The handle is allocated and owned by Rust; free it with `dart_quic_server_handle_free`.
+ * The handle is allocated and owned by Rust; free it with `dart_quic_server_handle_free`
+ * and the server with `dart_quic_server_free`.
  *
  * # Safety
  * - `config` and all data it references must remain valid until the callback fires
@@ -1204,14 +2406,473 @@ int32_t dart_quic_server_new_async(struct QuicExecutor *executor,
                                    UsizeCallback callback);
 
 /**
- * Free server handle and all its resources
+ * Free a server handle structure
  *
  * This frees:
- * - The server itself
  * - The local_addr_ptr string
  * - The handle structure
  *
+ * The server itself stays open; release it with `dart_quic_server_free`.
  */
 void dart_quic_server_handle_free(struct QuicServerHandle *handle);
 
+/**
+ * Get server endpoint statistics (sync)
+ *
+ * See `dart_quic_endpoint_stats`.
+ *
+ * # Safety
+ * out_stats must point to writable memory for a `QuicFfiEndpointStats`
+ */
+int32_t dart_quic_server_stats(uint64_t server, struct QuicFfiEndpointStats *out_stats);
+
+/**
+ * Enumerate stable IDs of live connections accepted by the server (sync)
+ *
+ * See `dart_quic_endpoint_connection_ids`. Free the array with
+ * `dart_free_memory(ids, count * sizeof(uint64_t))`.
+ *
+ * # Safety
+ * out_ids and out_count must be valid pointers
+ */
+int32_t dart_quic_server_connection_ids(uint64_t server, uint64_t **out_ids, uintptr_t *out_count);
+
+/**
+ * Replace the server certificate (sync, hot reload)
+ *
+ * Builds a new server configuration from `config`, exactly as `dart_quic_server_new_async`
+ * would (certificate from PEM files, DER memory or self-signed per `cert_mode`, plus SNI
+ * certificates, client authentication, transport and 0-RTT settings), and applies it to
+ * new connections. Established connections keep their certificate.
+ *
+ * # Returns
+ * - 0 on success
+ * - InvalidParameter if the handle is stale
+ * - Error code on failure (`result` carries the message; the current configuration stays)
+ *
+ * # Safety
+ * `config` and `result` must be valid pointers.
+ */
+int32_t dart_quic_server_set_config(uint64_t server,
+                                    const struct QuicFfiServerConfig *config,
+                                    struct QuicFfiResult *result);
+
+/**
+ * Reload the server certificate whenever its PEM files change (async)
+ *
+ * Watches the certificate and key files of `config` (`cert_mode` = 0 and file-based SNI
+ * certificates), checking them every `interval_ms` (0 = 5000). After a change the
+ * configuration is rebuilt from `config` as by `dart_quic_server_set_config`. A failed
+ * rebuild (e.g. the key was not rewritten yet) keeps the current certificate, logs a
+ * warning, and is retried on the next change.
+ *
+ * `config` is copied; it may be freed once this call returns.
+ *
+ * `callback` fires once, when the watch ends:
+ * - success once the server handle is released (`dart_quic_server_free`)
+ * - an error starting with `[Cancelled]` when cancelled with `dart_quic_operation_cancel`
+ * - an error right away if `config` is invalid or has no certificate files
+ *
+ * # Returns
+ * Operation ID for `dart_quic_operation_cancel`, or 0 if the watch was not started
+ * (the callback has then already been called with the error).
+ *
+ * # Safety
+ * `executor` must be valid; `config` must be a valid pointer.
+ */
+uint64_t dart_quic_server_watch_certs(struct QuicExecutor *executor,
+                                      uint64_t server,
+                                      const struct QuicFfiServerConfig *config,
+                                      uint64_t interval_ms,
+                                      VoidCallback callback);
+
+/**
+ * Register `Dart_PostCObject` for port-based completion
+ *
+ * Call once per process with `NativeApi.postCObject` before any `*_port` function.
+ * Without it, results are silently dropped.
+ *
+ * # Safety
+ * `post_cobject` must be Dart's `Dart_PostCObject` (valid for the process lifetime).
+ */
+void dart_quic_port_init(DartPostCObjectFn post_cobject);
+
+/**
+ * Create a QUIC client (async, port)
+ *
+ * Port variant of `dart_quic_client_new_async`. Payload: `QuicClientHandle*` address
+ * (free with `dart_quic_client_handle_free`, the client with `dart_quic_client_free`).
+ *
+ * # Safety
+ * `executor` must be valid; `config` and all data it references must remain valid
+ * until the result is posted.
+ */
+void dart_quic_client_new_async_port(struct QuicExecutor *executor,
+                                     const struct QuicFfiClientConfig *config,
+                                     int64_t port);
+
+/**
+ * Create a QUIC server (async, port)
+ *
+ * Port variant of `dart_quic_server_new_async`. Payload: `QuicServerHandle*` address
+ * (free with `dart_quic_server_handle_free`, the server with `dart_quic_server_free`).
+ *
+ * # Safety
+ * `executor` must be valid; `bind_addr` must be null-terminated; `config` and all data
+ * it references must remain valid until the result is posted.
+ */
+void dart_quic_server_new_async_port(struct QuicExecutor *executor,
+                                     const char *bind_addr,
+                                     const struct QuicFfiServerConfig *config,
+                                     int64_t port);
+
+/**
+ * Connect to server via client (async, port)
+ *
+ * Payload: `QuicConnectionHandle*` address (free with `dart_quic_connection_handle_free`).
+ *
+ * # Safety
+ * `executor` must be valid; strings must be null-terminated.
+ */
+void dart_quic_client_connect_port(struct QuicExecutor *executor,
+                                   uint64_t client,
+                                   const char *server_addr,
+                                   const char *server_name,
+                                   uint64_t timeout_ms,
+                                   int64_t port);
+
+/**
+ * Accept incoming connection via server (async, port)
+ *
+ * Payload: `QuicConnectionHandle*` address, or null when the server is closed.
+ * If cancelled, posts a `Cancelled` error.
+ *
+ * # Returns
+ * Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+ * (the error has then already been posted).
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+uint64_t dart_quic_server_accept_port(struct QuicExecutor *executor,
+                                      uint64_t server,
+                                      uint64_t timeout_ms,
+                                      int64_t port);
+
+/**
+ * Accept the next connection routed to an ALPN queue via server (async, port)
+ *
+ * Payload: `QuicConnectionHandle*` address, or null when the server is closed.
+ * See `dart_quic_server_accept_alpn`. If cancelled, posts a `Cancelled` error.
+ *
+ * # Returns
+ * Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+ * (the error has then already been posted).
+ *
+ * # Safety
+ * `executor` must be valid; `alpn` must be null or valid for `alpn_len` bytes.
+ */
+uint64_t dart_quic_server_accept_alpn_port(struct QuicExecutor *executor,
+                                           uint64_t server,
+                                           const uint8_t *alpn,
+                                           uintptr_t alpn_len,
+                                           uint64_t timeout_ms,
+                                           int64_t port);
+
+/**
+ * Connect to a remote server via endpoint (async, port)
+ *
+ * Payload: `QuicConnectionHandle*` address.
+ *
+ * # Safety
+ * `executor` must be valid; strings must be null-terminated.
+ */
+void dart_quic_endpoint_connect_port(struct QuicExecutor *executor,
+                                     uint64_t endpoint,
+                                     const char *server_addr,
+                                     const char *server_name,
+                                     uint64_t timeout_ms,
+                                     int64_t port);
+
+/**
+ * Accept incoming connection via endpoint (async, port)
+ *
+ * Payload: `QuicConnectionHandle*` address. Fails with `EndpointClosed` when the
+ * endpoint is closed.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_endpoint_accept_port(struct QuicExecutor *executor,
+                                    uint64_t endpoint,
+                                    uint64_t timeout_ms,
+                                    int64_t port);
+
+/**
+ * Connect to server with 0-RTT early data via client (async, port)
+ *
+ * Port variant of `dart_quic_client_connect_0rtt`. `port` receives the
+ * `QuicConnectionHandle*` address (immediately when early data is possible);
+ * `zero_rtt_port` receives the `QuicZeroRttStatus` as an `int` once the handshake
+ * completes. On error, including invalid arguments, both ports receive the error.
+ *
+ * # Safety
+ * `executor` must be valid; strings must be null-terminated.
+ */
+void dart_quic_client_connect_0rtt_port(struct QuicExecutor *executor,
+                                        uint64_t client,
+                                        const char *server_addr,
+                                        const char *server_name,
+                                        int64_t port,
+                                        int64_t zero_rtt_port);
+
+/**
+ * Accept incoming connection with 0.5-RTT via server (async, port)
+ *
+ * Port variant of `dart_quic_server_accept_0rtt`. `port` receives the
+ * `QuicConnectionHandle*` address as soon as the handshake starts, or null when the
+ * server is closed; `zero_rtt_port` receives the `QuicZeroRttStatus` as an `int`.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_server_accept_0rtt_port(struct QuicExecutor *executor,
+                                       uint64_t server,
+                                       int64_t port,
+                                       int64_t zero_rtt_port);
+
+/**
+ * Connect to a remote server with 0-RTT early data via endpoint (async, port)
+ *
+ * Same semantics as `dart_quic_client_connect_0rtt_port`.
+ *
+ * # Safety
+ * `executor` must be valid; strings must be null-terminated.
+ */
+void dart_quic_endpoint_connect_0rtt_port(struct QuicExecutor *executor,
+                                          uint64_t endpoint,
+                                          const char *server_addr,
+                                          const char *server_name,
+                                          int64_t port,
+                                          int64_t zero_rtt_port);
+
+/**
+ * Accept an incoming connection with 0.5-RTT via endpoint (async, port)
+ *
+ * Same semantics as `dart_quic_server_accept_0rtt_port`.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_endpoint_accept_0rtt_port(struct QuicExecutor *executor,
+                                         uint64_t endpoint,
+                                         int64_t port,
+                                         int64_t zero_rtt_port);
+
+/**
+ * Wait for all client connections to become idle (async, port)
+ *
+ * Port variant of `dart_quic_client_wait_idle`. Payload: null.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_client_wait_idle_port(struct QuicExecutor *executor, uint64_t client, int64_t port);
+
+/**
+ * Wait for all server connections to become idle (async, port)
+ *
+ * Port variant of `dart_quic_server_wait_idle`. Payload: null.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_server_wait_idle_port(struct QuicExecutor *executor, uint64_t server, int64_t port);
+
+/**
+ * Wait for all endpoint connections to close (async, port)
+ *
+ * Port variant of `dart_quic_endpoint_wait_idle`. Payload: null.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_endpoint_wait_idle_port(struct QuicExecutor *executor,
+                                       uint64_t endpoint,
+                                       int64_t port);
+
+/**
+ * Open bidirectional stream (async, port)
+ *
+ * Payload: `QuicFfiStreamPair*` address (free with `dart_quic_stream_pair_free`).
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_connection_open_bi_port(struct QuicExecutor *executor,
+                                       uint64_t handle,
+                                       int32_t priority,
+                                       uint64_t timeout_ms,
+                                       int64_t port);
+
+/**
+ * Open unidirectional stream (async, port)
+ *
+ * Payload: `QuicFfiStreamPair*` address with only `send_handle` set.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_connection_open_uni_port(struct QuicExecutor *executor,
+                                        uint64_t handle,
+                                        int32_t priority,
+                                        uint64_t timeout_ms,
+                                        int64_t port);
+
+/**
+ * Accept bidirectional stream (async, port)
+ *
+ * Payload: `QuicFfiStreamPair*` address. If cancelled, posts a `Cancelled` error.
+ *
+ * # Returns
+ * Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+ * (the error has then already been posted).
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+uint64_t dart_quic_connection_accept_bi_port(struct QuicExecutor *executor,
+                                             uint64_t handle,
+                                             uint64_t timeout_ms,
+                                             int64_t port);
+
+/**
+ * Accept unidirectional stream (async, port)
+ *
+ * Payload: `QuicFfiStreamPair*` address with only `recv_handle` set.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_connection_accept_uni_port(struct QuicExecutor *executor,
+                                          uint64_t handle,
+                                          uint64_t timeout_ms,
+                                          int64_t port);
+
+/**
+ * Read datagram (async, port)
+ *
+ * Payload: `Uint8List` with the datagram.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_connection_read_datagram_port(struct QuicExecutor *executor,
+                                             uint64_t handle,
+                                             int64_t port);
+
+/**
+ * Subscribe to the connection close event (async, port)
+ *
+ * Payload: `[kind: int, remote: bool, resultCode: int, errorCode: int, reason: Uint8List]`
+ * with the same meaning as the `QuicFfiCloseReason` fields. Like
+ * `dart_quic_connection_on_closed`, the subscription does not keep the connection alive.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_connection_on_closed_port(struct QuicExecutor *executor,
+                                         uint64_t handle,
+                                         int64_t port);
+
+/**
+ * Read up to `max_len` bytes from the stream (async, port)
+ *
+ * Payload: `Uint8List` with the bytes read, or null at end of stream. If cancelled,
+ * posts a `Cancelled` error.
+ *
+ * # Returns
+ * Operation ID for `dart_quic_operation_cancel`, or 0 if the read was not started
+ * (the error has then already been posted).
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+uint64_t dart_quic_recv_stream_read_port(struct QuicExecutor *executor,
+                                         uint64_t handle,
+                                         uintptr_t max_len,
+                                         uint64_t timeout_ms,
+                                         int64_t port);
+
+/**
+ * Read exactly `exact_len` bytes from the stream (async, port)
+ *
+ * Payload: `Uint8List` of `exact_len` bytes.
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_recv_stream_read_exact_port(struct QuicExecutor *executor,
+                                           uint64_t handle,
+                                           uintptr_t exact_len,
+                                           uint64_t timeout_ms,
+                                           int64_t port);
+
+/**
+ * Read all remaining data from the stream, up to `size_limit` bytes (async, port)
+ *
+ * Payload: `Uint8List` with the data (empty if the stream had none).
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_recv_stream_read_to_end_port(struct QuicExecutor *executor,
+                                            uint64_t handle,
+                                            uintptr_t size_limit,
+                                            uint64_t timeout_ms,
+                                            int64_t port);
+
+/**
+ * Write bytes to the send stream (async, port)
+ *
+ * The data is copied before returning. Payload: `int` number of bytes written.
+ *
+ * # Safety
+ * `executor` must be valid; `data` must point to `data_len` readable bytes.
+ */
+void dart_quic_send_stream_write_port(struct QuicExecutor *executor,
+                                      uint64_t handle,
+                                      const uint8_t *data,
+                                      uintptr_t data_len,
+                                      uint64_t timeout_ms,
+                                      int64_t port);
+
+/**
+ * Write all bytes to the send stream (async, port)
+ *
+ * The data is copied before returning. Payload: null.
+ *
+ * # Safety
+ * `executor` must be valid; `data` must point to `data_len` readable bytes.
+ */
+void dart_quic_send_stream_write_all_port(struct QuicExecutor *executor,
+                                          uint64_t handle,
+                                          const uint8_t *data,
+                                          uintptr_t data_len,
+                                          uint64_t timeout_ms,
+                                          int64_t port);
+
+/**
+ * Wait until the peer has received all data or stopped the stream (async, port)
+ *
+ * Payload: `bool`, true if the peer sent STOP_SENDING (the code is recorded on the
+ * handle, see `dart_quic_stream_peer_error_code`).
+ *
+ * # Safety
+ * `executor` must be valid.
+ */
+void dart_quic_send_stream_stopped_port(struct QuicExecutor *executor,
+                                        uint64_t handle,
+                                        int64_t port);
+
 #endif  /* DART_QUIC_FFI_H */
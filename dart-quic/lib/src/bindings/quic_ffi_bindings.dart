@@ -82,6 +82,43 @@ class QuicFFIBindings {
         void Function(ffi.Pointer<QuicExecutor>, int, BoolCallback)
       >();
 
+  /// Initialize the executor with a runtime configuration (async)
+  ///
+  /// Like `dart_quic_executor_init`, but exposes the tokio runtime builder options.
+  /// A null `config` uses the defaults. Invalid options or runtime build failures
+  /// are reported through `callback`.
+  ///
+  /// # Safety
+  /// `executor` must be null or a valid executor; `config` must be null or valid
+  /// for the duration of the call (it is copied before returning).
+  void dart_quic_executor_init_with_config(
+    ffi.Pointer<QuicExecutor> executor,
+    ffi.Pointer<QuicFfiRuntimeConfig> config,
+    BoolCallback callback,
+  ) {
+    return _dart_quic_executor_init_with_config(executor, config, callback);
+  }
+
+  late final _dart_quic_executor_init_with_configPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Pointer<QuicFfiRuntimeConfig>,
+            BoolCallback,
+          )
+        >
+      >('dart_quic_executor_init_with_config');
+  late final _dart_quic_executor_init_with_config =
+      _dart_quic_executor_init_with_configPtr
+          .asFunction<
+            void Function(
+              ffi.Pointer<QuicExecutor>,
+              ffi.Pointer<QuicFfiRuntimeConfig>,
+              BoolCallback,
+            )
+          >();
+
   bool dart_quic_executor_is_running(ffi.Pointer<QuicExecutor> executor) {
     return _dart_quic_executor_is_running(executor);
   }
@@ -104,6 +141,72 @@ class QuicFFIBindings {
   late final _dart_quic_executor_free = _dart_quic_executor_freePtr
       .asFunction<void Function(ffi.Pointer<QuicExecutor>)>();
 
+  /// Gracefully shut down the executor (async)
+  ///
+  /// Stops accepting new operations and closes every client / server created on the
+  /// executor (pending accepts complete as closed), then waits up to `grace_period_ms`
+  /// for in-flight operations to finish. Operations still running afterwards are aborted
+  /// without invoking their callbacks, and the tokio runtime is destroyed.
+  ///
+  /// `callback` fires once the runtime is gone, with `value` = true if all operations
+  /// finished within the grace period, false if some were aborted. It fails with
+  /// "Executor not running" if the executor is not initialized or already shutting down.
+  ///
+  /// After the callback fires the executor can be initialized again with
+  /// `dart_quic_executor_init`.
+  ///
+  /// # Safety
+  /// `executor` must be null or a valid executor.
+  void dart_quic_executor_shutdown(
+    ffi.Pointer<QuicExecutor> executor,
+    int grace_period_ms,
+    BoolCallback callback,
+  ) {
+    return _dart_quic_executor_shutdown(executor, grace_period_ms, callback);
+  }
+
+  late final _dart_quic_executor_shutdownPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, BoolCallback)
+        >
+      >('dart_quic_executor_shutdown');
+  late final _dart_quic_executor_shutdown = _dart_quic_executor_shutdownPtr
+      .asFunction<
+        void Function(ffi.Pointer<QuicExecutor>, int, BoolCallback)
+      >();
+
+  /// Cancel an in-flight async operation (sync)
+  ///
+  /// Applies to functions that return an operation ID (`dart_quic_recv_stream_read`,
+  /// `dart_quic_connection_accept_bi`, `dart_quic_server_accept`). The operation's
+  /// callback then fails with an error starting with `[Cancelled]` (QuicResult::Cancelled),
+  /// fired after the operation has stopped using its stream / connection.
+  ///
+  /// # Returns
+  /// - 0 (Success) if the operation was cancelled
+  /// - InvalidOperation if the ID is unknown or the operation already completed
+  /// (its callback fires with the real result)
+  /// - InvalidParameter if executor is null
+  ///
+  /// # Safety
+  /// `executor` must be null or a valid executor.
+  int dart_quic_operation_cancel(
+    ffi.Pointer<QuicExecutor> executor,
+    int operation_id,
+  ) {
+    return _dart_quic_operation_cancel(executor, operation_id);
+  }
+
+  late final _dart_quic_operation_cancelPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(ffi.Pointer<QuicExecutor>, ffi.Uint64)
+        >
+      >('dart_quic_operation_cancel');
+  late final _dart_quic_operation_cancel = _dart_quic_operation_cancelPtr
+      .asFunction<int Function(ffi.Pointer<QuicExecutor>, int)>();
+
   ffi.Pointer<ffi.Uint8> dart_allocate_memory(int size) {
     return _dart_allocate_memory(size);
   }
@@ -246,7 +349,84 @@ class QuicFFIBindings {
       _dart_quic_transport_config_freePtr
           .asFunction<void Function(ffi.Pointer<QuicFfiTransportConfig>)>();
 
+  /// Register a callback for every panic inside the library (sync)
+  ///
+  /// Pass null to unregister; panics are then printed to stderr. Caught panics are
+  /// still converted into `InternalError` results either way.
+  void dart_quic_set_panic_callback(PanicCallback callback) {
+    return _dart_quic_set_panic_callback(callback);
+  }
+
+  late final _dart_quic_set_panic_callbackPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(PanicCallback)>>(
+        'dart_quic_set_panic_callback',
+      );
+  late final _dart_quic_set_panic_callback = _dart_quic_set_panic_callbackPtr
+      .asFunction<void Function(PanicCallback)>();
+
+  /// Forward log records to `callback` (sync)
+  ///
+  /// Intended for native consumers: the callback is invoked synchronously on whichever
+  /// thread emitted the record, and the record strings are only valid until it returns.
+  /// Dart code should use `dart_quic_log_set_port` instead, since a `NativeCallable.listener`
+  /// runs after the callback has returned and would read freed strings.
+  ///
+  /// Replaces any previously registered callback or port; pass null to stop forwarding.
+  /// Records below the level set with
+  /// `dart_quic_log_set_level` (default Info) are dropped.
+  ///
+  /// Returns RuntimeError if the log subscriber could not be installed.
+  int dart_quic_log_set_callback(QuicLogCallback callback) {
+    return _dart_quic_log_set_callback(callback);
+  }
+
+  late final _dart_quic_log_set_callbackPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(QuicLogCallback)>>(
+        'dart_quic_log_set_callback',
+      );
+  late final _dart_quic_log_set_callback = _dart_quic_log_set_callbackPtr
+      .asFunction<int Function(QuicLogCallback)>();
+
+  /// Forward log records to a Dart native port (sync)
+  ///
+  /// This is the supported way to receive logs in Dart; records are copied into the message.
+  /// Replaces any previously registered callback or port; pass 0 to stop forwarding.
+  /// Requires `dart_quic_port_init`. See the module docs for the message format.
+  ///
+  /// Returns RuntimeError if the log subscriber could not be installed.
+  int dart_quic_log_set_port(int port) {
+    return _dart_quic_log_set_port(port);
+  }
+
+  late final _dart_quic_log_set_portPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Int64)>>(
+        'dart_quic_log_set_port',
+      );
+  late final _dart_quic_log_set_port = _dart_quic_log_set_portPtr
+      .asFunction<int Function(int)>();
+
+  /// Set the maximum level of forwarded records (sync)
+  ///
+  /// `level` is a `QuicLogLevel` value (0 = Off ... 5 = Trace). Takes effect immediately,
+  /// including for quinn and rustls.
+  ///
+  /// Returns InvalidParameter for an unknown level, RuntimeError if the log subscriber
+  /// could not be installed.
+  int dart_quic_log_set_level(int level) {
+    return _dart_quic_log_set_level(level);
+  }
+
+  late final _dart_quic_log_set_levelPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Int32)>>(
+        'dart_quic_log_set_level',
+      );
+  late final _dart_quic_log_set_level = _dart_quic_log_set_levelPtr
+      .asFunction<int Function(int)>();
+
   /// Free stream pair structure
+  ///
+  /// Frees the pair and its `QuicFfiStreamHandle` structures. The streams stay open
+  /// until released with `dart_quic_stream_free`.
   void dart_quic_stream_pair_free(ffi.Pointer<QuicFfiStreamPair> pair) {
     return _dart_quic_stream_pair_free(pair);
   }
@@ -258,7 +438,7 @@ class QuicFFIBindings {
   late final _dart_quic_stream_pair_free = _dart_quic_stream_pair_freePtr
       .asFunction<void Function(ffi.Pointer<QuicFfiStreamPair>)>();
 
-  /// Free stream handle (works for both send and recv streams)
+  /// Free a stream handle structure (does not release the stream)
   void dart_quic_stream_handle_free(ffi.Pointer<QuicFfiStreamHandle> handle) {
     return _dart_quic_stream_handle_free(handle);
   }
@@ -270,6 +450,25 @@ class QuicFFIBindings {
   late final _dart_quic_stream_handle_free = _dart_quic_stream_handle_freePtr
       .asFunction<void Function(ffi.Pointer<QuicFfiStreamHandle>)>();
 
+  /// Release a stream (works for both send and recv streams)
+  ///
+  /// Dropping a send stream finishes it; dropping a recv stream stops it with code 0.
+  /// In-flight operations keep the stream alive until they complete.
+  ///
+  /// # Returns
+  /// - 0 (Success) on success
+  /// - InvalidParameter if the handle is not a live stream (e.g. already freed)
+  int dart_quic_stream_free(int stream) {
+    return _dart_quic_stream_free(stream);
+  }
+
+  late final _dart_quic_stream_freePtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64)>>(
+        'dart_quic_stream_free',
+      );
+  late final _dart_quic_stream_free = _dart_quic_stream_freePtr
+      .asFunction<int Function(int)>();
+
   /// Read data contiguously from the stream
   ///
   /// Returns the number of bytes read via callback, or None (ptr=null, len=0) if stream is finished.
@@ -288,38 +487,50 @@ class QuicFFIBindings {
   /// - `executor`: QuicExecutor for async execution
   /// - `handle`: Stream handle (must be of type Recv)
   /// - `max_len`: Maximum bytes to read (will allocate this much memory)
+  /// - `timeout_ms`: Fail with a `[Timeout]` error if no data arrives in time (0 = no timeout)
   /// - `callback`: Called with (success, data_ptr, data_len, error_ptr, error_len)
   /// - On success: callback(true, buf, bytes_read, null, 0) where bytes_read <= max_len
   /// - On EOF: callback(true, null, 0, null, 0)
   /// - On error: callback(false, null, 0, error_ptr, error_len)
-  void dart_quic_recv_stream_read(
+  /// - On cancel: callback(false, null, 0, "[Cancelled] ...", len)
+  ///
+  /// # Returns
+  /// Operation ID for `dart_quic_operation_cancel`, or 0 if the read was not started
+  /// (the callback has then already been called with the error).
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  int dart_quic_recv_stream_read(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicFfiStreamHandle> handle,
+    int handle,
     int max_len,
+    int timeout_ms,
     BytesCallback callback,
   ) {
-    return _dart_quic_recv_stream_read(executor, handle, max_len, callback);
+    return _dart_quic_recv_stream_read(
+      executor,
+      handle,
+      max_len,
+      timeout_ms,
+      callback,
+    );
   }
 
   late final _dart_quic_recv_stream_readPtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
+          ffi.Uint64 Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicFfiStreamHandle>,
+            ffi.Uint64,
             ffi.UintPtr,
+            ffi.Uint64,
             BytesCallback,
           )
         >
       >('dart_quic_recv_stream_read');
   late final _dart_quic_recv_stream_read = _dart_quic_recv_stream_readPtr
       .asFunction<
-        void Function(
-          ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicFfiStreamHandle>,
-          int,
-          BytesCallback,
-        )
+        int Function(ffi.Pointer<QuicExecutor>, int, int, int, BytesCallback)
       >();
 
   /// Read exact number of bytes from the stream
@@ -334,19 +545,23 @@ class QuicFFIBindings {
   /// - `executor`: QuicExecutor for async execution
   /// - `handle`: Stream handle (must be of type Recv)
   /// - `exact_len`: Exact number of bytes to read
+  /// - `timeout_ms`: Fail with a `[Timeout]` error if all bytes do not arrive in time
+  /// (0 = no timeout); bytes consumed before the timeout are lost
   /// - `callback`: Called with (success, data_ptr, data_len, error_ptr, error_len)
   /// - On success: callback(true, buf, exact_len, null, 0)
   /// - On error: callback(false, null, 0, error_ptr, error_len)
   void dart_quic_recv_stream_read_exact(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicFfiStreamHandle> handle,
+    int handle,
     int exact_len,
+    int timeout_ms,
     BytesCallback callback,
   ) {
     return _dart_quic_recv_stream_read_exact(
       executor,
       handle,
       exact_len,
+      timeout_ms,
       callback,
     );
   }
@@ -356,8 +571,9 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicFfiStreamHandle>,
+            ffi.Uint64,
             ffi.UintPtr,
+            ffi.Uint64,
             BytesCallback,
           )
         >
@@ -367,7 +583,8 @@ class QuicFFIBindings {
           .asFunction<
             void Function(
               ffi.Pointer<QuicExecutor>,
-              ffi.Pointer<QuicFfiStreamHandle>,
+              int,
+              int,
               int,
               BytesCallback,
             )
@@ -381,19 +598,23 @@ class QuicFFIBindings {
   /// - `executor`: QuicExecutor for async execution
   /// - `handle`: Stream handle (must be of type Recv)
   /// - `size_limit`: Maximum bytes to read (prevents memory exhaustion)
+  /// - `timeout_ms`: Fail with a `[Timeout]` error if the stream does not finish in time
+  /// (0 = no timeout); data consumed before the timeout is lost
   /// - `callback`: Called with (success, data_ptr, data_len, error_ptr, error_len)
   /// - On success: callback(true, buf, total_bytes, null, 0)
   /// - On error: callback(false, null, 0, error_ptr, error_len)
   void dart_quic_recv_stream_read_to_end(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicFfiStreamHandle> handle,
+    int handle,
     int size_limit,
+    int timeout_ms,
     BytesCallback callback,
   ) {
     return _dart_quic_recv_stream_read_to_end(
       executor,
       handle,
       size_limit,
+      timeout_ms,
       callback,
     );
   }
@@ -403,8 +624,9 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicFfiStreamHandle>,
+            ffi.Uint64,
             ffi.UintPtr,
+            ffi.Uint64,
             BytesCallback,
           )
         >
@@ -414,7 +636,8 @@ class QuicFFIBindings {
           .asFunction<
             void Function(
               ffi.Pointer<QuicExecutor>,
-              ffi.Pointer<QuicFfiStreamHandle>,
+              int,
+              int,
               int,
               BytesCallback,
             )
@@ -430,14 +653,17 @@ class QuicFFIBindings {
   /// - `handle`: Stream handle (must be of type Send)
   /// - `data`: Data to write
   /// - `data_len`: Data length
+  /// - `timeout_ms`: Fail with a `[Timeout]` error if flow control blocks the write for this
+  /// long (0 = no timeout)
   /// - `callback`: Called with (success, bytes_written, error_ptr, error_len)
   /// - On success: callback(true, bytes_written, null, 0)
   /// - On error: callback(false, 0, error_ptr, error_len)
   void dart_quic_send_stream_write(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicFfiStreamHandle> handle,
+    int handle,
     ffi.Pointer<ffi.Uint8> data,
     int data_len,
+    int timeout_ms,
     UsizeCallback callback,
   ) {
     return _dart_quic_send_stream_write(
@@ -445,6 +671,7 @@ class QuicFFIBindings {
       handle,
       data,
       data_len,
+      timeout_ms,
       callback,
     );
   }
@@ -454,9 +681,10 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicFfiStreamHandle>,
+            ffi.Uint64,
             ffi.Pointer<ffi.Uint8>,
             ffi.UintPtr,
+            ffi.Uint64,
             UsizeCallback,
           )
         >
@@ -465,9 +693,10 @@ class QuicFFIBindings {
       .asFunction<
         void Function(
           ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicFfiStreamHandle>,
+          int,
           ffi.Pointer<ffi.Uint8>,
           int,
+          int,
           UsizeCallback,
         )
       >();
@@ -481,14 +710,17 @@ class QuicFFIBindings {
   /// - `handle`: Stream handle (must be of type Send)
   /// - `data`: Data to write
   /// - `data_len`: Data length
+  /// - `timeout_ms`: Fail with a `[Timeout]` error if not all data is written in time
+  /// (0 = no timeout); a prefix of the data may already have been written
   /// - `callback`: Called with (success, error_ptr, error_len)
   /// - On success: callback(true, null, 0)
   /// - On error: callback(false, error_ptr, error_len)
   void dart_quic_send_stream_write_all(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicFfiStreamHandle> handle,
+    int handle,
     ffi.Pointer<ffi.Uint8> data,
     int data_len,
+    int timeout_ms,
     VoidCallback callback,
   ) {
     return _dart_quic_send_stream_write_all(
@@ -496,6 +728,7 @@ class QuicFFIBindings {
       handle,
       data,
       data_len,
+      timeout_ms,
       callback,
     );
   }
@@ -505,9 +738,10 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicFfiStreamHandle>,
+            ffi.Uint64,
             ffi.Pointer<ffi.Uint8>,
             ffi.UintPtr,
+            ffi.Uint64,
             VoidCallback,
           )
         >
@@ -517,9 +751,10 @@ class QuicFFIBindings {
           .asFunction<
             void Function(
               ffi.Pointer<QuicExecutor>,
-              ffi.Pointer<QuicFfiStreamHandle>,
+              int,
               ffi.Pointer<ffi.Uint8>,
               int,
+              int,
               VoidCallback,
             )
           >();
@@ -534,16 +769,187 @@ class QuicFFIBindings {
   /// # Returns
   /// - 0 (Success) on success
   /// - Error code on failure
-  int dart_quic_send_stream_finish(ffi.Pointer<QuicFfiStreamHandle> handle) {
+  int dart_quic_send_stream_finish(int handle) {
     return _dart_quic_send_stream_finish(handle);
   }
 
   late final _dart_quic_send_stream_finishPtr =
-      _lookup<
-        ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<QuicFfiStreamHandle>)>
-      >('dart_quic_send_stream_finish');
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64)>>(
+        'dart_quic_send_stream_finish',
+      );
   late final _dart_quic_send_stream_finish = _dart_quic_send_stream_finishPtr
-      .asFunction<int Function(ffi.Pointer<QuicFfiStreamHandle>)>();
+      .asFunction<int Function(int)>();
+
+  /// Wait until the peer has received all data or stopped the stream
+  ///
+  /// Typically called after `dart_quic_send_stream_finish` to confirm delivery.
+  /// Writes may continue while waiting.
+  ///
+  /// # Parameters
+  /// - `executor`: QuicExecutor for async execution
+  /// - `handle`: Stream handle (must be of type Send)
+  /// - `callback`: Called with (success, stopped_by_peer, error_ptr, error_len)
+  /// - All data acknowledged: callback(true, false, null, 0)
+  /// - Peer sent STOP_SENDING: callback(true, true, null, 0); the stop code is recorded
+  /// on the stream (see `dart_quic_stream_peer_error_code`)
+  /// - On error (connection lost, 0-RTT rejected): callback(false, false, error_ptr, error_len)
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_send_stream_stopped(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    BoolCallback callback,
+  ) {
+    return _dart_quic_send_stream_stopped(executor, handle, callback);
+  }
+
+  late final _dart_quic_send_stream_stoppedPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, BoolCallback)
+        >
+      >('dart_quic_send_stream_stopped');
+  late final _dart_quic_send_stream_stopped = _dart_quic_send_stream_stoppedPtr
+      .asFunction<
+        void Function(ffi.Pointer<QuicExecutor>, int, BoolCallback)
+      >();
+
+  /// Abruptly close the send stream with an application error code (sync)
+  ///
+  /// Sends RESET_STREAM to the peer; data not yet acknowledged is discarded.
+  /// Pending writes on this stream fail.
+  ///
+  /// # Parameters
+  /// - `handle`: Stream handle (must be of type Send)
+  /// - `error_code`: Application error code (must be < 2^62)
+  ///
+  /// # Returns
+  /// - 0 (Success) on success
+  /// - InvalidParameter if the handle is invalid or `error_code` is out of range
+  /// - StreamClosed if the stream was already finished or reset
+  int dart_quic_send_stream_reset(int handle, int error_code) {
+    return _dart_quic_send_stream_reset(handle, error_code);
+  }
+
+  late final _dart_quic_send_stream_resetPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64, ffi.Uint64)>>(
+        'dart_quic_send_stream_reset',
+      );
+  late final _dart_quic_send_stream_reset = _dart_quic_send_stream_resetPtr
+      .asFunction<int Function(int, int)>();
+
+  /// Ask the peer to stop sending on this stream with an application error code (sync)
+  ///
+  /// Sends STOP_SENDING to the peer and discards data that arrives afterwards.
+  /// Pending reads on this stream fail.
+  ///
+  /// # Parameters
+  /// - `handle`: Stream handle (must be of type Recv)
+  /// - `error_code`: Application error code (must be < 2^62)
+  ///
+  /// # Returns
+  /// - 0 (Success) on success
+  /// - InvalidParameter if the handle is invalid or `error_code` is out of range
+  /// - StreamClosed if the stream was already stopped
+  int dart_quic_recv_stream_stop(int handle, int error_code) {
+    return _dart_quic_recv_stream_stop(handle, error_code);
+  }
+
+  late final _dart_quic_recv_stream_stopPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64, ffi.Uint64)>>(
+        'dart_quic_recv_stream_stop',
+      );
+  late final _dart_quic_recv_stream_stop = _dart_quic_recv_stream_stopPtr
+      .asFunction<int Function(int, int)>();
+
+  /// Set the send priority of the stream (sync)
+  ///
+  /// Streams with higher priority are sent before lower-priority streams on the same
+  /// connection; streams of equal priority are interleaved round-robin. Default is 0.
+  ///
+  /// # Parameters
+  /// - `handle`: Stream handle (must be of type Send)
+  /// - `priority`: New priority (higher is sent first, may be negative)
+  ///
+  /// # Returns
+  /// - 0 (Success) on success
+  /// - InvalidParameter if the handle is invalid
+  /// - StreamClosed if the stream was already finished or reset
+  int dart_quic_send_stream_set_priority(int handle, int priority) {
+    return _dart_quic_send_stream_set_priority(handle, priority);
+  }
+
+  late final _dart_quic_send_stream_set_priorityPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64, ffi.Int32)>>(
+        'dart_quic_send_stream_set_priority',
+      );
+  late final _dart_quic_send_stream_set_priority =
+      _dart_quic_send_stream_set_priorityPtr
+          .asFunction<int Function(int, int)>();
+
+  /// Get the send priority of the stream (sync)
+  ///
+  /// # Parameters
+  /// - `handle`: Stream handle (must be of type Send)
+  /// - `out_priority`: Receives the current priority
+  ///
+  /// # Returns
+  /// - 0 (Success) on success
+  /// - InvalidParameter if the handle or `out_priority` is invalid
+  /// - StreamClosed if the stream was already finished or reset
+  ///
+  /// # Safety
+  /// `out_priority` must be a valid pointer.
+  int dart_quic_send_stream_priority(
+    int handle,
+    ffi.Pointer<ffi.Int32> out_priority,
+  ) {
+    return _dart_quic_send_stream_priority(handle, out_priority);
+  }
+
+  late final _dart_quic_send_stream_priorityPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(ffi.Uint64, ffi.Pointer<ffi.Int32>)
+        >
+      >('dart_quic_send_stream_priority');
+  late final _dart_quic_send_stream_priority =
+      _dart_quic_send_stream_priorityPtr
+          .asFunction<int Function(int, ffi.Pointer<ffi.Int32>)>();
+
+  /// Get the application error code the peer used to reset or stop this stream (sync)
+  ///
+  /// The code is recorded when a read fails with `StreamReset` (Recv handle) or a
+  /// write fails with `StreamStopped` (Send handle).
+  ///
+  /// # Parameters
+  /// - `handle`: Stream handle (Send or Recv)
+  /// - `out_code`: Receives the application error code
+  ///
+  /// # Returns
+  /// - 0 (Success) if a code was recorded
+  /// - NoMoreData if the peer has not reset or stopped the stream (yet)
+  /// - InvalidParameter if the handle is not a live stream or `out_code` is null
+  ///
+  /// # Safety
+  /// `out_code` must be a valid pointer.
+  int dart_quic_stream_peer_error_code(
+    int handle,
+    ffi.Pointer<ffi.Uint64> out_code,
+  ) {
+    return _dart_quic_stream_peer_error_code(handle, out_code);
+  }
+
+  late final _dart_quic_stream_peer_error_codePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(ffi.Uint64, ffi.Pointer<ffi.Uint64>)
+        >
+      >('dart_quic_stream_peer_error_code');
+  late final _dart_quic_stream_peer_error_code =
+      _dart_quic_stream_peer_error_codePtr
+          .asFunction<int Function(int, ffi.Pointer<ffi.Uint64>)>();
 
   /// Create a QUIC endpoint with specified configuration
   ///
@@ -551,10 +957,11 @@ class QuicFFIBindings {
   /// - `config`: Endpoint configuration (mode, bind IP, bind port)
   /// - `client_config`: Client config handle (nullable based on mode)
   /// - `server_config`: Server config handle (nullable based on mode)
-  /// - `result`: Output parameter for endpoint pointer or error
+  /// - `result`: Output parameter for endpoint handle or error
   ///
   /// # Returns
-  /// - 0 on success (endpoint pointer in result)
+  /// - 0 on success (`QuicEndpointHandle*` in result; free it with `dart_quic_endpoint_handle_free`
+  /// and the endpoint with `dart_quic_endpoint_free`)
   /// - Non-zero error code on failure
   ///
   /// # Mode Requirements
@@ -574,7 +981,7 @@ class QuicFFIBindings {
   /// - `result`: Result output structure
   ///
   /// # Returns
-  /// - 0 on success (result.data contains endpoint pointer)
+  /// - 0 on success (result.data contains a `QuicEndpointHandle*`)
   /// - Error code on failure (result.error contains error details)
   ///
   /// # Mode Requirements
@@ -621,43 +1028,62 @@ class QuicFFIBindings {
         )
       >();
 
+  /// Free an endpoint handle structure (does not free the endpoint)
+  ///
+  /// # Safety
+  /// - handle must be null or a pointer returned by dart_quic_endpoint_create, freed only once
+  void dart_quic_endpoint_handle_free(ffi.Pointer<QuicEndpointHandle> handle) {
+    return _dart_quic_endpoint_handle_free(handle);
+  }
+
+  late final _dart_quic_endpoint_handle_freePtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Void Function(ffi.Pointer<QuicEndpointHandle>)>
+      >('dart_quic_endpoint_handle_free');
+  late final _dart_quic_endpoint_handle_free =
+      _dart_quic_endpoint_handle_freePtr
+          .asFunction<void Function(ffi.Pointer<QuicEndpointHandle>)>();
+
   /// Free an endpoint and close all connections
   ///
   /// # Parameters
-  /// - `endpoint`: Endpoint pointer to free
+  /// - `endpoint`: Endpoint handle to free
   ///
-  /// # Safety
-  /// - endpoint must be a valid pointer created by dart_quic_endpoint_create
-  /// - Must not be used after this call
-  void dart_quic_endpoint_free(ffi.Pointer<QuicEndpoint> endpoint) {
+  /// # Returns
+  /// - 0 on success
+  /// - InvalidParameter if the handle is not a live endpoint (e.g. already freed)
+  int dart_quic_endpoint_free(int endpoint) {
     return _dart_quic_endpoint_free(endpoint);
   }
 
   late final _dart_quic_endpoint_freePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<QuicEndpoint>)>>(
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64)>>(
         'dart_quic_endpoint_free',
       );
   late final _dart_quic_endpoint_free = _dart_quic_endpoint_freePtr
-      .asFunction<void Function(ffi.Pointer<QuicEndpoint>)>();
+      .asFunction<int Function(int)>();
 
   /// Connect to a remote server (async)
   ///
   /// # Parameters
   /// - `executor`: Executor pointer for async operations
-  /// - `endpoint`: Endpoint pointer
+  /// - `endpoint`: Endpoint handle
   /// - `server_addr`: Server address string (e.g., "127.0.0.1:4433")
   /// - `server_name`: Server name for SNI (e.g., "localhost")
+  /// - `timeout_ms`: Abandon resolution + handshake after this long with a `[Timeout]` error
+  /// (0 = no timeout)
   /// - `callback`: Callback invoked with connection pointer (or 0 on error)
   ///
   /// # Safety
-  /// - All pointers must be valid
+  /// - executor must be valid
   /// - Endpoint must have client capability (ClientOnly or Bidirectional mode)
   /// - server_addr and server_name must be valid null-terminated C strings
   void dart_quic_endpoint_connect(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicEndpoint> endpoint,
+    int endpoint,
     ffi.Pointer<ffi.Int8> server_addr,
     ffi.Pointer<ffi.Int8> server_name,
+    int timeout_ms,
     UsizeCallback callback,
   ) {
     return _dart_quic_endpoint_connect(
@@ -665,6 +1091,7 @@ class QuicFFIBindings {
       endpoint,
       server_addr,
       server_name,
+      timeout_ms,
       callback,
     );
   }
@@ -674,9 +1101,10 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicEndpoint>,
+            ffi.Uint64,
             ffi.Pointer<ffi.Int8>,
             ffi.Pointer<ffi.Int8>,
+            ffi.Uint64,
             UsizeCallback,
           )
         >
@@ -685,18 +1113,74 @@ class QuicFFIBindings {
       .asFunction<
         void Function(
           ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicEndpoint>,
+          int,
           ffi.Pointer<ffi.Int8>,
           ffi.Pointer<ffi.Int8>,
+          int,
           UsizeCallback,
         )
       >();
 
+  /// Connect to a remote server with 0-RTT early data (async)
+  ///
+  /// Same semantics as `dart_quic_client_connect_0rtt`: `callback` receives the
+  /// `QuicConnectionHandle*` (immediately when early data is possible), and
+  /// `zero_rtt_callback` receives the `QuicZeroRttStatus` once the handshake completes.
+  ///
+  /// # Safety
+  /// - executor must be valid
+  /// - Endpoint must have client capability (ClientOnly or Bidirectional mode)
+  /// - server_addr and server_name must be valid null-terminated C strings
+  void dart_quic_endpoint_connect_0rtt(
+    ffi.Pointer<QuicExecutor> executor,
+    int endpoint,
+    ffi.Pointer<ffi.Int8> server_addr,
+    ffi.Pointer<ffi.Int8> server_name,
+    UsizeCallback callback,
+    UsizeCallback zero_rtt_callback,
+  ) {
+    return _dart_quic_endpoint_connect_0rtt(
+      executor,
+      endpoint,
+      server_addr,
+      server_name,
+      callback,
+      zero_rtt_callback,
+    );
+  }
+
+  late final _dart_quic_endpoint_connect_0rttPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Int8>,
+            ffi.Pointer<ffi.Int8>,
+            UsizeCallback,
+            UsizeCallback,
+          )
+        >
+      >('dart_quic_endpoint_connect_0rtt');
+  late final _dart_quic_endpoint_connect_0rtt =
+      _dart_quic_endpoint_connect_0rttPtr
+          .asFunction<
+            void Function(
+              ffi.Pointer<QuicExecutor>,
+              int,
+              ffi.Pointer<ffi.Int8>,
+              ffi.Pointer<ffi.Int8>,
+              UsizeCallback,
+              UsizeCallback,
+            )
+          >();
+
   /// Accept an incoming connection (async)
   ///
   /// # Parameters
   /// - `executor`: Executor pointer for async operations
-  /// - `endpoint`: Endpoint pointer
+  /// - `endpoint`: Endpoint handle
+  /// - `timeout_ms`: Fail with a `[Timeout]` error if no connection arrives in time (0 = no timeout)
   /// - `callback`: Callback invoked with connection pointer (or 0 on error/close)
   ///
   /// # Returns
@@ -704,14 +1188,15 @@ class QuicFFIBindings {
   /// - 0 if endpoint is closing or on error
   ///
   /// # Safety
-  /// - All pointers must be valid
+  /// - executor must be valid
   /// - Endpoint must have server capability (ServerOnly or Bidirectional mode)
   void dart_quic_endpoint_accept(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicEndpoint> endpoint,
+    int endpoint,
+    int timeout_ms,
     UsizeCallback callback,
   ) {
-    return _dart_quic_endpoint_accept(executor, endpoint, callback);
+    return _dart_quic_endpoint_accept(executor, endpoint, timeout_ms, callback);
   }
 
   late final _dart_quic_endpoint_acceptPtr =
@@ -719,133 +1204,249 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicEndpoint>,
+            ffi.Uint64,
+            ffi.Uint64,
             UsizeCallback,
           )
         >
       >('dart_quic_endpoint_accept');
   late final _dart_quic_endpoint_accept = _dart_quic_endpoint_acceptPtr
       .asFunction<
-        void Function(
-          ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicEndpoint>,
-          UsizeCallback,
-        )
+        void Function(ffi.Pointer<QuicExecutor>, int, int, UsizeCallback)
       >();
 
-  /// Get the local bound address of the endpoint
+  /// Accept an incoming connection with 0.5-RTT (async)
   ///
-  /// # Parameters
-  /// - `endpoint`: Endpoint pointer
-  /// - `out_ip`: Output buffer for IP address (network byte order)
-  /// - `out_port`: Output buffer for port (host byte order)
-  ///
-  /// # Returns
-  /// - 0 on success
-  /// - Non-zero error code on failure
+  /// Same semantics as `dart_quic_server_accept_0rtt`.
   ///
   /// # Safety
-  /// - endpoint must be a valid pointer
-  /// - out_ip and out_port must be valid pointers
-  int dart_quic_endpoint_local_addr(
-    ffi.Pointer<QuicEndpoint> endpoint,
-    ffi.Pointer<ffi.Uint32> out_ip,
-    ffi.Pointer<ffi.Uint16> out_port,
+  /// - executor must be valid
+  /// - Endpoint must have server capability (ServerOnly or Bidirectional mode)
+  void dart_quic_endpoint_accept_0rtt(
+    ffi.Pointer<QuicExecutor> executor,
+    int endpoint,
+    UsizeCallback callback,
+    UsizeCallback zero_rtt_callback,
   ) {
-    return _dart_quic_endpoint_local_addr(endpoint, out_ip, out_port);
+    return _dart_quic_endpoint_accept_0rtt(
+      executor,
+      endpoint,
+      callback,
+      zero_rtt_callback,
+    );
   }
 
-  late final _dart_quic_endpoint_local_addrPtr =
+  late final _dart_quic_endpoint_accept_0rttPtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Int32 Function(
-            ffi.Pointer<QuicEndpoint>,
-            ffi.Pointer<ffi.Uint32>,
-            ffi.Pointer<ffi.Uint16>,
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            UsizeCallback,
+            UsizeCallback,
+          )
+        >
+      >('dart_quic_endpoint_accept_0rtt');
+  late final _dart_quic_endpoint_accept_0rtt =
+      _dart_quic_endpoint_accept_0rttPtr
+          .asFunction<
+            void Function(
+              ffi.Pointer<QuicExecutor>,
+              int,
+              UsizeCallback,
+              UsizeCallback,
+            )
+          >();
+
+  /// Replace the endpoint's server configuration (sync, hot certificate reload)
+  ///
+  /// See `dart_quic_server_set_config`.
+  ///
+  /// # Returns
+  /// - 0 on success
+  /// - InvalidParameter if the handle is stale or the endpoint has no server capability
+  /// - Error code on failure (`result` carries the message; the current configuration stays)
+  ///
+  /// # Safety
+  /// `config` and `result` must be valid pointers.
+  int dart_quic_endpoint_set_server_config(
+    int endpoint,
+    ffi.Pointer<QuicFfiServerConfig> config,
+    ffi.Pointer<QuicFfiResult> result,
+  ) {
+    return _dart_quic_endpoint_set_server_config(endpoint, config, result);
+  }
+
+  late final _dart_quic_endpoint_set_server_configPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(
+            ffi.Uint64,
+            ffi.Pointer<QuicFfiServerConfig>,
+            ffi.Pointer<QuicFfiResult>,
+          )
+        >
+      >('dart_quic_endpoint_set_server_config');
+  late final _dart_quic_endpoint_set_server_config =
+      _dart_quic_endpoint_set_server_configPtr
+          .asFunction<
+            int Function(
+              int,
+              ffi.Pointer<QuicFfiServerConfig>,
+              ffi.Pointer<QuicFfiResult>,
+            )
+          >();
+
+  /// Reload the endpoint's server certificate whenever its PEM files change (async)
+  ///
+  /// See `dart_quic_server_watch_certs`. The watch ends with success once the endpoint
+  /// handle is released.
+  ///
+  /// # Safety
+  /// - executor must be valid
+  /// - Endpoint must have server capability (ServerOnly or Bidirectional mode)
+  int dart_quic_endpoint_watch_certs(
+    ffi.Pointer<QuicExecutor> executor,
+    int endpoint,
+    ffi.Pointer<QuicFfiServerConfig> config,
+    int interval_ms,
+    VoidCallback callback,
+  ) {
+    return _dart_quic_endpoint_watch_certs(
+      executor,
+      endpoint,
+      config,
+      interval_ms,
+      callback,
+    );
+  }
+
+  late final _dart_quic_endpoint_watch_certsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Uint64 Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Pointer<QuicFfiServerConfig>,
+            ffi.Uint64,
+            VoidCallback,
           )
         >
+      >('dart_quic_endpoint_watch_certs');
+  late final _dart_quic_endpoint_watch_certs =
+      _dart_quic_endpoint_watch_certsPtr
+          .asFunction<
+            int Function(
+              ffi.Pointer<QuicExecutor>,
+              int,
+              ffi.Pointer<QuicFfiServerConfig>,
+              int,
+              VoidCallback,
+            )
+          >();
+
+  /// Get the local bound address of the endpoint (IPv4 or IPv6)
+  ///
+  /// # Parameters
+  /// - `endpoint`: Endpoint handle
+  /// - `out_addr`: Receives the local address
+  ///
+  /// # Returns
+  /// - 0 on success
+  /// - Non-zero error code on failure
+  ///
+  /// # Safety
+  /// - endpoint must be a valid pointer
+  /// - out_addr must be a valid pointer
+  int dart_quic_endpoint_local_addr(
+    int endpoint,
+    ffi.Pointer<QuicFfiSocketAddr> out_addr,
+  ) {
+    return _dart_quic_endpoint_local_addr(endpoint, out_addr);
+  }
+
+  late final _dart_quic_endpoint_local_addrPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(ffi.Uint64, ffi.Pointer<QuicFfiSocketAddr>)
+        >
       >('dart_quic_endpoint_local_addr');
   late final _dart_quic_endpoint_local_addr = _dart_quic_endpoint_local_addrPtr
-      .asFunction<
-        int Function(
-          ffi.Pointer<QuicEndpoint>,
-          ffi.Pointer<ffi.Uint32>,
-          ffi.Pointer<ffi.Uint16>,
-        )
-      >();
+      .asFunction<int Function(int, ffi.Pointer<QuicFfiSocketAddr>)>();
 
   /// Get the number of currently open connections
   ///
   /// # Parameters
-  /// - `endpoint`: Endpoint pointer
+  /// - `endpoint`: Endpoint handle
   ///
   /// # Returns
   /// - Number of open connections
-  /// - 0 if endpoint is null
-  int dart_quic_endpoint_open_connections(ffi.Pointer<QuicEndpoint> endpoint) {
+  /// - 0 if the endpoint handle is invalid
+  int dart_quic_endpoint_open_connections(int endpoint) {
     return _dart_quic_endpoint_open_connections(endpoint);
   }
 
   late final _dart_quic_endpoint_open_connectionsPtr =
-      _lookup<
-        ffi.NativeFunction<ffi.UintPtr Function(ffi.Pointer<QuicEndpoint>)>
-      >('dart_quic_endpoint_open_connections');
+      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Uint64)>>(
+        'dart_quic_endpoint_open_connections',
+      );
   late final _dart_quic_endpoint_open_connections =
-      _dart_quic_endpoint_open_connectionsPtr
-          .asFunction<int Function(ffi.Pointer<QuicEndpoint>)>();
+      _dart_quic_endpoint_open_connectionsPtr.asFunction<int Function(int)>();
 
   /// Check if endpoint has client capability
   ///
   /// # Parameters
-  /// - `endpoint`: Endpoint pointer
+  /// - `endpoint`: Endpoint handle
   ///
   /// # Returns
   /// - 1 if endpoint can connect to remote servers
   /// - 0 otherwise
-  int dart_quic_endpoint_can_connect(ffi.Pointer<QuicEndpoint> endpoint) {
+  int dart_quic_endpoint_can_connect(int endpoint) {
     return _dart_quic_endpoint_can_connect(endpoint);
   }
 
   late final _dart_quic_endpoint_can_connectPtr =
-      _lookup<
-        ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<QuicEndpoint>)>
-      >('dart_quic_endpoint_can_connect');
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64)>>(
+        'dart_quic_endpoint_can_connect',
+      );
   late final _dart_quic_endpoint_can_connect =
-      _dart_quic_endpoint_can_connectPtr
-          .asFunction<int Function(ffi.Pointer<QuicEndpoint>)>();
+      _dart_quic_endpoint_can_connectPtr.asFunction<int Function(int)>();
 
   /// Check if endpoint has server capability
   ///
   /// # Parameters
-  /// - `endpoint`: Endpoint pointer
+  /// - `endpoint`: Endpoint handle
   ///
   /// # Returns
   /// - 1 if endpoint can accept incoming connections
   /// - 0 otherwise
-  int dart_quic_endpoint_can_accept(ffi.Pointer<QuicEndpoint> endpoint) {
+  int dart_quic_endpoint_can_accept(int endpoint) {
     return _dart_quic_endpoint_can_accept(endpoint);
   }
 
   late final _dart_quic_endpoint_can_acceptPtr =
-      _lookup<
-        ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<QuicEndpoint>)>
-      >('dart_quic_endpoint_can_accept');
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64)>>(
+        'dart_quic_endpoint_can_accept',
+      );
   late final _dart_quic_endpoint_can_accept = _dart_quic_endpoint_can_acceptPtr
-      .asFunction<int Function(ffi.Pointer<QuicEndpoint>)>();
+      .asFunction<int Function(int)>();
 
   /// Close the endpoint and all connections gracefully
   ///
   /// # Parameters
-  /// - `endpoint`: Endpoint pointer
+  /// - `endpoint`: Endpoint handle
   /// - `error_code`: Application error code
   /// - `reason`: Reason bytes (nullable)
   /// - `reason_len`: Length of reason bytes
   ///
+  /// # Returns
+  /// - 0 on success
+  /// - InvalidParameter if the endpoint handle is invalid
+  ///
   /// # Safety
-  /// - endpoint must be a valid pointer
   /// - reason must be valid for reason_len bytes if not null
-  void dart_quic_endpoint_close(
-    ffi.Pointer<QuicEndpoint> endpoint,
+  int dart_quic_endpoint_close(
+    int endpoint,
     int error_code,
     ffi.Pointer<ffi.Uint8> reason,
     int reason_len,
@@ -856,8 +1457,8 @@ class QuicFFIBindings {
   late final _dart_quic_endpoint_closePtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Pointer<QuicEndpoint>,
+          ffi.Int32 Function(
+            ffi.Uint64,
             ffi.Uint32,
             ffi.Pointer<ffi.Uint8>,
             ffi.UintPtr,
@@ -865,28 +1466,21 @@ class QuicFFIBindings {
         >
       >('dart_quic_endpoint_close');
   late final _dart_quic_endpoint_close = _dart_quic_endpoint_closePtr
-      .asFunction<
-        void Function(
-          ffi.Pointer<QuicEndpoint>,
-          int,
-          ffi.Pointer<ffi.Uint8>,
-          int,
-        )
-      >();
+      .asFunction<int Function(int, int, ffi.Pointer<ffi.Uint8>, int)>();
 
   /// Wait for all connections to close (async)
   ///
   /// # Parameters
   /// - `executor`: Executor pointer for async operations
-  /// - `endpoint`: Endpoint pointer
+  /// - `endpoint`: Endpoint handle
   /// - `callback`: Callback invoked when all connections are closed
   ///
   /// # Safety
-  /// - All pointers must be valid
+  /// - executor must be valid
   /// - Should be called after dart_quic_endpoint_close
   void dart_quic_endpoint_wait_idle(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicEndpoint> endpoint,
+    int endpoint,
     VoidCallback callback,
   ) {
     return _dart_quic_endpoint_wait_idle(executor, endpoint, callback);
@@ -895,22 +1489,85 @@ class QuicFFIBindings {
   late final _dart_quic_endpoint_wait_idlePtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicEndpoint>,
-            VoidCallback,
-          )
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, VoidCallback)
         >
       >('dart_quic_endpoint_wait_idle');
   late final _dart_quic_endpoint_wait_idle = _dart_quic_endpoint_wait_idlePtr
       .asFunction<
-        void Function(
-          ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicEndpoint>,
-          VoidCallback,
-        )
+        void Function(ffi.Pointer<QuicExecutor>, int, VoidCallback)
       >();
 
+  /// Get endpoint statistics (sync)
+  ///
+  /// # Parameters
+  /// - `endpoint`: Endpoint handle
+  /// - `out_stats`: Output structure (caller allocated)
+  ///
+  /// # Returns
+  /// - 0 (Success) on success
+  /// - InvalidParameter if the handle is invalid or `out_stats` is null
+  ///
+  /// # Safety
+  /// - out_stats must point to writable memory for a `QuicFfiEndpointStats`
+  int dart_quic_endpoint_stats(
+    int endpoint,
+    ffi.Pointer<QuicFfiEndpointStats> out_stats,
+  ) {
+    return _dart_quic_endpoint_stats(endpoint, out_stats);
+  }
+
+  late final _dart_quic_endpoint_statsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(ffi.Uint64, ffi.Pointer<QuicFfiEndpointStats>)
+        >
+      >('dart_quic_endpoint_stats');
+  late final _dart_quic_endpoint_stats = _dart_quic_endpoint_statsPtr
+      .asFunction<int Function(int, ffi.Pointer<QuicFfiEndpointStats>)>();
+
+  /// Enumerate stable IDs of live connections on the endpoint (sync)
+  ///
+  /// IDs match `QuicConnectionHandle.stable_id`. Only connections whose handle
+  /// has not been freed and which are not yet closed are listed.
+  ///
+  /// # Parameters
+  /// - `endpoint`: Endpoint handle
+  /// - `out_ids`: Receives pointer to `u64` array (NULL when empty)
+  /// - `out_count`: Receives number of IDs
+  ///
+  /// # Memory
+  /// Free the array with `dart_free_memory(ids, count * sizeof(uint64_t))`.
+  ///
+  /// # Safety
+  /// - out_ids and out_count must be valid pointers
+  int dart_quic_endpoint_connection_ids(
+    int endpoint,
+    ffi.Pointer<ffi.Pointer<ffi.Uint64>> out_ids,
+    ffi.Pointer<ffi.UintPtr> out_count,
+  ) {
+    return _dart_quic_endpoint_connection_ids(endpoint, out_ids, out_count);
+  }
+
+  late final _dart_quic_endpoint_connection_idsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(
+            ffi.Uint64,
+            ffi.Pointer<ffi.Pointer<ffi.Uint64>>,
+            ffi.Pointer<ffi.UintPtr>,
+          )
+        >
+      >('dart_quic_endpoint_connection_ids');
+  late final _dart_quic_endpoint_connection_ids =
+      _dart_quic_endpoint_connection_idsPtr
+          .asFunction<
+            int Function(
+              int,
+              ffi.Pointer<ffi.Pointer<ffi.Uint64>>,
+              ffi.Pointer<ffi.UintPtr>,
+            )
+          >();
+
   /// Create QUIC client asynchronously (required when tokio runtime is managed by executor)
   ///
   /// This function must be called after `dart_quic_executor_init` because it needs
@@ -919,7 +1576,8 @@ class QuicFFIBindings {
   /// # Safety
   /// The `config` pointer and all data it references must remain valid until the callback is invoked.
   ///
-  /// Returns error code. Callback receives client pointer (as usize) on success.
+  /// Returns error code. Callback receives a `QuicClientHandle*` pointer (as usize) on success;
+  /// free it with `dart_quic_client_handle_free` and the client with `dart_quic_client_free`.
   int dart_quic_client_new_async(
     ffi.Pointer<QuicExecutor> executor,
     ffi.Pointer<QuicFfiClientConfig> config,
@@ -947,22 +1605,36 @@ class QuicFFIBindings {
         )
       >();
 
+  /// Free a client handle structure (does not free the client)
+  void dart_quic_client_handle_free(ffi.Pointer<QuicClientHandle> handle) {
+    return _dart_quic_client_handle_free(handle);
+  }
+
+  late final _dart_quic_client_handle_freePtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Void Function(ffi.Pointer<QuicClientHandle>)>
+      >('dart_quic_client_handle_free');
+  late final _dart_quic_client_handle_free = _dart_quic_client_handle_freePtr
+      .asFunction<void Function(ffi.Pointer<QuicClientHandle>)>();
+
   /// Free client
-  void dart_quic_client_free(ffi.Pointer<QuicClient> client) {
+  ///
+  /// Returns InvalidParameter if the handle is not a live client (e.g. already freed).
+  int dart_quic_client_free(int client) {
     return _dart_quic_client_free(client);
   }
 
   late final _dart_quic_client_freePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<QuicClient>)>>(
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64)>>(
         'dart_quic_client_free',
       );
   late final _dart_quic_client_free = _dart_quic_client_freePtr
-      .asFunction<void Function(ffi.Pointer<QuicClient>)>();
+      .asFunction<int Function(int)>();
 
   /// Close client (sync)
   /// Returns error code
   int dart_quic_client_close(
-    ffi.Pointer<QuicClient> client,
+    int client,
     int error_code,
     ffi.Pointer<ffi.Uint8> reason,
     int reason_len,
@@ -974,7 +1646,7 @@ class QuicFFIBindings {
       _lookup<
         ffi.NativeFunction<
           ffi.Int32 Function(
-            ffi.Pointer<QuicClient>,
+            ffi.Uint64,
             ffi.Uint32,
             ffi.Pointer<ffi.Uint8>,
             ffi.UintPtr,
@@ -982,24 +1654,30 @@ class QuicFFIBindings {
         >
       >('dart_quic_client_close');
   late final _dart_quic_client_close = _dart_quic_client_closePtr
-      .asFunction<
-        int Function(ffi.Pointer<QuicClient>, int, ffi.Pointer<ffi.Uint8>, int)
-      >();
+      .asFunction<int Function(int, int, ffi.Pointer<ffi.Uint8>, int)>();
 
   /// Connect to server asynchronously
   ///
   /// Callback receives QuicConnectionHandle pointer on success.
   /// The handle contains:
-  /// - connection pointer (for subsequent operations)
+  /// - connection registry handle (for subsequent operations)
   /// - stable_id (connection ID)
   /// - remote_addr (remote address string)
   ///
-  /// Use `dart_quic_connection_handle_free` to free the handle.
+  /// `server_addr` is "host:port"; host names are resolved asynchronously. Resolution
+  /// failures are reported with a `[HostResolutionFailed]` error message.
+  ///
+  /// `timeout_ms` bounds resolution plus handshake; when exceeded the attempt is abandoned
+  /// and the callback fails with a `[Timeout]` error (0 = no timeout).
+  ///
+  /// Use `dart_quic_connection_handle_free` to free the handle structure and
+  /// `dart_quic_connection_free` to release the connection.
   void dart_quic_client_connect(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicClient> client,
+    int client,
     ffi.Pointer<ffi.Char> server_addr,
     ffi.Pointer<ffi.Char> server_name,
+    int timeout_ms,
     UsizeCallback callback,
   ) {
     return _dart_quic_client_connect(
@@ -1007,6 +1685,7 @@ class QuicFFIBindings {
       client,
       server_addr,
       server_name,
+      timeout_ms,
       callback,
     );
   }
@@ -1016,9 +1695,10 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicClient>,
+            ffi.Uint64,
             ffi.Pointer<ffi.Char>,
             ffi.Pointer<ffi.Char>,
+            ffi.Uint64,
             UsizeCallback,
           )
         >
@@ -1027,17 +1707,78 @@ class QuicFFIBindings {
       .asFunction<
         void Function(
           ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicClient>,
+          int,
+          ffi.Pointer<ffi.Char>,
+          ffi.Pointer<ffi.Char>,
+          int,
+          UsizeCallback,
+        )
+      >();
+
+  /// Connect to server with 0-RTT early data (async)
+  ///
+  /// If a resumable session exists and `enable_early_data` was set in the client config,
+  /// `callback` fires immediately with a usable `QuicConnectionHandle*`, before the handshake
+  /// completes. Otherwise it fires after the full handshake, like `dart_quic_client_connect`.
+  ///
+  /// `zero_rtt_callback` fires once the handshake completes with a `QuicZeroRttStatus` value:
+  /// - 0 (NotAttempted): no early data was sent
+  /// - 1 (Accepted): early data was accepted by the server
+  /// - 2 (Rejected): early data was rejected; streams opened before this point fail with `ZeroRttRejected`
+  ///
+  /// On error, including invalid arguments, both callbacks receive the same error message.
+  ///
+  /// ⚠️ Early data is replayable; only send idempotent requests before the handshake completes.
+  ///
+  /// # Safety
+  /// `executor` must be valid; `server_addr` and `server_name` must be null-terminated C strings.
+  void dart_quic_client_connect_0rtt(
+    ffi.Pointer<QuicExecutor> executor,
+    int client,
+    ffi.Pointer<ffi.Char> server_addr,
+    ffi.Pointer<ffi.Char> server_name,
+    UsizeCallback callback,
+    UsizeCallback zero_rtt_callback,
+  ) {
+    return _dart_quic_client_connect_0rtt(
+      executor,
+      client,
+      server_addr,
+      server_name,
+      callback,
+      zero_rtt_callback,
+    );
+  }
+
+  late final _dart_quic_client_connect_0rttPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Char>,
+            ffi.Pointer<ffi.Char>,
+            UsizeCallback,
+            UsizeCallback,
+          )
+        >
+      >('dart_quic_client_connect_0rtt');
+  late final _dart_quic_client_connect_0rtt = _dart_quic_client_connect_0rttPtr
+      .asFunction<
+        void Function(
+          ffi.Pointer<QuicExecutor>,
+          int,
           ffi.Pointer<ffi.Char>,
           ffi.Pointer<ffi.Char>,
           UsizeCallback,
+          UsizeCallback,
         )
       >();
 
   /// Wait for client to become idle
   void dart_quic_client_wait_idle(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicClient> client,
+    int client,
     VoidCallback callback,
   ) {
     return _dart_quic_client_wait_idle(executor, client, callback);
@@ -1046,28 +1787,79 @@ class QuicFFIBindings {
   late final _dart_quic_client_wait_idlePtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicClient>,
-            VoidCallback,
-          )
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, VoidCallback)
         >
       >('dart_quic_client_wait_idle');
   late final _dart_quic_client_wait_idle = _dart_quic_client_wait_idlePtr
       .asFunction<
-        void Function(
-          ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicClient>,
-          VoidCallback,
-        )
+        void Function(ffi.Pointer<QuicExecutor>, int, VoidCallback)
       >();
 
-  /// Free connection handle and its resources
+  /// Get client endpoint statistics (sync)
+  ///
+  /// See `dart_quic_endpoint_stats`.
+  ///
+  /// # Safety
+  /// out_stats must point to writable memory for a `QuicFfiEndpointStats`
+  int dart_quic_client_stats(
+    int client,
+    ffi.Pointer<QuicFfiEndpointStats> out_stats,
+  ) {
+    return _dart_quic_client_stats(client, out_stats);
+  }
+
+  late final _dart_quic_client_statsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(ffi.Uint64, ffi.Pointer<QuicFfiEndpointStats>)
+        >
+      >('dart_quic_client_stats');
+  late final _dart_quic_client_stats = _dart_quic_client_statsPtr
+      .asFunction<int Function(int, ffi.Pointer<QuicFfiEndpointStats>)>();
+
+  /// Enumerate stable IDs of live connections opened by the client (sync)
+  ///
+  /// See `dart_quic_endpoint_connection_ids`. Free the array with
+  /// `dart_free_memory(ids, count * sizeof(uint64_t))`.
+  ///
+  /// # Safety
+  /// out_ids and out_count must be valid pointers
+  int dart_quic_client_connection_ids(
+    int client,
+    ffi.Pointer<ffi.Pointer<ffi.Uint64>> out_ids,
+    ffi.Pointer<ffi.UintPtr> out_count,
+  ) {
+    return _dart_quic_client_connection_ids(client, out_ids, out_count);
+  }
+
+  late final _dart_quic_client_connection_idsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(
+            ffi.Uint64,
+            ffi.Pointer<ffi.Pointer<ffi.Uint64>>,
+            ffi.Pointer<ffi.UintPtr>,
+          )
+        >
+      >('dart_quic_client_connection_ids');
+  late final _dart_quic_client_connection_ids =
+      _dart_quic_client_connection_idsPtr
+          .asFunction<
+            int Function(
+              int,
+              ffi.Pointer<ffi.Pointer<ffi.Uint64>>,
+              ffi.Pointer<ffi.UintPtr>,
+            )
+          >();
+
+  /// Free a connection handle structure
   ///
   /// This frees:
-  /// - The connection itself
   /// - The remote address string
+  /// - The handshake data strings
   /// - The handle structure
+  ///
+  /// The connection itself stays open; release it with `dart_quic_connection_free`.
   void dart_quic_connection_handle_free(
     ffi.Pointer<QuicConnectionHandle> handle,
   ) {
@@ -1082,27 +1874,186 @@ class QuicFFIBindings {
       _dart_quic_connection_handle_freePtr
           .asFunction<void Function(ffi.Pointer<QuicConnectionHandle>)>();
 
-  /// Close connection (sync)
+  /// Release a connection
   ///
-  /// # Parameters
-  /// - `handle`: Connection handle
-  /// - `error_code`: Application error code
-  /// - `reason`: Close reason bytes (nullable)
-  /// - `reason_len`: Length of reason bytes
-  void dart_quic_connection_close(
-    ffi.Pointer<QuicConnectionHandle> handle,
-    int error_code,
-    ffi.Pointer<ffi.Uint8> reason,
-    int reason_len,
-  ) {
-    return _dart_quic_connection_close(handle, error_code, reason, reason_len);
+  /// The connection is dropped (and implicitly closed with code 0 once its streams are
+  /// gone) after in-flight operations on it complete.
+  ///
+  /// # Returns
+  /// - 0 (Success) on success
+  /// - InvalidParameter if the handle is not a live connection (e.g. already freed)
+  int dart_quic_connection_free(int connection) {
+    return _dart_quic_connection_free(connection);
   }
 
-  late final _dart_quic_connection_closePtr =
+  late final _dart_quic_connection_freePtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64)>>(
+        'dart_quic_connection_free',
+      );
+  late final _dart_quic_connection_free = _dart_quic_connection_freePtr
+      .asFunction<int Function(int)>();
+
+  /// Subscribe to the connection close event (async)
+  ///
+  /// `callback` fires exactly once, when the connection closes for any reason, with a
+  /// `QuicFfiCloseReason*` (free with `dart_quic_close_reason_free`). If the connection
+  /// is already closed it fires immediately.
+  ///
+  /// The subscription does not keep the connection alive: once the handle is freed and
+  /// in-flight operations finish, the connection closes and the callback reports
+  /// `LocallyClosed` with code 0.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_connection_on_closed(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    UsizeCallback callback,
+  ) {
+    return _dart_quic_connection_on_closed(executor, handle, callback);
+  }
+
+  late final _dart_quic_connection_on_closedPtr =
       _lookup<
         ffi.NativeFunction<
           ffi.Void Function(
-            ffi.Pointer<QuicConnectionHandle>,
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            UsizeCallback,
+          )
+        >
+      >('dart_quic_connection_on_closed');
+  late final _dart_quic_connection_on_closed =
+      _dart_quic_connection_on_closedPtr
+          .asFunction<
+            void Function(ffi.Pointer<QuicExecutor>, int, UsizeCallback)
+          >();
+
+  /// Get the close reason if the connection is already closed (sync)
+  ///
+  /// # Parameters
+  /// - `handle`: Connection handle
+  /// - `out_reason`: Receives a `QuicFfiCloseReason*` (free with `dart_quic_close_reason_free`),
+  /// or null while the connection is still open
+  ///
+  /// # Returns
+  /// - 0 (Success), InvalidParameter if the handle is stale or `out_reason` is null
+  ///
+  /// # Safety
+  /// `out_reason` must be a valid pointer.
+  int dart_quic_connection_close_reason(
+    int handle,
+    ffi.Pointer<ffi.Pointer<QuicFfiCloseReason>> out_reason,
+  ) {
+    return _dart_quic_connection_close_reason(handle, out_reason);
+  }
+
+  late final _dart_quic_connection_close_reasonPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(
+            ffi.Uint64,
+            ffi.Pointer<ffi.Pointer<QuicFfiCloseReason>>,
+          )
+        >
+      >('dart_quic_connection_close_reason');
+  late final _dart_quic_connection_close_reason =
+      _dart_quic_connection_close_reasonPtr
+          .asFunction<
+            int Function(int, ffi.Pointer<ffi.Pointer<QuicFfiCloseReason>>)
+          >();
+
+  /// Get the TLS handshake results of a connection (sync)
+  ///
+  /// Use this for connections whose handle was created before the handshake data was
+  /// available (0-RTT connects); otherwise `QuicConnectionHandle::handshake` has it already.
+  ///
+  /// # Parameters
+  /// - `handle`: Connection handle
+  /// - `out_data`: Receives a `QuicFfiHandshakeData*` (free with `dart_quic_handshake_data_free`),
+  /// or null while the handshake data is not available yet
+  ///
+  /// # Returns
+  /// - 0 (Success), InvalidParameter if the handle is stale or `out_data` is null
+  ///
+  /// # Safety
+  /// `out_data` must be a valid pointer.
+  int dart_quic_connection_handshake_data(
+    int handle,
+    ffi.Pointer<ffi.Pointer<QuicFfiHandshakeData>> out_data,
+  ) {
+    return _dart_quic_connection_handshake_data(handle, out_data);
+  }
+
+  late final _dart_quic_connection_handshake_dataPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(
+            ffi.Uint64,
+            ffi.Pointer<ffi.Pointer<QuicFfiHandshakeData>>,
+          )
+        >
+      >('dart_quic_connection_handshake_data');
+  late final _dart_quic_connection_handshake_data =
+      _dart_quic_connection_handshake_dataPtr
+          .asFunction<
+            int Function(int, ffi.Pointer<ffi.Pointer<QuicFfiHandshakeData>>)
+          >();
+
+  /// Free handshake data returned by `dart_quic_connection_handshake_data`
+  ///
+  /// # Safety
+  /// `data` must be null or a pointer returned by that function, freed only once.
+  void dart_quic_handshake_data_free(ffi.Pointer<QuicFfiHandshakeData> data) {
+    return _dart_quic_handshake_data_free(data);
+  }
+
+  late final _dart_quic_handshake_data_freePtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Void Function(ffi.Pointer<QuicFfiHandshakeData>)>
+      >('dart_quic_handshake_data_free');
+  late final _dart_quic_handshake_data_free = _dart_quic_handshake_data_freePtr
+      .asFunction<void Function(ffi.Pointer<QuicFfiHandshakeData>)>();
+
+  /// Free a close reason returned by `dart_quic_connection_on_closed` / `dart_quic_connection_close_reason`
+  ///
+  /// # Safety
+  /// `reason` must be null or a pointer returned by those functions, freed only once.
+  void dart_quic_close_reason_free(ffi.Pointer<QuicFfiCloseReason> reason) {
+    return _dart_quic_close_reason_free(reason);
+  }
+
+  late final _dart_quic_close_reason_freePtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Void Function(ffi.Pointer<QuicFfiCloseReason>)>
+      >('dart_quic_close_reason_free');
+  late final _dart_quic_close_reason_free = _dart_quic_close_reason_freePtr
+      .asFunction<void Function(ffi.Pointer<QuicFfiCloseReason>)>();
+
+  /// Close connection (sync)
+  ///
+  /// # Parameters
+  /// - `handle`: Connection handle
+  /// - `error_code`: Application error code
+  /// - `reason`: Close reason bytes (nullable)
+  /// - `reason_len`: Length of reason bytes
+  ///
+  /// # Returns
+  /// - 0 (Success), InvalidParameter if the handle is stale
+  int dart_quic_connection_close(
+    int handle,
+    int error_code,
+    ffi.Pointer<ffi.Uint8> reason,
+    int reason_len,
+  ) {
+    return _dart_quic_connection_close(handle, error_code, reason, reason_len);
+  }
+
+  late final _dart_quic_connection_closePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(
+            ffi.Uint64,
             ffi.Uint32,
             ffi.Pointer<ffi.Uint8>,
             ffi.UintPtr,
@@ -1110,14 +2061,7 @@ class QuicFFIBindings {
         >
       >('dart_quic_connection_close');
   late final _dart_quic_connection_close = _dart_quic_connection_closePtr
-      .asFunction<
-        void Function(
-          ffi.Pointer<QuicConnectionHandle>,
-          int,
-          ffi.Pointer<ffi.Uint8>,
-          int,
-        )
-      >();
+      .asFunction<int Function(int, int, ffi.Pointer<ffi.Uint8>, int)>();
 
   /// Open bidirectional stream
   ///
@@ -1126,13 +2070,23 @@ class QuicFFIBindings {
   /// # Parameters
   /// - `executor`: Executor for async operations
   /// - `handle`: Connection handle
+  /// - `priority`: Initial send priority (higher is sent first, default 0)
+  /// - `timeout_ms`: Fail with `[Timeout]` if no stream is available in time (0 = no timeout)
   /// - `callback`: Callback receiving stream pair pointer
   void dart_quic_connection_open_bi(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicConnectionHandle> handle,
+    int handle,
+    int priority,
+    int timeout_ms,
     UsizeCallback callback,
   ) {
-    return _dart_quic_connection_open_bi(executor, handle, callback);
+    return _dart_quic_connection_open_bi(
+      executor,
+      handle,
+      priority,
+      timeout_ms,
+      callback,
+    );
   }
 
   late final _dart_quic_connection_open_biPtr =
@@ -1140,29 +2094,37 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicConnectionHandle>,
+            ffi.Uint64,
+            ffi.Int32,
+            ffi.Uint64,
             UsizeCallback,
           )
         >
       >('dart_quic_connection_open_bi');
   late final _dart_quic_connection_open_bi = _dart_quic_connection_open_biPtr
       .asFunction<
-        void Function(
-          ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicConnectionHandle>,
-          UsizeCallback,
-        )
+        void Function(ffi.Pointer<QuicExecutor>, int, int, int, UsizeCallback)
       >();
 
   /// Open unidirectional stream (send only)
   ///
   /// Returns QuicFfiStreamPair structure pointer via UsizeCallback.
+  /// `priority` sets the initial send priority (higher is sent first, default 0).
+  /// `timeout_ms` fails the open with `[Timeout]` if exceeded (0 = no timeout).
   void dart_quic_connection_open_uni(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicConnectionHandle> handle,
+    int handle,
+    int priority,
+    int timeout_ms,
     UsizeCallback callback,
   ) {
-    return _dart_quic_connection_open_uni(executor, handle, callback);
+    return _dart_quic_connection_open_uni(
+      executor,
+      handle,
+      priority,
+      timeout_ms,
+      callback,
+    );
   }
 
   late final _dart_quic_connection_open_uniPtr =
@@ -1170,37 +2132,51 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicConnectionHandle>,
+            ffi.Uint64,
+            ffi.Int32,
+            ffi.Uint64,
             UsizeCallback,
           )
         >
       >('dart_quic_connection_open_uni');
   late final _dart_quic_connection_open_uni = _dart_quic_connection_open_uniPtr
       .asFunction<
-        void Function(
-          ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicConnectionHandle>,
-          UsizeCallback,
-        )
+        void Function(ffi.Pointer<QuicExecutor>, int, int, int, UsizeCallback)
       >();
 
   /// Accept bidirectional stream
   ///
   /// Returns QuicFfiStreamPair structure pointer via UsizeCallback.
-  void dart_quic_connection_accept_bi(
+  /// `timeout_ms` fails the accept with `[Timeout]` if exceeded (0 = no timeout).
+  /// If cancelled, the callback fails with an error starting with `[Cancelled]`.
+  ///
+  /// # Returns
+  /// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+  /// (the callback has then already been called with the error).
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  int dart_quic_connection_accept_bi(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicConnectionHandle> handle,
+    int handle,
+    int timeout_ms,
     UsizeCallback callback,
   ) {
-    return _dart_quic_connection_accept_bi(executor, handle, callback);
+    return _dart_quic_connection_accept_bi(
+      executor,
+      handle,
+      timeout_ms,
+      callback,
+    );
   }
 
   late final _dart_quic_connection_accept_biPtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
+          ffi.Uint64 Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicConnectionHandle>,
+            ffi.Uint64,
+            ffi.Uint64,
             UsizeCallback,
           )
         >
@@ -1208,22 +2184,25 @@ class QuicFFIBindings {
   late final _dart_quic_connection_accept_bi =
       _dart_quic_connection_accept_biPtr
           .asFunction<
-            void Function(
-              ffi.Pointer<QuicExecutor>,
-              ffi.Pointer<QuicConnectionHandle>,
-              UsizeCallback,
-            )
+            int Function(ffi.Pointer<QuicExecutor>, int, int, UsizeCallback)
           >();
 
   /// Accept unidirectional stream (recv only)
   ///
   /// Returns QuicFfiStreamPair structure pointer via UsizeCallback.
+  /// `timeout_ms` fails the accept with `[Timeout]` if exceeded (0 = no timeout).
   void dart_quic_connection_accept_uni(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicConnectionHandle> handle,
+    int handle,
+    int timeout_ms,
     UsizeCallback callback,
   ) {
-    return _dart_quic_connection_accept_uni(executor, handle, callback);
+    return _dart_quic_connection_accept_uni(
+      executor,
+      handle,
+      timeout_ms,
+      callback,
+    );
   }
 
   late final _dart_quic_connection_accept_uniPtr =
@@ -1231,7 +2210,8 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicConnectionHandle>,
+            ffi.Uint64,
+            ffi.Uint64,
             UsizeCallback,
           )
         >
@@ -1239,11 +2219,7 @@ class QuicFFIBindings {
   late final _dart_quic_connection_accept_uni =
       _dart_quic_connection_accept_uniPtr
           .asFunction<
-            void Function(
-              ffi.Pointer<QuicExecutor>,
-              ffi.Pointer<QuicConnectionHandle>,
-              UsizeCallback,
-            )
+            void Function(ffi.Pointer<QuicExecutor>, int, int, UsizeCallback)
           >();
 
   /// Send datagram (sync, unreliable)
@@ -1257,7 +2233,7 @@ class QuicFFIBindings {
   /// - QuicResult::Success on success
   /// - Error code on failure
   int dart_quic_connection_send_datagram(
-    ffi.Pointer<QuicConnectionHandle> handle,
+    int handle,
     ffi.Pointer<ffi.Uint8> data,
     int data_len,
   ) {
@@ -1267,22 +2243,12 @@ class QuicFFIBindings {
   late final _dart_quic_connection_send_datagramPtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Int32 Function(
-            ffi.Pointer<QuicConnectionHandle>,
-            ffi.Pointer<ffi.Uint8>,
-            ffi.UintPtr,
-          )
+          ffi.Int32 Function(ffi.Uint64, ffi.Pointer<ffi.Uint8>, ffi.UintPtr)
         >
       >('dart_quic_connection_send_datagram');
   late final _dart_quic_connection_send_datagram =
       _dart_quic_connection_send_datagramPtr
-          .asFunction<
-            int Function(
-              ffi.Pointer<QuicConnectionHandle>,
-              ffi.Pointer<ffi.Uint8>,
-              int,
-            )
-          >();
+          .asFunction<int Function(int, ffi.Pointer<ffi.Uint8>, int)>();
 
   /// Read datagram (async)
   ///
@@ -1292,7 +2258,7 @@ class QuicFFIBindings {
   /// - `callback`: Callback receiving datagram data
   void dart_quic_connection_read_datagram(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicConnectionHandle> handle,
+    int handle,
     BytesCallback callback,
   ) {
     return _dart_quic_connection_read_datagram(executor, handle, callback);
@@ -1303,7 +2269,7 @@ class QuicFFIBindings {
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicConnectionHandle>,
+            ffi.Uint64,
             BytesCallback,
           )
         >
@@ -1311,15 +2277,41 @@ class QuicFFIBindings {
   late final _dart_quic_connection_read_datagram =
       _dart_quic_connection_read_datagramPtr
           .asFunction<
-            void Function(
-              ffi.Pointer<QuicExecutor>,
-              ffi.Pointer<QuicConnectionHandle>,
-              BytesCallback,
-            )
+            void Function(ffi.Pointer<QuicExecutor>, int, BytesCallback)
           >();
 
+  /// Get connection statistics snapshot (sync)
+  ///
+  /// Fills `out_stats` from `quinn::Connection::stats()`.
+  ///
+  /// # Parameters
+  /// - `handle`: Connection handle
+  /// - `out_stats`: Output structure (caller allocated)
+  ///
+  /// # Returns
+  /// - QuicResult::Success on success
+  /// - QuicResult::InvalidParameter if the handle is stale or `out_stats` is null
+  ///
+  /// # Safety
+  /// `out_stats` must point to writable memory for a `QuicFfiConnectionStats`
+  int dart_quic_connection_stats(
+    int handle,
+    ffi.Pointer<QuicFfiConnectionStats> out_stats,
+  ) {
+    return _dart_quic_connection_stats(handle, out_stats);
+  }
+
+  late final _dart_quic_connection_statsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(ffi.Uint64, ffi.Pointer<QuicFfiConnectionStats>)
+        >
+      >('dart_quic_connection_stats');
+  late final _dart_quic_connection_stats = _dart_quic_connection_statsPtr
+      .asFunction<int Function(int, ffi.Pointer<QuicFfiConnectionStats>)>();
+
   /// Create server with self-signed certificate (testing only!)
-  /// Returns error code, result written to `result` parameter
+  /// Returns error code, result written to `result` parameter (`QuicServerHandle*` on success)
   int dart_quic_server_new_self_signed(
     ffi.Pointer<ffi.Char> bind_addr,
     ffi.Pointer<ffi.Pointer<ffi.Char>> san_list,
@@ -1361,7 +2353,7 @@ class QuicFFIBindings {
           >();
 
   /// Create server with PEM certificate files
-  /// Returns error code, result written to `result` parameter
+  /// Returns error code, result written to `result` parameter (`QuicServerHandle*` on success)
   int dart_quic_server_new_with_cert_files(
     ffi.Pointer<ffi.Char> bind_addr,
     ffi.Pointer<ffi.Char> cert_path,
@@ -1403,20 +2395,23 @@ class QuicFFIBindings {
           >();
 
   /// Free server
-  void dart_quic_server_free(ffi.Pointer<QuicServer> server) {
+  ///
+  /// Returns InvalidParameter if the handle is not a live server (e.g. already freed).
+  int dart_quic_server_free(int server) {
     return _dart_quic_server_free(server);
   }
 
   late final _dart_quic_server_freePtr =
-      _lookup<ffi.NativeFunction<ffi.Void Function(ffi.Pointer<QuicServer>)>>(
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Uint64)>>(
         'dart_quic_server_free',
       );
   late final _dart_quic_server_free = _dart_quic_server_freePtr
-      .asFunction<void Function(ffi.Pointer<QuicServer>)>();
+      .asFunction<int Function(int)>();
 
   /// Close server
-  void dart_quic_server_close(
-    ffi.Pointer<QuicServer> server,
+  /// Returns error code
+  int dart_quic_server_close(
+    int server,
     int error_code,
     ffi.Pointer<ffi.Uint8> reason,
     int reason_len,
@@ -1427,8 +2422,8 @@ class QuicFFIBindings {
   late final _dart_quic_server_closePtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
-            ffi.Pointer<QuicServer>,
+          ffi.Int32 Function(
+            ffi.Uint64,
             ffi.Uint32,
             ffi.Pointer<ffi.Uint8>,
             ffi.UintPtr,
@@ -1436,13 +2431,11 @@ class QuicFFIBindings {
         >
       >('dart_quic_server_close');
   late final _dart_quic_server_close = _dart_quic_server_closePtr
-      .asFunction<
-        void Function(ffi.Pointer<QuicServer>, int, ffi.Pointer<ffi.Uint8>, int)
-      >();
+      .asFunction<int Function(int, int, ffi.Pointer<ffi.Uint8>, int)>();
 
   /// Get server local address
   bool dart_quic_server_local_addr(
-    ffi.Pointer<QuicServer> server,
+    int server,
     ffi.Pointer<ffi.Pointer<ffi.Uint8>> addr_out,
     ffi.Pointer<ffi.UintPtr> len_out,
   ) {
@@ -1453,7 +2446,7 @@ class QuicFFIBindings {
       _lookup<
         ffi.NativeFunction<
           ffi.Bool Function(
-            ffi.Pointer<QuicServer>,
+            ffi.Uint64,
             ffi.Pointer<ffi.Pointer<ffi.Uint8>>,
             ffi.Pointer<ffi.UintPtr>,
           )
@@ -1462,7 +2455,7 @@ class QuicFFIBindings {
   late final _dart_quic_server_local_addr = _dart_quic_server_local_addrPtr
       .asFunction<
         bool Function(
-          ffi.Pointer<QuicServer>,
+          int,
           ffi.Pointer<ffi.Pointer<ffi.Uint8>>,
           ffi.Pointer<ffi.UintPtr>,
         )
@@ -1472,34 +2465,184 @@ class QuicFFIBindings {
   ///
   /// Callback receives QuicConnectionHandle pointer on success.
   /// The handle contains:
-  /// - connection pointer (for subsequent operations)
+  /// - connection registry handle (for subsequent operations)
   /// - stable_id (connection ID)
   /// - remote_addr (remote address string)
   ///
-  /// Use `dart_quic_connection_handle_free` to free the handle.
-  void dart_quic_server_accept(
+  /// Use `dart_quic_connection_handle_free` to free the handle structure and
+  /// `dart_quic_connection_free` to release the connection.
+  /// `timeout_ms` fails the accept with `[Timeout]` if no connection arrives in time
+  /// (0 = no timeout). If cancelled, the callback fails with an error starting with `[Cancelled]`.
+  ///
+  /// # Returns
+  /// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+  /// (the callback has then already been called with the error).
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  int dart_quic_server_accept(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicServer> server,
+    int server,
+    int timeout_ms,
     UsizeCallback callback,
   ) {
-    return _dart_quic_server_accept(executor, server, callback);
+    return _dart_quic_server_accept(executor, server, timeout_ms, callback);
   }
 
   late final _dart_quic_server_acceptPtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Void Function(
+          ffi.Uint64 Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicServer>,
+            ffi.Uint64,
+            ffi.Uint64,
             UsizeCallback,
           )
         >
       >('dart_quic_server_accept');
   late final _dart_quic_server_accept = _dart_quic_server_acceptPtr
+      .asFunction<
+        int Function(ffi.Pointer<QuicExecutor>, int, int, UsizeCallback)
+      >();
+
+  /// Accept incoming connection with 0.5-RTT (async)
+  ///
+  /// `callback` fires with a `QuicConnectionHandle*` as soon as the handshake starts, so
+  /// the server can send data before the client completes the handshake.
+  /// `callback` receives value=0 when the server is closed.
+  ///
+  /// `zero_rtt_callback` fires once the handshake completes with a `QuicZeroRttStatus` value
+  /// (always 1 = Accepted for incoming connections, 0 if the server closed).
+  ///
+  /// ⚠️ 0.5-RTT data is sent before client authentication completes.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_server_accept_0rtt(
+    ffi.Pointer<QuicExecutor> executor,
+    int server,
+    UsizeCallback callback,
+    UsizeCallback zero_rtt_callback,
+  ) {
+    return _dart_quic_server_accept_0rtt(
+      executor,
+      server,
+      callback,
+      zero_rtt_callback,
+    );
+  }
+
+  late final _dart_quic_server_accept_0rttPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            UsizeCallback,
+            UsizeCallback,
+          )
+        >
+      >('dart_quic_server_accept_0rtt');
+  late final _dart_quic_server_accept_0rtt = _dart_quic_server_accept_0rttPtr
       .asFunction<
         void Function(
           ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicServer>,
+          int,
+          UsizeCallback,
+          UsizeCallback,
+        )
+      >();
+
+  /// Register a connection queue for an ALPN protocol (sync)
+  ///
+  /// Connections are then accepted per protocol with `dart_quic_server_accept_alpn`
+  /// instead of `dart_quic_server_accept`. Registering twice is a no-op.
+  ///
+  /// The first call starts a background task that routes incoming connections to their
+  /// queues until the server is closed or freed.
+  ///
+  /// # Parameters
+  /// - `server`: Server handle
+  /// - `alpn`, `alpn_len`: Protocol identifier bytes (e.g. "h3"); null or empty registers
+  /// the fallback queue, which receives connections whose protocol has no queue
+  ///
+  /// # Returns
+  /// - 0 (Success), InvalidParameter if the handle is stale
+  /// - RuntimeError if the server was not created on a runtime
+  ///
+  /// # Safety
+  /// `alpn` must be null or valid for `alpn_len` bytes.
+  int dart_quic_server_route_alpn(
+    int server,
+    ffi.Pointer<ffi.Uint8> alpn,
+    int alpn_len,
+  ) {
+    return _dart_quic_server_route_alpn(server, alpn, alpn_len);
+  }
+
+  late final _dart_quic_server_route_alpnPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(ffi.Uint64, ffi.Pointer<ffi.Uint8>, ffi.UintPtr)
+        >
+      >('dart_quic_server_route_alpn');
+  late final _dart_quic_server_route_alpn = _dart_quic_server_route_alpnPtr
+      .asFunction<int Function(int, ffi.Pointer<ffi.Uint8>, int)>();
+
+  /// Accept the next connection that negotiated an ALPN protocol (async)
+  ///
+  /// Same callback semantics as `dart_quic_server_accept`, for connections routed to the
+  /// queue registered with `dart_quic_server_route_alpn` (null or empty `alpn` = fallback).
+  /// Fails with InvalidParameter if no queue is registered for `alpn`.
+  ///
+  /// Only reads the queue: cancelling or timing out does not drop connections routed to
+  /// other protocols. Connections with no matching queue and no fallback are closed.
+  ///
+  /// # Returns
+  /// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+  /// (the callback has then already been called with the error).
+  ///
+  /// # Safety
+  /// `executor` must be valid; `alpn` must be null or valid for `alpn_len` bytes.
+  int dart_quic_server_accept_alpn(
+    ffi.Pointer<QuicExecutor> executor,
+    int server,
+    ffi.Pointer<ffi.Uint8> alpn,
+    int alpn_len,
+    int timeout_ms,
+    UsizeCallback callback,
+  ) {
+    return _dart_quic_server_accept_alpn(
+      executor,
+      server,
+      alpn,
+      alpn_len,
+      timeout_ms,
+      callback,
+    );
+  }
+
+  late final _dart_quic_server_accept_alpnPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Uint64 Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Uint8>,
+            ffi.UintPtr,
+            ffi.Uint64,
+            UsizeCallback,
+          )
+        >
+      >('dart_quic_server_accept_alpn');
+  late final _dart_quic_server_accept_alpn = _dart_quic_server_accept_alpnPtr
+      .asFunction<
+        int Function(
+          ffi.Pointer<QuicExecutor>,
+          int,
+          ffi.Pointer<ffi.Uint8>,
+          int,
+          int,
           UsizeCallback,
         )
       >();
@@ -1510,125 +2653,1302 @@ class QuicFFIBindings {
   /// Should typically be called after `dart_quic_server_close` for a graceful shutdown.
   ///
   /// # Safety
-  /// All pointers must be valid.
+  /// `executor` must be valid.
   void dart_quic_server_wait_idle(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<QuicServer> server,
+    int server,
     VoidCallback callback,
   ) {
     return _dart_quic_server_wait_idle(executor, server, callback);
   }
 
-  late final _dart_quic_server_wait_idlePtr =
+  late final _dart_quic_server_wait_idlePtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, VoidCallback)
+        >
+      >('dart_quic_server_wait_idle');
+  late final _dart_quic_server_wait_idle = _dart_quic_server_wait_idlePtr
+      .asFunction<
+        void Function(ffi.Pointer<QuicExecutor>, int, VoidCallback)
+      >();
+
+  /// Get the number of currently open connections on the server
+  ///
+  /// Returns 0 if the server handle is invalid.
+  int dart_quic_server_open_connections(int server) {
+    return _dart_quic_server_open_connections(server);
+  }
+
+  late final _dart_quic_server_open_connectionsPtr =
+      _lookup<ffi.NativeFunction<ffi.UintPtr Function(ffi.Uint64)>>(
+        'dart_quic_server_open_connections',
+      );
+  late final _dart_quic_server_open_connections =
+      _dart_quic_server_open_connectionsPtr.asFunction<int Function(int)>();
+
+  /// Get server local port
+  ///
+  /// Returns 0 if the server handle is invalid.
+  int dart_quic_server_local_port(int server) {
+    return _dart_quic_server_local_port(server);
+  }
+
+  late final _dart_quic_server_local_portPtr =
+      _lookup<ffi.NativeFunction<ffi.Uint16 Function(ffi.Uint64)>>(
+        'dart_quic_server_local_port',
+      );
+  late final _dart_quic_server_local_port = _dart_quic_server_local_portPtr
+      .asFunction<int Function(int)>();
+
+  /// Create a QUIC server asynchronously using unified FFI configuration
+  ///
+  /// Must be called after `dart_quic_executor_init` because Quinn requires
+  /// an active tokio runtime context when creating the endpoint.
+  ///
+  /// On success, the callback receives a `QuicServerHandle*` pointer (as usize).
+  /// The handle is allocated and owned by Rust; free it with `dart_quic_server_handle_free`
+  /// and the server with `dart_quic_server_free`.
+  ///
+  /// # Safety
+  /// - `config` and all data it references must remain valid until the callback fires
+  ///
+  /// # Parameters
+  /// - `executor`: Running QuicExecutor (must not be null)
+  /// - `bind_addr`: Address to bind, e.g. "0.0.0.0:4433"
+  /// - `config`: Pointer to FFI server configuration (must not be null)
+  /// - `callback`: UsizeCallback receiving QuicServerHandle* pointer on success
+  int dart_quic_server_new_async(
+    ffi.Pointer<QuicExecutor> executor,
+    ffi.Pointer<ffi.Char> bind_addr,
+    ffi.Pointer<QuicFfiServerConfig> config,
+    UsizeCallback callback,
+  ) {
+    return _dart_quic_server_new_async(executor, bind_addr, config, callback);
+  }
+
+  late final _dart_quic_server_new_asyncPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Pointer<ffi.Char>,
+            ffi.Pointer<QuicFfiServerConfig>,
+            UsizeCallback,
+          )
+        >
+      >('dart_quic_server_new_async');
+  late final _dart_quic_server_new_async = _dart_quic_server_new_asyncPtr
+      .asFunction<
+        int Function(
+          ffi.Pointer<QuicExecutor>,
+          ffi.Pointer<ffi.Char>,
+          ffi.Pointer<QuicFfiServerConfig>,
+          UsizeCallback,
+        )
+      >();
+
+  /// Free a server handle structure
+  ///
+  /// This frees:
+  /// - The local_addr_ptr string
+  /// - The handle structure
+  ///
+  /// The server itself stays open; release it with `dart_quic_server_free`.
+  void dart_quic_server_handle_free(ffi.Pointer<QuicServerHandle> handle) {
+    return _dart_quic_server_handle_free(handle);
+  }
+
+  late final _dart_quic_server_handle_freePtr =
+      _lookup<
+        ffi.NativeFunction<ffi.Void Function(ffi.Pointer<QuicServerHandle>)>
+      >('dart_quic_server_handle_free');
+  late final _dart_quic_server_handle_free = _dart_quic_server_handle_freePtr
+      .asFunction<void Function(ffi.Pointer<QuicServerHandle>)>();
+
+  /// Get server endpoint statistics (sync)
+  ///
+  /// See `dart_quic_endpoint_stats`.
+  ///
+  /// # Safety
+  /// out_stats must point to writable memory for a `QuicFfiEndpointStats`
+  int dart_quic_server_stats(
+    int server,
+    ffi.Pointer<QuicFfiEndpointStats> out_stats,
+  ) {
+    return _dart_quic_server_stats(server, out_stats);
+  }
+
+  late final _dart_quic_server_statsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(ffi.Uint64, ffi.Pointer<QuicFfiEndpointStats>)
+        >
+      >('dart_quic_server_stats');
+  late final _dart_quic_server_stats = _dart_quic_server_statsPtr
+      .asFunction<int Function(int, ffi.Pointer<QuicFfiEndpointStats>)>();
+
+  /// Enumerate stable IDs of live connections accepted by the server (sync)
+  ///
+  /// See `dart_quic_endpoint_connection_ids`. Free the array with
+  /// `dart_free_memory(ids, count * sizeof(uint64_t))`.
+  ///
+  /// # Safety
+  /// out_ids and out_count must be valid pointers
+  int dart_quic_server_connection_ids(
+    int server,
+    ffi.Pointer<ffi.Pointer<ffi.Uint64>> out_ids,
+    ffi.Pointer<ffi.UintPtr> out_count,
+  ) {
+    return _dart_quic_server_connection_ids(server, out_ids, out_count);
+  }
+
+  late final _dart_quic_server_connection_idsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(
+            ffi.Uint64,
+            ffi.Pointer<ffi.Pointer<ffi.Uint64>>,
+            ffi.Pointer<ffi.UintPtr>,
+          )
+        >
+      >('dart_quic_server_connection_ids');
+  late final _dart_quic_server_connection_ids =
+      _dart_quic_server_connection_idsPtr
+          .asFunction<
+            int Function(
+              int,
+              ffi.Pointer<ffi.Pointer<ffi.Uint64>>,
+              ffi.Pointer<ffi.UintPtr>,
+            )
+          >();
+
+  /// Replace the server certificate (sync, hot reload)
+  ///
+  /// Builds a new server configuration from `config`, exactly as `dart_quic_server_new_async`
+  /// would (certificate from PEM files, DER memory or self-signed per `cert_mode`, plus SNI
+  /// certificates, client authentication, transport and 0-RTT settings), and applies it to
+  /// new connections. Established connections keep their certificate.
+  ///
+  /// # Returns
+  /// - 0 on success
+  /// - InvalidParameter if the handle is stale
+  /// - Error code on failure (`result` carries the message; the current configuration stays)
+  ///
+  /// # Safety
+  /// `config` and `result` must be valid pointers.
+  int dart_quic_server_set_config(
+    int server,
+    ffi.Pointer<QuicFfiServerConfig> config,
+    ffi.Pointer<QuicFfiResult> result,
+  ) {
+    return _dart_quic_server_set_config(server, config, result);
+  }
+
+  late final _dart_quic_server_set_configPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Int32 Function(
+            ffi.Uint64,
+            ffi.Pointer<QuicFfiServerConfig>,
+            ffi.Pointer<QuicFfiResult>,
+          )
+        >
+      >('dart_quic_server_set_config');
+  late final _dart_quic_server_set_config = _dart_quic_server_set_configPtr
+      .asFunction<
+        int Function(
+          int,
+          ffi.Pointer<QuicFfiServerConfig>,
+          ffi.Pointer<QuicFfiResult>,
+        )
+      >();
+
+  /// Reload the server certificate whenever its PEM files change (async)
+  ///
+  /// Watches the certificate and key files of `config` (`cert_mode` = 0 and file-based SNI
+  /// certificates), checking them every `interval_ms` (0 = 5000). After a change the
+  /// configuration is rebuilt from `config` as by `dart_quic_server_set_config`. A failed
+  /// rebuild (e.g. the key was not rewritten yet) keeps the current certificate, logs a
+  /// warning, and is retried on the next change.
+  ///
+  /// `config` is copied; it may be freed once this call returns.
+  ///
+  /// `callback` fires once, when the watch ends:
+  /// - success once the server handle is released (`dart_quic_server_free`)
+  /// - an error starting with `[Cancelled]` when cancelled with `dart_quic_operation_cancel`
+  /// - an error right away if `config` is invalid or has no certificate files
+  ///
+  /// # Returns
+  /// Operation ID for `dart_quic_operation_cancel`, or 0 if the watch was not started
+  /// (the callback has then already been called with the error).
+  ///
+  /// # Safety
+  /// `executor` must be valid; `config` must be a valid pointer.
+  int dart_quic_server_watch_certs(
+    ffi.Pointer<QuicExecutor> executor,
+    int server,
+    ffi.Pointer<QuicFfiServerConfig> config,
+    int interval_ms,
+    VoidCallback callback,
+  ) {
+    return _dart_quic_server_watch_certs(
+      executor,
+      server,
+      config,
+      interval_ms,
+      callback,
+    );
+  }
+
+  late final _dart_quic_server_watch_certsPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Uint64 Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Pointer<QuicFfiServerConfig>,
+            ffi.Uint64,
+            VoidCallback,
+          )
+        >
+      >('dart_quic_server_watch_certs');
+  late final _dart_quic_server_watch_certs = _dart_quic_server_watch_certsPtr
+      .asFunction<
+        int Function(
+          ffi.Pointer<QuicExecutor>,
+          int,
+          ffi.Pointer<QuicFfiServerConfig>,
+          int,
+          VoidCallback,
+        )
+      >();
+
+  /// Register `Dart_PostCObject` for port-based completion
+  ///
+  /// Call once per process with `NativeApi.postCObject` before any `*_port` function.
+  /// Without it, results are silently dropped.
+  ///
+  /// # Safety
+  /// `post_cobject` must be Dart's `Dart_PostCObject` (valid for the process lifetime).
+  void dart_quic_port_init(DartPostCObjectFn post_cobject) {
+    return _dart_quic_port_init(post_cobject);
+  }
+
+  late final _dart_quic_port_initPtr =
+      _lookup<ffi.NativeFunction<ffi.Void Function(DartPostCObjectFn)>>(
+        'dart_quic_port_init',
+      );
+  late final _dart_quic_port_init = _dart_quic_port_initPtr
+      .asFunction<void Function(DartPostCObjectFn)>();
+
+  /// Create a QUIC client (async, port)
+  ///
+  /// Port variant of `dart_quic_client_new_async`. Payload: `QuicClientHandle*` address
+  /// (free with `dart_quic_client_handle_free`, the client with `dart_quic_client_free`).
+  ///
+  /// # Safety
+  /// `executor` must be valid; `config` and all data it references must remain valid
+  /// until the result is posted.
+  void dart_quic_client_new_async_port(
+    ffi.Pointer<QuicExecutor> executor,
+    ffi.Pointer<QuicFfiClientConfig> config,
+    int port,
+  ) {
+    return _dart_quic_client_new_async_port(executor, config, port);
+  }
+
+  late final _dart_quic_client_new_async_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Pointer<QuicFfiClientConfig>,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_client_new_async_port');
+  late final _dart_quic_client_new_async_port =
+      _dart_quic_client_new_async_portPtr
+          .asFunction<
+            void Function(
+              ffi.Pointer<QuicExecutor>,
+              ffi.Pointer<QuicFfiClientConfig>,
+              int,
+            )
+          >();
+
+  /// Create a QUIC server (async, port)
+  ///
+  /// Port variant of `dart_quic_server_new_async`. Payload: `QuicServerHandle*` address
+  /// (free with `dart_quic_server_handle_free`, the server with `dart_quic_server_free`).
+  ///
+  /// # Safety
+  /// `executor` must be valid; `bind_addr` must be null-terminated; `config` and all data
+  /// it references must remain valid until the result is posted.
+  void dart_quic_server_new_async_port(
+    ffi.Pointer<QuicExecutor> executor,
+    ffi.Pointer<ffi.Char> bind_addr,
+    ffi.Pointer<QuicFfiServerConfig> config,
+    int port,
+  ) {
+    return _dart_quic_server_new_async_port(executor, bind_addr, config, port);
+  }
+
+  late final _dart_quic_server_new_async_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Pointer<ffi.Char>,
+            ffi.Pointer<QuicFfiServerConfig>,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_server_new_async_port');
+  late final _dart_quic_server_new_async_port =
+      _dart_quic_server_new_async_portPtr
+          .asFunction<
+            void Function(
+              ffi.Pointer<QuicExecutor>,
+              ffi.Pointer<ffi.Char>,
+              ffi.Pointer<QuicFfiServerConfig>,
+              int,
+            )
+          >();
+
+  /// Connect to server via client (async, port)
+  ///
+  /// Payload: `QuicConnectionHandle*` address (free with `dart_quic_connection_handle_free`).
+  ///
+  /// # Safety
+  /// `executor` must be valid; strings must be null-terminated.
+  void dart_quic_client_connect_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int client,
+    ffi.Pointer<ffi.Char> server_addr,
+    ffi.Pointer<ffi.Char> server_name,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_client_connect_port(
+      executor,
+      client,
+      server_addr,
+      server_name,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_client_connect_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Char>,
+            ffi.Pointer<ffi.Char>,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_client_connect_port');
+  late final _dart_quic_client_connect_port = _dart_quic_client_connect_portPtr
+      .asFunction<
+        void Function(
+          ffi.Pointer<QuicExecutor>,
+          int,
+          ffi.Pointer<ffi.Char>,
+          ffi.Pointer<ffi.Char>,
+          int,
+          int,
+        )
+      >();
+
+  /// Accept incoming connection via server (async, port)
+  ///
+  /// Payload: `QuicConnectionHandle*` address, or null when the server is closed.
+  /// If cancelled, posts a `Cancelled` error.
+  ///
+  /// # Returns
+  /// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+  /// (the error has then already been posted).
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  int dart_quic_server_accept_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int server,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_server_accept_port(executor, server, timeout_ms, port);
+  }
+
+  late final _dart_quic_server_accept_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Uint64 Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_server_accept_port');
+  late final _dart_quic_server_accept_port = _dart_quic_server_accept_portPtr
+      .asFunction<int Function(ffi.Pointer<QuicExecutor>, int, int, int)>();
+
+  /// Accept the next connection routed to an ALPN queue via server (async, port)
+  ///
+  /// Payload: `QuicConnectionHandle*` address, or null when the server is closed.
+  /// See `dart_quic_server_accept_alpn`. If cancelled, posts a `Cancelled` error.
+  ///
+  /// # Returns
+  /// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+  /// (the error has then already been posted).
+  ///
+  /// # Safety
+  /// `executor` must be valid; `alpn` must be null or valid for `alpn_len` bytes.
+  int dart_quic_server_accept_alpn_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int server,
+    ffi.Pointer<ffi.Uint8> alpn,
+    int alpn_len,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_server_accept_alpn_port(
+      executor,
+      server,
+      alpn,
+      alpn_len,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_server_accept_alpn_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Uint64 Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Uint8>,
+            ffi.UintPtr,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_server_accept_alpn_port');
+  late final _dart_quic_server_accept_alpn_port =
+      _dart_quic_server_accept_alpn_portPtr
+          .asFunction<
+            int Function(
+              ffi.Pointer<QuicExecutor>,
+              int,
+              ffi.Pointer<ffi.Uint8>,
+              int,
+              int,
+              int,
+            )
+          >();
+
+  /// Connect to a remote server via endpoint (async, port)
+  ///
+  /// Payload: `QuicConnectionHandle*` address.
+  ///
+  /// # Safety
+  /// `executor` must be valid; strings must be null-terminated.
+  void dart_quic_endpoint_connect_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int endpoint,
+    ffi.Pointer<ffi.Char> server_addr,
+    ffi.Pointer<ffi.Char> server_name,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_endpoint_connect_port(
+      executor,
+      endpoint,
+      server_addr,
+      server_name,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_endpoint_connect_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Char>,
+            ffi.Pointer<ffi.Char>,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_endpoint_connect_port');
+  late final _dart_quic_endpoint_connect_port =
+      _dart_quic_endpoint_connect_portPtr
+          .asFunction<
+            void Function(
+              ffi.Pointer<QuicExecutor>,
+              int,
+              ffi.Pointer<ffi.Char>,
+              ffi.Pointer<ffi.Char>,
+              int,
+              int,
+            )
+          >();
+
+  /// Accept incoming connection via endpoint (async, port)
+  ///
+  /// Payload: `QuicConnectionHandle*` address. Fails with `EndpointClosed` when the
+  /// endpoint is closed.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_endpoint_accept_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int endpoint,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_endpoint_accept_port(
+      executor,
+      endpoint,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_endpoint_accept_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_endpoint_accept_port');
+  late final _dart_quic_endpoint_accept_port =
+      _dart_quic_endpoint_accept_portPtr
+          .asFunction<
+            void Function(ffi.Pointer<QuicExecutor>, int, int, int)
+          >();
+
+  /// Connect to server with 0-RTT early data via client (async, port)
+  ///
+  /// Port variant of `dart_quic_client_connect_0rtt`. `port` receives the
+  /// `QuicConnectionHandle*` address (immediately when early data is possible);
+  /// `zero_rtt_port` receives the `QuicZeroRttStatus` as an `int` once the handshake
+  /// completes. On error, including invalid arguments, both ports receive the error.
+  ///
+  /// # Safety
+  /// `executor` must be valid; strings must be null-terminated.
+  void dart_quic_client_connect_0rtt_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int client,
+    ffi.Pointer<ffi.Char> server_addr,
+    ffi.Pointer<ffi.Char> server_name,
+    int port,
+    int zero_rtt_port,
+  ) {
+    return _dart_quic_client_connect_0rtt_port(
+      executor,
+      client,
+      server_addr,
+      server_name,
+      port,
+      zero_rtt_port,
+    );
+  }
+
+  late final _dart_quic_client_connect_0rtt_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Char>,
+            ffi.Pointer<ffi.Char>,
+            ffi.Int64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_client_connect_0rtt_port');
+  late final _dart_quic_client_connect_0rtt_port =
+      _dart_quic_client_connect_0rtt_portPtr
+          .asFunction<
+            void Function(
+              ffi.Pointer<QuicExecutor>,
+              int,
+              ffi.Pointer<ffi.Char>,
+              ffi.Pointer<ffi.Char>,
+              int,
+              int,
+            )
+          >();
+
+  /// Accept incoming connection with 0.5-RTT via server (async, port)
+  ///
+  /// Port variant of `dart_quic_server_accept_0rtt`. `port` receives the
+  /// `QuicConnectionHandle*` address as soon as the handshake starts, or null when the
+  /// server is closed; `zero_rtt_port` receives the `QuicZeroRttStatus` as an `int`.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_server_accept_0rtt_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int server,
+    int port,
+    int zero_rtt_port,
+  ) {
+    return _dart_quic_server_accept_0rtt_port(
+      executor,
+      server,
+      port,
+      zero_rtt_port,
+    );
+  }
+
+  late final _dart_quic_server_accept_0rtt_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Int64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_server_accept_0rtt_port');
+  late final _dart_quic_server_accept_0rtt_port =
+      _dart_quic_server_accept_0rtt_portPtr
+          .asFunction<
+            void Function(ffi.Pointer<QuicExecutor>, int, int, int)
+          >();
+
+  /// Connect to a remote server with 0-RTT early data via endpoint (async, port)
+  ///
+  /// Same semantics as `dart_quic_client_connect_0rtt_port`.
+  ///
+  /// # Safety
+  /// `executor` must be valid; strings must be null-terminated.
+  void dart_quic_endpoint_connect_0rtt_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int endpoint,
+    ffi.Pointer<ffi.Char> server_addr,
+    ffi.Pointer<ffi.Char> server_name,
+    int port,
+    int zero_rtt_port,
+  ) {
+    return _dart_quic_endpoint_connect_0rtt_port(
+      executor,
+      endpoint,
+      server_addr,
+      server_name,
+      port,
+      zero_rtt_port,
+    );
+  }
+
+  late final _dart_quic_endpoint_connect_0rtt_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Char>,
+            ffi.Pointer<ffi.Char>,
+            ffi.Int64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_endpoint_connect_0rtt_port');
+  late final _dart_quic_endpoint_connect_0rtt_port =
+      _dart_quic_endpoint_connect_0rtt_portPtr
+          .asFunction<
+            void Function(
+              ffi.Pointer<QuicExecutor>,
+              int,
+              ffi.Pointer<ffi.Char>,
+              ffi.Pointer<ffi.Char>,
+              int,
+              int,
+            )
+          >();
+
+  /// Accept an incoming connection with 0.5-RTT via endpoint (async, port)
+  ///
+  /// Same semantics as `dart_quic_server_accept_0rtt_port`.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_endpoint_accept_0rtt_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int endpoint,
+    int port,
+    int zero_rtt_port,
+  ) {
+    return _dart_quic_endpoint_accept_0rtt_port(
+      executor,
+      endpoint,
+      port,
+      zero_rtt_port,
+    );
+  }
+
+  late final _dart_quic_endpoint_accept_0rtt_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Int64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_endpoint_accept_0rtt_port');
+  late final _dart_quic_endpoint_accept_0rtt_port =
+      _dart_quic_endpoint_accept_0rtt_portPtr
+          .asFunction<
+            void Function(ffi.Pointer<QuicExecutor>, int, int, int)
+          >();
+
+  /// Wait for all client connections to become idle (async, port)
+  ///
+  /// Port variant of `dart_quic_client_wait_idle`. Payload: null.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_client_wait_idle_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int client,
+    int port,
+  ) {
+    return _dart_quic_client_wait_idle_port(executor, client, port);
+  }
+
+  late final _dart_quic_client_wait_idle_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, ffi.Int64)
+        >
+      >('dart_quic_client_wait_idle_port');
+  late final _dart_quic_client_wait_idle_port =
+      _dart_quic_client_wait_idle_portPtr
+          .asFunction<void Function(ffi.Pointer<QuicExecutor>, int, int)>();
+
+  /// Wait for all server connections to become idle (async, port)
+  ///
+  /// Port variant of `dart_quic_server_wait_idle`. Payload: null.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_server_wait_idle_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int server,
+    int port,
+  ) {
+    return _dart_quic_server_wait_idle_port(executor, server, port);
+  }
+
+  late final _dart_quic_server_wait_idle_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, ffi.Int64)
+        >
+      >('dart_quic_server_wait_idle_port');
+  late final _dart_quic_server_wait_idle_port =
+      _dart_quic_server_wait_idle_portPtr
+          .asFunction<void Function(ffi.Pointer<QuicExecutor>, int, int)>();
+
+  /// Wait for all endpoint connections to close (async, port)
+  ///
+  /// Port variant of `dart_quic_endpoint_wait_idle`. Payload: null.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_endpoint_wait_idle_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int endpoint,
+    int port,
+  ) {
+    return _dart_quic_endpoint_wait_idle_port(executor, endpoint, port);
+  }
+
+  late final _dart_quic_endpoint_wait_idle_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, ffi.Int64)
+        >
+      >('dart_quic_endpoint_wait_idle_port');
+  late final _dart_quic_endpoint_wait_idle_port =
+      _dart_quic_endpoint_wait_idle_portPtr
+          .asFunction<void Function(ffi.Pointer<QuicExecutor>, int, int)>();
+
+  /// Open bidirectional stream (async, port)
+  ///
+  /// Payload: `QuicFfiStreamPair*` address (free with `dart_quic_stream_pair_free`).
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_connection_open_bi_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    int priority,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_connection_open_bi_port(
+      executor,
+      handle,
+      priority,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_connection_open_bi_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Int32,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_connection_open_bi_port');
+  late final _dart_quic_connection_open_bi_port =
+      _dart_quic_connection_open_bi_portPtr
+          .asFunction<
+            void Function(ffi.Pointer<QuicExecutor>, int, int, int, int)
+          >();
+
+  /// Open unidirectional stream (async, port)
+  ///
+  /// Payload: `QuicFfiStreamPair*` address with only `send_handle` set.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_connection_open_uni_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    int priority,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_connection_open_uni_port(
+      executor,
+      handle,
+      priority,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_connection_open_uni_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Int32,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_connection_open_uni_port');
+  late final _dart_quic_connection_open_uni_port =
+      _dart_quic_connection_open_uni_portPtr
+          .asFunction<
+            void Function(ffi.Pointer<QuicExecutor>, int, int, int, int)
+          >();
+
+  /// Accept bidirectional stream (async, port)
+  ///
+  /// Payload: `QuicFfiStreamPair*` address. If cancelled, posts a `Cancelled` error.
+  ///
+  /// # Returns
+  /// Operation ID for `dart_quic_operation_cancel`, or 0 if the accept was not started
+  /// (the error has then already been posted).
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  int dart_quic_connection_accept_bi_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_connection_accept_bi_port(
+      executor,
+      handle,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_connection_accept_bi_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Uint64 Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_connection_accept_bi_port');
+  late final _dart_quic_connection_accept_bi_port =
+      _dart_quic_connection_accept_bi_portPtr
+          .asFunction<int Function(ffi.Pointer<QuicExecutor>, int, int, int)>();
+
+  /// Accept unidirectional stream (async, port)
+  ///
+  /// Payload: `QuicFfiStreamPair*` address with only `recv_handle` set.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_connection_accept_uni_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_connection_accept_uni_port(
+      executor,
+      handle,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_connection_accept_uni_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_connection_accept_uni_port');
+  late final _dart_quic_connection_accept_uni_port =
+      _dart_quic_connection_accept_uni_portPtr
+          .asFunction<
+            void Function(ffi.Pointer<QuicExecutor>, int, int, int)
+          >();
+
+  /// Read datagram (async, port)
+  ///
+  /// Payload: `Uint8List` with the datagram.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_connection_read_datagram_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    int port,
+  ) {
+    return _dart_quic_connection_read_datagram_port(executor, handle, port);
+  }
+
+  late final _dart_quic_connection_read_datagram_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, ffi.Int64)
+        >
+      >('dart_quic_connection_read_datagram_port');
+  late final _dart_quic_connection_read_datagram_port =
+      _dart_quic_connection_read_datagram_portPtr
+          .asFunction<void Function(ffi.Pointer<QuicExecutor>, int, int)>();
+
+  /// Subscribe to the connection close event (async, port)
+  ///
+  /// Payload: `[kind: int, remote: bool, resultCode: int, errorCode: int, reason: Uint8List]`
+  /// with the same meaning as the `QuicFfiCloseReason` fields. Like
+  /// `dart_quic_connection_on_closed`, the subscription does not keep the connection alive.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_connection_on_closed_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    int port,
+  ) {
+    return _dart_quic_connection_on_closed_port(executor, handle, port);
+  }
+
+  late final _dart_quic_connection_on_closed_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, ffi.Int64)
+        >
+      >('dart_quic_connection_on_closed_port');
+  late final _dart_quic_connection_on_closed_port =
+      _dart_quic_connection_on_closed_portPtr
+          .asFunction<void Function(ffi.Pointer<QuicExecutor>, int, int)>();
+
+  /// Read up to `max_len` bytes from the stream (async, port)
+  ///
+  /// Payload: `Uint8List` with the bytes read, or null at end of stream. If cancelled,
+  /// posts a `Cancelled` error.
+  ///
+  /// # Returns
+  /// Operation ID for `dart_quic_operation_cancel`, or 0 if the read was not started
+  /// (the error has then already been posted).
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  int dart_quic_recv_stream_read_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    int max_len,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_recv_stream_read_port(
+      executor,
+      handle,
+      max_len,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_recv_stream_read_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Uint64 Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.UintPtr,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_recv_stream_read_port');
+  late final _dart_quic_recv_stream_read_port =
+      _dart_quic_recv_stream_read_portPtr
+          .asFunction<
+            int Function(ffi.Pointer<QuicExecutor>, int, int, int, int)
+          >();
+
+  /// Read exactly `exact_len` bytes from the stream (async, port)
+  ///
+  /// Payload: `Uint8List` of `exact_len` bytes.
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_recv_stream_read_exact_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    int exact_len,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_recv_stream_read_exact_port(
+      executor,
+      handle,
+      exact_len,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_recv_stream_read_exact_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.UintPtr,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_recv_stream_read_exact_port');
+  late final _dart_quic_recv_stream_read_exact_port =
+      _dart_quic_recv_stream_read_exact_portPtr
+          .asFunction<
+            void Function(ffi.Pointer<QuicExecutor>, int, int, int, int)
+          >();
+
+  /// Read all remaining data from the stream, up to `size_limit` bytes (async, port)
+  ///
+  /// Payload: `Uint8List` with the data (empty if the stream had none).
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_recv_stream_read_to_end_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    int size_limit,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_recv_stream_read_to_end_port(
+      executor,
+      handle,
+      size_limit,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_recv_stream_read_to_end_portPtr =
+      _lookup<
+        ffi.NativeFunction<
+          ffi.Void Function(
+            ffi.Pointer<QuicExecutor>,
+            ffi.Uint64,
+            ffi.UintPtr,
+            ffi.Uint64,
+            ffi.Int64,
+          )
+        >
+      >('dart_quic_recv_stream_read_to_end_port');
+  late final _dart_quic_recv_stream_read_to_end_port =
+      _dart_quic_recv_stream_read_to_end_portPtr
+          .asFunction<
+            void Function(ffi.Pointer<QuicExecutor>, int, int, int, int)
+          >();
+
+  /// Write bytes to the send stream (async, port)
+  ///
+  /// The data is copied before returning. Payload: `int` number of bytes written.
+  ///
+  /// # Safety
+  /// `executor` must be valid; `data` must point to `data_len` readable bytes.
+  void dart_quic_send_stream_write_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    ffi.Pointer<ffi.Uint8> data,
+    int data_len,
+    int timeout_ms,
+    int port,
+  ) {
+    return _dart_quic_send_stream_write_port(
+      executor,
+      handle,
+      data,
+      data_len,
+      timeout_ms,
+      port,
+    );
+  }
+
+  late final _dart_quic_send_stream_write_portPtr =
       _lookup<
         ffi.NativeFunction<
           ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<QuicServer>,
-            VoidCallback,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Uint8>,
+            ffi.UintPtr,
+            ffi.Uint64,
+            ffi.Int64,
           )
         >
-      >('dart_quic_server_wait_idle');
-  late final _dart_quic_server_wait_idle = _dart_quic_server_wait_idlePtr
-      .asFunction<
-        void Function(
-          ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<QuicServer>,
-          VoidCallback,
-        )
-      >();
-
-  /// Get the number of currently open connections on the server
-  ///
-  /// Returns 0 if the server pointer is null.
-  int dart_quic_server_open_connections(ffi.Pointer<QuicServer> server) {
-    return _dart_quic_server_open_connections(server);
-  }
-
-  late final _dart_quic_server_open_connectionsPtr =
-      _lookup<
-        ffi.NativeFunction<ffi.UintPtr Function(ffi.Pointer<QuicServer>)>
-      >('dart_quic_server_open_connections');
-  late final _dart_quic_server_open_connections =
-      _dart_quic_server_open_connectionsPtr
-          .asFunction<int Function(ffi.Pointer<QuicServer>)>();
-
-  /// Get server local port
-  ///
-  /// Returns 0 if the server pointer is null.
-  int dart_quic_server_local_port(ffi.Pointer<QuicServer> server) {
-    return _dart_quic_server_local_port(server);
-  }
-
-  late final _dart_quic_server_local_portPtr =
-      _lookup<ffi.NativeFunction<ffi.Uint16 Function(ffi.Pointer<QuicServer>)>>(
-        'dart_quic_server_local_port',
-      );
-  late final _dart_quic_server_local_port = _dart_quic_server_local_portPtr
-      .asFunction<int Function(ffi.Pointer<QuicServer>)>();
+      >('dart_quic_send_stream_write_port');
+  late final _dart_quic_send_stream_write_port =
+      _dart_quic_send_stream_write_portPtr
+          .asFunction<
+            void Function(
+              ffi.Pointer<QuicExecutor>,
+              int,
+              ffi.Pointer<ffi.Uint8>,
+              int,
+              int,
+              int,
+            )
+          >();
 
-  /// Create a QUIC server asynchronously using unified FFI configuration
-  ///
-  /// Must be called after `dart_quic_executor_init` because Quinn requires
-  /// an active tokio runtime context when creating the endpoint.
+  /// Write all bytes to the send stream (async, port)
   ///
-  /// On success, the callback receives a `QuicServerHandle*` pointer (as usize).
-  /// The handle is allocated and owned by Rust; free it with `dart_quic_server_handle_free`.
+  /// The data is copied before returning. Payload: null.
   ///
   /// # Safety
-  /// - `config` and all data it references must remain valid until the callback fires
-  ///
-  /// # Parameters
-  /// - `executor`: Running QuicExecutor (must not be null)
-  /// - `bind_addr`: Address to bind, e.g. "0.0.0.0:4433"
-  /// - `config`: Pointer to FFI server configuration (must not be null)
-  /// - `callback`: UsizeCallback receiving QuicServerHandle* pointer on success
-  int dart_quic_server_new_async(
+  /// `executor` must be valid; `data` must point to `data_len` readable bytes.
+  void dart_quic_send_stream_write_all_port(
     ffi.Pointer<QuicExecutor> executor,
-    ffi.Pointer<ffi.Char> bind_addr,
-    ffi.Pointer<QuicFfiServerConfig> config,
-    UsizeCallback callback,
+    int handle,
+    ffi.Pointer<ffi.Uint8> data,
+    int data_len,
+    int timeout_ms,
+    int port,
   ) {
-    return _dart_quic_server_new_async(executor, bind_addr, config, callback);
+    return _dart_quic_send_stream_write_all_port(
+      executor,
+      handle,
+      data,
+      data_len,
+      timeout_ms,
+      port,
+    );
   }
 
-  late final _dart_quic_server_new_asyncPtr =
+  late final _dart_quic_send_stream_write_all_portPtr =
       _lookup<
         ffi.NativeFunction<
-          ffi.Int32 Function(
+          ffi.Void Function(
             ffi.Pointer<QuicExecutor>,
-            ffi.Pointer<ffi.Char>,
-            ffi.Pointer<QuicFfiServerConfig>,
-            UsizeCallback,
+            ffi.Uint64,
+            ffi.Pointer<ffi.Uint8>,
+            ffi.UintPtr,
+            ffi.Uint64,
+            ffi.Int64,
           )
         >
-      >('dart_quic_server_new_async');
-  late final _dart_quic_server_new_async = _dart_quic_server_new_asyncPtr
-      .asFunction<
-        int Function(
-          ffi.Pointer<QuicExecutor>,
-          ffi.Pointer<ffi.Char>,
-          ffi.Pointer<QuicFfiServerConfig>,
-          UsizeCallback,
-        )
-      >();
+      >('dart_quic_send_stream_write_all_port');
+  late final _dart_quic_send_stream_write_all_port =
+      _dart_quic_send_stream_write_all_portPtr
+          .asFunction<
+            void Function(
+              ffi.Pointer<QuicExecutor>,
+              int,
+              ffi.Pointer<ffi.Uint8>,
+              int,
+              int,
+              int,
+            )
+          >();
 
-  /// Free server handle and all its resources
+  /// Wait until the peer has received all data or stopped the stream (async, port)
   ///
-  /// This frees:
-  /// - The server itself
-  /// - The local_addr_ptr string
-  /// - The handle structure
-  void dart_quic_server_handle_free(ffi.Pointer<QuicServerHandle> handle) {
-    return _dart_quic_server_handle_free(handle);
+  /// Payload: `bool`, true if the peer sent STOP_SENDING (the code is recorded on the
+  /// handle, see `dart_quic_stream_peer_error_code`).
+  ///
+  /// # Safety
+  /// `executor` must be valid.
+  void dart_quic_send_stream_stopped_port(
+    ffi.Pointer<QuicExecutor> executor,
+    int handle,
+    int port,
+  ) {
+    return _dart_quic_send_stream_stopped_port(executor, handle, port);
   }
 
-  late final _dart_quic_server_handle_freePtr =
+  late final _dart_quic_send_stream_stopped_portPtr =
       _lookup<
-        ffi.NativeFunction<ffi.Void Function(ffi.Pointer<QuicServerHandle>)>
-      >('dart_quic_server_handle_free');
-  late final _dart_quic_server_handle_free = _dart_quic_server_handle_freePtr
-      .asFunction<void Function(ffi.Pointer<QuicServerHandle>)>();
+        ffi.NativeFunction<
+          ffi.Void Function(ffi.Pointer<QuicExecutor>, ffi.Uint64, ffi.Int64)
+        >
+      >('dart_quic_send_stream_stopped_port');
+  late final _dart_quic_send_stream_stopped_port =
+      _dart_quic_send_stream_stopped_portPtr
+          .asFunction<void Function(ffi.Pointer<QuicExecutor>, int, int)>();
 }
 
 typedef va_list = ffi.Pointer<ffi.Char>;
@@ -1820,6 +4140,31 @@ final class _LDBL12 extends ffi.Struct {
   external ffi.Array<ffi.UnsignedChar> ld12;
 }
 
+/// Log level (also used as the filter value for `dart_quic_log_set_level`)
+enum QuicLogLevel {
+  /// Filter only: disable all records
+  Off(0),
+
+  Error(1),
+  Warn(2),
+  Info(3),
+  Debug(4),
+  Trace(5);
+
+  final int value;
+  const QuicLogLevel(this.value);
+
+  static QuicLogLevel fromValue(int value) => switch (value) {
+    0 => Off,
+    1 => Error,
+    2 => Warn,
+    3 => Info,
+    4 => Debug,
+    5 => Trace,
+    _ => throw ArgumentError('Unknown value for QuicLogLevel: $value'),
+  };
+}
+
 /// Endpoint operation mode (for C API)
 ///
 /// The QUIC protocol allows the same UDP port to act as both client and server simultaneously,
@@ -1830,10 +4175,9 @@ final class _LDBL12 extends ffi.Struct {
 ///
 /// ```c
 /// // C API example
-/// FfiEndpointConfig config = {
+/// QuicFfiEndpointConfig config = {
 /// .mode = QuicEndpointMode_ClientOnly,  // Client-only
-/// .bind_ip = 0,      // INADDR_ANY
-/// .bind_port = 0,    // System-assigned port
+/// .bind_addr = { .family = QUIC_ADDR_FAMILY_IPV4, .port = 0 },  // 0.0.0.0, system-assigned port
 /// };
 /// ```
 enum QuicEndpointMode {
@@ -1878,6 +4222,39 @@ enum QuicEndpointMode {
 typedef QuicEndpointMode$1 = ffi.Uint8;
 typedef DartQuicEndpointMode = int;
 
+/// Trust mode
+enum QuicFfiTrustMode {
+  /// Skip verification (testing only! dangerous!)
+  SkipVerification(0),
+
+  /// Use system root certificates (recommended for production)
+  SystemRoots(1),
+
+  /// Use custom CA (DER in memory)
+  CustomCaDer(2),
+
+  /// Use custom CA (PEM file)
+  CustomCaPemFile(3),
+
+  /// Use custom CA (DER file)
+  CustomCaDerFile(4);
+
+  final int value;
+  const QuicFfiTrustMode(this.value);
+
+  static QuicFfiTrustMode fromValue(int value) => switch (value) {
+    0 => SkipVerification,
+    1 => SystemRoots,
+    2 => CustomCaDer,
+    3 => CustomCaPemFile,
+    4 => CustomCaDerFile,
+    _ => throw ArgumentError('Unknown value for QuicFfiTrustMode: $value'),
+  };
+}
+
+typedef QuicFfiTrustMode$1 = ffi.Uint8;
+typedef DartQuicFfiTrustMode = int;
+
 /// Client certificate mode (mTLS)
 enum QuicFfiClientCertMode {
   /// No client certificate
@@ -1907,50 +4284,104 @@ enum QuicFfiClientCertMode {
 typedef QuicFfiClientCertMode$1 = ffi.Uint8;
 typedef DartQuicFfiClientCertMode = int;
 
-/// Trust mode
-enum QuicFfiTrustMode {
-  /// Skip verification (testing only! dangerous!)
-  SkipVerification(0),
+/// TLS session cache mode
+///
+/// Both modes keep sessions in memory only, so resumption and 0-RTT do not survive a
+/// process restart. A file-backed mode needs rustls to expose an encoding for TLS 1.3
+/// tickets (`Tls13ClientSessionValue` can only be built inside rustls 0.23).
+enum QuicFfiSessionCacheMode {
+  /// rustls built-in in-memory cache
+  Default(0),
 
-  /// Use system root certificates (recommended for production)
-  SystemRoots(1),
+  /// Bounded in-memory LRU cache (`session_cache_capacity` servers)
+  Memory(1);
 
-  /// Use custom CA (DER in memory)
-  CustomCaDer(2),
+  final int value;
+  const QuicFfiSessionCacheMode(this.value);
 
-  /// Use custom CA (PEM file)
-  CustomCaPemFile(3),
+  static QuicFfiSessionCacheMode fromValue(int value) => switch (value) {
+    0 => Default,
+    1 => Memory,
+    _ => throw ArgumentError('Unknown value for QuicFfiSessionCacheMode: $value'),
+  };
+}
 
-  /// Use custom CA (DER file)
-  CustomCaDerFile(4);
+typedef QuicFfiSessionCacheMode$1 = ffi.Uint8;
+typedef DartQuicFfiSessionCacheMode = int;
+
+/// TLS key log mode
+enum QuicFfiKeyLogMode {
+  /// No key logging
+  Disabled(0),
+
+  /// Append to the file named by the `SSLKEYLOGFILE` environment variable
+  Env(1),
+
+  /// Append to `key_log_path`
+  File(2);
 
   final int value;
-  const QuicFfiTrustMode(this.value);
+  const QuicFfiKeyLogMode(this.value);
 
-  static QuicFfiTrustMode fromValue(int value) => switch (value) {
-    0 => SkipVerification,
-    1 => SystemRoots,
-    2 => CustomCaDer,
-    3 => CustomCaPemFile,
-    4 => CustomCaDerFile,
-    _ => throw ArgumentError('Unknown value for QuicFfiTrustMode: $value'),
+  static QuicFfiKeyLogMode fromValue(int value) => switch (value) {
+    0 => Disabled,
+    1 => Env,
+    2 => File,
+    _ => throw ArgumentError('Unknown value for QuicFfiKeyLogMode: $value'),
   };
 }
 
-typedef QuicFfiTrustMode$1 = ffi.Uint8;
-typedef DartQuicFfiTrustMode = int;
+typedef QuicFfiKeyLogMode$1 = ffi.Uint8;
+typedef DartQuicFfiKeyLogMode = int;
 
-final class MemoryStats extends ffi.Opaque {}
+/// How a connection was closed
+enum QuicCloseKind {
+  /// Peer closed with a transport error code (CONNECTION_CLOSE 0x1c)
+  TransportClosed(0),
 
-final class QuicClient extends ffi.Opaque {}
+  /// Peer closed with an application error code (CONNECTION_CLOSE 0x1d)
+  ApplicationClosed(1),
 
-final class QuicConnection extends ffi.Opaque {}
+  /// Closed locally via `close()`
+  LocallyClosed(2),
 
-final class QuicEndpoint extends ffi.Opaque {}
+  /// Idle timeout expired
+  TimedOut(3),
 
-final class QuicExecutor extends ffi.Opaque {}
+  /// Peer sent a stateless reset
+  Reset(4),
+
+  /// Peer does not support our QUIC version
+  VersionMismatch(5),
+
+  /// Local transport error detected (protocol violation by the peer, TLS failure, ...)
+  TransportError(6),
+
+  /// Ran out of connection IDs
+  CidsExhausted(7);
+
+  final int value;
+  const QuicCloseKind(this.value);
+
+  static QuicCloseKind fromValue(int value) => switch (value) {
+    0 => TransportClosed,
+    1 => ApplicationClosed,
+    2 => LocallyClosed,
+    3 => TimedOut,
+    4 => Reset,
+    5 => VersionMismatch,
+    6 => TransportError,
+    7 => CidsExhausted,
+    _ => throw ArgumentError('Unknown value for QuicCloseKind: $value'),
+  };
+}
 
-final class QuicServer extends ffi.Opaque {}
+typedef QuicCloseKind$1 = ffi.Uint8;
+typedef DartQuicCloseKind = int;
+
+final class MemoryStats extends ffi.Opaque {}
+
+final class QuicExecutor extends ffi.Opaque {}
 
 /// Generic FFI result structure for C API interop.
 /// Used for sync operations that need to return both a handle and potential error.
@@ -1981,18 +4412,60 @@ typedef DartBoolCallbackFunction =
 /// Callback for bool result
 typedef BoolCallback = ffi.Pointer<ffi.NativeFunction<BoolCallbackFunction>>;
 
+/// Tokio runtime configuration (C-compatible)
+///
+/// Zero / null fields fall back to tokio's defaults, so a zero-initialized
+/// struct behaves like `dart_quic_executor_init` with `threads = 0`.
+final class QuicFfiRuntimeConfig extends ffi.Struct {
+  /// 0 = CPU cores, 1 = current-thread runtime, N = multi-thread with N workers
+  @ffi.UintPtr()
+  external int worker_threads;
+
+  /// Maximum number of threads for blocking work (0 = default, 512)
+  @ffi.UintPtr()
+  external int max_blocking_threads;
+
+  /// Thread name, null-terminated (null = "tokio-runtime-worker")
+  external ffi.Pointer<ffi.Char> thread_name;
+
+  /// Thread stack size in bytes (0 = default)
+  @ffi.UintPtr()
+  external int thread_stack_size;
+
+  /// Scheduler ticks between polls for I/O and timer events (0 = default, 61)
+  @ffi.Uint32()
+  external int event_interval;
+
+  /// How long idle blocking threads are kept alive, in ms (0 = default, 10s)
+  @ffi.Uint64()
+  external int thread_keep_alive_ms;
+}
+
 /// FFI-friendly transport configuration
 ///
+/// `struct_size` must be set to `sizeof(QuicFfiTransportConfig)` as seen by the caller.
+/// Fields are only ever appended, so a caller built against an older header passes a
+/// smaller size and the fields it doesn't know keep their defaults.
+///
+/// Fields documented with "0 = default" use the `QuicTransportConfig` default when zero.
+///
 /// # C Language Usage Example
 ///
 /// ```c
+/// const char *alpn[] = { "h3" };
 /// QuicFfiTransportConfig transport = {
+/// .struct_size = sizeof(QuicFfiTransportConfig),
 /// .max_idle_timeout_ms = 30000,
 /// .keep_alive_interval_ms = 15000,
+/// .alpn_protocols = alpn,
+/// .alpn_protocols_count = 1,
 /// // ...
 /// };
 /// ```
 final class QuicFfiTransportConfig extends ffi.Struct {
+  @ffi.Uint32()
+  external int struct_size;
+
   @ffi.Uint64()
   external int max_idle_timeout_ms;
 
@@ -2000,52 +4473,156 @@ final class QuicFfiTransportConfig extends ffi.Struct {
   external int keep_alive_interval_ms;
 
   @ffi.Uint32()
-  external int max_concurrent_bi_streams;
+  external int max_concurrent_bi_streams;
+
+  @ffi.Uint32()
+  external int max_concurrent_uni_streams;
+
+  @ffi.Uint32()
+  external int stream_receive_window;
+
+  @ffi.Uint64()
+  external int send_window;
+
+  @ffi.Uint64()
+  external int initial_rtt_ms;
+
+  @ffi.Uint16()
+  external int initial_mtu;
+
+  @ffi.Uint16()
+  external int min_mtu;
+
+  @ffi.Bool()
+  external bool enable_mtu_discovery;
+
+  @ffi.Uint32()
+  external int datagram_receive_buffer_size;
+
+  @ffi.Uint32()
+  external int datagram_send_buffer_size;
+
+  @ffi.Uint8()
+  external int congestion_controller;
+
+  @ffi.Bool()
+  external bool allow_spin;
+
+  @ffi.Bool()
+  external bool enable_gso;
+
+  external ffi.Pointer<ffi.Char> qlog_dir;
+
+  @ffi.Uint64()
+  external int receive_window;
+
+  @ffi.Bool()
+  external bool send_fairness;
+
+  @ffi.Uint32()
+  external int packet_threshold;
 
-  @ffi.Uint32()
-  external int max_concurrent_uni_streams;
+  @ffi.Float()
+  external double time_threshold;
 
   @ffi.Uint32()
-  external int stream_receive_window;
+  external int persistent_congestion_threshold;
 
-  @ffi.Uint64()
-  external int send_window;
+  @ffi.Uint32()
+  external int crypto_buffer_size;
 
   @ffi.Uint64()
-  external int initial_rtt_ms;
+  external int mtu_discovery_interval_ms;
 
   @ffi.Uint16()
-  external int initial_mtu;
+  external int mtu_discovery_upper_bound;
 
   @ffi.Uint16()
-  external int min_mtu;
+  external int mtu_discovery_minimum_change;
+
+  @ffi.Uint64()
+  external int mtu_black_hole_cooldown_ms;
 
   @ffi.Bool()
-  external bool enable_mtu_discovery;
+  external bool enable_ack_frequency;
 
   @ffi.Uint32()
-  external int datagram_receive_buffer_size;
+  external int ack_eliciting_threshold;
+
+  @ffi.Uint64()
+  external int ack_max_delay_ms;
 
   @ffi.Uint32()
-  external int datagram_send_buffer_size;
+  external int ack_reordering_threshold;
 
-  @ffi.Uint8()
-  external int congestion_controller;
+  external ffi.Pointer<ffi.Pointer<ffi.Char>> alpn_protocols;
 
-  @ffi.Bool()
-  external bool allow_spin;
+  @ffi.Uint32()
+  external int alpn_protocols_count;
+}
 
-  @ffi.Bool()
-  external bool enable_gso;
+typedef PanicCallbackFunction =
+    ffi.Void Function(ffi.Pointer<ffi.Uint8> message, ffi.UintPtr message_len);
+typedef DartPanicCallbackFunction =
+    void Function(ffi.Pointer<ffi.Uint8> message, int message_len);
+
+/// Callback receiving a panic description ("panicked at <file>:<line>:<col>:\n<message>")
+///
+/// Invoked on the panicking thread; the message is only valid during the call.
+/// Nullable: pass null to unregister.
+typedef PanicCallback = ffi.Pointer<ffi.NativeFunction<PanicCallbackFunction>>;
+
+/// Log record passed to `QuicLogCallback` (C-compatible)
+///
+/// Strings are UTF-8, not null-terminated, and only valid during the callback.
+final class QuicFfiLogRecord extends ffi.Struct {
+  @ffi.UnsignedInt()
+  external int levelAsInt;
+
+  QuicLogLevel get level => QuicLogLevel.fromValue(levelAsInt);
+
+  set level(QuicLogLevel value) => levelAsInt = value.value;
+
+  external ffi.Pointer<ffi.Uint8> target_ptr;
+
+  @ffi.UintPtr()
+  external int target_len;
+
+  external ffi.Pointer<ffi.Uint8> message_ptr;
+
+  @ffi.UintPtr()
+  external int message_len;
+
+  external ffi.Pointer<ffi.Uint8> fields_ptr;
+
+  @ffi.UintPtr()
+  external int fields_len;
+
+  external ffi.Pointer<ffi.Uint8> spans_ptr;
+
+  @ffi.UintPtr()
+  external int spans_len;
 }
 
-/// Unified stream handle with metadata
+typedef QuicLogCallbackFunction =
+    ffi.Void Function(ffi.Pointer<QuicFfiLogRecord> record);
+typedef DartQuicLogCallbackFunction =
+    void Function(ffi.Pointer<QuicFfiLogRecord> record);
+
+/// Callback receiving log records, invoked synchronously on the logging thread (nullable)
+typedef QuicLogCallback =
+    ffi.Pointer<ffi.NativeFunction<QuicLogCallbackFunction>>;
+
+/// Stream handle with metadata
 ///
-/// FFI-friendly structure wrapping a stream pointer, its ID, and type.
-/// The stream pointer can be either `SendStream*` or `RecvStream*` depending on `stream_type`.
+/// FFI-friendly structure carrying a stream's registry handle, its ID, and type.
+/// The handle refers to a Send or Recv stream depending on `stream_type`; release it
+/// with `dart_quic_stream_free`. The structure itself is freed together with its
+/// `QuicFfiStreamPair`.
 final class QuicFfiStreamHandle extends ffi.Struct {
-  /// Stream pointer (cast to void* for FFI, actual type depends on stream_type)
-  external ffi.Pointer<ffi.Void> stream;
+  /// Stream registry handle (pass to all subsequent stream operations)
+  @ffi.Uint64()
+  external int stream;
 
   /// Stream ID
   @ffi.Uint64()
@@ -2118,21 +4695,49 @@ typedef DartVoidCallbackFunction =
 /// Callback for void result
 typedef VoidCallback = ffi.Pointer<ffi.NativeFunction<VoidCallbackFunction>>;
 
+/// FFI socket address supporting IPv4 and IPv6 (for C API)
+///
+/// `family` is `QUIC_ADDR_FAMILY_IPV4` or `QUIC_ADDR_FAMILY_IPV6`; anything else is rejected.
+/// `ip` holds the address octets in network byte order; IPv4 uses the first 4 bytes.
+/// `flowinfo` and `scope_id` are only meaningful for IPv6 (`scope_id` selects the
+/// interface for link-local addresses such as `fe80::1%eth0`).
+final class QuicFfiSocketAddr extends ffi.Struct {
+  /// Address family (`QUIC_ADDR_FAMILY_IPV4` / `QUIC_ADDR_FAMILY_IPV6`)
+  @ffi.Uint8()
+  external int family;
+
+  /// Address octets (network byte order)
+  @ffi.Array.multi([16])
+  external ffi.Array<ffi.Uint8> ip;
+
+  /// Port (host byte order, 0 means system-assigned when binding)
+  @ffi.Uint16()
+  external int port;
+
+  /// IPv6 flow information
+  @ffi.Uint32()
+  external int flowinfo;
+
+  /// IPv6 scope ID (interface index)
+  @ffi.Uint32()
+  external int scope_id;
+}
+
 /// FFI endpoint configuration (for C API)
 ///
 /// # Field Descriptions
 ///
 /// - `mode`: Endpoint operation mode, determines what operations the endpoint can perform
-/// - `bind_ip`: Local IP address (network byte order), 0 means bind to all interfaces
-/// - `bind_port`: Local port number (host byte order), 0 means system auto-assign
+/// - `bind_addr`: Local address (IPv4 or IPv6); the unspecified address binds all interfaces
+/// - `dual_stack`: For IPv6 `bind_addr`, clear IPV6_V6ONLY so the endpoint also serves IPv4
 ///
 /// # C Language Usage Example
 ///
 /// ```c
 /// QuicFfiEndpointConfig config = {
-/// .mode = QuicEndpointMode_ClientOnly,
-/// .bind_ip = 0,      // INADDR_ANY
-/// .bind_port = 0,    // System-assigned port
+/// .mode = QuicEndpointMode_Bidirectional,
+/// .bind_addr = { .family = QUIC_ADDR_FAMILY_IPV6, .port = 4433 },  // [::]:4433
+/// .dual_stack = true,  // Accept IPv4 peers too
 /// };
 ///
 /// QuicEndpoint* endpoint = quic_endpoint_create(&config);
@@ -2142,13 +4747,12 @@ final class QuicFfiEndpointConfig extends ffi.Struct {
   @QuicEndpointMode$1()
   external int mode;
 
-  /// Local bind IP (network byte order, 0 means INADDR_ANY)
-  @ffi.Uint32()
-  external int bind_ip;
+  /// Local bind address
+  external QuicFfiSocketAddr bind_addr;
 
-  /// Local bind port (host byte order, 0 means system-assigned)
-  @ffi.Uint16()
-  external int bind_port;
+  /// Dual-stack IPv6 socket (IPV6_V6ONLY off); IPv6-only when false. Ignored for IPv4.
+  @ffi.Bool()
+  external bool dual_stack;
 }
 
 /// FFI-friendly client configuration
@@ -2240,6 +4844,68 @@ final class QuicFfiClientConfig extends ffi.Struct {
 
   /// Local bind address (optional, NULL or empty string uses "0.0.0.0:0")
   external ffi.Pointer<ffi.Char> bind_addr;
+
+  /// Enable 0-RTT early data on resumed sessions (used by `dart_quic_client_connect_0rtt`)
+  @ffi.Bool()
+  external bool enable_early_data;
+
+  /// Session cache mode
+  @QuicFfiSessionCacheMode$1()
+  external int session_cache_mode;
+
+  /// Maximum number of servers kept in the session cache (0 uses default 256)
+  @ffi.Uint32()
+  external int session_cache_capacity;
+
+  /// TLS key log mode (for decrypting packet captures; default Disabled)
+  @QuicFfiKeyLogMode$1()
+  external int key_log_mode;
+
+  /// Key log file path (for File mode), UTF-8 encoded C string
+  external ffi.Pointer<ffi.Char> key_log_path;
+}
+
+/// FFI certificate for one server name (entry of `QuicFfiServerConfig::sni_certs`)
+///
+/// # C Language Usage Example
+///
+/// ```c
+/// QuicFfiSniCert sni[] = {
+/// { .server_name = "api.example.com", .cert_mode = 0,
+/// .cert_path_ptr = "api.crt", .key_path_ptr = "api.key" },
+/// { .server_name = "*.example.org", .cert_mode = 0,
+/// .cert_path_ptr = "org.crt", .key_path_ptr = "org.key" },
+/// };
+/// config.sni_certs = sni;
+/// config.sni_cert_count = 2;
+/// ```
+final class QuicFfiSniCert extends ffi.Struct {
+  /// Server name, optionally with a leading `*.` wildcard label
+  external ffi.Pointer<ffi.Char> server_name;
+
+  /// Certificate mode: 0 = file, 1 = memory
+  @ffi.Uint32()
+  external int cert_mode;
+
+  /// Certificate file path (used when cert_mode = 0)
+  external ffi.Pointer<ffi.Char> cert_path_ptr;
+
+  /// Private key file path (used when cert_mode = 0)
+  external ffi.Pointer<ffi.Char> key_path_ptr;
+
+  /// Certificate DER data (used when cert_mode = 1)
+  external ffi.Pointer<ffi.Uint8> cert_der_ptr;
+
+  /// Certificate DER data length (bytes)
+  @ffi.Uint32()
+  external int cert_der_len;
+
+  /// Private key DER data (used when cert_mode = 1)
+  external ffi.Pointer<ffi.Uint8> key_der_ptr;
+
+  /// Private key DER data length (bytes)
+  @ffi.Uint32()
+  external int key_der_len;
 }
 
 /// FFI server configuration (for C API)
@@ -2297,28 +4963,134 @@ final class QuicFfiServerConfig extends ffi.Struct {
 
   /// Transport configuration (optional, null uses default)
   external ffi.Pointer<QuicFfiTransportConfig> transport;
+
+  /// Accept 0-RTT early data from resuming clients (enables session tickets)
+  @ffi.Bool()
+  external bool enable_0rtt;
+
+  /// TLS key log mode (for decrypting packet captures; default Disabled)
+  @QuicFfiKeyLogMode$1()
+  external int key_log_mode;
+
+  /// Key log file path (used when key_log_mode = File)
+  external ffi.Pointer<ffi.Char> key_log_path;
+
+  /// Per-server-name certificates (optional; enables SNI mode, in which the certificate
+  /// selected by `cert_mode` is the default for unknown names)
+  external ffi.Pointer<QuicFfiSniCert> sni_certs;
+
+  /// SNI certificate count
+  @ffi.Uint32()
+  external int sni_cert_count;
+
+  /// In SNI mode, fail handshakes for unknown names instead of using the default certificate
+  @ffi.Bool()
+  external bool sni_reject_unknown;
+}
+
+/// FFI endpoint handle
+///
+/// Returned by `dart_quic_endpoint_create`; carries the registry handle of the
+/// new endpoint. Free the structure with `dart_quic_endpoint_handle_free` and the
+/// endpoint with `dart_quic_endpoint_free`.
+final class QuicEndpointHandle extends ffi.Struct {
+  /// Endpoint registry handle (pass to all subsequent endpoint operations)
+  @ffi.Uint64()
+  external int endpoint;
+
+  /// Local bind port
+  @ffi.Uint16()
+  external int local_port;
+}
+
+/// Endpoint statistics snapshot (for C API)
+///
+/// Filled by `dart_quic_endpoint_stats` (and the server/client variants)
+/// from `quinn::Endpoint::stats()`. Handshake counters are cumulative.
+final class QuicFfiEndpointStats extends ffi.Struct {
+  /// Incoming handshakes accepted
+  @ffi.Uint64()
+  external int accepted_handshakes;
+
+  /// Outgoing handshakes initiated
+  @ffi.Uint64()
+  external int outgoing_handshakes;
+
+  /// Incoming handshakes refused (e.g. server config removed or connection limit reached)
+  @ffi.Uint64()
+  external int refused_handshakes;
+
+  /// Incoming handshakes ignored (dropped without response)
+  @ffi.Uint64()
+  external int ignored_handshakes;
+
+  /// Currently open connections
+  @ffi.Uint64()
+  external int open_connections;
+}
+
+/// FFI client handle
+///
+/// Returned by `dart_quic_client_new_async`; carries the registry handle of the
+/// new client. Free the structure with `dart_quic_client_handle_free` and the
+/// client with `dart_quic_client_free`.
+final class QuicClientHandle extends ffi.Struct {
+  /// Client registry handle (pass to all subsequent client operations)
+  @ffi.Uint64()
+  external int client;
+
+  /// Local bind port
+  @ffi.Uint16()
+  external int local_port;
+}
+
+/// TLS handshake results (for C API)
+///
+/// Strings are owned by this struct and null when absent.
+final class QuicFfiHandshakeData extends ffi.Struct {
+  /// Negotiated ALPN protocol bytes
+  external ffi.Pointer<ffi.Uint8> alpn;
+
+  /// ALPN protocol length
+  @ffi.Uint32()
+  external int alpn_len;
+
+  /// SNI server name (UTF-8, server side only)
+  external ffi.Pointer<ffi.Uint8> server_name;
+
+  /// Server name length
+  @ffi.Uint32()
+  external int server_name_len;
 }
 
 /// Connection handle (for C API)
 ///
-/// FFI-friendly structure that wraps a QuicConnection pointer along with
-/// commonly used connection information. This allows the Dart layer to
+/// FFI-friendly structure carrying the registry handle of a new connection along
+/// with commonly used connection information. This allows the Dart layer to
 /// directly access connection info without additional FFI calls.
 ///
 /// # Memory Management
-/// - `connection`: Owned pointer, must be freed via `dart_quic_connection_handle_free`
-/// - `remote_addr`: Owned string pointer, freed together with handle
+/// - `connection`: Registry handle, released via `dart_quic_connection_free`
+/// - `remote_addr`, `handshake`: Owned strings, freed together with this structure
+/// by `dart_quic_connection_handle_free`
+///
+/// The structure and the connection have independent lifetimes: the structure
+/// can be freed as soon as its fields have been read.
 ///
 /// # C API Usage
 /// ```c
 /// QuicConnectionHandle* handle = ...;
 /// printf("Connected to: %.*s\n", (int)handle->remote_addr_len, handle->remote_addr);
-/// // Use handle->connection for stream operations
+/// printf("ALPN: %.*s\n", (int)handle->handshake.alpn_len, handle->handshake.alpn);
+/// uint64_t connection = handle->connection;
 /// dart_quic_connection_handle_free(handle);
+/// // Use connection for stream operations, then
+/// dart_quic_connection_free(connection);
 /// ```
 final class QuicConnectionHandle extends ffi.Struct {
-  /// Connection pointer (for subsequent operations)
-  external ffi.Pointer<QuicConnection> connection;
+  /// Connection registry handle (for subsequent operations)
+  @ffi.Uint64()
+  external int connection;
 
   /// Connection stable ID (unique identifier)
   @ffi.Uint64()
@@ -2330,30 +5102,230 @@ final class QuicConnectionHandle extends ffi.Struct {
   /// Remote address string length
   @ffi.Uint32()
   external int remote_addr_len;
+
+  /// TLS handshake results at the time the handle was created
+  external QuicFfiHandshakeData handshake;
+}
+
+/// Connection close reason (for C API)
+///
+/// Delivered by `dart_quic_connection_on_closed`; free with `dart_quic_close_reason_free`.
+final class QuicFfiCloseReason extends ffi.Struct {
+  /// Close kind
+  @QuicCloseKind$1()
+  external int kind;
+
+  /// Whether the remote peer initiated the close
+  @ffi.Bool()
+  external bool remote;
+
+  /// Lossy `QuicResult` code for the close
+  @ffi.Int32()
+  external int result_code;
+
+  /// Transport or application error code (see `QuicCloseReason::error_code`)
+  @ffi.Uint64()
+  external int error_code;
+
+  /// Reason bytes (null if empty, owned by this struct)
+  external ffi.Pointer<ffi.Uint8> reason;
+
+  /// Reason length
+  @ffi.UintPtr()
+  external int reason_len;
+}
+
+/// Transmission path statistics (for C API)
+final class QuicFfiPathStats extends ffi.Struct {
+  /// Current RTT estimate (microseconds)
+  @ffi.Uint64()
+  external int rtt_us;
+
+  /// Minimum RTT observed, ignoring ack delay (microseconds)
+  @ffi.Uint64()
+  external int min_rtt_us;
+
+  /// Current congestion window (bytes)
+  @ffi.Uint64()
+  external int cwnd;
+
+  /// Number of congestion events
+  @ffi.Uint64()
+  external int congestion_events;
+
+  /// Number of packets lost
+  @ffi.Uint64()
+  external int lost_packets;
+
+  /// Number of bytes lost
+  @ffi.Uint64()
+  external int lost_bytes;
+
+  /// Number of packets sent
+  @ffi.Uint64()
+  external int sent_packets;
+
+  /// Number of PLPMTUD probes sent (also counted in `sent_packets`)
+  @ffi.Uint64()
+  external int sent_plpmtud_probes;
+
+  /// Number of PLPMTUD probes lost (not counted in `lost_packets`)
+  @ffi.Uint64()
+  external int lost_plpmtud_probes;
+
+  /// Number of black holes detected
+  @ffi.Uint64()
+  external int black_holes_detected;
+
+  /// Largest UDP payload size the path currently supports
+  @ffi.Uint16()
+  external int current_mtu;
+}
+
+/// Frame counters per frame type (for C API)
+final class QuicFfiFrameStats extends ffi.Struct {
+  @ffi.Uint64()
+  external int acks;
+
+  @ffi.Uint64()
+  external int ack_frequency;
+
+  @ffi.Uint64()
+  external int crypto;
+
+  @ffi.Uint64()
+  external int connection_close;
+
+  @ffi.Uint64()
+  external int data_blocked;
+
+  @ffi.Uint64()
+  external int datagram;
+
+  @ffi.Uint64()
+  external int handshake_done;
+
+  @ffi.Uint64()
+  external int immediate_ack;
+
+  @ffi.Uint64()
+  external int max_data;
+
+  @ffi.Uint64()
+  external int max_stream_data;
+
+  @ffi.Uint64()
+  external int max_streams_bidi;
+
+  @ffi.Uint64()
+  external int max_streams_uni;
+
+  @ffi.Uint64()
+  external int new_connection_id;
+
+  @ffi.Uint64()
+  external int new_token;
+
+  @ffi.Uint64()
+  external int path_challenge;
+
+  @ffi.Uint64()
+  external int path_response;
+
+  @ffi.Uint64()
+  external int ping;
+
+  @ffi.Uint64()
+  external int reset_stream;
+
+  @ffi.Uint64()
+  external int retire_connection_id;
+
+  @ffi.Uint64()
+  external int stream_data_blocked;
+
+  @ffi.Uint64()
+  external int streams_blocked_bidi;
+
+  @ffi.Uint64()
+  external int streams_blocked_uni;
+
+  @ffi.Uint64()
+  external int stop_sending;
+
+  @ffi.Uint64()
+  external int stream;
+}
+
+/// UDP traffic statistics (for C API)
+final class QuicFfiUdpStats extends ffi.Struct {
+  /// Number of UDP datagrams
+  @ffi.Uint64()
+  external int datagrams;
+
+  /// Total bytes carried inside UDP datagrams
+  @ffi.Uint64()
+  external int bytes;
+
+  /// Number of I/O operations (may be less than `datagrams` with GSO/GRO)
+  @ffi.Uint64()
+  external int ios;
+}
+
+/// Connection statistics snapshot (for C API)
+///
+/// Filled by `dart_quic_connection_stats` from `quinn::Connection::stats()`.
+/// The structure is plain data and can be allocated by the caller.
+///
+/// # C API Usage
+/// ```c
+/// QuicFfiConnectionStats stats;
+/// if (dart_quic_connection_stats(handle, &stats) == 0) {
+/// printf("rtt=%lluus lost=%llu\n", stats.path.rtt_us, stats.path.lost_packets);
+/// }
+/// ```
+final class QuicFfiConnectionStats extends ffi.Struct {
+  /// Path statistics (RTT, congestion window, loss)
+  external QuicFfiPathStats path;
+
+  /// Frames transmitted
+  external QuicFfiFrameStats frame_tx;
+
+  /// Frames received
+  external QuicFfiFrameStats frame_rx;
+
+  /// UDP datagrams transmitted
+  external QuicFfiUdpStats udp_tx;
+
+  /// UDP datagrams received
+  external QuicFfiUdpStats udp_rx;
 }
 
 /// FFI server handle
 ///
-/// FFI-friendly structure that wraps a QuicServer pointer along with
-/// commonly used server information. This allows the Dart layer to
+/// FFI-friendly structure carrying the registry handle of a new server along
+/// with commonly used server information. This allows the Dart layer to
 /// directly access server info without additional FFI calls.
 ///
 /// # Memory Management
 /// - Allocated and owned entirely by Rust via `Box::into_raw`
-/// - `server`: owned QuicServer pointer
+/// - `server`: registry handle, released via `dart_quic_server_free`
 /// - `local_addr_ptr`: owned string pointer, allocated via `crate::allocate`
-/// - Free the entire handle with `dart_quic_server_handle_free`
+/// - Free the structure with `dart_quic_server_handle_free` (does not free the server)
 ///
 /// # C API Usage
 /// ```c
 /// QuicServerHandle* handle = ...;
 /// printf("Listening on: %.*s\n", (int)handle->local_addr_len, handle->local_addr_ptr);
-/// // Use handle->server for server operations
+/// uint64_t server = handle->server;
 /// dart_quic_server_handle_free(handle);
+/// // Use server for server operations, then
+/// dart_quic_server_free(server);
 /// ```
 final class QuicServerHandle extends ffi.Struct {
-  /// Server pointer (pass to all subsequent server operations)
-  external ffi.Pointer<QuicServer> server;
+  /// Server registry handle (pass to all subsequent server operations)
+  @ffi.Uint64()
+  external int server;
 
   /// Local bind port
   @ffi.Uint16()
@@ -2367,6 +5339,15 @@ final class QuicServerHandle extends ffi.Struct {
   external ffi.Pointer<ffi.Uint8> local_addr_ptr;
 }
 
+typedef DartPostCObjectFnFunction =
+    ffi.Bool Function(ffi.Int64 port, ffi.Pointer<ffi.Void> message);
+typedef DartDartPostCObjectFnFunction =
+    bool Function(int port, ffi.Pointer<ffi.Void> message);
+
+/// `Dart_PostCObject` function pointer (pass `NativeApi.postCObject` from Dart)
+typedef DartPostCObjectFn =
+    ffi.Pointer<ffi.NativeFunction<DartPostCObjectFnFunction>>;
+
 const int _VCRT_COMPILER_PREPROCESSOR = 1;
 
 const int _SAL_VERSION = 20;
@@ -2682,3 +5663,9 @@ const int _MAX_FNAME = 256;
 const int _MAX_EXT = 256;
 
 const int _MAX_ENV = 32767;
+
+const int QUIC_ADDR_FAMILY_IPV4 = 4;
+
+const int QUIC_ADDR_FAMILY_IPV6 = 6;
+
+const int DEFAULT_SESSION_CACHE_CAPACITY = 256;
@@ -39,7 +39,8 @@ class QuicClientEndpoint {
 
   final QuicFFIBindings _bindings;
   final _arena = ffi.Arena();
-  Pointer<Void>? _clientPtr;
+  /// Registry handle of the native client (0 once disposed)
+  int _client;
   Pointer<QuicExecutor>? _executorPtr;
   bool _isDisposed = false;
 
@@ -47,12 +48,12 @@ class QuicClientEndpoint {
   QuicClientEndpoint._internal(
     this._bindings,
     this._executorPtr,
-    this._clientPtr,
+    this._client,
   ) {
     // Attach finalizer as safety net
     final resources = _QuicClientResources(
       _bindings,
-      _clientPtr,
+      _client,
       _executorPtr,
       _arena,
     );
@@ -78,9 +79,9 @@ class QuicClientEndpoint {
       await _initializeRuntime(bindings, executorPtr);
 
       // Step 2: Create the QUIC client asynchronously
-      final clientPtr = await _createClientAsync(bindings, executorPtr, config);
+      final client = await _createClientAsync(bindings, executorPtr, config);
 
-      return QuicClientEndpoint._internal(bindings, executorPtr, clientPtr);
+      return QuicClientEndpoint._internal(bindings, executorPtr, client);
     } catch (e) {
       // Cleanup on failure
       if (executorPtr != null) {
@@ -131,12 +132,13 @@ class QuicClientEndpoint {
     }
   }
 
-  static Future<Pointer<Void>> _createClientAsync(
+  /// Returns the registry handle of the new client.
+  static Future<int> _createClientAsync(
     QuicFFIBindings bindings,
     Pointer<QuicExecutor> executorPtr,
     QuicClientConfig config,
   ) async {
-    final completer = Completer<Pointer<Void>>();
+    final completer = Completer<int>();
     late final NativeCallable<UsizeCallbackFunction> callback;
 
     void onResult(
@@ -146,7 +148,10 @@ class QuicClientEndpoint {
       int errorLen,
     ) {
       if (success) {
-        completer.complete(Pointer<Void>.fromAddress(value));
+        final handlePtr = Pointer<QuicClientHandle>.fromAddress(value);
+        final client = handlePtr.ref.client;
+        bindings.dart_quic_client_handle_free(handlePtr);
+        completer.complete(client);
       } else {
         final errorMsg = errorLen > 0
             ? errorPtr.cast<ffi.Utf8>().toDartString(length: errorLen)
@@ -183,12 +188,16 @@ class QuicClientEndpoint {
   /// - [serverName]: Server name for SNI (TLS Server Name Indication).
   ///
   /// Returns a [Future] that completes with a [QuicConn] on success.
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
+  ///
   /// Throws [StateError] if the client is disposed or connection fails.
   ///
   /// See also [connectTo] for a convenience overload that accepts a plain string.
   Future<QuicConn> connect({
     required SocketAddress serverAddr,
     required String serverName,
+    Duration? timeout,
   }) async {
     _checkDisposed();
 
@@ -238,9 +247,10 @@ class QuicClientEndpoint {
 
     _bindings.dart_quic_client_connect(
       _executorPtr!,
-      _clientPtr!.cast(),
+      _client,
       serverAddrStr,
       serverNameStr,
+      timeout?.inMilliseconds ?? 0,
       nativeCallback.nativeFunction,
     );
 
@@ -255,9 +265,11 @@ class QuicClientEndpoint {
   Future<QuicConn> connectTo({
     required String serverAddr,
     required String serverName,
+    Duration? timeout,
   }) => connect(
     serverAddr: SocketAddress.parse(serverAddr),
     serverName: serverName,
+    timeout: timeout,
   );
 
   /// Close the client and all its connections.
@@ -268,7 +280,7 @@ class QuicClientEndpoint {
   ///
   /// Returns a [Future] that completes when the client is closed.
   Future<void> close({int errorCode = 0, String? reason}) async {
-    if (_isDisposed || _clientPtr == null) return;
+    if (_isDisposed) return;
 
     final completer = Completer<void>();
     late final NativeCallable<VoidCallbackFunction> nativeCallback;
@@ -294,7 +306,7 @@ class QuicClientEndpoint {
     }
 
     final resultCode = _bindings.dart_quic_client_close(
-      _clientPtr!.cast(),
+      _client,
       errorCode,
       reasonPtr,
       reasonLen,
@@ -306,7 +318,7 @@ class QuicClientEndpoint {
     // Wait for client to become idle
     _bindings.dart_quic_client_wait_idle(
       _executorPtr!,
-      _clientPtr!.cast(),
+      _client,
       nativeCallback.nativeFunction,
     );
 
@@ -324,10 +336,10 @@ class QuicClientEndpoint {
     // Prevent finalizer from running after explicit dispose
     _finalizer.detach(this);
 
-    // Free client pointer
-    if (_clientPtr != null) {
-      _bindings.dart_quic_client_free(_clientPtr!.cast());
-      _clientPtr = null;
+    // Release the client
+    if (_client != 0) {
+      _bindings.dart_quic_client_free(_client);
+      _client = 0;
     }
 
     // Free executor
@@ -353,20 +365,20 @@ class QuicClientEndpoint {
 /// Internal class to hold resources for finalizer.
 class _QuicClientResources {
   final QuicFFIBindings _bindings;
-  final Pointer<Void>? _clientPtr;
+  final int _client;
   final Pointer<QuicExecutor>? _executorPtr;
   final ffi.Arena _arena;
 
   _QuicClientResources(
     this._bindings,
-    this._clientPtr,
+    this._client,
     this._executorPtr,
     this._arena,
   );
 
   void cleanup() {
-    if (_clientPtr != null) {
-      _bindings.dart_quic_client_free(_clientPtr!.cast());
+    if (_client != 0) {
+      _bindings.dart_quic_client_free(_client);
     }
     if (_executorPtr != null) {
       _bindings.dart_quic_executor_free(_executorPtr!);
@@ -98,6 +98,8 @@ class QuicClientConfig {
     _config.ref.client_key_path = ffi.nullptr;
     _config.ref.transport_config = ffi.nullptr;
     _config.ref.bind_addr = ffi.nullptr;
+    _config.ref.key_log_mode = QuicFfiKeyLogMode.Disabled.value;
+    _config.ref.key_log_path = ffi.nullptr;
   }
 
   // ========== CA Certificate Configuration ==========
@@ -201,6 +203,26 @@ class QuicClientConfig {
     return this;
   }
 
+  // ========== Debugging ==========
+
+  /// Log TLS secrets so packet captures can be decrypted (e.g. in Wireshark)
+  ///
+  /// Appends to [path], or to the file named by the `SSLKEYLOGFILE`
+  /// environment variable if [path] is null.
+  ///
+  /// ⚠️ Anyone holding the key log can decrypt the logged connections.
+  QuicClientConfig setKeyLog([String? path]) {
+    if (path == null) {
+      _config.ref.key_log_mode = QuicFfiKeyLogMode.Env.value;
+    } else {
+      _config.ref.key_log_mode = QuicFfiKeyLogMode.File.value;
+      _config.ref.key_log_path = path
+          .toNativeUtf8(allocator: _arena)
+          .cast<ffi.Char>();
+    }
+    return this;
+  }
+
   // ========== Getters ==========
 
   /// Get the underlying FFI config pointer
@@ -1,5 +1,7 @@
 import 'dart:async';
+import 'dart:convert';
 import 'dart:ffi' as ffi;
+import 'dart:typed_data';
 
 import 'package:dart_quic/src/bindings/quic_ffi_bindings.dart';
 import 'package:dart_quic/src/common/quic_stream.dart';
@@ -41,21 +43,25 @@ class QuicConn {
 
   final QuicFFIBindings _bindings;
   final Arena _arena = Arena();
-  ffi.Pointer<QuicConnectionHandle>? _handlePtr;
+  /// Registry handle of the native connection (0 once disposed)
+  int _connection = 0;
   ffi.Pointer<QuicExecutor>? _executorPtr;
   late final int _stableId;
   late final String _remoteAddr;
+  late final Uint8List? _alpnProtocolBytes;
+  late final String? _serverName;
   bool _isDisposed = false;
 
   /// Internal constructor. Use [QuicClientEndpoint.connect] to create connections.
   ///
   /// The [executor] is shared from the parent endpoint and should not be freed by this class.
+  /// The [handle] structure is consumed: its fields are copied and it is freed here.
   QuicConn.fromHandle(
     this._bindings,
     ffi.Pointer<QuicConnectionHandle> handle,
     ffi.Pointer<QuicExecutor> executor,
   ) {
-    _handlePtr = handle;
+    _connection = handle.ref.connection;
     _executorPtr = executor;
     _stableId = handle.ref.stable_id;
 
@@ -66,8 +72,20 @@ class QuicConn {
       length: remoteAddrLen,
     );
 
-    // Attach finalizer as safety net (only for connection handle, not executor)
-    final resources = _QuicConnectionResources(_bindings, _handlePtr, _arena);
+    // Copy handshake results
+    final handshake = handle.ref.handshake;
+    _alpnProtocolBytes = handshake.alpn == ffi.nullptr
+        ? null
+        : Uint8List.fromList(handshake.alpn.asTypedList(handshake.alpn_len));
+    _serverName = handshake.server_name == ffi.nullptr
+        ? null
+        : handshake.server_name.cast<Utf8>().toDartString(
+            length: handshake.server_name_len,
+          );
+    _bindings.dart_quic_connection_handle_free(handle);
+
+    // Attach finalizer as safety net (only for the connection, not executor)
+    final resources = _QuicConnectionResources(_bindings, _connection, _arena);
     _finalizer.attach(this, resources, detach: this);
   }
 
@@ -77,13 +95,34 @@ class QuicConn {
   /// Get the remote address.
   String get remoteAddr => _remoteAddr;
 
+  /// Get the negotiated ALPN protocol, or null if ALPN was not used.
+  ///
+  /// Protocol ids are arbitrary bytes; invalid UTF-8 is replaced with U+FFFD.
+  /// Use [alpnProtocolBytes] for the exact id.
+  String? get alpnProtocol {
+    final bytes = _alpnProtocolBytes;
+    return bytes == null ? null : utf8.decode(bytes, allowMalformed: true);
+  }
+
+  /// Get the negotiated ALPN protocol id as raw bytes, or null if ALPN was not used.
+  Uint8List? get alpnProtocolBytes => _alpnProtocolBytes;
+
+  /// Get the server name (SNI) sent by the client.
+  ///
+  /// Only set for connections accepted by a server.
+  String? get serverName => _serverName;
+
   /// Open a bidirectional stream.
   ///
   /// Returns a [Future] that completes with a [QuicStream] on success.
   /// The stream supports both reading and writing ([canRead] and [canWrite] are true).
+  /// Streams with a higher [priority] are sent first on this connection.
+  ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
   ///
   /// Throws [StateError] if the connection is disposed or opening fails.
-  Future<QuicStream> openBiStream() async {
+  Future<QuicStream> openBiStream({int priority = 0, Duration? timeout}) async {
     _checkDisposed();
 
     final completer = Completer<QuicStream>();
@@ -121,7 +160,9 @@ class QuicConn {
 
     _bindings.dart_quic_connection_open_bi(
       _executorPtr!,
-      _handlePtr!,
+      _connection,
+      priority,
+      timeout?.inMilliseconds ?? 0,
       nativeCallback.nativeFunction,
     );
 
@@ -132,9 +173,13 @@ class QuicConn {
   ///
   /// Returns a [Future] that completes with a [QuicStream] on success.
   /// The stream only supports writing ([canWrite] is true, [canRead] is false).
+  /// Streams with a higher [priority] are sent first on this connection.
+  ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
   ///
   /// Throws [StateError] if the connection is disposed or opening fails.
-  Future<QuicStream> openUniStream() async {
+  Future<QuicStream> openUniStream({int priority = 0, Duration? timeout}) async {
     _checkDisposed();
 
     final completer = Completer<QuicStream>();
@@ -172,7 +217,9 @@ class QuicConn {
 
     _bindings.dart_quic_connection_open_uni(
       _executorPtr!,
-      _handlePtr!,
+      _connection,
+      priority,
+      timeout?.inMilliseconds ?? 0,
       nativeCallback.nativeFunction,
     );
 
@@ -184,8 +231,11 @@ class QuicConn {
   /// Returns a [Future] that completes with a [QuicStream] on success.
   /// The stream supports both reading and writing ([canRead] and [canWrite] are true).
   ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
+  ///
   /// Throws [StateError] if the connection is disposed or accept fails.
-  Future<QuicStream> acceptBiStream() async {
+  Future<QuicStream> acceptBiStream({Duration? timeout}) async {
     _checkDisposed();
 
     final completer = Completer<QuicStream>();
@@ -223,7 +273,8 @@ class QuicConn {
 
     _bindings.dart_quic_connection_accept_bi(
       _executorPtr!,
-      _handlePtr!,
+      _connection,
+      timeout?.inMilliseconds ?? 0,
       nativeCallback.nativeFunction,
     );
 
@@ -235,8 +286,11 @@ class QuicConn {
   /// Returns a [Future] that completes with a [QuicStream] on success.
   /// The stream only supports reading ([canRead] is true, [canWrite] is false).
   ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
+  ///
   /// Throws [StateError] if the connection is disposed or accept fails.
-  Future<QuicStream> acceptUniStream() async {
+  Future<QuicStream> acceptUniStream({Duration? timeout}) async {
     _checkDisposed();
 
     final completer = Completer<QuicStream>();
@@ -274,7 +328,8 @@ class QuicConn {
 
     _bindings.dart_quic_connection_accept_uni(
       _executorPtr!,
-      _handlePtr!,
+      _connection,
+      timeout?.inMilliseconds ?? 0,
       nativeCallback.nativeFunction,
     );
 
@@ -293,7 +348,7 @@ class QuicConn {
     _checkDisposed();
 
     final result = _bindings.dart_quic_connection_send_datagram(
-      _handlePtr!,
+      _connection,
       data,
       length,
     );
@@ -336,7 +391,7 @@ class QuicConn {
 
     _bindings.dart_quic_connection_read_datagram(
       _executorPtr!,
-      _handlePtr!,
+      _connection,
       nativeCallback.nativeFunction,
     );
 
@@ -349,7 +404,7 @@ class QuicConn {
   /// - [errorCode]: Application error code (default: 0)
   /// - [reason]: Close reason string (optional)
   void close({int errorCode = 0, String? reason}) {
-    if (_isDisposed || _handlePtr == null) return;
+    if (_isDisposed) return;
 
     final ffi.Pointer<ffi.Uint8> reasonPtr;
     final int reasonLen;
@@ -363,7 +418,7 @@ class QuicConn {
     }
 
     _bindings.dart_quic_connection_close(
-      _handlePtr!,
+      _connection,
       errorCode,
       reasonPtr,
       reasonLen,
@@ -383,10 +438,10 @@ class QuicConn {
     // Prevent finalizer from running after explicit dispose
     _finalizer.detach(this);
 
-    // Free connection handle
-    if (_handlePtr != null) {
-      _bindings.dart_quic_connection_handle_free(_handlePtr!);
-      _handlePtr = null;
+    // Release the connection
+    if (_connection != 0) {
+      _bindings.dart_quic_connection_free(_connection);
+      _connection = 0;
     }
 
     // Note: _executorPtr is shared from parent endpoint, do not free it here
@@ -416,14 +471,14 @@ class QuicConn {
 /// Note: The executor is shared from parent endpoint and is not managed here.
 class _QuicConnectionResources {
   final QuicFFIBindings _bindings;
-  final ffi.Pointer<QuicConnectionHandle>? _handlePtr;
+  final int _connection;
   final Arena _arena;
 
-  _QuicConnectionResources(this._bindings, this._handlePtr, this._arena);
+  _QuicConnectionResources(this._bindings, this._connection, this._arena);
 
   void cleanup() {
-    if (_handlePtr != null) {
-      _bindings.dart_quic_connection_handle_free(_handlePtr!);
+    if (_connection != 0) {
+      _bindings.dart_quic_connection_free(_connection);
     }
     // Note: executor is shared from parent, do not free it here
     _arena.releaseAll();
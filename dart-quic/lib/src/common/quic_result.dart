@@ -72,6 +72,9 @@ abstract final class QuicResultCode {
   /// Certificate error
   static const int certificateError = 110;
 
+  /// Host name resolution failed
+  static const int hostResolutionFailed = 111;
+
   // ===== Stream-related errors (200-299) =====
   /// Stream operation error
   static const int streamError = 200;
@@ -169,6 +172,7 @@ abstract final class QuicResultCode {
     handshakeFailed: 'Handshake failed',
     tlsError: 'TLS error',
     certificateError: 'Certificate error',
+    hostResolutionFailed: 'Host name resolution failed',
 
     // Stream-related
     streamError: 'Stream operation error',
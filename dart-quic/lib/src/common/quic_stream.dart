@@ -26,9 +26,9 @@ import 'package:ffi/ffi.dart';
 class QuicStream {
   final QuicFFIBindings _bindings;
   final ffi.Pointer<QuicExecutor> _executorPtr;
-  final ffi.Pointer<QuicFfiStreamPair> _pairPtr;
-  final ffi.Pointer<QuicFfiStreamHandle>? _sendHandle;
-  final ffi.Pointer<QuicFfiStreamHandle>? _recvHandle;
+  /// Registry handles of the native send / recv streams (null if not applicable)
+  final int? _sendStream;
+  final int? _recvStream;
   final QuicAllocator _allocator;
 
   bool _isDisposed = false;
@@ -40,21 +40,20 @@ class QuicStream {
   QuicStream._({
     required QuicFFIBindings bindings,
     required ffi.Pointer<QuicExecutor> executor,
-    required ffi.Pointer<QuicFfiStreamPair> pairPtr,
-    required ffi.Pointer<QuicFfiStreamHandle>? sendHandle,
-    required ffi.Pointer<QuicFfiStreamHandle>? recvHandle,
+    required int? sendStream,
+    required int? recvStream,
     required this.streamId,
     required QuicAllocator allocator,
   }) : _bindings = bindings,
        _executorPtr = executor,
-       _pairPtr = pairPtr,
-       _sendHandle = sendHandle,
-       _recvHandle = recvHandle,
+       _sendStream = sendStream,
+       _recvStream = recvStream,
        _allocator = allocator;
 
   /// Create a stream from a stream pair pointer.
   ///
   /// The [executor] is shared from the parent connection.
+  /// The [pairPtr] structure is consumed: its handles are copied and it is freed here.
   factory QuicStream.fromPair(
     QuicFFIBindings bindings,
     ffi.Pointer<QuicExecutor> executor,
@@ -67,6 +66,7 @@ class QuicStream {
     final hasRecv = recvHandle != ffi.nullptr;
 
     if (!hasSend && !hasRecv) {
+      bindings.dart_quic_stream_pair_free(pairPtr);
       throw StateError('Invalid stream pair: no handles');
     }
 
@@ -74,23 +74,25 @@ class QuicStream {
     final streamId = hasSend
         ? sendHandle.ref.stream_id
         : recvHandle.ref.stream_id;
+    final sendStream = hasSend ? sendHandle.ref.stream : null;
+    final recvStream = hasRecv ? recvHandle.ref.stream : null;
+    bindings.dart_quic_stream_pair_free(pairPtr);
 
     return QuicStream._(
       bindings: bindings,
       executor: executor,
-      pairPtr: pairPtr,
-      sendHandle: hasSend ? sendHandle : null,
-      recvHandle: hasRecv ? recvHandle : null,
+      sendStream: sendStream,
+      recvStream: recvStream,
       streamId: streamId,
       allocator: QuicAllocator(bindings),
     );
   }
 
   /// Whether this stream supports writing.
-  bool get canWrite => _sendHandle != null;
+  bool get canWrite => _sendStream != null;
 
   /// Whether this stream supports reading.
-  bool get canRead => _recvHandle != null;
+  bool get canRead => _recvStream != null;
 
   /// Whether this is a bidirectional stream.
   bool get isBidirectional => canRead && canWrite;
@@ -110,8 +112,11 @@ class QuicStream {
   /// Returns the number of bytes written.
   /// May write fewer bytes than requested due to flow control.
   ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
+  ///
   /// Throws [StateError] if stream does not support writing.
-  Future<int> write(Uint8List data) async {
+  Future<int> write(Uint8List data, {Duration? timeout}) async {
     _checkWritable();
 
     final completer = Completer<int>();
@@ -144,9 +149,10 @@ class QuicStream {
 
       _bindings.dart_quic_send_stream_write(
         _executorPtr,
-        _sendHandle!,
+        _sendStream!,
         dataPtr,
         data.length,
+        timeout?.inMilliseconds ?? 0,
         nativeCallback.nativeFunction,
       );
 
@@ -158,8 +164,11 @@ class QuicStream {
   ///
   /// Loops internally until all data is written.
   ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
+  ///
   /// Throws [StateError] if stream does not support writing.
-  Future<void> writeAll(Uint8List data) async {
+  Future<void> writeAll(Uint8List data, {Duration? timeout}) async {
     _checkWritable();
 
     final completer = Completer<void>();
@@ -187,9 +196,10 @@ class QuicStream {
 
       _bindings.dart_quic_send_stream_write_all(
         _executorPtr,
-        _sendHandle!,
+        _sendStream!,
         dataPtr,
         data.length,
+        timeout?.inMilliseconds ?? 0,
         nativeCallback.nativeFunction,
       );
 
@@ -202,8 +212,11 @@ class QuicStream {
   /// The string is encoded as UTF-8 bytes.
   /// Loops internally until all data is written.
   ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
+  ///
   /// Throws [StateError] if stream does not support writing.
-  Future<void> writeString(String str) async {
+  Future<void> writeString(String str, {Duration? timeout}) async {
     _checkWritable();
 
     final completer = Completer<void>();
@@ -231,9 +244,10 @@ class QuicStream {
 
     _bindings.dart_quic_send_stream_write_all(
       _executorPtr,
-      _sendHandle!,
+      _sendStream!,
       dataPtr.cast<ffi.Uint8>(),
       byteLen,
+      timeout?.inMilliseconds ?? 0,
       nativeCallback.nativeFunction,
     );
 
@@ -252,7 +266,7 @@ class QuicStream {
     }
 
     _isFinished = true;
-    final result = _bindings.dart_quic_send_stream_finish(_sendHandle!);
+    final result = _bindings.dart_quic_send_stream_finish(_sendStream!);
     if (result != 0) {
       throw StateError('Failed to finish stream: error code $result');
     }
@@ -267,8 +281,11 @@ class QuicStream {
   /// Returns the data read. May return fewer bytes than requested.
   /// Returns empty Uint8List if stream is finished.
   ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
+  ///
   /// Throws [StateError] if stream does not support reading.
-  Future<Uint8List> read(int maxLen) async {
+  Future<Uint8List> read(int maxLen, {Duration? timeout}) async {
     _checkReadable();
 
     final completer = Completer<Uint8List>();
@@ -305,8 +322,9 @@ class QuicStream {
 
     _bindings.dart_quic_recv_stream_read(
       _executorPtr,
-      _recvHandle!,
+      _recvStream!,
       maxLen,
+      timeout?.inMilliseconds ?? 0,
       nativeCallback.nativeFunction,
     );
 
@@ -315,10 +333,13 @@ class QuicStream {
 
   /// Read exactly [len] bytes from the stream.
   ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
+  ///
   /// Throws if the stream ends before [len] bytes are read.
   ///
   /// Throws [StateError] if stream does not support reading.
-  Future<Uint8List> readExact(int len) async {
+  Future<Uint8List> readExact(int len, {Duration? timeout}) async {
     _checkReadable();
 
     final completer = Completer<Uint8List>();
@@ -355,8 +376,9 @@ class QuicStream {
 
     _bindings.dart_quic_recv_stream_read_exact(
       _executorPtr,
-      _recvHandle!,
+      _recvStream!,
       len,
+      timeout?.inMilliseconds ?? 0,
       nativeCallback.nativeFunction,
     );
 
@@ -367,8 +389,14 @@ class QuicStream {
   ///
   /// Use [maxSize] to limit the maximum amount of data read.
   ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
+  ///
   /// Throws [StateError] if stream does not support reading.
-  Future<Uint8List> readToEnd({int maxSize = 10 * 1024 * 1024}) async {
+  Future<Uint8List> readToEnd({
+    int maxSize = 10 * 1024 * 1024,
+    Duration? timeout,
+  }) async {
     _checkReadable();
 
     final completer = Completer<Uint8List>();
@@ -405,8 +433,9 @@ class QuicStream {
 
     _bindings.dart_quic_recv_stream_read_to_end(
       _executorPtr,
-      _recvHandle!,
+      _recvStream!,
       maxSize,
+      timeout?.inMilliseconds ?? 0,
       nativeCallback.nativeFunction,
     );
 
@@ -424,8 +453,13 @@ class QuicStream {
     if (_isDisposed) return;
     _isDisposed = true;
 
-    // Free the stream pair (this also frees the handles)
-    _bindings.dart_quic_stream_pair_free(_pairPtr);
+    // Release the native streams
+    if (_sendStream != null) {
+      _bindings.dart_quic_stream_free(_sendStream!);
+    }
+    if (_recvStream != null) {
+      _bindings.dart_quic_stream_free(_recvStream!);
+    }
     // Free any remaining allocations (shouldn't have any in normal usage)
     _allocator.freeAll();
   }
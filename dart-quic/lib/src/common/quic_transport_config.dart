@@ -32,6 +32,7 @@ class QuicTransportConfig {
   }
 
   void _initializeDefaults() {
+    _config.ref.struct_size = ffi.sizeOf<QuicFfiTransportConfig>();
     _config.ref.max_idle_timeout_ms = 30000; // 30 seconds
     _config.ref.keep_alive_interval_ms = 0; // Disabled by default
     _config.ref.max_concurrent_bi_streams = 100;
@@ -47,6 +48,11 @@ class QuicTransportConfig {
     _config.ref.congestion_controller = 0; // Cubic (default)
     _config.ref.allow_spin = false;
     _config.ref.enable_gso = false;
+    _config.ref.send_fairness = true;
+    // Zero selects the native default for the remaining numeric fields
+    _config.ref.ack_eliciting_threshold = 1;
+    _config.ref.ack_reordering_threshold = 2;
+    _config.ref.alpn_protocols = ffi.nullptr; // Default: h3, hq-29
   }
 
   // ========== Timeout Configuration ==========
@@ -101,6 +107,20 @@ class QuicTransportConfig {
     return this;
   }
 
+  /// Set connection-level receive window size in bytes (0 = unlimited)
+  QuicTransportConfig setReceiveWindow(int bytes) {
+    _checkDisposed();
+    _config.ref.receive_window = bytes;
+    return this;
+  }
+
+  /// Enable or disable fair scheduling of stream data (default true)
+  QuicTransportConfig setSendFairness(bool enable) {
+    _checkDisposed();
+    _config.ref.send_fairness = enable;
+    return this;
+  }
+
   // ========== RTT Configuration ==========
 
   /// Set initial round-trip time estimate in milliseconds
@@ -110,6 +130,29 @@ class QuicTransportConfig {
     return this;
   }
 
+  // ========== Loss Detection ==========
+
+  /// Set packet reordering threshold for loss detection (default 3)
+  QuicTransportConfig setPacketThreshold(int packets) {
+    _checkDisposed();
+    _config.ref.packet_threshold = packets;
+    return this;
+  }
+
+  /// Set time threshold factor for loss detection, relative to RTT (default 9/8)
+  QuicTransportConfig setTimeThreshold(double factor) {
+    _checkDisposed();
+    _config.ref.time_threshold = factor;
+    return this;
+  }
+
+  /// Set number of PTOs before declaring persistent congestion (default 3)
+  QuicTransportConfig setPersistentCongestionThreshold(int ptos) {
+    _checkDisposed();
+    _config.ref.persistent_congestion_threshold = ptos;
+    return this;
+  }
+
   // ========== MTU Configuration ==========
 
   /// Set initial MTU (Maximum Transmission Unit) in bytes
@@ -133,6 +176,27 @@ class QuicTransportConfig {
     return this;
   }
 
+  /// Tune MTU discovery (enables it; omitted values keep their defaults)
+  ///
+  /// - [intervalMs]: time between discovery runs (default 600000)
+  /// - [upperBound]: largest MTU probed in bytes (default 1452)
+  /// - [minimumChange]: smallest MTU change worth probing (default 20)
+  /// - [blackHoleCooldownMs]: wait after a black hole before probing again (default 60000)
+  QuicTransportConfig setMtuDiscovery({
+    int? intervalMs,
+    int? upperBound,
+    int? minimumChange,
+    int? blackHoleCooldownMs,
+  }) {
+    _checkDisposed();
+    _config.ref.enable_mtu_discovery = true;
+    _config.ref.mtu_discovery_interval_ms = intervalMs ?? 0;
+    _config.ref.mtu_discovery_upper_bound = upperBound ?? 0;
+    _config.ref.mtu_discovery_minimum_change = minimumChange ?? 0;
+    _config.ref.mtu_black_hole_cooldown_ms = blackHoleCooldownMs ?? 0;
+    return this;
+  }
+
   // ========== Datagram Configuration ==========
 
   /// Set datagram receive buffer size in bytes
@@ -149,6 +213,49 @@ class QuicTransportConfig {
     return this;
   }
 
+  // ========== Protocol Configuration ==========
+
+  /// Set ALPN protocols, in preference order (default: h3, hq-29)
+  ///
+  /// An empty list disables ALPN.
+  QuicTransportConfig setAlpnProtocols(List<String> protocols) {
+    _checkDisposed();
+    final ptrs = _arena<ffi.Pointer<ffi.Char>>(
+      protocols.isEmpty ? 1 : protocols.length,
+    );
+    for (var i = 0; i < protocols.length; i++) {
+      ptrs[i] = protocols[i].toNativeUtf8(allocator: _arena).cast();
+    }
+    _config.ref.alpn_protocols = ptrs;
+    _config.ref.alpn_protocols_count = protocols.length;
+    return this;
+  }
+
+  /// Set crypto handshake buffer size in bytes (default 16 KB)
+  QuicTransportConfig setCryptoBufferSize(int bytes) {
+    _checkDisposed();
+    _config.ref.crypto_buffer_size = bytes;
+    return this;
+  }
+
+  /// Request ACK frequency from the peer (requires peer support for the extension)
+  ///
+  /// - [ackElicitingThreshold]: ack-eliciting packets received before an ACK is sent
+  /// - [maxAckDelayMs]: maximum ACK delay (null uses the peer's max_ack_delay)
+  /// - [reorderingThreshold]: out-of-order packets before an immediate ACK
+  QuicTransportConfig setAckFrequency({
+    int ackElicitingThreshold = 1,
+    int? maxAckDelayMs,
+    int reorderingThreshold = 2,
+  }) {
+    _checkDisposed();
+    _config.ref.enable_ack_frequency = true;
+    _config.ref.ack_eliciting_threshold = ackElicitingThreshold;
+    _config.ref.ack_max_delay_ms = maxAckDelayMs ?? 0;
+    _config.ref.ack_reordering_threshold = reorderingThreshold;
+    return this;
+  }
+
   // ========== Congestion Control ==========
 
   /// Set congestion controller algorithm
@@ -178,6 +285,19 @@ class QuicTransportConfig {
     return this;
   }
 
+  // ========== Debugging ==========
+
+  /// Write a qlog trace per connection into [dir] (null disables)
+  ///
+  /// Files are named `<odcid>_<client|server>.sqlog` and can be opened in qvis.
+  QuicTransportConfig setQlogDir(String? dir) {
+    _checkDisposed();
+    _config.ref.qlog_dir = dir == null
+        ? ffi.nullptr
+        : dir.toNativeUtf8(allocator: _arena).cast();
+    return this;
+  }
+
   // ========== Getters ==========
 
   /// Get the underlying FFI config pointer (internal use)
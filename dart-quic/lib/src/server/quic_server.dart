@@ -46,22 +46,32 @@ class QuicServerEndpoint {
 
   final QuicFFIBindings _bindings;
   Pointer<QuicServerHandle>? _handlePtr;
-  Pointer<QuicServer>? _serverPtr;
+
+  /// Registry handle of the native server (0 once disposed)
+  int _server;
   Pointer<QuicExecutor>? _executorPtr;
   final String _localAddr;
   final int _localPort;
   bool _isDisposed = false;
   bool _isClosed = false;
 
+  /// Operation ID of the running certificate watch (0 if none)
+  int _certWatchId = 0;
+
   QuicServerEndpoint._internal(
     this._bindings,
     this._executorPtr,
     this._handlePtr,
-    this._serverPtr,
+    this._server,
     this._localAddr,
     this._localPort,
   ) {
-    final resources = _QuicServerResources(_bindings, _handlePtr, _executorPtr);
+    final resources = _QuicServerResources(
+      _bindings,
+      _handlePtr,
+      _server,
+      _executorPtr,
+    );
     _finalizer.attach(this, resources, detach: this);
   }
 
@@ -82,14 +92,14 @@ class QuicServerEndpoint {
       await _initializeRuntime(bindings, executorPtr);
 
       // Step 2: Create server asynchronously inside the tokio runtime
-      final (handlePtr, serverPtr, localAddr, localPort) =
+      final (handlePtr, server, localAddr, localPort) =
           await _createServerAsync(bindings, executorPtr, config);
 
       return QuicServerEndpoint._internal(
         bindings,
         executorPtr,
         handlePtr,
-        serverPtr,
+        server,
         localAddr,
         localPort,
       );
@@ -144,11 +154,11 @@ class QuicServerEndpoint {
   ///
   /// Supports all certificate modes
   /// (self-signed, PEM files, DER memory) uniformly through [QuicFfiServerConfig].
-  /// Returns (handlePtr, serverPtr, localAddr, localPort).
+  /// Returns (handlePtr, server, localAddr, localPort).
   ///
   /// NOTE: The handle is NOT freed here. It is kept alive for the lifetime of
-  /// [QuicServerEndpoint] and freed together with the server in [dispose()].
-  static Future<(Pointer<QuicServerHandle>, Pointer<QuicServer>, String, int)>
+  /// [QuicServerEndpoint] and freed after the server in [dispose()].
+  static Future<(Pointer<QuicServerHandle>, int, String, int)>
   _createServerAsync(
     QuicFFIBindings bindings,
     Pointer<QuicExecutor> executorPtr,
@@ -192,9 +202,9 @@ class QuicServerEndpoint {
 
     final handlePtr = await completer.future;
 
-    // Extract server pointer, local address and port from handle for caching.
-    // The handle (and server) remain alive until dispose().
-    final serverPtr = handlePtr.ref.server;
+    // Extract server handle, local address and port from handle for caching.
+    // The handle structure and the server remain alive until dispose().
+    final server = handlePtr.ref.server;
     final localPort = handlePtr.ref.local_port;
     final addrLen = handlePtr.ref.local_addr_len;
     final String localAddr;
@@ -206,7 +216,7 @@ class QuicServerEndpoint {
       localAddr = config.bindAddr;
     }
 
-    return (handlePtr, serverPtr, localAddr, localPort);
+    return (handlePtr, server, localAddr, localPort);
   }
 
   /// Accept an incoming connection.
@@ -223,10 +233,107 @@ class QuicServerEndpoint {
   /// }
   /// ```
   ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`.
+  ///
+  /// Throws [StateError] if the server is disposed.
+  Future<QuicConn?> accept({Duration? timeout}) {
+    _checkDisposed();
+
+    return _acceptWith(
+      (callback) => _bindings.dart_quic_server_accept(
+        _executorPtr!,
+        _server,
+        timeout?.inMilliseconds ?? 0,
+        callback,
+      ),
+    );
+  }
+
+  /// Register a connection queue for the ALPN [protocol].
+  ///
+  /// Connections that negotiated [protocol] are then accepted with
+  /// [acceptAlpn] instead of [accept]. A `null` [protocol] registers the
+  /// fallback queue, which receives connections whose protocol has no queue
+  /// of its own. Connections matching no queue (and no fallback) are closed.
+  ///
+  /// Running two protocols on one port:
+  /// ```dart
+  /// server.routeAlpn('h3');
+  /// server.routeAlpn('my-proto');
+  ///
+  /// unawaited(serveH3(server));       // loops on server.acceptAlpn('h3')
+  /// unawaited(serveMyProto(server));  // loops on server.acceptAlpn('my-proto')
+  /// ```
+  ///
+  /// Throws [StateError] if the server is disposed.
+  void routeAlpn(String? protocol) {
+    _checkDisposed();
+
+    final arena = ffi.Arena();
+    try {
+      final (alpnPtr, alpnLen) = _alpnArg(protocol, arena);
+      final result = _bindings.dart_quic_server_route_alpn(
+        _server,
+        alpnPtr,
+        alpnLen,
+      );
+      if (result.isFailure) {
+        throw StateError(
+          'Failed to register ALPN route: ${result.errorMessage}',
+        );
+      }
+    } finally {
+      arena.releaseAll();
+    }
+  }
+
+  /// Accept the next connection routed to the queue of the ALPN [protocol].
+  ///
+  /// The queue must have been registered with [routeAlpn]; `null` accepts
+  /// from the fallback queue. Completes with `null` if the server has been
+  /// closed, like [accept].
+  ///
+  /// Connections are routed in the background from the first [routeAlpn]
+  /// call on; each queue holds up to 64 connections, and further ones are
+  /// closed until it is accepted from.
+  ///
+  /// If [timeout] elapses first, the operation is abandoned and fails with a
+  /// [StateError] whose message contains `[Timeout]`. Connections routed to
+  /// other queues are not affected.
+  ///
   /// Throws [StateError] if the server is disposed.
-  Future<QuicConn?> accept() async {
+  Future<QuicConn?> acceptAlpn(String? protocol, {Duration? timeout}) {
     _checkDisposed();
 
+    return _acceptWith((callback) {
+      // The protocol is copied before the call returns
+      final arena = ffi.Arena();
+      try {
+        final (alpnPtr, alpnLen) = _alpnArg(protocol, arena);
+        _bindings.dart_quic_server_accept_alpn(
+          _executorPtr!,
+          _server,
+          alpnPtr,
+          alpnLen,
+          timeout?.inMilliseconds ?? 0,
+          callback,
+        );
+      } finally {
+        arena.releaseAll();
+      }
+    });
+  }
+
+  /// UTF-8 bytes of an ALPN protocol (null pointer for the fallback queue)
+  static (Pointer<Uint8>, int) _alpnArg(String? protocol, ffi.Arena arena) {
+    if (protocol == null) return (nullptr, 0);
+    final utf8Ptr = protocol.toNativeUtf8(allocator: arena);
+    return (utf8Ptr.cast<Uint8>(), utf8Ptr.length);
+  }
+
+  /// Run an accept operation started by [start] and wrap its result
+  Future<QuicConn?> _acceptWith(void Function(UsizeCallback callback) start) {
     final completer = Completer<QuicConn?>();
     late final NativeCallable<UsizeCallbackFunction> nativeCallback;
 
@@ -269,15 +376,118 @@ class QuicServerEndpoint {
 
     nativeCallback = NativeCallable<UsizeCallbackFunction>.listener(onResult);
 
-    _bindings.dart_quic_server_accept(
-      _executorPtr!,
-      _serverPtr!,
-      nativeCallback.nativeFunction,
-    );
+    start(nativeCallback.nativeFunction);
+
+    return completer.future;
+  }
+
+  /// Replace the server certificate without restarting the server.
+  ///
+  /// Builds a new TLS configuration from [config] (certificate, SNI
+  /// certificates, client authentication and transport settings) and applies
+  /// it to new connections. Established connections keep their certificate.
+  /// The bind address of [config] is ignored.
+  ///
+  /// The [config] will be disposed automatically.
+  /// Throws [StateError] if the configuration cannot be built; the current
+  /// certificate then stays in use.
+  void reloadConfig(QuicServerConfig config) {
+    _checkDisposed();
+
+    final result = ffi.calloc<QuicFfiResult>();
+    try {
+      final code = _bindings.dart_quic_server_set_config(
+        _server,
+        config.ffiConfig,
+        result,
+      );
+      if (code.isFailure) {
+        final msg = result.ref.error_msg_len > 0
+            ? result.ref.error_msg.cast<ffi.Utf8>().toDartString(
+                length: result.ref.error_msg_len,
+              )
+            : code.errorMessage;
+        throw StateError('Failed to reload server config: $msg');
+      }
+    } finally {
+      _bindings.dart_quic_ffi_result_free_error(result);
+      ffi.calloc.free(result);
+      config.dispose();
+    }
+  }
+
+  /// Reload the server certificate whenever its PEM files change.
+  ///
+  /// Checks the certificate and key files of [config] (the PEM file
+  /// certificate and file-based SNI certificates) every [interval]
+  /// (default: 5 seconds) and applies the rebuilt configuration like
+  /// [reloadConfig]. A failed rebuild (e.g. the key has not been rewritten
+  /// yet) keeps the current certificate and is retried on the next change.
+  ///
+  /// Returns a [Future] that completes when the watch ends, i.e. after
+  /// [stopWatchingCertificates] or [dispose]. It fails with a [StateError]
+  /// if [config] is invalid or has no certificate files.
+  ///
+  /// The [config] will be disposed automatically.
+  /// Throws [StateError] if the server is disposed or a watch is already
+  /// running.
+  Future<void> watchCertificates(
+    QuicServerConfig config, {
+    Duration? interval,
+  }) {
+    _checkDisposed();
+    if (_certWatchId != 0) {
+      config.dispose();
+      throw StateError('Certificate watch is already running');
+    }
+
+    final completer = Completer<void>();
+    late final NativeCallable<VoidCallbackFunction> nativeCallback;
+    var watchId = 0;
+
+    void onResult(bool success, Pointer<Uint8> errorPtr, int errorLen) {
+      if (_certWatchId == watchId) _certWatchId = 0;
+      final msg = errorLen > 0
+          ? errorPtr.cast<ffi.Utf8>().toDartString(length: errorLen)
+          : 'Unknown error';
+      if (success || msg.startsWith('[Cancelled]')) {
+        completer.complete();
+      } else {
+        completer.completeError(
+          StateError('Certificate watch failed: $msg'),
+        );
+      }
+      nativeCallback.close();
+    }
+
+    nativeCallback = NativeCallable<VoidCallbackFunction>.listener(onResult);
+
+    try {
+      watchId = _bindings.dart_quic_server_watch_certs(
+        _executorPtr!,
+        _server,
+        config.ffiConfig,
+        interval?.inMilliseconds ?? 0,
+        nativeCallback.nativeFunction,
+      );
+      _certWatchId = watchId;
+    } finally {
+      // The native side copies the configuration
+      config.dispose();
+    }
 
     return completer.future;
   }
 
+  /// Stop the watch started by [watchCertificates].
+  ///
+  /// Does nothing if no watch is running.
+  void stopWatchingCertificates() {
+    if (_isDisposed || _certWatchId == 0) return;
+    _bindings.dart_quic_operation_cancel(_executorPtr!, _certWatchId);
+    _certWatchId = 0;
+  }
+
   /// Wait for all active connections to close (idle state).
   ///
   /// Typically called after [close] for a graceful shutdown sequence:
@@ -310,7 +520,7 @@ class QuicServerEndpoint {
 
     _bindings.dart_quic_server_wait_idle(
       _executorPtr!,
-      _serverPtr!,
+      _server,
       nativeCallback.nativeFunction,
     );
 
@@ -325,7 +535,7 @@ class QuicServerEndpoint {
   ///
   /// After closing, [accept] will return `null`.
   void close({int errorCode = 0, String? reason}) {
-    if (_isDisposed || _serverPtr == null || _isClosed) return;
+    if (_isDisposed || _isClosed) return;
     _isClosed = true;
 
     Pointer<Uint8> reasonPtr = nullptr;
@@ -349,7 +559,7 @@ class QuicServerEndpoint {
     }
 
     _bindings.dart_quic_server_close(
-      _serverPtr!,
+      _server,
       errorCode,
       reasonPtr,
       reasonLen,
@@ -370,11 +580,20 @@ class QuicServerEndpoint {
 
     _finalizer.detach(this);
 
-    // Free server + handle together — dart_quic_server_handle_free owns both.
+    // Cancel the certificate watch while the executor is still alive
+    if (_certWatchId != 0) {
+      _bindings.dart_quic_operation_cancel(_executorPtr!, _certWatchId);
+      _certWatchId = 0;
+    }
+
+    // Release the server, then the handle structure describing it.
+    if (_server != 0) {
+      _bindings.dart_quic_server_free(_server);
+      _server = 0;
+    }
     if (_handlePtr != null) {
       _bindings.dart_quic_server_handle_free(_handlePtr!);
       _handlePtr = null;
-      _serverPtr = null;
     }
 
     if (_executorPtr != null) {
@@ -397,8 +616,8 @@ class QuicServerEndpoint {
 
   /// The number of currently open connections
   int get openConnections {
-    if (_isDisposed || _serverPtr == null) return 0;
-    return _bindings.dart_quic_server_open_connections(_serverPtr!);
+    if (_isDisposed) return 0;
+    return _bindings.dart_quic_server_open_connections(_server);
   }
 
   /// Whether the server has been closed
@@ -415,11 +634,20 @@ class QuicServerEndpoint {
 class _QuicServerResources {
   final QuicFFIBindings _bindings;
   final Pointer<QuicServerHandle>? _handlePtr;
+  final int _server;
   final Pointer<QuicExecutor>? _executorPtr;
 
-  _QuicServerResources(this._bindings, this._handlePtr, this._executorPtr);
+  _QuicServerResources(
+    this._bindings,
+    this._handlePtr,
+    this._server,
+    this._executorPtr,
+  );
 
   void cleanup() {
+    if (_server != 0) {
+      _bindings.dart_quic_server_free(_server);
+    }
     if (_handlePtr != null) {
       _bindings.dart_quic_server_handle_free(_handlePtr!);
     }
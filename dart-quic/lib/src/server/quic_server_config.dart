@@ -60,6 +60,15 @@ enum QuicClientAuthMode {
 ///   certDer: certBytes,
 ///   keyDer: keyBytes,
 /// );
+///
+/// // Several domains: per-name certificates, the one above as default
+/// final config = QuicServerConfig.withCertFiles(
+///   bindAddr: '0.0.0.0:4433',
+///   certPath: '/path/to/default.pem',
+///   keyPath: '/path/to/default.key',
+/// )
+///   ..addSniCertFiles('api.example.com', '/path/to/api.pem', '/path/to/api.key')
+///   ..addSniCertFiles('*.example.org', '/path/to/org.pem', '/path/to/org.key');
 /// ```
 class QuicServerConfig {
   static final Finalizer<Arena> _arenaFinalizer = Finalizer<Arena>((arena) {
@@ -84,6 +93,9 @@ class QuicServerConfig {
   // For convenience when calling the correct FFI function
   final QuicServerCertMode _certMode;
 
+  // Per-server-name certificates, rebuilt into one native array on change
+  final List<void Function(QuicFfiSniCert entry)> _sniCerts = [];
+
   QuicServerConfig._internal({
     required String bindAddr,
     required QuicServerCertMode certMode,
@@ -217,6 +229,11 @@ class QuicServerConfig {
     _config.ref.client_ca_ptr = ffi.nullptr;
     _config.ref.client_ca_len = 0;
     _config.ref.transport = ffi.nullptr;
+    _config.ref.key_log_mode = QuicFfiKeyLogMode.Disabled.value;
+    _config.ref.key_log_path = ffi.nullptr;
+    _config.ref.sni_certs = ffi.nullptr;
+    _config.ref.sni_cert_count = 0;
+    _config.ref.sni_reject_unknown = false;
   }
 
   void _applyConfig({
@@ -281,6 +298,95 @@ class QuicServerConfig {
     }
   }
 
+  // ========== SNI Certificates ==========
+
+  /// Serve the PEM certificate files to clients requesting [serverName]
+  ///
+  /// Enables SNI mode: the certificate is chosen by the server name the
+  /// client requests, and the certificate this config was created with
+  /// becomes the default for unknown names. [serverName] may be a wildcard
+  /// such as `*.example.com`, matching exactly one extra label; exact names
+  /// take precedence over wildcards.
+  QuicServerConfig addSniCertFiles(
+    String serverName,
+    String certPath,
+    String keyPath,
+  ) {
+    final namePtr = serverName.toNativeUtf8(allocator: _arena);
+    final certPathPtr = certPath.toNativeUtf8(allocator: _arena);
+    final keyPathPtr = keyPath.toNativeUtf8(allocator: _arena);
+    _sniCerts.add((entry) {
+      entry.server_name = namePtr.cast<ffi.Char>();
+      entry.cert_mode = QuicServerCertMode.file.value;
+      entry.cert_path_ptr = certPathPtr.cast<ffi.Char>();
+      entry.key_path_ptr = keyPathPtr.cast<ffi.Char>();
+    });
+    _syncSniCerts();
+    return this;
+  }
+
+  /// Serve the DER certificate and key to clients requesting [serverName]
+  ///
+  /// See [addSniCertFiles].
+  QuicServerConfig addSniCertDer(
+    String serverName,
+    Uint8List certDer,
+    Uint8List keyDer,
+  ) {
+    final namePtr = serverName.toNativeUtf8(allocator: _arena);
+    final certPtr = _arena<ffi.Uint8>(certDer.length);
+    certPtr.asTypedList(certDer.length).setRange(0, certDer.length, certDer);
+    final keyPtr = _arena<ffi.Uint8>(keyDer.length);
+    keyPtr.asTypedList(keyDer.length).setRange(0, keyDer.length, keyDer);
+    _sniCerts.add((entry) {
+      entry.server_name = namePtr.cast<ffi.Char>();
+      entry.cert_mode = QuicServerCertMode.memory.value;
+      entry.cert_der_ptr = certPtr;
+      entry.cert_der_len = certDer.length;
+      entry.key_der_ptr = keyPtr;
+      entry.key_der_len = keyDer.length;
+    });
+    _syncSniCerts();
+    return this;
+  }
+
+  /// In SNI mode, fail handshakes for unknown or missing server names
+  /// instead of serving the default certificate (default false)
+  QuicServerConfig setSniRejectUnknown(bool reject) {
+    _config.ref.sni_reject_unknown = reject;
+    return this;
+  }
+
+  void _syncSniCerts() {
+    // Arena memory is zeroed, so unused fields stay null
+    final entries = _arena<QuicFfiSniCert>(_sniCerts.length);
+    for (var i = 0; i < _sniCerts.length; i++) {
+      _sniCerts[i](entries[i]);
+    }
+    _config.ref.sni_certs = entries;
+    _config.ref.sni_cert_count = _sniCerts.length;
+  }
+
+  // ========== Debugging ==========
+
+  /// Log TLS secrets so packet captures can be decrypted (e.g. in Wireshark)
+  ///
+  /// Appends to [path], or to the file named by the `SSLKEYLOGFILE`
+  /// environment variable if [path] is null.
+  ///
+  /// ⚠️ Anyone holding the key log can decrypt the logged connections.
+  QuicServerConfig setKeyLog([String? path]) {
+    if (path == null) {
+      _config.ref.key_log_mode = QuicFfiKeyLogMode.Env.value;
+    } else {
+      _config.ref.key_log_mode = QuicFfiKeyLogMode.File.value;
+      _config.ref.key_log_path = path
+          .toNativeUtf8(allocator: _arena)
+          .cast<ffi.Char>();
+    }
+    return this;
+  }
+
   // ========== Getters ==========
 
   /// Local bind address